@@ -0,0 +1,299 @@
+//! Serializable documents of the structure of a guild.
+
+use serde::{Deserialize, Serialize};
+use twilight_model::{
+    channel::{permission_overwrite::PermissionOverwrite, ChannelType, GuildChannel},
+    guild::{
+        DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, Guild, Permissions, Role,
+        VerificationLevel,
+    },
+    id::{ChannelId, EmojiId, GuildId, RoleId},
+};
+
+/// Backup of the structure of a guild.
+///
+/// Create one with [`export`], and re-apply it to a guild with [`Restore`].
+///
+/// Roles are sorted by position and channels are sorted so that categories
+/// come before the channels in them. The IDs are those of the guild that the
+/// backup was created from, and are remapped when restoring.
+///
+/// [`Restore`]: crate::Restore
+/// [`export`]: crate::export
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GuildBackup {
+    /// Channels of the guild, including categories.
+    pub channels: Vec<ChannelBackup>,
+    /// Custom emojis of the guild.
+    pub emojis: Vec<EmojiBackup>,
+    /// ID of the guild that the backup was created from.
+    pub guild_id: GuildId,
+    /// Roles of the guild, including the `@everyone` role.
+    pub roles: Vec<RoleBackup>,
+    /// Settings of the guild.
+    pub settings: SettingsBackup,
+    /// Version of the format of the document.
+    pub version: u64,
+}
+
+impl GuildBackup {
+    /// Current version of the format of documents.
+    pub const VERSION: u64 = 1;
+
+    /// Create a backup from a guild and its channels.
+    pub fn new(guild: &Guild, channels: &[GuildChannel]) -> Self {
+        let mut roles = guild.roles.iter().map(RoleBackup::from).collect::<Vec<_>>();
+        roles.sort_by_key(|role| (role.position, role.id));
+
+        // Threads are transient and can't be recreated without their
+        // messages, so they aren't part of the structure.
+        let mut channels = channels
+            .iter()
+            .filter(|channel| !channel.kind().is_thread())
+            .map(ChannelBackup::from)
+            .collect::<Vec<_>>();
+        channels.sort_by_key(|channel| {
+            (
+                channel.kind != ChannelType::GuildCategory,
+                channel.position,
+                channel.id,
+            )
+        });
+
+        Self {
+            channels,
+            emojis: guild.emojis.iter().map(EmojiBackup::from).collect(),
+            guild_id: guild.id,
+            roles,
+            settings: SettingsBackup::from(guild),
+            version: Self::VERSION,
+        }
+    }
+
+    /// Return the `@everyone` role, which has the ID of the guild.
+    pub fn everyone_role(&self) -> Option<&RoleBackup> {
+        self.roles.iter().find(|role| role.id.0 == self.guild_id.0)
+    }
+}
+
+/// Backup of a channel or category.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ChannelBackup {
+    /// Bitrate of a voice channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bitrate: Option<u64>,
+    pub id: ChannelId,
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    pub name: String,
+    #[serde(default)]
+    pub nsfw: bool,
+    /// ID of the category that the channel is in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<ChannelId>,
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+    pub position: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    /// User limit of a voice channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_limit: Option<u64>,
+}
+
+impl From<&GuildChannel> for ChannelBackup {
+    fn from(channel: &GuildChannel) -> Self {
+        match channel {
+            GuildChannel::Category(category) => Self {
+                bitrate: None,
+                id: category.id,
+                kind: category.kind,
+                name: category.name.clone(),
+                nsfw: false,
+                parent_id: None,
+                permission_overwrites: category.permission_overwrites.clone(),
+                position: category.position,
+                rate_limit_per_user: None,
+                topic: None,
+                user_limit: None,
+            },
+            GuildChannel::Text(text) => Self {
+                bitrate: None,
+                id: text.id,
+                kind: text.kind,
+                name: text.name.clone(),
+                nsfw: text.nsfw,
+                parent_id: text.parent_id,
+                permission_overwrites: text.permission_overwrites.clone(),
+                position: text.position,
+                rate_limit_per_user: text.rate_limit_per_user,
+                topic: text.topic.clone(),
+                user_limit: None,
+            },
+            GuildChannel::Thread(thread) => Self {
+                bitrate: None,
+                id: thread.id,
+                kind: thread.kind,
+                name: thread.name.clone(),
+                nsfw: false,
+                parent_id: thread.parent_id,
+                permission_overwrites: Vec::new(),
+                position: 0,
+                rate_limit_per_user: thread.rate_limit_per_user,
+                topic: None,
+                user_limit: None,
+            },
+            GuildChannel::Stage(voice) | GuildChannel::Voice(voice) => Self {
+                bitrate: Some(voice.bitrate),
+                id: voice.id,
+                kind: voice.kind,
+                name: voice.name.clone(),
+                nsfw: false,
+                parent_id: voice.parent_id,
+                permission_overwrites: voice.permission_overwrites.clone(),
+                position: voice.position,
+                rate_limit_per_user: None,
+                topic: None,
+                user_limit: voice.user_limit,
+            },
+        }
+    }
+}
+
+/// Backup of a custom emoji.
+///
+/// Emoji images aren't available through the API, so they aren't included
+/// when exporting. Set [`image`] to a data URI of the image downloaded from
+/// [`url`] for the emoji to be created when restoring.
+///
+/// [`image`]: Self::image
+/// [`url`]: Self::url
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct EmojiBackup {
+    #[serde(default)]
+    pub animated: bool,
+    pub id: EmojiId,
+    /// Image of the emoji as a data URI, in the form of
+    /// `data:image/{type};base64,{data}`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    pub name: String,
+    /// Roles that the emoji is limited to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<RoleId>,
+}
+
+impl EmojiBackup {
+    /// Return the CDN URL of the image of the emoji.
+    pub fn url(&self) -> String {
+        let extension = if self.animated { "gif" } else { "png" };
+
+        format!(
+            "https://cdn.discordapp.com/emojis/{}.{}",
+            self.id, extension
+        )
+    }
+}
+
+impl From<&Emoji> for EmojiBackup {
+    fn from(emoji: &Emoji) -> Self {
+        Self {
+            animated: emoji.animated,
+            id: emoji.id,
+            image: None,
+            name: emoji.name.clone(),
+            roles: emoji.roles.clone(),
+        }
+    }
+}
+
+/// Backup of a role.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RoleBackup {
+    pub color: u32,
+    pub hoist: bool,
+    pub id: RoleId,
+    /// Whether the role is managed by an integration, in which case it can't
+    /// be created.
+    pub managed: bool,
+    pub mentionable: bool,
+    pub name: String,
+    pub permissions: Permissions,
+    pub position: i64,
+}
+
+impl From<&Role> for RoleBackup {
+    fn from(role: &Role) -> Self {
+        Self {
+            color: role.color,
+            hoist: role.hoist,
+            id: role.id,
+            managed: role.managed,
+            mentionable: role.mentionable,
+            name: role.name.clone(),
+            permissions: role.permissions,
+            position: role.position,
+        }
+    }
+}
+
+/// Backup of the settings of a guild.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SettingsBackup {
+    pub afk_channel_id: Option<ChannelId>,
+    pub afk_timeout: u64,
+    pub default_message_notifications: DefaultMessageNotificationLevel,
+    pub explicit_content_filter: ExplicitContentFilter,
+    pub name: String,
+    pub preferred_locale: String,
+    pub region: String,
+    pub system_channel_id: Option<ChannelId>,
+    pub verification_level: VerificationLevel,
+}
+
+impl From<&Guild> for SettingsBackup {
+    fn from(guild: &Guild) -> Self {
+        Self {
+            afk_channel_id: guild.afk_channel_id,
+            afk_timeout: guild.afk_timeout,
+            default_message_notifications: guild.default_message_notifications,
+            explicit_content_filter: guild.explicit_content_filter,
+            name: guild.name.clone(),
+            preferred_locale: guild.preferred_locale.clone(),
+            region: guild.region.clone(),
+            system_channel_id: guild.system_channel_id,
+            verification_level: guild.verification_level,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChannelBackup, EmojiBackup, GuildBackup, RoleBackup, SettingsBackup};
+    use serde::{de::DeserializeOwned, Serialize};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::id::EmojiId;
+
+    assert_impl_all!(ChannelBackup: Clone, Debug, DeserializeOwned, Send, Serialize, Sync);
+    assert_impl_all!(EmojiBackup: Clone, Debug, DeserializeOwned, Send, Serialize, Sync);
+    assert_impl_all!(GuildBackup: Clone, Debug, DeserializeOwned, Send, Serialize, Sync);
+    assert_impl_all!(RoleBackup: Clone, Debug, DeserializeOwned, Send, Serialize, Sync);
+    assert_impl_all!(SettingsBackup: Clone, Debug, DeserializeOwned, Send, Serialize, Sync);
+
+    #[test]
+    fn test_emoji_url() {
+        let mut emoji = EmojiBackup {
+            animated: false,
+            id: EmojiId(1),
+            image: None,
+            name: "twilight".to_owned(),
+            roles: Vec::new(),
+        };
+        assert_eq!("https://cdn.discordapp.com/emojis/1.png", emoji.url());
+
+        emoji.animated = true;
+        assert_eq!("https://cdn.discordapp.com/emojis/1.gif", emoji.url());
+    }
+}
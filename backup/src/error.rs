@@ -0,0 +1,116 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_http::Error as HttpError;
+use twilight_model::id::GuildId;
+
+/// Exporting a guild failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ExportError {
+    /// The guild wasn't found.
+    GuildNotFound {
+        /// ID of the guild.
+        guild_id: GuildId,
+    },
+    /// Sending a request or reading its response failed.
+    Http {
+        /// Reason for the error.
+        source: HttpError,
+    },
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::GuildNotFound { guild_id } => write!(f, "guild {} wasn't found", guild_id),
+            Self::Http { .. } => f.write_str("sending a request failed"),
+        }
+    }
+}
+
+impl Error for ExportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::GuildNotFound { .. } => None,
+            Self::Http { source } => Some(source),
+        }
+    }
+}
+
+impl From<HttpError> for ExportError {
+    fn from(source: HttpError) -> Self {
+        Self::Http { source }
+    }
+}
+
+/// Planning or applying a restore failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RestoreError {
+    /// The guild wasn't found.
+    GuildNotFound {
+        /// ID of the guild.
+        guild_id: GuildId,
+    },
+    /// Sending a request or reading its response failed.
+    Http {
+        /// Reason for the error.
+        source: HttpError,
+    },
+    /// The plan being applied wasn't planned from the backup being restored.
+    PlanMismatch,
+    /// A request couldn't be built from the backup, such as because a name is
+    /// too long.
+    Validation {
+        /// Reason for the error.
+        source: Box<dyn Error + Send + Sync>,
+    },
+    /// The backup was created with a newer, unsupported version of the
+    /// format.
+    VersionUnsupported {
+        /// Version of the backup.
+        version: u64,
+    },
+}
+
+impl RestoreError {
+    pub(crate) fn validation(source: impl Error + Send + Sync + 'static) -> Self {
+        Self::Validation {
+            source: Box::new(source),
+        }
+    }
+}
+
+impl Display for RestoreError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::GuildNotFound { guild_id } => write!(f, "guild {} wasn't found", guild_id),
+            Self::Http { .. } => f.write_str("sending a request failed"),
+            Self::PlanMismatch => f.write_str("the plan isn't of the backup being restored"),
+            Self::Validation { .. } => f.write_str("the backup contains an invalid value"),
+            Self::VersionUnsupported { version } => {
+                write!(f, "backup version {} is unsupported", version)
+            }
+        }
+    }
+}
+
+impl Error for RestoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::GuildNotFound { .. } | Self::PlanMismatch | Self::VersionUnsupported { .. } => {
+                None
+            }
+            Self::Http { source } => Some(source),
+            Self::Validation { source } => Some(source.as_ref()),
+        }
+    }
+}
+
+impl From<HttpError> for RestoreError {
+    fn from(source: HttpError) -> Self {
+        Self::Http { source }
+    }
+}
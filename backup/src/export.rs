@@ -0,0 +1,31 @@
+use crate::{document::GuildBackup, error::ExportError};
+use twilight_http::Client;
+use twilight_model::id::GuildId;
+
+/// Export the structure of a guild into a backup.
+///
+/// This fetches the guild, including its roles and emojis, and its channels.
+///
+/// # Errors
+///
+/// Returns [`ExportError::GuildNotFound`] if the guild wasn't found.
+///
+/// Returns [`ExportError::Http`] if a request failed.
+pub async fn export(http: &Client, guild_id: GuildId) -> Result<GuildBackup, ExportError> {
+    let guild = http
+        .guild(guild_id)
+        .await?
+        .model()
+        .await?
+        .ok_or(ExportError::GuildNotFound { guild_id })?;
+    let channels = http.guild_channels(guild_id).await?.model().await?;
+
+    tracing::debug!(
+        %guild_id,
+        channels = channels.len(),
+        roles = guild.roles.len(),
+        "exported guild",
+    );
+
+    Ok(GuildBackup::new(&guild, &channels))
+}
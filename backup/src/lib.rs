@@ -0,0 +1,80 @@
+//! # twilight-backup
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-backup` backs up the structure of guilds for the [`twilight-rs`]
+//! ecosystem through `twilight-http`.
+//!
+//! [`export`] creates a [`GuildBackup`] of a guild's settings, roles,
+//! categories, channels with their permission overwrites, and emojis. Backups
+//! are serializable, so they can be stored as JSON or any other format
+//! supported by `serde`.
+//!
+//! [`Restore`] re-applies a backup to the same or another guild. Existing
+//! roles, channels and emojis are matched by ID and then by name and updated,
+//! and missing ones are created. Changes are applied in dependency order -
+//! roles, categories, channels, emojis and then settings - remapping the IDs
+//! of roles and categories in overwrites, channel parents and settings to the
+//! ones in the guild.
+//!
+//! Planning a restore without applying it is a dry run, and the plan can be
+//! displayed as a diff of the changes that would be made.
+//!
+//! ## Examples
+//!
+//! Back up a guild, print what restoring it to another guild would change,
+//! and restore it:
+//!
+//! ```rust,no_run
+//! use twilight_backup::Restore;
+//! use twilight_http::Client;
+//! use twilight_model::id::GuildId;
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new("my token");
+//!
+//! let backup = twilight_backup::export(&client, GuildId(1)).await?;
+//! let json = serde_json::to_string(&backup)?;
+//! println!("backup: {}", json);
+//!
+//! let restore = Restore::new(&client, &backup, GuildId(2));
+//! let plan = restore.plan().await?;
+//! print!("{}", plan);
+//!
+//! let ids = restore.apply(plan).await?;
+//! println!("{:?}", ids.role(backup.roles[1].id));
+//! # Ok(()) }
+//! ```
+//!
+//! [`twilight-rs`]: https://github.com/twilight-rs/twilight
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.48+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    broken_intra_doc_links,
+    clippy::all,
+    future_incompatible,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+
+pub mod document;
+
+mod error;
+mod export;
+mod restore;
+
+pub use self::{
+    document::GuildBackup,
+    error::{ExportError, RestoreError},
+    export::export,
+    restore::{Change, IdMap, Plan, Restore},
+};
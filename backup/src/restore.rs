@@ -0,0 +1,1076 @@
+use crate::{
+    document::{ChannelBackup, EmojiBackup, GuildBackup, RoleBackup},
+    error::RestoreError,
+};
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_http::Client;
+use twilight_model::{
+    channel::{
+        permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        ChannelType, GuildChannel,
+    },
+    guild::{Emoji, Guild, Role},
+    id::{ChannelId, EmojiId, GuildId, RoleId},
+};
+
+/// Change to a guild that is part of a [`Plan`].
+///
+/// IDs are those of the backup, while target IDs are of existing resources
+/// in the guild being restored to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Change {
+    /// Create a channel or category.
+    CreateChannel {
+        id: ChannelId,
+        kind: ChannelType,
+        name: String,
+    },
+    /// Create an emoji.
+    CreateEmoji { id: EmojiId, name: String },
+    /// Create a role.
+    CreateRole { id: RoleId, name: String },
+    /// Skip an emoji, because it has no image and doesn't exist in the guild.
+    SkipEmoji { id: EmojiId, name: String },
+    /// Skip a role, because it's managed by an integration and doesn't exist
+    /// in the guild.
+    SkipRole { id: RoleId, name: String },
+    /// Update an existing channel or category.
+    UpdateChannel {
+        id: ChannelId,
+        kind: ChannelType,
+        name: String,
+        target_id: ChannelId,
+    },
+    /// Update an existing emoji.
+    UpdateEmoji {
+        id: EmojiId,
+        name: String,
+        target_id: EmojiId,
+    },
+    /// Update an existing role.
+    UpdateRole {
+        id: RoleId,
+        name: String,
+        target_id: RoleId,
+    },
+    /// Update the positions of the roles.
+    UpdateRolePositions,
+    /// Update the settings of the guild.
+    UpdateSettings,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::CreateChannel { kind, name, .. } => {
+                write!(f, "+ {} {:?}", channel_kind(*kind), name)
+            }
+            Self::CreateEmoji { name, .. } => write!(f, "+ emoji {:?}", name),
+            Self::CreateRole { name, .. } => write!(f, "+ role {:?}", name),
+            Self::SkipEmoji { name, .. } => write!(f, "! emoji {:?} has no image", name),
+            Self::SkipRole { name, .. } => write!(f, "! role {:?} is managed", name),
+            Self::UpdateChannel {
+                kind,
+                name,
+                target_id,
+                ..
+            } => write!(f, "~ {} {:?} ({})", channel_kind(*kind), name, target_id),
+            Self::UpdateEmoji {
+                name, target_id, ..
+            } => write!(f, "~ emoji {:?} ({})", name, target_id),
+            Self::UpdateRole {
+                name, target_id, ..
+            } => write!(f, "~ role {:?} ({})", name, target_id),
+            Self::UpdateRolePositions => f.write_str("~ role positions"),
+            Self::UpdateSettings => f.write_str("~ settings"),
+        }
+    }
+}
+
+fn channel_kind(kind: ChannelType) -> &'static str {
+    match kind {
+        ChannelType::GuildCategory => "category",
+        ChannelType::GuildStageVoice => "stage channel",
+        ChannelType::GuildVoice => "voice channel",
+        _ => "channel",
+    }
+}
+
+/// Mapping of the IDs of a backup to the IDs in the guild it was restored to.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IdMap {
+    channels: HashMap<ChannelId, ChannelId>,
+    emojis: HashMap<EmojiId, EmojiId>,
+    roles: HashMap<RoleId, RoleId>,
+}
+
+impl IdMap {
+    /// Return the ID in the guild of a channel in the backup.
+    pub fn channel(&self, id: ChannelId) -> Option<ChannelId> {
+        self.channels.get(&id).copied()
+    }
+
+    /// Return the ID in the guild of an emoji in the backup.
+    pub fn emoji(&self, id: EmojiId) -> Option<EmojiId> {
+        self.emojis.get(&id).copied()
+    }
+
+    /// Return the ID in the guild of a role in the backup.
+    pub fn role(&self, id: RoleId) -> Option<RoleId> {
+        self.roles.get(&id).copied()
+    }
+
+    /// Remap the targets of permission overwrites.
+    ///
+    /// Overwrites of roles that aren't mapped are removed, and overwrites of
+    /// members are kept as-is.
+    fn overwrites(&self, overwrites: &[PermissionOverwrite]) -> Vec<PermissionOverwrite> {
+        overwrites
+            .iter()
+            .filter_map(|overwrite| {
+                let kind = match overwrite.kind {
+                    PermissionOverwriteType::Member(user_id) => {
+                        PermissionOverwriteType::Member(user_id)
+                    }
+                    PermissionOverwriteType::Role(role_id) => {
+                        PermissionOverwriteType::Role(self.role(role_id)?)
+                    }
+                };
+
+                Some(PermissionOverwrite {
+                    allow: overwrite.allow,
+                    deny: overwrite.deny,
+                    kind,
+                })
+            })
+            .collect()
+    }
+
+    /// Whether a channel of the backup differs from a channel in the guild,
+    /// which is the case if it isn't mapped yet.
+    fn channel_differs(&self, id: Option<ChannelId>, existing: Option<ChannelId>) -> bool {
+        match id {
+            Some(id) => self.channel(id).map_or(true, |id| Some(id) != existing),
+            None => existing.is_some(),
+        }
+    }
+
+    fn emoji_roles(&self, roles: &[RoleId]) -> Vec<RoleId> {
+        roles.iter().filter_map(|id| self.role(*id)).collect()
+    }
+}
+
+/// Changes needed to restore a backup to a guild, in the order that they're
+/// applied.
+///
+/// Roles are created first so that channel overwrites and emojis can refer to
+/// them, then categories so that channels can be placed in them, then the
+/// other channels and emojis, and the settings last since they refer to
+/// channels.
+///
+/// Displaying a plan lists its changes as a diff, one per line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Plan {
+    changes: Vec<Change>,
+    ids: IdMap,
+}
+
+impl Plan {
+    /// Plan the changes to restore a backup to a guild with the current
+    /// state of the guild.
+    pub(crate) fn new(backup: &GuildBackup, guild: &Guild, channels: &[GuildChannel]) -> Self {
+        let mut plan = Self {
+            changes: Vec::new(),
+            ids: IdMap::default(),
+        };
+
+        plan.roles(backup, guild);
+        plan.channels(backup, channels);
+        plan.emojis(backup, &guild.emojis);
+
+        let settings = &backup.settings;
+
+        if settings.afk_timeout != guild.afk_timeout
+            || plan
+                .ids
+                .channel_differs(settings.afk_channel_id, guild.afk_channel_id)
+            || settings.default_message_notifications != guild.default_message_notifications
+            || settings.explicit_content_filter != guild.explicit_content_filter
+            || settings.name != guild.name
+            || settings.preferred_locale != guild.preferred_locale
+            || settings.region != guild.region
+            || plan
+                .ids
+                .channel_differs(settings.system_channel_id, guild.system_channel_id)
+            || settings.verification_level != guild.verification_level
+        {
+            plan.changes.push(Change::UpdateSettings);
+        }
+
+        plan
+    }
+
+    /// Return the changes of the plan.
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// Return whether the guild already matches the backup.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    fn roles(&mut self, backup: &GuildBackup, guild: &Guild) {
+        let mut available = guild.roles.iter().collect::<Vec<_>>();
+        let mut reorder = false;
+
+        for role in &backup.roles {
+            let is_everyone = role.id.0 == backup.guild_id.0;
+
+            let index = if is_everyone {
+                available.iter().position(|r| r.id.0 == guild.id.0)
+            } else {
+                available.iter().position(|r| r.id == role.id).or_else(|| {
+                    available
+                        .iter()
+                        .position(|r| r.id.0 != guild.id.0 && r.name == role.name)
+                })
+            };
+
+            match index.map(|index| available.remove(index)) {
+                Some(existing) => {
+                    self.ids.roles.insert(role.id, existing.id);
+
+                    if !is_everyone && existing.position != role.position {
+                        reorder = true;
+                    }
+
+                    if !role.managed && role_differs(role, existing) {
+                        self.changes.push(Change::UpdateRole {
+                            id: role.id,
+                            name: role.name.clone(),
+                            target_id: existing.id,
+                        });
+                    }
+                }
+                None if role.managed => self.changes.push(Change::SkipRole {
+                    id: role.id,
+                    name: role.name.clone(),
+                }),
+                None => {
+                    reorder = true;
+
+                    self.changes.push(Change::CreateRole {
+                        id: role.id,
+                        name: role.name.clone(),
+                    });
+                }
+            }
+        }
+
+        if reorder {
+            self.changes.push(Change::UpdateRolePositions);
+        }
+    }
+
+    fn channels(&mut self, backup: &GuildBackup, channels: &[GuildChannel]) {
+        let mut available = channels.iter().collect::<Vec<_>>();
+
+        let (categories, others): (Vec<_>, Vec<_>) = backup
+            .channels
+            .iter()
+            .partition(|channel| channel.kind == ChannelType::GuildCategory);
+
+        for channel in categories.into_iter().chain(others) {
+            let index = available
+                .iter()
+                .position(|c| c.id() == channel.id)
+                .or_else(|| {
+                    available
+                        .iter()
+                        .position(|c| c.kind() == channel.kind && c.name() == channel.name)
+                });
+
+            match index.map(|index| available.remove(index)) {
+                Some(existing) => {
+                    self.ids.channels.insert(channel.id, existing.id());
+
+                    if self.channel_differs(channel, existing) {
+                        self.changes.push(Change::UpdateChannel {
+                            id: channel.id,
+                            kind: channel.kind,
+                            name: channel.name.clone(),
+                            target_id: existing.id(),
+                        });
+                    }
+                }
+                None => self.changes.push(Change::CreateChannel {
+                    id: channel.id,
+                    kind: channel.kind,
+                    name: channel.name.clone(),
+                }),
+            }
+        }
+    }
+
+    fn emojis(&mut self, backup: &GuildBackup, emojis: &[Emoji]) {
+        let mut available = emojis.iter().collect::<Vec<_>>();
+
+        for emoji in &backup.emojis {
+            let index = available
+                .iter()
+                .position(|e| e.id == emoji.id)
+                .or_else(|| available.iter().position(|e| e.name == emoji.name));
+
+            match index.map(|index| available.remove(index)) {
+                Some(existing) => {
+                    self.ids.emojis.insert(emoji.id, existing.id);
+
+                    let roles = self.ids.emoji_roles(&emoji.roles);
+
+                    if existing.name != emoji.name
+                        || roles.len() != emoji.roles.len()
+                        || !same_items(&roles, &existing.roles)
+                    {
+                        self.changes.push(Change::UpdateEmoji {
+                            id: emoji.id,
+                            name: emoji.name.clone(),
+                            target_id: existing.id,
+                        });
+                    }
+                }
+                None if emoji.image.is_some() => self.changes.push(Change::CreateEmoji {
+                    id: emoji.id,
+                    name: emoji.name.clone(),
+                }),
+                None => self.changes.push(Change::SkipEmoji {
+                    id: emoji.id,
+                    name: emoji.name.clone(),
+                }),
+            }
+        }
+    }
+
+    fn channel_differs(&self, channel: &ChannelBackup, existing: &GuildChannel) -> bool {
+        let existing = ChannelBackup::from(existing);
+        let overwrites = self.ids.overwrites(&channel.permission_overwrites);
+
+        channel.name != existing.name
+            || channel.position != existing.position
+            || channel.topic != existing.topic
+            || channel.nsfw != existing.nsfw
+            || channel.rate_limit_per_user.unwrap_or(0) != existing.rate_limit_per_user.unwrap_or(0)
+            || channel.bitrate != existing.bitrate
+            || channel.user_limit != existing.user_limit
+            || self
+                .ids
+                .channel_differs(channel.parent_id, existing.parent_id)
+            || overwrites.len() != channel.permission_overwrites.len()
+            || !same_items(&overwrites, &existing.permission_overwrites)
+    }
+}
+
+impl Display for Plan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn role_differs(role: &RoleBackup, existing: &Role) -> bool {
+    role.name != existing.name
+        || role.color != existing.color
+        || role.hoist != existing.hoist
+        || role.mentionable != existing.mentionable
+        || role.permissions != existing.permissions
+}
+
+/// Whether two lists contain the same items, regardless of their order.
+fn same_items<T: PartialEq>(a: &[T], b: &[T]) -> bool {
+    a.len() == b.len() && a.iter().all(|item| b.contains(item))
+}
+
+/// Restore a backup to a guild.
+///
+/// The changes are first planned with [`plan`] by comparing the backup with
+/// the current state of the guild, matching existing roles, channels and
+/// emojis by ID and then by name. Only calling [`plan`] is a dry run, and its
+/// plan can be displayed as a diff. The plan is then applied with [`apply`].
+///
+/// Restoring to the guild that the backup was created from updates the
+/// existing resources, while restoring to another guild creates them and
+/// remaps their IDs.
+///
+/// Resources that aren't in the backup are left as-is.
+///
+/// [`apply`]: Self::apply
+/// [`plan`]: Self::plan
+#[derive(Debug)]
+pub struct Restore<'a> {
+    backup: &'a GuildBackup,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> Restore<'a> {
+    /// Create a new restore of a backup to a guild.
+    pub fn new(http: &'a Client, backup: &'a GuildBackup, guild_id: GuildId) -> Self {
+        Self {
+            backup,
+            guild_id,
+            http,
+        }
+    }
+
+    /// Plan the changes to the guild without applying them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RestoreError::VersionUnsupported`] if the backup was created
+    /// with a newer version of the format.
+    ///
+    /// Returns [`RestoreError::GuildNotFound`] if the guild wasn't found.
+    ///
+    /// Returns [`RestoreError::Http`] if fetching the guild failed.
+    pub async fn plan(&self) -> Result<Plan, RestoreError> {
+        if self.backup.version > GuildBackup::VERSION {
+            return Err(RestoreError::VersionUnsupported {
+                version: self.backup.version,
+            });
+        }
+
+        let guild = self.http.guild(self.guild_id).await?.model().await?.ok_or(
+            RestoreError::GuildNotFound {
+                guild_id: self.guild_id,
+            },
+        )?;
+        let channels = self
+            .http
+            .guild_channels(self.guild_id)
+            .await?
+            .model()
+            .await?;
+
+        Ok(Plan::new(self.backup, &guild, &channels))
+    }
+
+    /// Apply a plan to the guild, returning the mapping of the IDs of the
+    /// backup to those in the guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RestoreError::PlanMismatch`] if the plan refers to a role,
+    /// channel or emoji that isn't in the backup, such as because it was
+    /// planned by a restore of another backup.
+    ///
+    /// Returns [`RestoreError::Validation`] if a request couldn't be built
+    /// from the backup.
+    ///
+    /// Returns [`RestoreError::Http`] if a request failed. Changes applied
+    /// before the failed request aren't reverted.
+    pub async fn apply(&self, plan: Plan) -> Result<IdMap, RestoreError> {
+        let mut ids = plan.ids;
+
+        for change in plan.changes {
+            tracing::debug!(guild_id = %self.guild_id, %change, "applying change");
+
+            match change {
+                Change::CreateChannel { id, .. } => {
+                    let channel = self.channel(id)?;
+                    let created = self.create_channel(channel, &ids).await?;
+
+                    ids.channels.insert(id, created.id());
+                }
+                Change::CreateEmoji { id, .. } => {
+                    let emoji = self.emoji(id)?;
+                    let image = emoji.image.clone().unwrap_or_default();
+
+                    let created = self
+                        .http
+                        .create_emoji(self.guild_id, emoji.name.clone(), image)
+                        .roles(ids.emoji_roles(&emoji.roles))
+                        .await?
+                        .model()
+                        .await?;
+
+                    ids.emojis.insert(id, created.id);
+                }
+                Change::CreateRole { id, .. } => {
+                    let role = self.role(id)?;
+
+                    let created = self
+                        .http
+                        .create_role(self.guild_id)
+                        .color(role.color)
+                        .hoist(role.hoist)
+                        .mentionable(role.mentionable)
+                        .name(role.name.clone())
+                        .permissions(role.permissions)
+                        .await?
+                        .model()
+                        .await?;
+
+                    ids.roles.insert(id, created.id);
+                }
+                Change::SkipEmoji { .. } | Change::SkipRole { .. } => {}
+                Change::UpdateChannel { id, target_id, .. } => {
+                    let channel = self.channel(id)?;
+
+                    self.update_channel(channel, target_id, &ids).await?;
+                }
+                Change::UpdateEmoji { id, target_id, .. } => {
+                    let emoji = self.emoji(id)?;
+
+                    self.http
+                        .update_emoji(self.guild_id, target_id)
+                        .name(emoji.name.clone())
+                        .roles(ids.emoji_roles(&emoji.roles))
+                        .await?;
+                }
+                Change::UpdateRole { id, target_id, .. } => {
+                    let role = self.role(id)?;
+
+                    self.http
+                        .update_role(self.guild_id, target_id)
+                        .color(role.color)
+                        .hoist(role.hoist)
+                        .mentionable(role.mentionable)
+                        .name(role.name.clone())
+                        .permissions(role.permissions)
+                        .await?;
+                }
+                Change::UpdateRolePositions => {
+                    let mut positions = Vec::new();
+
+                    for role in &self.backup.roles {
+                        if role.id.0 == self.backup.guild_id.0 {
+                            continue;
+                        }
+
+                        if let Some(target_id) = ids.role(role.id) {
+                            let position =
+                                u64::try_from(role.position).map_err(RestoreError::validation)?;
+
+                            positions.push((target_id, position));
+                        }
+                    }
+
+                    self.http
+                        .update_role_positions(self.guild_id, positions.into_iter())
+                        .await?;
+                }
+                Change::UpdateSettings => self.update_settings(&ids).await?,
+            }
+        }
+
+        Ok(ids)
+    }
+
+    /// Plan the changes to the guild and apply them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`plan`] and [`apply`].
+    ///
+    /// [`apply`]: Self::apply
+    /// [`plan`]: Self::plan
+    pub async fn run(&self) -> Result<IdMap, RestoreError> {
+        let plan = self.plan().await?;
+
+        self.apply(plan).await
+    }
+
+    async fn create_channel(
+        &self,
+        channel: &ChannelBackup,
+        ids: &IdMap,
+    ) -> Result<GuildChannel, RestoreError> {
+        let mut request = self
+            .http
+            .create_guild_channel(self.guild_id, channel.name.clone())
+            .map_err(RestoreError::validation)?
+            .kind(channel.kind)
+            .nsfw(channel.nsfw)
+            .permission_overwrites(ids.overwrites(&channel.permission_overwrites))
+            .position(u64::try_from(channel.position).map_err(RestoreError::validation)?);
+
+        if let Some(parent_id) = channel.parent_id.and_then(|id| ids.channel(id)) {
+            request = request.parent_id(parent_id);
+        }
+
+        if let Some(bitrate) = channel.bitrate {
+            request = request.bitrate(bitrate);
+        }
+
+        if let Some(user_limit) = channel.user_limit {
+            request = request.user_limit(user_limit);
+        }
+
+        if let Some(rate_limit_per_user) = channel.rate_limit_per_user {
+            request = request
+                .rate_limit_per_user(rate_limit_per_user)
+                .map_err(RestoreError::validation)?;
+        }
+
+        if let Some(topic) = &channel.topic {
+            request = request
+                .topic(topic.clone())
+                .map_err(RestoreError::validation)?;
+        }
+
+        Ok(request.await?.model().await?)
+    }
+
+    async fn update_channel(
+        &self,
+        channel: &ChannelBackup,
+        target_id: ChannelId,
+        ids: &IdMap,
+    ) -> Result<(), RestoreError> {
+        let mut request = self
+            .http
+            .update_channel(target_id)
+            .name(channel.name.clone())
+            .map_err(RestoreError::validation)?
+            .permission_overwrites(ids.overwrites(&channel.permission_overwrites))
+            .position(u64::try_from(channel.position).map_err(RestoreError::validation)?);
+
+        if channel.kind != ChannelType::GuildCategory {
+            request = request.parent_id(channel.parent_id.and_then(|id| ids.channel(id)));
+        }
+
+        if !matches!(
+            channel.kind,
+            ChannelType::GuildCategory | ChannelType::GuildStageVoice | ChannelType::GuildVoice
+        ) {
+            request = request
+                .nsfw(channel.nsfw)
+                .rate_limit_per_user(channel.rate_limit_per_user.unwrap_or(0))
+                .map_err(RestoreError::validation)?;
+        }
+
+        if let Some(bitrate) = channel.bitrate {
+            request = request.bitrate(bitrate);
+        }
+
+        if let Some(user_limit) = channel.user_limit {
+            request = request.user_limit(user_limit);
+        }
+
+        if let Some(topic) = &channel.topic {
+            request = request
+                .topic(topic.clone())
+                .map_err(RestoreError::validation)?;
+        }
+
+        request.await?;
+
+        Ok(())
+    }
+
+    async fn update_settings(&self, ids: &IdMap) -> Result<(), RestoreError> {
+        let settings = &self.backup.settings;
+
+        self.http
+            .update_guild(self.guild_id)
+            .name(settings.name.clone())
+            .map_err(RestoreError::validation)?
+            .afk_channel_id(settings.afk_channel_id.and_then(|id| ids.channel(id)))
+            .afk_timeout(settings.afk_timeout)
+            .default_message_notifications(settings.default_message_notifications)
+            .explicit_content_filter(settings.explicit_content_filter)
+            .preferred_locale(settings.preferred_locale.clone())
+            .region(settings.region.clone())
+            .system_channel(settings.system_channel_id.and_then(|id| ids.channel(id)))
+            .verification_level(settings.verification_level)
+            .await?;
+
+        Ok(())
+    }
+
+    fn channel(&self, id: ChannelId) -> Result<&'a ChannelBackup, RestoreError> {
+        self.backup
+            .channels
+            .iter()
+            .find(|channel| channel.id == id)
+            .ok_or(RestoreError::PlanMismatch)
+    }
+
+    fn emoji(&self, id: EmojiId) -> Result<&'a EmojiBackup, RestoreError> {
+        self.backup
+            .emojis
+            .iter()
+            .find(|emoji| emoji.id == id)
+            .ok_or(RestoreError::PlanMismatch)
+    }
+
+    fn role(&self, id: RoleId) -> Result<&'a RoleBackup, RestoreError> {
+        self.backup
+            .roles
+            .iter()
+            .find(|role| role.id == id)
+            .ok_or(RestoreError::PlanMismatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Change, IdMap, Plan, Restore, RestoreError};
+    use crate::document::{ChannelBackup, EmojiBackup, GuildBackup, RoleBackup, SettingsBackup};
+    use hyper::{
+        service::{make_service_fn, service_fn},
+        Body, Method, Request, Response, Server,
+    };
+    use serde_json::{json, Value};
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::Infallible,
+        fmt::Debug,
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc, Mutex,
+        },
+    };
+    use twilight_http::Client;
+    use twilight_model::{
+        channel::{
+            permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+            ChannelType,
+        },
+        guild::{
+            DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, Permissions,
+            VerificationLevel,
+        },
+        id::{ChannelId, EmojiId, GuildId, RoleId},
+    };
+
+    assert_impl_all!(Change: Clone, Debug, Eq, Send, Sync);
+    assert_impl_all!(IdMap: Clone, Debug, Default, Eq, Send, Sync);
+    assert_impl_all!(Plan: Clone, Debug, Eq, Send, Sync);
+    assert_impl_all!(Restore<'_>: Debug, Send, Sync);
+
+    type Requests = Arc<Mutex<Vec<(Method, String, Value)>>>;
+
+    fn role(id: u64, name: &str, position: i64, managed: bool) -> RoleBackup {
+        RoleBackup {
+            color: 0,
+            hoist: false,
+            id: RoleId(id),
+            managed,
+            mentionable: false,
+            name: name.to_owned(),
+            permissions: Permissions::SEND_MESSAGES,
+            position,
+        }
+    }
+
+    fn backup() -> GuildBackup {
+        GuildBackup {
+            channels: vec![
+                ChannelBackup {
+                    bitrate: None,
+                    id: ChannelId(20),
+                    kind: ChannelType::GuildCategory,
+                    name: "info".to_owned(),
+                    nsfw: false,
+                    parent_id: None,
+                    permission_overwrites: Vec::new(),
+                    position: 0,
+                    rate_limit_per_user: None,
+                    topic: None,
+                    user_limit: None,
+                },
+                ChannelBackup {
+                    bitrate: None,
+                    id: ChannelId(21),
+                    kind: ChannelType::GuildText,
+                    name: "rules".to_owned(),
+                    nsfw: false,
+                    parent_id: Some(ChannelId(20)),
+                    permission_overwrites: vec![
+                        PermissionOverwrite {
+                            allow: Permissions::empty(),
+                            deny: Permissions::SEND_MESSAGES,
+                            kind: PermissionOverwriteType::Role(RoleId(1)),
+                        },
+                        PermissionOverwrite {
+                            allow: Permissions::SEND_MESSAGES,
+                            deny: Permissions::empty(),
+                            kind: PermissionOverwriteType::Role(RoleId(10)),
+                        },
+                    ],
+                    position: 1,
+                    rate_limit_per_user: None,
+                    topic: Some("read me".to_owned()),
+                    user_limit: None,
+                },
+            ],
+            emojis: vec![
+                EmojiBackup {
+                    animated: false,
+                    id: EmojiId(30),
+                    image: Some("data:image/png;base64,AAAA".to_owned()),
+                    name: "wave".to_owned(),
+                    roles: vec![RoleId(10)],
+                },
+                EmojiBackup {
+                    animated: false,
+                    id: EmojiId(31),
+                    image: None,
+                    name: "missing".to_owned(),
+                    roles: Vec::new(),
+                },
+            ],
+            guild_id: GuildId(1),
+            roles: vec![
+                role(1, "@everyone", 0, false),
+                role(10, "moderators", 1, false),
+                role(11, "bot", 2, true),
+            ],
+            settings: SettingsBackup {
+                afk_channel_id: None,
+                afk_timeout: 300,
+                default_message_notifications: DefaultMessageNotificationLevel::Mentions,
+                explicit_content_filter: ExplicitContentFilter::AllMembers,
+                name: "backup".to_owned(),
+                preferred_locale: "en-US".to_owned(),
+                region: "us-west".to_owned(),
+                system_channel_id: Some(ChannelId(21)),
+                verification_level: VerificationLevel::Low,
+            },
+            version: GuildBackup::VERSION,
+        }
+    }
+
+    /// Empty guild with only an `@everyone` role without permissions.
+    fn target_guild() -> Value {
+        json!({
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "application_id": null,
+            "banner": null,
+            "default_message_notifications": 0,
+            "description": null,
+            "discovery_splash": null,
+            "emojis": [],
+            "explicit_content_filter": 0,
+            "features": [],
+            "icon": null,
+            "id": "2",
+            "mfa_level": 0,
+            "name": "target",
+            "owner_id": "3",
+            "preferred_locale": "en-US",
+            "premium_tier": 0,
+            "region": "us-west",
+            "roles": [{
+                "color": 0,
+                "hoist": false,
+                "id": "2",
+                "managed": false,
+                "mentionable": false,
+                "name": "@everyone",
+                "permissions": "0",
+                "position": 0
+            }],
+            "rules_channel_id": null,
+            "splash": null,
+            "system_channel_flags": 0,
+            "system_channel_id": null,
+            "vanity_url_code": null,
+            "verification_level": 0
+        })
+    }
+
+    /// Respond to a request to the mocked API, assigning IDs starting from
+    /// 100 to created resources.
+    fn respond(method: &Method, path: &str, mut body: Value, ids: &AtomicU64) -> Value {
+        let mut created = || {
+            body["id"] = Value::String(ids.fetch_add(1, Ordering::SeqCst).to_string());
+
+            body.clone()
+        };
+
+        match (method, path) {
+            (&Method::GET, "/api/v9/guilds/2") | (&Method::PATCH, "/api/v9/guilds/2") => {
+                target_guild()
+            }
+            (&Method::GET, "/api/v9/guilds/2/channels")
+            | (&Method::PATCH, "/api/v9/guilds/2/roles") => {
+                json!([])
+            }
+            (&Method::POST, "/api/v9/guilds/2/channels") => {
+                let mut channel = created();
+                channel["position"] = json!(0);
+
+                channel
+            }
+            (&Method::POST, "/api/v9/guilds/2/emojis") => created(),
+            (&Method::PATCH, "/api/v9/guilds/2/roles/2") => target_guild()["roles"][0].clone(),
+            (&Method::POST, "/api/v9/guilds/2/roles") => {
+                let mut role = created();
+                role["managed"] = json!(false);
+                role["position"] = json!(1);
+
+                role
+            }
+            _ => panic!("unexpected request: {} {}", method, path),
+        }
+    }
+
+    async fn server() -> (Client, Requests) {
+        let requests = Requests::default();
+        let ids = Arc::new(AtomicU64::new(100));
+
+        let service_requests = Arc::clone(&requests);
+        let make_service = make_service_fn(move |_| {
+            let requests = Arc::clone(&service_requests);
+            let ids = Arc::clone(&ids);
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                    let requests = Arc::clone(&requests);
+                    let ids = Arc::clone(&ids);
+
+                    async move {
+                        let (parts, body) = request.into_parts();
+                        let bytes = hyper::body::to_bytes(body).await.unwrap();
+                        let body = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+                        let path = parts.uri.path().to_owned();
+
+                        let response = respond(&parts.method, &path, body.clone(), &ids);
+                        requests.lock().unwrap().push((parts.method, path, body));
+
+                        Ok::<_, Infallible>(Response::new(Body::from(response.to_string())))
+                    }
+                }))
+            }
+        });
+
+        let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+        let addr = server.local_addr();
+        tokio::spawn(server);
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .ratelimiter(None)
+            .token("token")
+            .build();
+
+        (client, requests)
+    }
+
+    #[test]
+    fn test_plan_display() {
+        let guild = serde_json::from_value::<Guild>(target_guild()).unwrap();
+        let plan = Plan::new(&backup(), &guild, &[]);
+
+        let expected = "~ role \"@everyone\" (2)\n\
+                        + role \"moderators\"\n\
+                        ! role \"bot\" is managed\n\
+                        ~ role positions\n\
+                        + category \"info\"\n\
+                        + channel \"rules\"\n\
+                        + emoji \"wave\"\n\
+                        ! emoji \"missing\" has no image\n\
+                        ~ settings\n";
+        assert_eq!(expected, plan.to_string());
+        assert_eq!(Some(RoleId(2)), plan.ids.role(RoleId(1)));
+    }
+
+    #[test]
+    fn test_plan_unchanged() {
+        let mut backup = backup();
+        backup.channels.clear();
+        backup.emojis.clear();
+        backup.roles.truncate(1);
+        backup.roles[0].permissions = Permissions::empty();
+        backup.settings.default_message_notifications = DefaultMessageNotificationLevel::All;
+        backup.settings.explicit_content_filter = ExplicitContentFilter::None;
+        backup.settings.name = "target".to_owned();
+        backup.settings.system_channel_id = None;
+        backup.settings.verification_level = VerificationLevel::None;
+
+        let guild = serde_json::from_value::<Guild>(target_guild()).unwrap();
+        assert!(Plan::new(&backup, &guild, &[]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_apply_plan_mismatch() {
+        let client = Client::new("token");
+        let guild = serde_json::from_value::<Guild>(target_guild()).unwrap();
+        let plan = Plan::new(&backup(), &guild, &[]);
+
+        let mut other = backup();
+        other.roles.clear();
+
+        let result = Restore::new(&client, &other, GuildId(2)).apply(plan).await;
+        assert!(matches!(result, Err(RestoreError::PlanMismatch)));
+    }
+
+    #[tokio::test]
+    async fn test_restore_negative_position() {
+        let (client, _) = server().await;
+        let mut backup = backup();
+        backup.channels[0].position = -1;
+
+        let result = Restore::new(&client, &backup, GuildId(2)).run().await;
+        assert!(matches!(result, Err(RestoreError::Validation { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_restore_remaps_ids() {
+        let (client, requests) = server().await;
+        let backup = backup();
+
+        let ids = Restore::new(&client, &backup, GuildId(2))
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(Some(RoleId(2)), ids.role(RoleId(1)));
+        assert_eq!(Some(RoleId(100)), ids.role(RoleId(10)));
+        assert_eq!(None, ids.role(RoleId(11)));
+        assert_eq!(Some(ChannelId(101)), ids.channel(ChannelId(20)));
+        assert_eq!(Some(ChannelId(102)), ids.channel(ChannelId(21)));
+        assert_eq!(Some(EmojiId(103)), ids.emoji(EmojiId(30)));
+        assert_eq!(None, ids.emoji(EmojiId(31)));
+
+        let requests = requests.lock().unwrap();
+        let sent = requests
+            .iter()
+            .map(|(method, path, _)| format!("{} {}", method, path))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "GET /api/v9/guilds/2",
+                "GET /api/v9/guilds/2/channels",
+                "PATCH /api/v9/guilds/2/roles/2",
+                "POST /api/v9/guilds/2/roles",
+                "PATCH /api/v9/guilds/2/roles",
+                "POST /api/v9/guilds/2/channels",
+                "POST /api/v9/guilds/2/channels",
+                "POST /api/v9/guilds/2/emojis",
+                "PATCH /api/v9/guilds/2",
+            ],
+            sent,
+        );
+
+        let rules = &requests[6].2;
+        assert_eq!("101", rules["parent_id"]);
+        assert_eq!("2", rules["permission_overwrites"][0]["id"]);
+        assert_eq!("100", rules["permission_overwrites"][1]["id"]);
+
+        assert_eq!(json!(["100"]), requests[7].2["roles"]);
+        assert_eq!("102", requests[8].2["system_channel_id"]);
+    }
+}
@@ -1,11 +1,23 @@
 use super::{
     config::{Config, ResourceType},
+    snapshot::Snapshot,
     InMemoryCache,
 };
+use std::{sync::Arc, time::Duration};
+use twilight_model::{
+    channel::{ChannelType, GuildChannel, Message},
+    gateway::presence::Presence,
+    guild::{Emoji, Member, Role},
+    id::GuildId,
+    voice::VoiceState,
+};
 
 /// Builder to configure and construct an [`InMemoryCache`].
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct InMemoryCacheBuilder(Config);
+pub struct InMemoryCacheBuilder {
+    config: Config,
+    snapshot: Option<Snapshot>,
+}
 
 impl InMemoryCacheBuilder {
     /// Creates a builder to configure and construct an [`InMemoryCache`].
@@ -15,23 +27,225 @@ impl InMemoryCacheBuilder {
 
     /// Consume the builder, returning a configured cache.
     pub fn build(self) -> InMemoryCache {
-        InMemoryCache::new_with_config(self.0)
+        InMemoryCache::new_with_config(self.config, self.snapshot)
     }
 
     /// Sets the list of resource types for the cache to handle.
     ///
     /// Defaults to all types.
     pub fn resource_types(mut self, resource_types: ResourceType) -> Self {
-        self.0.resource_types = resource_types;
+        self.config.resource_types = resource_types;
+
+        self
+    }
+
+    /// Sets the guilds to cache.
+    ///
+    /// Events of other guilds are ignored, so their resources aren't cached
+    /// at all.
+    ///
+    /// Defaults to all guilds.
+    ///
+    /// # Examples
+    ///
+    /// Only cache two guilds, and only the current user's member in them:
+    ///
+    /// ```
+    /// use twilight_cache_inmemory::InMemoryCache;
+    /// use twilight_model::id::GuildId;
+    ///
+    /// let cache = InMemoryCache::builder()
+    ///     .guilds(vec![GuildId(1), GuildId(2)])
+    ///     .current_member_only(true)
+    ///     .build();
+    /// ```
+    pub fn guilds(mut self, guilds: impl IntoIterator<Item = GuildId>) -> Self {
+        self.config
+            .filter
+            .guilds
+            .replace(guilds.into_iter().collect());
+
+        self
+    }
+
+    /// Sets whether to only cache the current user's member in each guild.
+    ///
+    /// This requires the [`ResourceType::USER_CURRENT`] resource type to know
+    /// the current user.
+    ///
+    /// Defaults to caching all members.
+    pub fn current_member_only(mut self, current_member_only: bool) -> Self {
+        self.config.filter.current_member_only = current_member_only;
+
+        self
+    }
+
+    /// Sets the types of guild channels to cache.
+    ///
+    /// Defaults to all types.
+    pub fn channel_types(mut self, channel_types: impl IntoIterator<Item = ChannelType>) -> Self {
+        self.config
+            .filter
+            .channel_types
+            .replace(channel_types.into_iter().collect());
+
+        self
+    }
+
+    /// Sets a predicate deciding whether to cache a guild channel.
+    ///
+    /// Channels updated to no longer match the predicate are removed.
+    pub fn filter_channel(
+        mut self,
+        predicate: impl Fn(&GuildChannel) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.config.filter.channel.replace(Arc::new(predicate));
+
+        self
+    }
+
+    /// Sets a predicate deciding whether to cache an emoji.
+    pub fn filter_emoji(
+        mut self,
+        predicate: impl Fn(&Emoji) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.config.filter.emoji.replace(Arc::new(predicate));
+
+        self
+    }
+
+    /// Sets a predicate deciding whether to cache a member.
+    ///
+    /// Partial members, such as the authors of messages, aren't full members
+    /// and so only update members that are already cached.
+    pub fn filter_member(
+        mut self,
+        predicate: impl Fn(&Member) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.config.filter.member.replace(Arc::new(predicate));
+
+        self
+    }
+
+    /// Sets a predicate deciding whether to cache a message.
+    pub fn filter_message(
+        mut self,
+        predicate: impl Fn(&Message) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.config.filter.message.replace(Arc::new(predicate));
+
+        self
+    }
+
+    /// Sets a predicate deciding whether to cache a presence.
+    pub fn filter_presence(
+        mut self,
+        predicate: impl Fn(&Presence) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.config.filter.presence.replace(Arc::new(predicate));
+
+        self
+    }
+
+    /// Sets a predicate deciding whether to cache a role.
+    ///
+    /// Roles updated to no longer match the predicate are removed.
+    pub fn filter_role(
+        mut self,
+        predicate: impl Fn(&Role) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.config.filter.role.replace(Arc::new(predicate));
+
+        self
+    }
+
+    /// Sets a predicate deciding whether to cache a voice state.
+    ///
+    /// Voice states updated to no longer match the predicate are removed.
+    pub fn filter_voice_state(
+        mut self,
+        predicate: impl Fn(&VoiceState) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.config.filter.voice_state.replace(Arc::new(predicate));
 
         self
     }
 
     /// Sets the number of messages to cache per channel.
     ///
+    /// The oldest messages of a channel are evicted first.
+    ///
     /// Defaults to 100.
     pub fn message_cache_size(mut self, message_cache_size: usize) -> Self {
-        self.0.message_cache_size = message_cache_size;
+        self.config.message_cache_size = message_cache_size;
+
+        self
+    }
+
+    /// Sets the number of messages to cache across all channels.
+    ///
+    /// The oldest messages across all channels are evicted first. This bounds
+    /// the memory used by the message cache regardless of the number of
+    /// channels.
+    ///
+    /// Defaults to no limit.
+    pub fn message_cache_size_global(mut self, message_cache_size_global: usize) -> Self {
+        self.config
+            .message_cache_size_global
+            .replace(message_cache_size_global);
+
+        self
+    }
+
+    /// Sets the number of channels to cache messages in.
+    ///
+    /// All messages of the channels whose newest message is the oldest are
+    /// evicted first, so the cache keeps the messages of the most recently
+    /// active channels.
+    ///
+    /// Defaults to no limit.
+    pub fn message_channel_limit(mut self, message_channel_limit: usize) -> Self {
+        self.config
+            .message_channel_limit
+            .replace(message_channel_limit);
+
+        self
+    }
+
+    /// Sets the duration after which messages expire.
+    ///
+    /// Messages are expired by the time at which they were sent. Expired
+    /// messages are evicted whenever a new message is cached.
+    ///
+    /// Defaults to messages not expiring.
+    pub fn message_ttl(mut self, message_ttl: Duration) -> Self {
+        self.config.message_ttl.replace(message_ttl);
+
+        self
+    }
+
+    /// Sets a snapshot to restore the state of the cache from.
+    ///
+    /// Restoring the cache alongside resuming the sessions of the shards it
+    /// was created with keeps the cache complete, since resumed shards don't
+    /// receive the guilds again.
+    ///
+    /// # Examples
+    ///
+    /// Restore a cache from a snapshot file written by
+    /// [`InMemoryCache::snapshot`]:
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use twilight_cache_inmemory::{InMemoryCache, Snapshot};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let snapshot = Snapshot::from_reader(File::open("cache.snapshot")?)?;
+    /// let cache = InMemoryCache::builder().snapshot(snapshot).build();
+    /// # Ok(()) }
+    /// ```
+    pub fn snapshot(mut self, snapshot: Snapshot) -> Self {
+        self.snapshot.replace(snapshot);
 
         self
     }
@@ -0,0 +1,322 @@
+use super::{
+    model::{CachedEmoji, CachedGuild, CachedMember, CachedMessage, CachedPresence},
+    InMemoryCache,
+};
+use std::{
+    collections::HashSet,
+    convert::Infallible,
+    error::Error,
+    future::{self, Future},
+    pin::Pin,
+    sync::Arc,
+};
+use twilight_model::{
+    channel::{Group, GuildChannel, PrivateChannel},
+    guild::Role,
+    id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, UserId},
+    user::{CurrentUser, User},
+    voice::VoiceState,
+};
+
+/// Future returned by lookups of a [`Cache`].
+pub type CacheFuture<'a, T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'a>>;
+
+/// Lookups of cached Discord data, independent of where the data is stored.
+///
+/// This allows code to work with the [`InMemoryCache`] as well as caches
+/// backed by external storage shared between processes, such as a key-value
+/// store. Lookups are asynchronous and fallible since external storage may
+/// require I/O.
+///
+/// Caches are updated with types implementing [`UpdateCache`] for them.
+///
+/// [`UpdateCache`]: crate::UpdateCache
+pub trait Cache: Send + Sync {
+    /// Error returned when a lookup fails.
+    type Error: Error + Send + Sync + 'static;
+
+    /// Output of updating the cache via [`UpdateCache`].
+    ///
+    /// For caches which are updated synchronously this is `()`, while caches
+    /// backed by asynchronous storage return a future.
+    ///
+    /// [`UpdateCache`]: crate::UpdateCache
+    type Update: Default;
+
+    /// Gets the current user.
+    fn current_user(&self) -> CacheFuture<'_, Option<Arc<CurrentUser>>, Self::Error>;
+
+    /// Gets an emoji by ID.
+    fn emoji(&self, emoji_id: EmojiId) -> CacheFuture<'_, Option<Arc<CachedEmoji>>, Self::Error>;
+
+    /// Gets a group by ID.
+    fn group(&self, channel_id: ChannelId) -> CacheFuture<'_, Option<Arc<Group>>, Self::Error>;
+
+    /// Gets a guild by ID.
+    fn guild(&self, guild_id: GuildId) -> CacheFuture<'_, Option<Arc<CachedGuild>>, Self::Error>;
+
+    /// Gets a guild channel by ID.
+    fn guild_channel(
+        &self,
+        channel_id: ChannelId,
+    ) -> CacheFuture<'_, Option<Arc<GuildChannel>>, Self::Error>;
+
+    /// Gets the set of channels in a guild.
+    fn guild_channels(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<ChannelId>>, Self::Error>;
+
+    /// Gets the set of emojis in a guild.
+    fn guild_emojis(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<EmojiId>>, Self::Error>;
+
+    /// Gets the set of members in a guild.
+    fn guild_members(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<UserId>>, Self::Error>;
+
+    /// Gets the set of presences in a guild.
+    fn guild_presences(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<UserId>>, Self::Error>;
+
+    /// Gets the set of roles in a guild.
+    fn guild_roles(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<RoleId>>, Self::Error>;
+
+    /// Gets a member by guild ID and user ID.
+    fn member(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> CacheFuture<'_, Option<Arc<CachedMember>>, Self::Error>;
+
+    /// Gets a message by channel ID and message ID.
+    fn message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> CacheFuture<'_, Option<Arc<CachedMessage>>, Self::Error>;
+
+    /// Gets a presence by guild ID and user ID.
+    fn presence(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> CacheFuture<'_, Option<Arc<CachedPresence>>, Self::Error>;
+
+    /// Gets a private channel by ID.
+    fn private_channel(
+        &self,
+        channel_id: ChannelId,
+    ) -> CacheFuture<'_, Option<Arc<PrivateChannel>>, Self::Error>;
+
+    /// Gets a role by ID.
+    fn role(&self, role_id: RoleId) -> CacheFuture<'_, Option<Arc<Role>>, Self::Error>;
+
+    /// Gets a user by ID.
+    fn user(&self, user_id: UserId) -> CacheFuture<'_, Option<Arc<User>>, Self::Error>;
+
+    /// Gets the voice states within a voice channel.
+    fn voice_channel_states(
+        &self,
+        channel_id: ChannelId,
+    ) -> CacheFuture<'_, Option<Vec<Arc<VoiceState>>>, Self::Error>;
+
+    /// Gets a voice state by user ID and guild ID.
+    fn voice_state(
+        &self,
+        user_id: UserId,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<Arc<VoiceState>>, Self::Error>;
+}
+
+/// Lookups of the in-memory cache never fail and resolve immediately.
+impl Cache for InMemoryCache {
+    type Error = Infallible;
+    type Update = ();
+
+    fn current_user(&self) -> CacheFuture<'_, Option<Arc<CurrentUser>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::current_user(self))))
+    }
+
+    fn emoji(&self, emoji_id: EmojiId) -> CacheFuture<'_, Option<Arc<CachedEmoji>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::emoji(self, emoji_id))))
+    }
+
+    fn group(&self, channel_id: ChannelId) -> CacheFuture<'_, Option<Arc<Group>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::group(self, channel_id))))
+    }
+
+    fn guild(&self, guild_id: GuildId) -> CacheFuture<'_, Option<Arc<CachedGuild>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::guild(self, guild_id))))
+    }
+
+    fn guild_channel(
+        &self,
+        channel_id: ChannelId,
+    ) -> CacheFuture<'_, Option<Arc<GuildChannel>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::guild_channel(
+            self, channel_id,
+        ))))
+    }
+
+    fn guild_channels(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<ChannelId>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::guild_channels(
+            self, guild_id,
+        ))))
+    }
+
+    fn guild_emojis(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<EmojiId>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::guild_emojis(
+            self, guild_id,
+        ))))
+    }
+
+    fn guild_members(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<UserId>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::guild_members(
+            self, guild_id,
+        ))))
+    }
+
+    fn guild_presences(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<UserId>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::guild_presences(
+            self, guild_id,
+        ))))
+    }
+
+    fn guild_roles(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<RoleId>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::guild_roles(
+            self, guild_id,
+        ))))
+    }
+
+    fn member(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> CacheFuture<'_, Option<Arc<CachedMember>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::member(
+            self, guild_id, user_id,
+        ))))
+    }
+
+    fn message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> CacheFuture<'_, Option<Arc<CachedMessage>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::message(
+            self, channel_id, message_id,
+        ))))
+    }
+
+    fn presence(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> CacheFuture<'_, Option<Arc<CachedPresence>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::presence(
+            self, guild_id, user_id,
+        ))))
+    }
+
+    fn private_channel(
+        &self,
+        channel_id: ChannelId,
+    ) -> CacheFuture<'_, Option<Arc<PrivateChannel>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::private_channel(
+            self, channel_id,
+        ))))
+    }
+
+    fn role(&self, role_id: RoleId) -> CacheFuture<'_, Option<Arc<Role>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::role(self, role_id))))
+    }
+
+    fn user(&self, user_id: UserId) -> CacheFuture<'_, Option<Arc<User>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::user(self, user_id))))
+    }
+
+    fn voice_channel_states(
+        &self,
+        channel_id: ChannelId,
+    ) -> CacheFuture<'_, Option<Vec<Arc<VoiceState>>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::voice_channel_states(
+            self, channel_id,
+        ))))
+    }
+
+    fn voice_state(
+        &self,
+        user_id: UserId,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<Arc<VoiceState>>, Self::Error> {
+        Box::pin(future::ready(Ok(InMemoryCache::voice_state(
+            self, user_id, guild_id,
+        ))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cache;
+    use crate::InMemoryCache;
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{convert::Infallible, sync::Arc};
+    use twilight_model::{
+        guild::{Permissions, Role},
+        id::{GuildId, RoleId},
+    };
+
+    assert_impl_all!(InMemoryCache: Cache);
+    assert_obj_safe!(Cache<Error = Infallible, Update = ()>);
+
+    #[tokio::test]
+    async fn test_lookup_through_trait() {
+        async fn role<C: Cache>(cache: &C, role_id: RoleId) -> Option<Arc<Role>> {
+            cache.role(role_id).await.ok().flatten()
+        }
+
+        let cache = InMemoryCache::new();
+        cache.cache_role(
+            GuildId(1),
+            Role {
+                color: 0,
+                hoist: false,
+                id: RoleId(2),
+                managed: false,
+                mentionable: false,
+                name: "test".to_owned(),
+                permissions: Permissions::empty(),
+                position: 0,
+                tags: None,
+            },
+        );
+
+        assert!(role(&cache, RoleId(2)).await.is_some());
+        assert!(role(&cache, RoleId(3)).await.is_none());
+    }
+}
@@ -1,4 +1,6 @@
+use super::filter::Filter;
 use bitflags::bitflags;
+use std::time::Duration;
 
 bitflags! {
     /// A set of bitflags which can be used to specify what resource to process
@@ -19,6 +21,12 @@ bitflags! {
         const USER_CURRENT = 1 << 8;
         const USER = 1 << 9;
         const VOICE_STATE = 1 << 10;
+        const BAN = 1 << 11;
+        const INTEGRATION = 1 << 12;
+        const INVITE = 1 << 13;
+        const WEBHOOK = 1 << 14;
+        const STAGE_INSTANCE = 1 << 15;
+        const SCHEDULED_EVENT = 1 << 16;
     }
 }
 
@@ -27,11 +35,21 @@ bitflags! {
 /// [`InMemoryCache`]: crate::InMemoryCache
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Config {
+    pub(super) filter: Filter,
     pub(super) resource_types: ResourceType,
     pub(super) message_cache_size: usize,
+    pub(super) message_cache_size_global: Option<usize>,
+    pub(super) message_channel_limit: Option<usize>,
+    pub(super) message_ttl: Option<Duration>,
 }
 
 impl Config {
+    /// Returns an immutable reference to the filters of which guilds and
+    /// resources are cached.
+    pub fn filter(&self) -> &Filter {
+        &self.filter
+    }
+
     /// Returns an immutable reference to the message cache size.
     pub fn message_cache_size(&self) -> usize {
         self.message_cache_size
@@ -41,6 +59,41 @@ impl Config {
     pub fn message_cache_size_mut(&mut self) -> &mut usize {
         &mut self.message_cache_size
     }
+
+    /// Returns the number of messages to cache across all channels, if
+    /// limited.
+    pub fn message_cache_size_global(&self) -> Option<usize> {
+        self.message_cache_size_global
+    }
+
+    /// Returns a mutable reference to the number of messages to cache across
+    /// all channels.
+    pub fn message_cache_size_global_mut(&mut self) -> &mut Option<usize> {
+        &mut self.message_cache_size_global
+    }
+
+    /// Returns the number of channels to cache messages in, if limited.
+    pub fn message_channel_limit(&self) -> Option<usize> {
+        self.message_channel_limit
+    }
+
+    /// Returns a mutable reference to the number of channels to cache
+    /// messages in.
+    pub fn message_channel_limit_mut(&mut self) -> &mut Option<usize> {
+        &mut self.message_channel_limit
+    }
+
+    /// Returns the duration after which messages expire, if any.
+    pub fn message_ttl(&self) -> Option<Duration> {
+        self.message_ttl
+    }
+
+    /// Returns a mutable reference to the duration after which messages
+    /// expire.
+    pub fn message_ttl_mut(&mut self) -> &mut Option<Duration> {
+        &mut self.message_ttl
+    }
+
     /// Returns an immutable reference to the resource types enabled.
     pub fn resource_types(&self) -> ResourceType {
         self.resource_types
@@ -55,15 +108,19 @@ impl Config {
 impl Default for Config {
     fn default() -> Self {
         Self {
+            filter: Filter::default(),
             resource_types: ResourceType::all(),
             message_cache_size: 100,
+            message_cache_size_global: None,
+            message_channel_limit: None,
+            message_ttl: None,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, ResourceType};
+    use super::{Config, Filter, ResourceType};
 
     #[test]
     #[allow(clippy::cognitive_complexity)]
@@ -79,21 +136,37 @@ mod tests {
         assert_eq!(1 << 8, ResourceType::USER_CURRENT.bits());
         assert_eq!(1 << 9, ResourceType::USER.bits());
         assert_eq!(1 << 10, ResourceType::VOICE_STATE.bits());
+        assert_eq!(1 << 11, ResourceType::BAN.bits());
+        assert_eq!(1 << 12, ResourceType::INTEGRATION.bits());
+        assert_eq!(1 << 13, ResourceType::INVITE.bits());
+        assert_eq!(1 << 14, ResourceType::WEBHOOK.bits());
     }
 
     #[test]
     fn test_defaults() {
         let conf = Config {
+            filter: Filter::default(),
             resource_types: ResourceType::all(),
             message_cache_size: 100,
+            message_cache_size_global: None,
+            message_channel_limit: None,
+            message_ttl: None,
         };
         let default = Config::default();
         assert_eq!(conf.resource_types, default.resource_types);
         assert_eq!(conf.message_cache_size, default.message_cache_size);
+        assert_eq!(conf, default);
     }
 
     #[test]
     fn test_config_fields() {
-        static_assertions::assert_fields!(Config: resource_types, message_cache_size);
+        static_assertions::assert_fields!(
+            Config: filter,
+            resource_types,
+            message_cache_size,
+            message_cache_size_global,
+            message_channel_limit,
+            message_ttl
+        );
     }
 }
@@ -0,0 +1,387 @@
+use super::{
+    model::{CachedGuild, CachedMember, CachedMessage},
+    InMemoryCache,
+};
+use std::{ops::Deref, sync::Arc};
+use twilight_model::{
+    channel::{Channel, GuildChannel},
+    gateway::event::Event,
+    guild::Role,
+};
+
+/// Previous and new state of a resource changed by an event.
+///
+/// This is returned by [`InMemoryCache::update_with_diff`]. The previous value
+/// is `None` if the resource wasn't cached before the event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CacheDiff {
+    /// A guild channel was updated.
+    ChannelUpdate {
+        /// Channel after the update.
+        new: Arc<GuildChannel>,
+        /// Channel before the update.
+        old: Option<Arc<GuildChannel>>,
+    },
+    /// A guild was updated.
+    GuildUpdate {
+        /// Guild after the update.
+        new: Arc<CachedGuild>,
+        /// Guild before the update.
+        old: Option<Arc<CachedGuild>>,
+    },
+    /// A member was updated.
+    MemberUpdate {
+        /// Member after the update.
+        new: Arc<CachedMember>,
+        /// Member before the update.
+        old: Option<Arc<CachedMember>>,
+    },
+    /// A cached message was deleted.
+    MessageDelete {
+        /// Message that was deleted.
+        old: Arc<CachedMessage>,
+    },
+    /// Messages were deleted in bulk, at least one of which was cached.
+    MessageDeleteBulk {
+        /// Deleted messages that were cached.
+        old: Vec<Arc<CachedMessage>>,
+    },
+    /// A message was updated.
+    MessageUpdate {
+        /// Message after the update.
+        new: Arc<CachedMessage>,
+        /// Message before the update.
+        old: Option<Arc<CachedMessage>>,
+    },
+    /// A role was updated.
+    RoleUpdate {
+        /// Role after the update.
+        new: Arc<Role>,
+        /// Role before the update.
+        old: Option<Arc<Role>>,
+    },
+}
+
+/// Update the cache with an event, returning the diff of the changed resource
+/// if the event is of a kind that has one.
+///
+/// Previous values are looked up before the event is processed. The cache
+/// replaces values instead of mutating them in place while they're referenced,
+/// so the previous values stay intact.
+pub(super) fn update(cache: &InMemoryCache, event: &Event) -> Option<CacheDiff> {
+    match event {
+        Event::ChannelUpdate(update) => {
+            let channel_id = match &update.0 {
+                Channel::Guild(channel) => channel.id(),
+                Channel::Group(_) | Channel::Private(_) => {
+                    cache.update(update);
+
+                    return None;
+                }
+            };
+
+            let old = cache.guild_channel(channel_id);
+            cache.update(update);
+
+            Some(CacheDiff::ChannelUpdate {
+                new: cache.guild_channel(channel_id)?,
+                old,
+            })
+        }
+        Event::GuildUpdate(update) => {
+            let old = cache.guild(update.0.id);
+            cache.update(update.deref());
+
+            Some(CacheDiff::GuildUpdate {
+                new: cache.guild(update.0.id)?,
+                old,
+            })
+        }
+        Event::MemberUpdate(update) => {
+            let old = cache.member(update.guild_id, update.user.id);
+            cache.update(update.deref());
+
+            Some(CacheDiff::MemberUpdate {
+                new: cache.member(update.guild_id, update.user.id)?,
+                old,
+            })
+        }
+        Event::MessageDelete(delete) => {
+            let old = cache.message(delete.channel_id, delete.id);
+            cache.update(delete);
+
+            Some(CacheDiff::MessageDelete { old: old? })
+        }
+        Event::MessageDeleteBulk(delete) => {
+            let old = delete
+                .ids
+                .iter()
+                .filter_map(|id| cache.message(delete.channel_id, *id))
+                .collect::<Vec<_>>();
+            cache.update(delete);
+
+            if old.is_empty() {
+                return None;
+            }
+
+            Some(CacheDiff::MessageDeleteBulk { old })
+        }
+        Event::MessageUpdate(update) => {
+            let old = cache.message(update.channel_id, update.id);
+            cache.update(update.deref());
+
+            Some(CacheDiff::MessageUpdate {
+                new: cache.message(update.channel_id, update.id)?,
+                old,
+            })
+        }
+        Event::RoleUpdate(update) => {
+            let old = cache.role(update.role.id);
+            cache.update(update);
+
+            Some(CacheDiff::RoleUpdate {
+                new: cache.role(update.role.id)?,
+                old,
+            })
+        }
+        event => {
+            cache.update(event);
+
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CacheDiff;
+    use crate::InMemoryCache;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::fmt::Debug;
+    use twilight_model::{
+        channel::message::{Message, MessageType},
+        gateway::{
+            event::Event,
+            payload::{
+                MemberUpdate, MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
+                RoleUpdate,
+            },
+        },
+        guild::{Member, Permissions, Role},
+        id::{ChannelId, GuildId, MessageId, RoleId, UserId},
+        user::User,
+    };
+
+    assert_fields!(CacheDiff::ChannelUpdate: new, old);
+    assert_fields!(CacheDiff::GuildUpdate: new, old);
+    assert_fields!(CacheDiff::MemberUpdate: new, old);
+    assert_fields!(CacheDiff::MessageDelete: old);
+    assert_fields!(CacheDiff::MessageDeleteBulk: old);
+    assert_fields!(CacheDiff::MessageUpdate: new, old);
+    assert_fields!(CacheDiff::RoleUpdate: new, old);
+    assert_impl_all!(CacheDiff: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    fn message(id: u64) -> Message {
+        Message {
+            activity: None,
+            application: None,
+            attachments: Vec::new(),
+            author: user(),
+            channel_id: ChannelId(1),
+            components: Vec::new(),
+            content: "before".to_owned(),
+            edited_timestamp: None,
+            embeds: Vec::new(),
+            flags: None,
+            guild_id: None,
+            id: MessageId(id),
+            kind: MessageType::Regular,
+            member: None,
+            mention_channels: Vec::new(),
+            mention_everyone: false,
+            mention_roles: Vec::new(),
+            mentions: Vec::new(),
+            pinned: false,
+            reactions: Vec::new(),
+            reference: None,
+            stickers: Vec::new(),
+            referenced_message: None,
+            timestamp: String::new(),
+            tts: false,
+            webhook_id: None,
+        }
+    }
+
+    fn role(name: &str) -> Role {
+        Role {
+            color: 0,
+            hoist: false,
+            id: RoleId(2),
+            managed: false,
+            mentionable: false,
+            name: name.to_owned(),
+            permissions: Permissions::empty(),
+            position: 0,
+            tags: None,
+        }
+    }
+
+    #[test]
+    fn test_role_update_diff() {
+        let cache = InMemoryCache::new();
+        cache.cache_role(GuildId(1), role("before"));
+
+        let diff = cache.update_with_diff(&Event::RoleUpdate(RoleUpdate {
+            guild_id: GuildId(1),
+            role: role("after"),
+        }));
+
+        match diff {
+            Some(CacheDiff::RoleUpdate { new, old }) => {
+                assert_eq!("after", new.name);
+                assert_eq!("before", old.expect("role was cached").name);
+            }
+            other => panic!("unexpected diff: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_uncached_message_delete_diff() {
+        let cache = InMemoryCache::new();
+
+        let diff = cache.update_with_diff(&Event::MessageDelete(MessageDelete {
+            channel_id: ChannelId(1),
+            guild_id: None,
+            id: MessageId(2),
+        }));
+
+        assert!(diff.is_none());
+    }
+
+    fn user() -> User {
+        User {
+            avatar: None,
+            bot: false,
+            discriminator: "0001".to_owned(),
+            email: None,
+            flags: None,
+            id: UserId(3),
+            locale: None,
+            mfa_enabled: None,
+            name: "test".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    #[test]
+    fn test_member_update_diff() {
+        let cache = InMemoryCache::new();
+        cache.cache_member(
+            GuildId(1),
+            Member {
+                deaf: false,
+                guild_id: GuildId(1),
+                hoisted_role: None,
+                joined_at: None,
+                mute: false,
+                nick: Some("before".to_owned()),
+                pending: false,
+                premium_since: None,
+                roles: Vec::new(),
+                user: user(),
+            },
+        );
+
+        let diff = cache.update_with_diff(&Event::MemberUpdate(Box::new(MemberUpdate {
+            guild_id: GuildId(1),
+            joined_at: String::new(),
+            nick: Some("after".to_owned()),
+            pending: false,
+            premium_since: None,
+            roles: vec![RoleId(2)],
+            user: user(),
+        })));
+
+        // The member is updated in place, which must not affect the previous
+        // value.
+        match diff {
+            Some(CacheDiff::MemberUpdate { new, old }) => {
+                let old = old.expect("member was cached");
+                assert_eq!(Some("before"), old.nick.as_deref());
+                assert!(old.roles.is_empty());
+                assert_eq!(Some("after"), new.nick.as_deref());
+                assert_eq!(vec![RoleId(2)], new.roles);
+            }
+            other => panic!("unexpected diff: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_message_update_diff() {
+        let cache = InMemoryCache::new();
+        cache.update(&MessageCreate(message(2)));
+
+        let diff = cache.update_with_diff(&Event::MessageUpdate(Box::new(MessageUpdate {
+            attachments: None,
+            author: None,
+            channel_id: ChannelId(1),
+            components: None,
+            content: Some("after".to_owned()),
+            edited_timestamp: None,
+            embeds: None,
+            guild_id: None,
+            id: MessageId(2),
+            kind: None,
+            mention_everyone: None,
+            mention_roles: None,
+            mentions: None,
+            pinned: Some(true),
+            timestamp: None,
+            tts: None,
+        })));
+
+        match diff {
+            Some(CacheDiff::MessageUpdate { new, old }) => {
+                let old = old.expect("message was cached");
+                assert_eq!("before", old.content);
+                assert!(!old.pinned);
+                assert_eq!("after", new.content);
+                assert!(new.pinned);
+            }
+            other => panic!("unexpected diff: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_message_delete_bulk_diff() {
+        let cache = InMemoryCache::new();
+        cache.update(&MessageCreate(message(2)));
+
+        let diff = cache.update_with_diff(&Event::MessageDeleteBulk(MessageDeleteBulk {
+            channel_id: ChannelId(1),
+            guild_id: None,
+            ids: vec![MessageId(2), MessageId(3)],
+        }));
+
+        match diff {
+            Some(CacheDiff::MessageDeleteBulk { old }) => {
+                assert_eq!(1, old.len());
+                assert_eq!(MessageId(2), old[0].id);
+            }
+            other => panic!("unexpected diff: {:?}", other),
+        }
+
+        // None of the messages are cached anymore.
+        let diff = cache.update_with_diff(&Event::MessageDeleteBulk(MessageDeleteBulk {
+            channel_id: ChannelId(1),
+            guild_id: None,
+            ids: vec![MessageId(2)],
+        }));
+
+        assert!(diff.is_none());
+    }
+}
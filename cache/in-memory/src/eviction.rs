@@ -0,0 +1,337 @@
+use super::{model::CachedMessage, InMemoryCache};
+use std::{
+    collections::BTreeSet,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use twilight_model::id::{ChannelId, MessageId};
+
+/// Discord's epoch, the unix time in milliseconds of the first second of 2015.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Order of the cached messages and of the channels they're in.
+///
+/// Message IDs are snowflakes, so ordering by them orders by the time the
+/// messages were sent. This is used to evict the oldest messages across all
+/// channels and the channels with the least recent messages.
+#[derive(Debug, Default)]
+pub(super) struct MessageOrder {
+    /// Channels with cached messages by the ID of their newest message, least
+    /// recently active first.
+    channels: BTreeSet<(MessageId, ChannelId)>,
+    /// All cached messages, oldest first.
+    messages: BTreeSet<(MessageId, ChannelId)>,
+}
+
+/// Cache a message, evicting messages according to the configured limits.
+pub(super) fn cache_message(cache: &InMemoryCache, message: CachedMessage) {
+    let channel_id = message.channel_id;
+    let mut order = cache
+        .0
+        .message_order
+        .lock()
+        .expect("message order poisoned");
+
+    {
+        let mut channel = cache.0.messages.entry(channel_id).or_default();
+        let newest = channel.keys().next_back().copied();
+
+        order.messages.insert((message.id, channel_id));
+        channel.insert(message.id, Arc::new(message));
+
+        // Evict the oldest messages of the channel beyond the channel's limit.
+        while channel.len() > cache.0.config.message_cache_size() {
+            let oldest = match channel.keys().next() {
+                Some(oldest) => *oldest,
+                None => break,
+            };
+
+            channel.remove(&oldest);
+            order.messages.remove(&(oldest, channel_id));
+        }
+
+        order.reorder_channel(channel_id, newest, channel.keys().next_back().copied());
+    }
+
+    cache
+        .0
+        .messages
+        .remove_if(&channel_id, |_, messages| messages.is_empty());
+
+    evict(cache, &mut order);
+}
+
+/// Remove a message from the cache.
+pub(super) fn remove_message(
+    cache: &InMemoryCache,
+    channel_id: ChannelId,
+    message_id: MessageId,
+) -> Option<Arc<CachedMessage>> {
+    let mut order = cache
+        .0
+        .message_order
+        .lock()
+        .expect("message order poisoned");
+
+    remove(cache, &mut order, channel_id, message_id)
+}
+
+/// Rebuild the order of the cached messages, such as after restoring them from
+/// a snapshot.
+pub(super) fn reorder(cache: &InMemoryCache) {
+    let mut order = cache
+        .0
+        .message_order
+        .lock()
+        .expect("message order poisoned");
+    *order = MessageOrder::default();
+
+    for channel in cache.0.messages.iter() {
+        let channel_id = *channel.key();
+
+        order
+            .messages
+            .extend(channel.keys().map(|message_id| (*message_id, channel_id)));
+
+        if let Some(newest) = channel.keys().next_back() {
+            order.channels.insert((*newest, channel_id));
+        }
+    }
+
+    evict(cache, &mut order);
+}
+
+impl MessageOrder {
+    /// Clear the order, for when all messages are removed from the cache.
+    pub(super) fn clear(&mut self) {
+        self.channels.clear();
+        self.messages.clear();
+    }
+
+    /// Update the position of a channel after its newest message changed.
+    fn reorder_channel(
+        &mut self,
+        channel_id: ChannelId,
+        previous: Option<MessageId>,
+        newest: Option<MessageId>,
+    ) {
+        if previous == newest {
+            return;
+        }
+
+        if let Some(previous) = previous {
+            self.channels.remove(&(previous, channel_id));
+        }
+
+        if let Some(newest) = newest {
+            self.channels.insert((newest, channel_id));
+        }
+    }
+}
+
+/// Evict messages beyond the global limit, messages that expired, and the
+/// messages of the least recently active channels beyond the channel limit.
+fn evict(cache: &InMemoryCache, order: &mut MessageOrder) {
+    let config = &cache.0.config;
+
+    if let Some(limit) = config.message_cache_size_global() {
+        while order.messages.len() > limit {
+            let (message_id, channel_id) = match order.messages.iter().next() {
+                Some(oldest) => *oldest,
+                None => break,
+            };
+
+            remove(cache, order, channel_id, message_id);
+        }
+    }
+
+    if let Some(ttl) = config.message_ttl() {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let cutoff = now.checked_sub(ttl).unwrap_or_default();
+        #[allow(clippy::cast_possible_truncation)]
+        let cutoff = MessageId((cutoff.as_millis() as u64).saturating_sub(DISCORD_EPOCH) << 22);
+
+        while let Some((message_id, channel_id)) = order.messages.iter().next().copied() {
+            if message_id >= cutoff {
+                break;
+            }
+
+            remove(cache, order, channel_id, message_id);
+        }
+    }
+
+    if let Some(limit) = config.message_channel_limit() {
+        while order.channels.len() > limit {
+            let channel = match order.channels.iter().next() {
+                Some(channel) => *channel,
+                None => break,
+            };
+
+            order.channels.remove(&channel);
+
+            let channel_id = channel.1;
+
+            if let Some((_, messages)) = cache.0.messages.remove(&channel_id) {
+                for message_id in messages.keys() {
+                    order.messages.remove(&(*message_id, channel_id));
+                }
+            }
+        }
+    }
+}
+
+fn remove(
+    cache: &InMemoryCache,
+    order: &mut MessageOrder,
+    channel_id: ChannelId,
+    message_id: MessageId,
+) -> Option<Arc<CachedMessage>> {
+    order.messages.remove(&(message_id, channel_id));
+
+    let message = {
+        let mut channel = cache.0.messages.get_mut(&channel_id)?;
+        let newest = channel.keys().next_back().copied();
+        let message = channel.remove(&message_id);
+
+        order.reorder_channel(channel_id, newest, channel.keys().next_back().copied());
+
+        message
+    };
+
+    cache
+        .0
+        .messages
+        .remove_if(&channel_id, |_, messages| messages.is_empty());
+
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cache_message, remove_message, DISCORD_EPOCH};
+    use crate::{model::CachedMessage, InMemoryCache};
+    use std::{
+        collections::HashSet,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+    use twilight_model::{
+        channel::message::MessageType,
+        id::{ChannelId, MessageId, UserId},
+    };
+
+    fn message(channel_id: u64, message_id: u64) -> CachedMessage {
+        CachedMessage {
+            id: MessageId(message_id),
+            activity: None,
+            application: None,
+            attachments: Vec::new(),
+            author: UserId(1),
+            channel_id: ChannelId(channel_id),
+            components: Vec::new(),
+            content: String::new(),
+            edited_timestamp: None,
+            embeds: Vec::new(),
+            flags: None,
+            guild_id: None,
+            kind: MessageType::Regular,
+            member: None,
+            mention_channels: Vec::new(),
+            mention_everyone: false,
+            mention_roles: Vec::new(),
+            mentions: Vec::new(),
+            pinned: false,
+            reactions: Vec::new(),
+            reference: None,
+            stickers: Vec::new(),
+            timestamp: String::new(),
+            tts: false,
+            webhook_id: None,
+        }
+    }
+
+    fn cached(cache: &InMemoryCache) -> HashSet<(u64, u64)> {
+        cache
+            .0
+            .messages
+            .iter()
+            .flat_map(|channel| {
+                let channel_id = channel.key().0;
+
+                channel
+                    .keys()
+                    .map(move |message_id| (channel_id, message_id.0))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_channel_size_evicts_oldest() {
+        let cache = InMemoryCache::builder().message_cache_size(2).build();
+
+        for id in 1..=3 {
+            cache_message(&cache, message(1, id));
+        }
+
+        assert_eq!(
+            vec![(1, 2), (1, 3)].into_iter().collect::<HashSet<_>>(),
+            cached(&cache)
+        );
+    }
+
+    #[test]
+    fn test_global_size() {
+        let cache = InMemoryCache::builder()
+            .message_cache_size_global(2)
+            .build();
+        cache_message(&cache, message(1, 1));
+        cache_message(&cache, message(2, 2));
+        cache_message(&cache, message(1, 3));
+
+        assert_eq!(
+            vec![(2, 2), (1, 3)].into_iter().collect::<HashSet<_>>(),
+            cached(&cache)
+        );
+
+        remove_message(&cache, ChannelId(2), MessageId(2));
+        assert!(cache.0.messages.get(&ChannelId(2)).is_none());
+    }
+
+    #[test]
+    fn test_channel_limit_evicts_least_recently_active() {
+        let cache = InMemoryCache::builder().message_channel_limit(2).build();
+        cache_message(&cache, message(1, 1));
+        cache_message(&cache, message(2, 2));
+        cache_message(&cache, message(1, 3));
+        cache_message(&cache, message(3, 4));
+
+        assert_eq!(
+            vec![(1, 1), (1, 3), (3, 4)]
+                .into_iter()
+                .collect::<HashSet<_>>(),
+            cached(&cache)
+        );
+    }
+
+    #[test]
+    fn test_ttl() {
+        let cache = InMemoryCache::builder()
+            .message_ttl(Duration::from_secs(60))
+            .build();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let recent = (now - DISCORD_EPOCH) << 22;
+
+        cache_message(&cache, message(1, 1));
+        cache_message(&cache, message(1, recent));
+
+        assert_eq!(
+            vec![(1, recent)].into_iter().collect::<HashSet<_>>(),
+            cached(&cache)
+        );
+    }
+}
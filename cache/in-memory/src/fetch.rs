@@ -0,0 +1,690 @@
+use super::{
+    eviction,
+    model::{CachedEmoji, CachedGuild, CachedMember, CachedMessage},
+    InMemoryCache, ResourceType,
+};
+use dashmap::DashMap;
+use std::{
+    any::Any,
+    collections::{hash_map::Entry, BTreeSet, HashMap},
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+use twilight_http::{Client, Error};
+use twilight_model::{
+    channel::{Channel, GuildChannel},
+    guild::{Emoji, Guild, Member},
+    id::{ChannelId, EmojiId, GuildId, MessageId, UserId},
+    user::User,
+};
+
+/// Value fetched by the leader of a request, shared with the waiting requests.
+type Shared = Arc<dyn Any + Send + Sync>;
+
+/// Resource being fetched, used for deduplicating requests and remembering
+/// resources that don't exist.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Key {
+    Channel(ChannelId),
+    Emoji(GuildId, EmojiId),
+    Guild(GuildId),
+    Member(GuildId, UserId),
+    Message(ChannelId, MessageId),
+    User(UserId),
+}
+
+/// State of a request that other requests for the same resource wait on.
+#[derive(Debug, Default)]
+struct Flight {
+    finished: bool,
+    /// Result of the request, if it succeeded.
+    value: Option<Option<Shared>>,
+    wakers: Vec<Waker>,
+}
+
+/// Marks a request as finished and wakes the waiting requests when dropped,
+/// including when the request is cancelled.
+struct FlightGuard<'a> {
+    fetch: &'a FetchCacheRef,
+    flight: Arc<Mutex<Flight>>,
+    key: Key,
+}
+
+impl FlightGuard<'_> {
+    fn finish(self, value: Option<Shared>) {
+        self.flight.lock().expect("flight poisoned").value = Some(value);
+    }
+}
+
+impl Drop for FlightGuard<'_> {
+    fn drop(&mut self) {
+        self.fetch
+            .in_flight
+            .lock()
+            .expect("in flight poisoned")
+            .remove(&self.key);
+
+        let mut flight = self.flight.lock().expect("flight poisoned");
+        flight.finished = true;
+
+        for waker in mem::take(&mut flight.wakers) {
+            waker.wake();
+        }
+    }
+}
+
+/// Future resolving to the result of another request for the same resource.
+struct Wait(Arc<Mutex<Flight>>);
+
+impl Future for Wait {
+    type Output = Option<Option<Shared>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut flight = self.0.lock().expect("flight poisoned");
+
+        if flight.finished {
+            return Poll::Ready(flight.value.clone());
+        }
+
+        flight.wakers.push(cx.waker().clone());
+
+        Poll::Pending
+    }
+}
+
+enum Claim<'a> {
+    Leader(FlightGuard<'a>),
+    Follower(Wait),
+}
+
+#[derive(Debug)]
+struct FetchCacheRef {
+    cache: InMemoryCache,
+    http: Client,
+    in_flight: Mutex<HashMap<Key, Arc<Mutex<Flight>>>>,
+    not_found: DashMap<Key, Instant>,
+    not_found_ttl: Duration,
+}
+
+/// Cache that fetches resources over HTTP when they aren't cached.
+///
+/// Each getter returns the cached resource if there is one. Otherwise it's
+/// fetched, inserted into the cache if the cache's configuration wants it, and
+/// returned.
+///
+/// Concurrent requests for the same resource are deduplicated: only one of
+/// them is sent, and the others wait for its result. Resources that don't
+/// exist are remembered for a short time, so repeatedly getting them doesn't
+/// send a request each time.
+///
+/// This requires the `twilight-http` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::env;
+/// use twilight_cache_inmemory::{FetchCache, InMemoryCache};
+/// use twilight_http::Client;
+/// use twilight_model::id::UserId;
+///
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let cache = InMemoryCache::new();
+/// let http = Client::new(env::var("DISCORD_TOKEN")?);
+/// let fetch = FetchCache::new(cache, http);
+///
+/// if let Some(user) = fetch.user(UserId(3)).await? {
+///     println!("{}#{}", user.name, user.discriminator);
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// Cloning is cheap, and the clones share the same cache, client, and
+/// requests.
+#[derive(Clone, Debug)]
+pub struct FetchCache(Arc<FetchCacheRef>);
+
+impl FetchCache {
+    /// Default duration that resources that don't exist are remembered for.
+    pub const NOT_FOUND_TTL: Duration = Duration::from_secs(30);
+
+    /// Create a new fetching cache, remembering resources that don't exist
+    /// for [`NOT_FOUND_TTL`].
+    ///
+    /// [`NOT_FOUND_TTL`]: Self::NOT_FOUND_TTL
+    pub fn new(cache: InMemoryCache, http: Client) -> Self {
+        Self::with_not_found_ttl(cache, http, Self::NOT_FOUND_TTL)
+    }
+
+    /// Create a new fetching cache, remembering resources that don't exist
+    /// for the given duration.
+    ///
+    /// A duration of zero doesn't remember them.
+    pub fn with_not_found_ttl(cache: InMemoryCache, http: Client, not_found_ttl: Duration) -> Self {
+        Self(Arc::new(FetchCacheRef {
+            cache,
+            http,
+            in_flight: Mutex::default(),
+            not_found: DashMap::new(),
+            not_found_ttl,
+        }))
+    }
+
+    /// Return an immutable reference to the cache.
+    pub fn cache(&self) -> &InMemoryCache {
+        &self.0.cache
+    }
+
+    /// Return an immutable reference to the HTTP client.
+    pub fn http(&self) -> &Client {
+        &self.0.http
+    }
+
+    /// Return the duration that resources that don't exist are remembered
+    /// for.
+    pub fn not_found_ttl(&self) -> Duration {
+        self.0.not_found_ttl
+    }
+
+    /// Get an emoji by guild ID and emoji ID, fetching it if it isn't cached.
+    ///
+    /// Returns `None` if the emoji doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the emoji couldn't be fetched.
+    pub async fn emoji(
+        &self,
+        guild_id: GuildId,
+        emoji_id: EmojiId,
+    ) -> Result<Option<Arc<CachedEmoji>>, Error> {
+        self.get(
+            Key::Emoji(guild_id, emoji_id),
+            |cache| cache.emoji(emoji_id),
+            || async move {
+                let emoji = match self.0.http.emoji(guild_id, emoji_id).await? {
+                    Some(response) => response.model().await?,
+                    None => return Ok(None),
+                };
+
+                Ok(Some(self.insert_emoji(guild_id, emoji)))
+            },
+        )
+        .await
+    }
+
+    /// Get a guild by ID, fetching it if it isn't cached.
+    ///
+    /// Fetching a guild caches its emojis and roles, but not its channels or
+    /// members, which aren't included.
+    ///
+    /// Returns `None` if the guild doesn't exist or the current user isn't in
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the guild couldn't be fetched.
+    pub async fn guild(&self, guild_id: GuildId) -> Result<Option<Arc<CachedGuild>>, Error> {
+        self.get(
+            Key::Guild(guild_id),
+            |cache| cache.guild(guild_id),
+            || async move {
+                let guild = match self.0.http.guild(guild_id).await?.model().await? {
+                    Some(guild) => guild,
+                    None => return Ok(None),
+                };
+
+                let cache = &self.0.cache;
+
+                if !cache.wants(ResourceType::GUILD) || !cache.filter().wants_guild(guild_id) {
+                    return Ok(Some(Arc::new(CachedGuild::from(guild))));
+                }
+
+                // A guild received over the gateway while fetching includes
+                // its channels and members, so don't replace it.
+                if let Some(cached) = cache.guild(guild_id) {
+                    return Ok(Some(cached));
+                }
+
+                Ok(Some(self.insert_guild(guild)))
+            },
+        )
+        .await
+    }
+
+    /// Get a guild channel by ID, fetching it if it isn't cached.
+    ///
+    /// Returns `None` if the channel doesn't exist or isn't a guild channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the channel couldn't be fetched.
+    pub async fn guild_channel(
+        &self,
+        channel_id: ChannelId,
+    ) -> Result<Option<Arc<GuildChannel>>, Error> {
+        self.get(
+            Key::Channel(channel_id),
+            |cache| cache.guild_channel(channel_id),
+            || async move {
+                let channel = match self.0.http.channel(channel_id).await? {
+                    Some(response) => response.model().await?,
+                    None => return Ok(None),
+                };
+
+                let channel = match channel {
+                    Channel::Guild(channel) => channel,
+                    _ => return Ok(None),
+                };
+
+                let cache = &self.0.cache;
+
+                match channel.guild_id() {
+                    Some(guild_id)
+                        if cache.wants(ResourceType::CHANNEL)
+                            && cache.filter().wants_guild(guild_id)
+                            && cache.filter().wants_channel(guild_id, &channel) =>
+                    {
+                        Ok(Some(cache.cache_guild_channel(guild_id, channel)))
+                    }
+                    Some(_) | None => Ok(Some(Arc::new(channel))),
+                }
+            },
+        )
+        .await
+    }
+
+    /// Get a member by guild ID and user ID, fetching it if it isn't cached.
+    ///
+    /// Returns `None` if the member doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the member couldn't be fetched.
+    pub async fn member(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<Option<Arc<CachedMember>>, Error> {
+        self.get(
+            Key::Member(guild_id, user_id),
+            |cache| cache.member(guild_id, user_id),
+            || async move {
+                let member = match self.0.http.guild_member(guild_id, user_id).await? {
+                    Some(response) => response.model().await?,
+                    None => return Ok(None),
+                };
+
+                Ok(Some(self.insert_member(guild_id, member)))
+            },
+        )
+        .await
+    }
+
+    /// Get a message by channel ID and message ID, fetching it if it isn't
+    /// cached.
+    ///
+    /// Returns `None` if the message doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message couldn't be fetched.
+    pub async fn message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<Option<Arc<CachedMessage>>, Error> {
+        self.get(
+            Key::Message(channel_id, message_id),
+            |cache| cache.message(channel_id, message_id),
+            || async move {
+                let message = match self.0.http.message(channel_id, message_id).await? {
+                    Some(response) => response.model().await?,
+                    None => return Ok(None),
+                };
+
+                let cache = &self.0.cache;
+                let wanted =
+                    cache.wants(ResourceType::MESSAGE) && cache.filter().wants_message(&message);
+                let message = CachedMessage::from(message);
+
+                if wanted {
+                    eviction::cache_message(cache, message.clone());
+
+                    // The message may have been evicted right away if it's
+                    // older than the other cached messages.
+                    if let Some(cached) = cache.message(channel_id, message_id) {
+                        return Ok(Some(cached));
+                    }
+                }
+
+                Ok(Some(Arc::new(message)))
+            },
+        )
+        .await
+    }
+
+    /// Get a user by ID, fetching it if it isn't cached.
+    ///
+    /// Fetched users that don't share a guild with the current user are
+    /// cached until the cache is cleared.
+    ///
+    /// Returns `None` if the user doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user couldn't be fetched.
+    pub async fn user(&self, user_id: UserId) -> Result<Option<Arc<User>>, Error> {
+        self.get(
+            Key::User(user_id),
+            |cache| cache.user(user_id),
+            || async move {
+                let user = match self.0.http.user(user_id).await? {
+                    Some(response) => Arc::new(response.model().await?),
+                    None => return Ok(None),
+                };
+
+                if self.0.cache.wants(ResourceType::USER) {
+                    self.0
+                        .cache
+                        .0
+                        .users
+                        .entry(user_id)
+                        .and_modify(|(cached, _)| *cached = Arc::clone(&user))
+                        .or_insert_with(|| (Arc::clone(&user), BTreeSet::new()));
+                }
+
+                Ok(Some(user))
+            },
+        )
+        .await
+    }
+
+    /// Get a resource from the cache, or fetch it if it isn't cached.
+    ///
+    /// Only one request is sent for concurrent calls with the same key. If it
+    /// fails or is cancelled, one of the waiting calls sends a new request.
+    async fn get<'a, T, Fut>(
+        &'a self,
+        key: Key,
+        cached: impl Fn(&InMemoryCache) -> Option<Arc<T>>,
+        fetch: impl FnOnce() -> Fut,
+    ) -> Result<Option<Arc<T>>, Error>
+    where
+        T: Send + Sync + 'static,
+        Fut: Future<Output = Result<Option<Arc<T>>, Error>> + 'a,
+    {
+        let mut fetch = Some(fetch);
+
+        loop {
+            if let Some(value) = cached(&self.0.cache) {
+                return Ok(Some(value));
+            }
+
+            if self.is_not_found(key) {
+                return Ok(None);
+            }
+
+            let guard = match self.claim(key) {
+                Claim::Leader(guard) => guard,
+                Claim::Follower(wait) => match wait.await {
+                    Some(Some(value)) => {
+                        return Ok(Some(
+                            Arc::downcast(value).expect("value of key has same type"),
+                        ))
+                    }
+                    Some(None) => return Ok(None),
+                    None => continue,
+                },
+            };
+
+            // The leader always returns, so the fetch is only taken once.
+            let fetch = fetch.take().expect("fetch only called once");
+            let result = fetch().await;
+
+            match result {
+                Ok(Some(ref value)) => guard.finish(Some(Arc::clone(value) as Shared)),
+                Ok(None) => {
+                    self.insert_not_found(key);
+
+                    guard.finish(None);
+                }
+                Err(_) => drop(guard),
+            }
+
+            return result;
+        }
+    }
+
+    /// Become the leader of the requests for a key, or wait for the current
+    /// leader.
+    fn claim(&self, key: Key) -> Claim<'_> {
+        let mut in_flight = self.0.in_flight.lock().expect("in flight poisoned");
+
+        match in_flight.entry(key) {
+            Entry::Occupied(entry) => Claim::Follower(Wait(Arc::clone(entry.get()))),
+            Entry::Vacant(entry) => {
+                let flight = Arc::clone(entry.insert(Arc::default()));
+
+                Claim::Leader(FlightGuard {
+                    fetch: &self.0,
+                    flight,
+                    key,
+                })
+            }
+        }
+    }
+
+    /// Remember that a resource doesn't exist.
+    ///
+    /// Expired entries are purged, so resources that are never requested
+    /// again don't accumulate.
+    fn insert_not_found(&self, key: Key) {
+        let ttl = self.0.not_found_ttl;
+
+        if ttl == Duration::from_secs(0) {
+            return;
+        }
+
+        self.0.not_found.retain(|_, at| at.elapsed() < ttl);
+        self.0.not_found.insert(key, Instant::now());
+    }
+
+    /// Whether a resource was recently found to not exist.
+    fn is_not_found(&self, key: Key) -> bool {
+        let ttl = self.0.not_found_ttl;
+
+        self.0
+            .not_found
+            .remove_if(&key, |_, at| at.elapsed() >= ttl);
+
+        self.0.not_found.contains_key(&key)
+    }
+
+    fn insert_emoji(&self, guild_id: GuildId, emoji: Emoji) -> Arc<CachedEmoji> {
+        let cache = &self.0.cache;
+
+        if cache.wants(ResourceType::EMOJI)
+            && cache.filter().wants_guild(guild_id)
+            && cache.filter().wants_emoji(guild_id, &emoji)
+        {
+            return cache.cache_emoji(guild_id, emoji);
+        }
+
+        Arc::new(CachedEmoji {
+            id: emoji.id,
+            animated: emoji.animated,
+            name: emoji.name,
+            managed: emoji.managed,
+            require_colons: emoji.require_colons,
+            roles: emoji.roles,
+            user: emoji.user.map(Arc::new),
+            available: emoji.available,
+        })
+    }
+
+    fn insert_guild(&self, mut guild: Guild) -> Arc<CachedGuild> {
+        let cache = &self.0.cache;
+
+        if cache.wants(ResourceType::EMOJI) {
+            cache.cache_emojis(guild.id, mem::take(&mut guild.emojis));
+        }
+
+        if cache.wants(ResourceType::ROLE) {
+            cache.cache_roles(guild.id, mem::take(&mut guild.roles));
+        }
+
+        let guild = Arc::new(CachedGuild::from(guild));
+
+        cache.0.unavailable_guilds.remove(&guild.id);
+        cache.0.guilds.insert(guild.id, Arc::clone(&guild));
+
+        guild
+    }
+
+    fn insert_member(&self, guild_id: GuildId, member: Member) -> Arc<CachedMember> {
+        let cache = &self.0.cache;
+
+        if cache.wants(ResourceType::MEMBER)
+            && cache.filter().wants_guild(guild_id)
+            && cache
+                .filter()
+                .wants_member(guild_id, &member, cache.current_user_id())
+        {
+            return cache.cache_member(guild_id, member);
+        }
+
+        let user = match cache.user(member.user.id) {
+            Some(user) if *user == member.user => user,
+            Some(_) | None => Arc::new(member.user),
+        };
+
+        Arc::new(CachedMember {
+            deaf: member.deaf,
+            guild_id,
+            joined_at: member.joined_at,
+            mute: member.mute,
+            nick: member.nick,
+            pending: member.pending,
+            premium_since: member.premium_since,
+            roles: member.roles,
+            user,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FetchCache, Key};
+    use crate::InMemoryCache;
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+    use twilight_http::Client;
+    use twilight_model::{id::UserId, user::User};
+
+    assert_impl_all!(FetchCache: Clone, Debug, Send, Sync);
+
+    fn fetch_cache(ttl: Duration) -> FetchCache {
+        FetchCache::with_not_found_ttl(InMemoryCache::new(), Client::new("token"), ttl)
+    }
+
+    fn user(id: u64) -> User {
+        User {
+            avatar: None,
+            bot: false,
+            discriminator: "0001".to_owned(),
+            email: None,
+            flags: None,
+            id: UserId(id),
+            locale: None,
+            mfa_enabled: None,
+            name: "twilight".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deduplicates_requests() {
+        let fetch = fetch_cache(FetchCache::NOT_FOUND_TTL);
+        let calls = AtomicUsize::new(0);
+
+        let get = || {
+            fetch.get(
+                Key::User(UserId(1)),
+                |_| None,
+                || async {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    tokio::task::yield_now().await;
+
+                    Ok(Some(Arc::new(user(1))))
+                },
+            )
+        };
+
+        let (a, b, c) = tokio::join!(get(), get(), get());
+
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+        assert!(Arc::ptr_eq(&a.unwrap().unwrap(), &b.unwrap().unwrap()));
+        assert_eq!(UserId(1), c.unwrap().unwrap().id);
+        assert!(fetch.0.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let fetch = fetch_cache(FetchCache::NOT_FOUND_TTL);
+        let calls = AtomicUsize::new(0);
+
+        for _ in 0..2 {
+            let value = fetch
+                .get::<User, _>(
+                    Key::User(UserId(1)),
+                    |_| None,
+                    || async {
+                        calls.fetch_add(1, Ordering::SeqCst);
+
+                        Ok(None)
+                    },
+                )
+                .await;
+
+            assert!(value.unwrap().is_none());
+        }
+
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+
+        // Without a duration, resources that don't exist aren't remembered.
+        let fetch = fetch_cache(Duration::from_secs(0));
+        let value = fetch
+            .get::<User, _>(Key::User(UserId(1)), |_| None, || async { Ok(None) })
+            .await;
+
+        assert!(value.unwrap().is_none());
+        assert!(!fetch.is_not_found(Key::User(UserId(1))));
+    }
+
+    #[tokio::test]
+    async fn test_not_found_purged() {
+        let fetch = fetch_cache(Duration::from_millis(10));
+        fetch.insert_not_found(Key::User(UserId(1)));
+        fetch.insert_not_found(Key::User(UserId(2)));
+        assert_eq!(2, fetch.0.not_found.len());
+
+        std::thread::sleep(Duration::from_millis(20));
+        fetch.insert_not_found(Key::User(UserId(3)));
+        assert_eq!(1, fetch.0.not_found.len());
+        assert!(fetch.is_not_found(Key::User(UserId(3))));
+    }
+}
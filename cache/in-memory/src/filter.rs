@@ -0,0 +1,220 @@
+use std::{
+    collections::HashSet,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+use twilight_model::{
+    channel::{ChannelType, GuildChannel, Message},
+    gateway::presence::Presence,
+    guild::{Emoji, Member, Role},
+    id::{GuildId, UserId},
+    voice::VoiceState,
+};
+
+/// Predicate deciding whether a resource is cached.
+pub(super) type Predicate<T> = Arc<dyn Fn(&T) -> bool + Send + Sync>;
+
+/// Filters deciding which guilds and resources within them are cached.
+///
+/// Filters are configured via the [`InMemoryCacheBuilder`] and apply on top
+/// of the enabled [`ResourceType`]s. By default nothing is filtered.
+///
+/// [`InMemoryCacheBuilder`]: crate::InMemoryCacheBuilder
+/// [`ResourceType`]: crate::ResourceType
+#[derive(Clone, Default)]
+pub struct Filter {
+    pub(super) channel: Option<Predicate<GuildChannel>>,
+    pub(super) channel_types: Option<HashSet<ChannelType>>,
+    pub(super) current_member_only: bool,
+    pub(super) emoji: Option<Predicate<Emoji>>,
+    pub(super) guilds: Option<HashSet<GuildId>>,
+    pub(super) member: Option<Predicate<Member>>,
+    pub(super) message: Option<Predicate<Message>>,
+    pub(super) presence: Option<Predicate<Presence>>,
+    pub(super) role: Option<Predicate<Role>>,
+    pub(super) voice_state: Option<Predicate<VoiceState>>,
+}
+
+impl Filter {
+    /// Returns the types of guild channels to cache, if limited.
+    pub fn channel_types(&self) -> Option<&HashSet<ChannelType>> {
+        self.channel_types.as_ref()
+    }
+
+    /// Returns whether only the member of the current user is cached in each
+    /// guild.
+    pub fn current_member_only(&self) -> bool {
+        self.current_member_only
+    }
+
+    /// Returns the IDs of the guilds to cache, if limited.
+    pub fn guilds(&self) -> Option<&HashSet<GuildId>> {
+        self.guilds.as_ref()
+    }
+
+    /// Whether a guild channel is cached.
+    pub(super) fn wants_channel(&self, guild_id: GuildId, channel: &GuildChannel) -> bool {
+        self.wants_guild(guild_id)
+            && self
+                .channel_types
+                .as_ref()
+                .map_or(true, |kinds| kinds.contains(&channel.kind()))
+            && wants(&self.channel, channel)
+    }
+
+    /// Whether an emoji is cached.
+    pub(super) fn wants_emoji(&self, guild_id: GuildId, emoji: &Emoji) -> bool {
+        self.wants_guild(guild_id) && wants(&self.emoji, emoji)
+    }
+
+    /// Whether the resources of a guild are cached.
+    pub(super) fn wants_guild(&self, guild_id: GuildId) -> bool {
+        self.guilds
+            .as_ref()
+            .map_or(true, |guilds| guilds.contains(&guild_id))
+    }
+
+    /// Whether a member is cached.
+    pub(super) fn wants_member(
+        &self,
+        guild_id: GuildId,
+        member: &Member,
+        current_user_id: Option<UserId>,
+    ) -> bool {
+        self.wants_member_id(guild_id, member.user.id, current_user_id)
+            && wants(&self.member, member)
+    }
+
+    /// Whether a member is cached, by its user ID.
+    ///
+    /// This doesn't check the member predicate, which requires the full member.
+    pub(super) fn wants_member_id(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        current_user_id: Option<UserId>,
+    ) -> bool {
+        self.wants_guild(guild_id)
+            && (!self.current_member_only || current_user_id == Some(user_id))
+    }
+
+    /// Whether a message is cached.
+    pub(super) fn wants_message(&self, message: &Message) -> bool {
+        message.guild_id.map_or(true, |id| self.wants_guild(id)) && wants(&self.message, message)
+    }
+
+    /// Whether a presence is cached.
+    pub(super) fn wants_presence(&self, guild_id: GuildId, presence: &Presence) -> bool {
+        self.wants_guild(guild_id) && wants(&self.presence, presence)
+    }
+
+    /// Whether a role is cached.
+    pub(super) fn wants_role(&self, guild_id: GuildId, role: &Role) -> bool {
+        self.wants_guild(guild_id) && wants(&self.role, role)
+    }
+
+    /// Whether a voice state is cached.
+    pub(super) fn wants_voice_state(&self, voice_state: &VoiceState) -> bool {
+        voice_state.guild_id.map_or(true, |id| self.wants_guild(id))
+            && wants(&self.voice_state, voice_state)
+    }
+}
+
+impl Debug for Filter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Filter")
+            .field("channel", &self.channel.is_some())
+            .field("channel_types", &self.channel_types)
+            .field("current_member_only", &self.current_member_only)
+            .field("emoji", &self.emoji.is_some())
+            .field("guilds", &self.guilds)
+            .field("member", &self.member.is_some())
+            .field("message", &self.message.is_some())
+            .field("presence", &self.presence.is_some())
+            .field("role", &self.role.is_some())
+            .field("voice_state", &self.voice_state.is_some())
+            .finish()
+    }
+}
+
+impl Eq for Filter {}
+
+/// Filters are equal if they have the same settings and the same predicates,
+/// meaning clones of the same closures.
+impl PartialEq for Filter {
+    fn eq(&self, other: &Self) -> bool {
+        same(&self.channel, &other.channel)
+            && self.channel_types == other.channel_types
+            && self.current_member_only == other.current_member_only
+            && same(&self.emoji, &other.emoji)
+            && self.guilds == other.guilds
+            && same(&self.member, &other.member)
+            && same(&self.message, &other.message)
+            && same(&self.presence, &other.presence)
+            && same(&self.role, &other.role)
+            && same(&self.voice_state, &other.voice_state)
+    }
+}
+
+fn same<T>(a: &Option<Predicate<T>>, b: &Option<Predicate<T>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => Arc::as_ptr(a) as *const () == Arc::as_ptr(b) as *const (),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn wants<T>(predicate: &Option<Predicate<T>>, item: &T) -> bool {
+    predicate.as_ref().map_or(true, |predicate| predicate(item))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Filter;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, sync::Arc};
+    use twilight_model::id::{GuildId, UserId};
+
+    assert_impl_all!(Filter: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_default_wants_all() {
+        let filter = Filter::default();
+
+        assert!(filter.wants_guild(GuildId(1)));
+        assert!(filter.wants_member_id(GuildId(1), UserId(2), None));
+    }
+
+    #[test]
+    fn test_guilds_and_current_member() {
+        let filter = Filter {
+            current_member_only: true,
+            guilds: Some(vec![GuildId(1)].into_iter().collect()),
+            ..Filter::default()
+        };
+
+        assert!(filter.wants_guild(GuildId(1)));
+        assert!(!filter.wants_guild(GuildId(2)));
+        assert!(filter.wants_member_id(GuildId(1), UserId(2), Some(UserId(2))));
+        assert!(!filter.wants_member_id(GuildId(1), UserId(3), Some(UserId(2))));
+        assert!(!filter.wants_member_id(GuildId(2), UserId(2), Some(UserId(2))));
+    }
+
+    #[test]
+    fn test_eq_compares_predicates() {
+        let filter = Filter {
+            role: Some(Arc::new(|role| role.hoist)),
+            ..Filter::default()
+        };
+
+        assert_eq!(filter, filter.clone());
+        assert_ne!(
+            filter,
+            Filter {
+                role: Some(Arc::new(|role| role.hoist)),
+                ..Filter::default()
+            }
+        );
+        assert_ne!(filter, Filter::default());
+    }
+}
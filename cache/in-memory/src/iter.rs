@@ -0,0 +1,217 @@
+use super::{
+    model::{CachedEmoji, CachedGuild, CachedMember, CachedMessage},
+    InMemoryCache,
+};
+use std::sync::Arc;
+use twilight_model::{
+    channel::{Group, GuildChannel, PrivateChannel},
+    guild::Role,
+    id::{ChannelId, GuildId},
+    user::User,
+    voice::VoiceState,
+};
+
+/// Iterators over the resources of an [`InMemoryCache`].
+///
+/// This is obtained through [`InMemoryCache::iter`].
+///
+/// # Locking
+///
+/// Iterators over all resources of a type lock parts of the cache while
+/// they're alive. Updating the cache while holding one of these iterators on
+/// the same thread may deadlock, so collect the items first if the cache
+/// needs to be updated during iteration.
+#[derive(Debug)]
+pub struct InMemoryCacheIter<'a>(&'a InMemoryCache);
+
+impl<'a> InMemoryCacheIter<'a> {
+    pub(super) fn new(cache: &'a InMemoryCache) -> Self {
+        Self(cache)
+    }
+
+    /// Iterate over all guild channels.
+    pub fn channels(&self) -> impl Iterator<Item = Arc<GuildChannel>> + 'a {
+        self.0
+             .0
+            .channels_guild
+            .iter()
+            .map(|r| Arc::clone(&r.value().data))
+    }
+
+    /// Iterate over all emojis.
+    pub fn emojis(&self) -> impl Iterator<Item = Arc<CachedEmoji>> + 'a {
+        self.0 .0.emojis.iter().map(|r| Arc::clone(&r.value().data))
+    }
+
+    /// Iterate over all groups.
+    pub fn groups(&self) -> impl Iterator<Item = Arc<Group>> + 'a {
+        self.0 .0.groups.iter().map(|r| Arc::clone(r.value()))
+    }
+
+    /// Iterate over all guilds.
+    pub fn guilds(&self) -> impl Iterator<Item = Arc<CachedGuild>> + 'a {
+        self.0 .0.guilds.iter().map(|r| Arc::clone(r.value()))
+    }
+
+    /// Iterate over the members of a guild.
+    ///
+    /// The IDs of the members are copied before iterating, so this doesn't
+    /// lock the cache.
+    pub fn members(&self, guild_id: GuildId) -> impl Iterator<Item = Arc<CachedMember>> + 'a {
+        let cache = self.0;
+
+        cache
+            .guild_members(guild_id)
+            .into_iter()
+            .flatten()
+            .filter_map(move |user_id| cache.member(guild_id, user_id))
+    }
+
+    /// Iterate over the cached messages of a channel in chronological order,
+    /// oldest first.
+    ///
+    /// The messages are copied before iterating, so this doesn't lock the
+    /// cache.
+    pub fn messages(&self, channel_id: ChannelId) -> impl Iterator<Item = Arc<CachedMessage>> {
+        self.0
+             .0
+            .messages
+            .get(&channel_id)
+            .map(|channel| channel.values().cloned().collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+    }
+
+    /// Iterate over all private channels.
+    pub fn private_channels(&self) -> impl Iterator<Item = Arc<PrivateChannel>> + 'a {
+        self.0
+             .0
+            .channels_private
+            .iter()
+            .map(|r| Arc::clone(r.value()))
+    }
+
+    /// Iterate over all roles.
+    pub fn roles(&self) -> impl Iterator<Item = Arc<Role>> + 'a {
+        self.0 .0.roles.iter().map(|r| Arc::clone(&r.value().data))
+    }
+
+    /// Iterate over all users.
+    pub fn users(&self) -> impl Iterator<Item = Arc<User>> + 'a {
+        self.0 .0.users.iter().map(|r| Arc::clone(&r.value().0))
+    }
+
+    /// Iterate over all voice states.
+    pub fn voice_states(&self) -> impl Iterator<Item = Arc<VoiceState>> + 'a {
+        self.0 .0.voice_states.iter().map(|r| Arc::clone(r.value()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InMemoryCacheIter;
+    use crate::InMemoryCache;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        channel::message::{Message, MessageType},
+        gateway::payload::MessageCreate,
+        guild::{Permissions, Role},
+        id::{ChannelId, GuildId, MessageId, RoleId, UserId},
+        user::User,
+    };
+
+    assert_impl_all!(InMemoryCacheIter<'_>: Debug, Send, Sync);
+
+    fn message(id: u64) -> Message {
+        Message {
+            activity: None,
+            application: None,
+            attachments: Vec::new(),
+            author: User {
+                avatar: None,
+                bot: false,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id: UserId(3),
+                locale: None,
+                mfa_enabled: None,
+                name: "test".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+            channel_id: ChannelId(2),
+            components: Vec::new(),
+            content: "ping".to_owned(),
+            edited_timestamp: None,
+            embeds: Vec::new(),
+            flags: None,
+            guild_id: None,
+            id: MessageId(id),
+            kind: MessageType::Regular,
+            member: None,
+            mention_channels: Vec::new(),
+            mention_everyone: false,
+            mention_roles: Vec::new(),
+            mentions: Vec::new(),
+            pinned: false,
+            reactions: Vec::new(),
+            reference: None,
+            stickers: Vec::new(),
+            referenced_message: None,
+            timestamp: String::new(),
+            tts: false,
+            webhook_id: None,
+        }
+    }
+
+    #[test]
+    fn test_messages() {
+        let cache = InMemoryCache::new();
+
+        // Messages received out of order are still iterated oldest first.
+        for id in [5, 3, 4].iter() {
+            cache.update(&MessageCreate(message(*id)));
+        }
+
+        let ids = cache
+            .iter()
+            .messages(ChannelId(2))
+            .map(|message| message.id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![MessageId(3), MessageId(4), MessageId(5)], ids);
+        assert_eq!(0, cache.iter().messages(ChannelId(1)).count());
+    }
+
+    #[test]
+    fn test_roles() {
+        let cache = InMemoryCache::new();
+
+        for id in 1..=2 {
+            cache.cache_role(
+                GuildId(1),
+                Role {
+                    color: 0,
+                    hoist: false,
+                    id: RoleId(id),
+                    managed: false,
+                    mentionable: false,
+                    name: "test".to_owned(),
+                    permissions: Permissions::empty(),
+                    position: 0,
+                    tags: None,
+                },
+            );
+        }
+
+        let mut ids = cache.iter().roles().map(|role| role.id).collect::<Vec<_>>();
+        ids.sort();
+
+        assert_eq!(vec![RoleId(1), RoleId(2)], ids);
+        assert_eq!(0, cache.iter().guilds().count());
+    }
+}
@@ -33,6 +33,28 @@
 //! # Ok(()) }
 //! ```
 //!
+//! ## Features
+//!
+//! ### Metrics
+//!
+//! The `metrics` feature provides the statistics of the cache via the `metrics`
+//! crate. Calling `InMemoryCacheStats::emit_metrics` logs the number of each
+//! cached resource and the estimated memory usage of the cache as gauges.
+//!
+//! This is disabled by default.
+//!
+//! ### HTTP
+//!
+//! The `twilight-http` feature provides methods to refresh the webhooks,
+//! integrations and invites of the cache over HTTP. The gateway only notifies
+//! that webhooks and integrations changed without including them, and only
+//! sends invites created while running.
+//!
+//! It also provides `FetchCache`, which wraps a cache and an HTTP client and
+//! fetches resources that aren't cached.
+//!
+//! This is disabled by default.
+//!
 //! ## License
 //!
 //! All first-party crates are licensed under [ISC][LICENSE.md]
@@ -52,33 +74,68 @@
 pub mod model;
 
 mod builder;
+mod cache;
 mod config;
+mod diff;
+mod eviction;
+#[cfg(feature = "twilight-http")]
+mod fetch;
+mod filter;
+mod iter;
+#[cfg(feature = "twilight-http")]
+mod refresh;
+mod snapshot;
+mod stats;
 mod updates;
 
 pub use self::{
     builder::InMemoryCacheBuilder,
+    cache::{Cache, CacheFuture},
     config::{Config, ResourceType},
+    diff::CacheDiff,
+    filter::Filter,
+    iter::InMemoryCacheIter,
+    snapshot::{Snapshot, SnapshotError},
+    stats::{GuildStats, InMemoryCacheStats},
     updates::UpdateCache,
 };
 
-use self::model::*;
+#[cfg(feature = "twilight-http")]
+pub use self::fetch::FetchCache;
+
+use self::{eviction::MessageOrder, model::*};
 use dashmap::{mapref::entry::Entry, DashMap, DashSet};
+use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
     collections::{BTreeMap, BTreeSet, HashSet},
     hash::Hash,
+    io::Write,
+    mem,
     sync::{Arc, Mutex},
 };
 use twilight_model::{
-    channel::{Group, GuildChannel, PrivateChannel},
-    gateway::presence::{Presence, UserOrId},
-    guild::{Emoji, Guild, Member, PartialMember, Role},
-    id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, UserId},
+    channel::{
+        stage_instance::StageInstance, ChannelType, Group, GuildChannel, PrivateChannel, Webhook,
+    },
+    gateway::{
+        event::Event,
+        presence::{Presence, UserOrId},
+    },
+    guild::{
+        scheduled_event::GuildScheduledEvent, Emoji, Guild, GuildIntegration, Member,
+        PartialMember, Role,
+    },
+    id::{
+        ChannelId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, ScheduledEventId, StageId,
+        UserId, WebhookId,
+    },
+    invite::Invite,
     user::{CurrentUser, User},
     voice::VoiceState,
 };
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 struct GuildItem<T> {
     data: Arc<T>,
     guild_id: GuildId,
@@ -129,11 +186,13 @@ fn upsert_item<K: Eq + Hash, V: PartialEq>(map: &DashMap<K, Arc<V>>, k: K, v: V)
     }
 }
 
-// When adding a field here, be sure to add it to `InMemoryCache::clear` if
-// necessary.
+// When adding a field here, be sure to add it to `InMemoryCache::clear` and
+// `Snapshot` if necessary.
 #[derive(Debug, Default)]
 struct InMemoryCacheRef {
     config: Arc<Config>,
+    bans: DashMap<(GuildId, UserId), Arc<User>>,
+    channel_webhooks: DashMap<ChannelId, HashSet<WebhookId>>,
     channels_guild: DashMap<ChannelId, GuildItem<GuildChannel>>,
     channels_private: DashMap<ChannelId, Arc<PrivateChannel>>,
     // So long as the lock isn't held across await or panic points this is fine.
@@ -142,14 +201,26 @@ struct InMemoryCacheRef {
     groups: DashMap<ChannelId, Arc<Group>>,
     guilds: DashMap<GuildId, Arc<CachedGuild>>,
     guild_channels: DashMap<GuildId, HashSet<ChannelId>>,
+    guild_bans: DashMap<GuildId, HashSet<UserId>>,
     guild_emojis: DashMap<GuildId, HashSet<EmojiId>>,
+    guild_integrations: DashMap<GuildId, HashSet<IntegrationId>>,
+    guild_invites: DashMap<GuildId, HashSet<String>>,
     guild_members: DashMap<GuildId, HashSet<UserId>>,
     guild_presences: DashMap<GuildId, HashSet<UserId>>,
     guild_roles: DashMap<GuildId, HashSet<RoleId>>,
+    guild_scheduled_events: DashMap<GuildId, HashSet<ScheduledEventId>>,
+    guild_stage_instances: DashMap<GuildId, HashSet<StageId>>,
+    integrations: DashMap<IntegrationId, GuildItem<GuildIntegration>>,
+    invites: DashMap<String, Arc<CachedInvite>>,
     members: DashMap<(GuildId, UserId), Arc<CachedMember>>,
     messages: DashMap<ChannelId, BTreeMap<MessageId, Arc<CachedMessage>>>,
+    // Locked before the message map whenever messages are added or removed.
+    message_order: Mutex<MessageOrder>,
     presences: DashMap<(GuildId, UserId), Arc<CachedPresence>>,
     roles: DashMap<RoleId, GuildItem<Role>>,
+    scheduled_events: DashMap<ScheduledEventId, GuildItem<GuildScheduledEvent>>,
+    scheduled_event_users: DashMap<ScheduledEventId, HashSet<UserId>>,
+    stage_instances: DashMap<StageId, GuildItem<StageInstance>>,
     unavailable_guilds: DashSet<GuildId>,
     users: DashMap<UserId, (Arc<User>, BTreeSet<GuildId>)>,
     /// Mapping of channels and the users currently connected.
@@ -158,6 +229,7 @@ struct InMemoryCacheRef {
     voice_state_guilds: DashMap<GuildId, HashSet<UserId>>,
     /// Mapping of guild ID and user ID pairs to their voice states.
     voice_states: DashMap<(GuildId, UserId), Arc<VoiceState>>,
+    webhooks: DashMap<WebhookId, Arc<Webhook>>,
 }
 
 /// A thread-safe, in-memory-process cache of Discord data. It can be cloned and
@@ -219,11 +291,18 @@ impl InMemoryCache {
         Self::default()
     }
 
-    fn new_with_config(config: Config) -> Self {
-        Self(Arc::new(InMemoryCacheRef {
+    fn new_with_config(config: Config, snapshot: Option<Snapshot>) -> Self {
+        let cache = Self(Arc::new(InMemoryCacheRef {
             config: Arc::new(config),
             ..Default::default()
-        }))
+        }));
+
+        if let Some(snapshot) = snapshot {
+            snapshot.restore(&cache);
+            eviction::reorder(&cache);
+        }
+
+        cache
     }
 
     /// Create a new builder to configure and construct an in-memory cache.
@@ -241,6 +320,245 @@ impl InMemoryCache {
         value.update(self);
     }
 
+    /// Update the cache with an event from the gateway, returning the
+    /// previous and new state of the resource it changed.
+    ///
+    /// This is useful for logging changes, such as the content of a message
+    /// before it was edited or deleted. Refer to [`CacheDiff`] for the events
+    /// with a diff; other events update the cache and return `None`. `None` is
+    /// also returned if the resource isn't cached after the event, such as
+    /// when deleting a message that wasn't cached.
+    ///
+    /// # Examples
+    ///
+    /// Log the content of deleted messages:
+    ///
+    /// ```
+    /// use twilight_cache_inmemory::{CacheDiff, InMemoryCache};
+    /// use twilight_model::gateway::event::Event;
+    ///
+    /// fn handle(cache: &InMemoryCache, event: &Event) {
+    ///     if let Some(CacheDiff::MessageDelete { old }) = cache.update_with_diff(event) {
+    ///         println!("message deleted: {}", old.content);
+    ///     }
+    /// }
+    /// ```
+    pub fn update_with_diff(&self, event: &Event) -> Option<CacheDiff> {
+        diff::update(self, event)
+    }
+
+    /// Create an interface for iterating over the resources in the cache.
+    ///
+    /// Refer to [`InMemoryCacheIter`] for the available iterators and the
+    /// caveats of holding them while the cache is updated.
+    ///
+    /// # Examples
+    ///
+    /// Count the number of guilds in the cache:
+    ///
+    /// ```
+    /// use twilight_cache_inmemory::InMemoryCache;
+    ///
+    /// let cache = InMemoryCache::new();
+    ///
+    /// println!("guilds: {}", cache.iter().guilds().count());
+    /// ```
+    pub fn iter(&self) -> InMemoryCacheIter<'_> {
+        InMemoryCacheIter::new(self)
+    }
+
+    /// Create an interface for retrieving statistics about the cache, such as
+    /// the number of cached resources.
+    ///
+    /// # Examples
+    ///
+    /// Print the number of members and the estimated memory usage:
+    ///
+    /// ```
+    /// use twilight_cache_inmemory::InMemoryCache;
+    ///
+    /// let cache = InMemoryCache::new();
+    /// let stats = cache.stats();
+    ///
+    /// println!("members: {}", stats.members());
+    /// println!("memory: ~{} bytes", stats.memory_estimate());
+    /// ```
+    pub fn stats(&self) -> InMemoryCacheStats<'_> {
+        InMemoryCacheStats::new(self)
+    }
+
+    /// Replace the cached webhooks of a channel.
+    ///
+    /// The gateway only notifies that the webhooks of a channel changed
+    /// without including them, so they need to be fetched over HTTP, such as
+    /// with `InMemoryCache::refresh_webhooks` when the `twilight-http` feature
+    /// is enabled.
+    pub fn set_channel_webhooks(
+        &self,
+        channel_id: ChannelId,
+        webhooks: impl IntoIterator<Item = Webhook>,
+    ) {
+        if !self.wants(ResourceType::WEBHOOK) {
+            return;
+        }
+
+        if let Some((_, ids)) = self.0.channel_webhooks.remove(&channel_id) {
+            for id in ids {
+                self.0.webhooks.remove(&id);
+            }
+        }
+
+        let mut ids = HashSet::new();
+
+        for webhook in webhooks {
+            if let Some(guild_id) = webhook.guild_id {
+                if !self.filter().wants_guild(guild_id) {
+                    continue;
+                }
+            }
+
+            ids.insert(webhook.id);
+            self.0.webhooks.insert(webhook.id, Arc::new(webhook));
+        }
+
+        self.0.channel_webhooks.insert(channel_id, ids);
+    }
+
+    /// Replace the cached integrations of a guild.
+    ///
+    /// The gateway only notifies that the integrations of a guild changed
+    /// without including them, so they need to be fetched over HTTP, such as
+    /// with `InMemoryCache::refresh_integrations` when the `twilight-http`
+    /// feature is enabled.
+    pub fn set_guild_integrations(
+        &self,
+        guild_id: GuildId,
+        integrations: impl IntoIterator<Item = GuildIntegration>,
+    ) {
+        if !self.wants(ResourceType::INTEGRATION) || !self.filter().wants_guild(guild_id) {
+            return;
+        }
+
+        if let Some((_, ids)) = self.0.guild_integrations.remove(&guild_id) {
+            for id in ids {
+                self.0.integrations.remove(&id);
+            }
+        }
+
+        let mut ids = HashSet::new();
+
+        for integration in integrations {
+            ids.insert(integration.id);
+            upsert_guild_item(&self.0.integrations, guild_id, integration.id, integration);
+        }
+
+        self.0.guild_integrations.insert(guild_id, ids);
+    }
+
+    /// Replace the cached invites of a guild.
+    ///
+    /// Only invites created while running are received over the gateway, so
+    /// the invites that already exist need to be fetched over HTTP to track
+    /// their uses, such as with `InMemoryCache::refresh_invites` when the
+    /// `twilight-http` feature is enabled.
+    pub fn set_guild_invites(&self, guild_id: GuildId, invites: impl IntoIterator<Item = Invite>) {
+        if !self.wants(ResourceType::INVITE) || !self.filter().wants_guild(guild_id) {
+            return;
+        }
+
+        if let Some((_, codes)) = self.0.guild_invites.remove(&guild_id) {
+            for code in codes {
+                self.0.invites.remove(&code);
+            }
+        }
+
+        let mut codes = HashSet::new();
+
+        for invite in invites {
+            codes.insert(invite.code.clone());
+            self.0.invites.insert(
+                invite.code.clone(),
+                Arc::new(CachedInvite::from_invite(guild_id, invite)),
+            );
+        }
+
+        self.0.guild_invites.insert(guild_id, codes);
+    }
+
+    /// Update the use counts of the cached invites of a guild, returning the
+    /// invites that were used since their counts were last updated.
+    ///
+    /// The gateway doesn't notify when an invite is used, so the counts need
+    /// to be fetched over HTTP. Updating the counts when a member joins finds
+    /// the invite they joined with. Codes of invites that aren't cached are
+    /// ignored, so the existing invites of a guild need to be cached with
+    /// [`set_guild_invites`] first.
+    ///
+    /// [`set_guild_invites`]: Self::set_guild_invites
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_cache_inmemory::InMemoryCache;
+    /// use twilight_model::id::GuildId;
+    ///
+    /// let cache = InMemoryCache::new();
+    ///
+    /// // Counts retrieved from the invites of the guild over HTTP.
+    /// let uses = vec![("twilight".to_owned(), 3)];
+    ///
+    /// for invite in cache.update_invite_uses(GuildId(1), uses) {
+    ///     println!("member joined with {}", invite.code);
+    /// }
+    /// ```
+    pub fn update_invite_uses(
+        &self,
+        guild_id: GuildId,
+        uses: impl IntoIterator<Item = (String, u64)>,
+    ) -> Vec<Arc<CachedInvite>> {
+        let mut used = Vec::new();
+
+        for (code, uses) in uses {
+            let mut invite = match self.0.invites.get_mut(&code) {
+                Some(invite) if invite.guild_id == guild_id => invite,
+                Some(_) | None => continue,
+            };
+
+            if uses > invite.uses {
+                Arc::make_mut(&mut invite).uses = uses;
+                used.push(Arc::clone(&invite));
+            }
+        }
+
+        used
+    }
+
+    /// Gets the user of a guild ban by guild ID and user ID.
+    ///
+    /// Only bans received while running are cached. This is an O(1)
+    /// operation. This requires the [`GUILD_BANS`] intent.
+    ///
+    /// [`GUILD_BANS`]: ::twilight_model::gateway::Intents::GUILD_BANS
+    pub fn ban(&self, guild_id: GuildId, user_id: UserId) -> Option<Arc<User>> {
+        self.0
+            .bans
+            .get(&(guild_id, user_id))
+            .map(|r| Arc::clone(r.value()))
+    }
+
+    /// Gets the set of webhooks in a channel.
+    ///
+    /// This is a O(m) operation, where m is the amount of webhooks in the
+    /// channel. This requires the [`GUILD_WEBHOOKS`] intent.
+    ///
+    /// [`GUILD_WEBHOOKS`]: ::twilight_model::gateway::Intents::GUILD_WEBHOOKS
+    pub fn channel_webhooks(&self, channel_id: ChannelId) -> Option<HashSet<WebhookId>> {
+        self.0
+            .channel_webhooks
+            .get(&channel_id)
+            .map(|r| r.value().clone())
+    }
+
     /// Gets a channel by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILDS`] intent.
@@ -292,7 +610,7 @@ impl InMemoryCache {
         self.0.guilds.get(&guild_id).map(|r| Arc::clone(r.value()))
     }
 
-    /// Gets the set of channels in a guild.
+    /// Gets the set of channels in a guild, including its active threads.
     ///
     /// This is a O(m) operation, where m is the amount of channels in the
     /// guild. This requires the [`GUILDS`] intent.
@@ -319,6 +637,47 @@ impl InMemoryCache {
             .map(|r| r.value().clone())
     }
 
+    /// Gets the set of banned users in a guild.
+    ///
+    /// Only bans received while running are cached. This is a O(m)
+    /// operation, where m is the amount of bans in the guild. This requires
+    /// the [`GUILD_BANS`] intent.
+    ///
+    /// [`GUILD_BANS`]: ::twilight_model::gateway::Intents::GUILD_BANS
+    pub fn guild_bans(&self, guild_id: GuildId) -> Option<HashSet<UserId>> {
+        self.0.guild_bans.get(&guild_id).map(|r| r.value().clone())
+    }
+
+    /// Gets the set of integrations in a guild.
+    ///
+    /// This is a O(m) operation, where m is the amount of integrations in the
+    /// guild. This requires the [`GUILD_INTEGRATIONS`] intent.
+    ///
+    /// [`GUILD_INTEGRATIONS`]: ::twilight_model::gateway::Intents::GUILD_INTEGRATIONS
+    pub fn guild_integrations(&self, guild_id: GuildId) -> Option<HashSet<IntegrationId>> {
+        self.0
+            .guild_integrations
+            .get(&guild_id)
+            .map(|r| r.value().clone())
+    }
+
+    /// Gets the set of invite codes in a guild.
+    ///
+    /// Only invites created while running or set with
+    /// [`set_guild_invites`] are cached. This is a O(m) operation, where m is
+    /// the amount of invites in the guild. This requires the
+    /// [`GUILD_INVITES`] intent.
+    ///
+    /// [`set_guild_invites`]: Self::set_guild_invites
+    ///
+    /// [`GUILD_INVITES`]: ::twilight_model::gateway::Intents::GUILD_INVITES
+    pub fn guild_invites(&self, guild_id: GuildId) -> Option<HashSet<String>> {
+        self.0
+            .guild_invites
+            .get(&guild_id)
+            .map(|r| r.value().clone())
+    }
+
     /// Gets the set of members in a guild.
     ///
     /// This list may be incomplete if not all members have been cached.
@@ -359,6 +718,90 @@ impl InMemoryCache {
         self.0.guild_roles.get(&guild_id).map(|r| r.value().clone())
     }
 
+    /// Gets the set of scheduled events in a guild.
+    ///
+    /// This is a O(m) operation, where m is the amount of scheduled events in
+    /// the guild. This requires the [`GUILDS`] and [`GUILD_SCHEDULED_EVENTS`]
+    /// intents.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_SCHEDULED_EVENTS`]: ::twilight_model::gateway::Intents::GUILD_SCHEDULED_EVENTS
+    pub fn guild_scheduled_events(&self, guild_id: GuildId) -> Option<HashSet<ScheduledEventId>> {
+        self.0
+            .guild_scheduled_events
+            .get(&guild_id)
+            .map(|r| r.value().clone())
+    }
+
+    /// Gets the set of live stage instances in a guild.
+    ///
+    /// This is a O(m) operation, where m is the amount of stage instances in
+    /// the guild. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn guild_stage_instances(&self, guild_id: GuildId) -> Option<HashSet<StageId>> {
+        self.0
+            .guild_stage_instances
+            .get(&guild_id)
+            .map(|r| r.value().clone())
+    }
+
+    /// Gets the channels of a guild of a given type.
+    ///
+    /// This is a O(m) operation, where m is the amount of channels in the
+    /// guild. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn guild_channels_of_kind(
+        &self,
+        guild_id: GuildId,
+        kind: ChannelType,
+    ) -> Vec<Arc<GuildChannel>> {
+        self.guild_channels(guild_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|channel_id| self.guild_channel(channel_id))
+            .filter(|channel| channel.kind() == kind)
+            .collect()
+    }
+
+    /// Gets the active threads of a guild that the current user can access.
+    ///
+    /// This is a O(m) operation, where m is the amount of channels in the
+    /// guild. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn guild_threads(&self, guild_id: GuildId) -> Vec<Arc<GuildChannel>> {
+        self.guild_channels(guild_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|channel_id| self.guild_channel(channel_id))
+            .filter(|channel| channel.kind().is_thread())
+            .collect()
+    }
+
+    /// Gets an integration by ID.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_INTEGRATIONS`]
+    /// intent.
+    ///
+    /// [`GUILD_INTEGRATIONS`]: ::twilight_model::gateway::Intents::GUILD_INTEGRATIONS
+    pub fn integration(&self, integration_id: IntegrationId) -> Option<Arc<GuildIntegration>> {
+        self.0
+            .integrations
+            .get(&integration_id)
+            .map(|r| Arc::clone(&r.data))
+    }
+
+    /// Gets an invite by code.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_INVITES`] intent.
+    ///
+    /// [`GUILD_INVITES`]: ::twilight_model::gateway::Intents::GUILD_INVITES
+    pub fn invite(&self, code: &str) -> Option<Arc<CachedInvite>> {
+        self.0.invites.get(code).map(|r| Arc::clone(r.value()))
+    }
+
     /// Gets a member by guild ID and user ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_MEMBERS`] intent.
@@ -371,6 +814,21 @@ impl InMemoryCache {
             .map(|r| Arc::clone(r.value()))
     }
 
+    /// Gets the members of a guild that have a role.
+    ///
+    /// This list may be incomplete if not all members have been cached.
+    ///
+    /// This is a O(m) operation, where m is the amount of members in the guild.
+    /// This requires the [`GUILD_MEMBERS`] intent.
+    ///
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn members_with_role(&self, guild_id: GuildId, role_id: RoleId) -> Vec<Arc<CachedMember>> {
+        self.iter()
+            .members(guild_id)
+            .filter(|member| member.roles.contains(&role_id))
+            .collect()
+    }
+
     /// Gets a message by channel ID and message ID.
     ///
     /// This is an O(log n) operation. This requires one or both of the
@@ -424,6 +882,60 @@ impl InMemoryCache {
             .map(|role| Arc::clone(&role.data))
     }
 
+    /// Gets a scheduled event by ID.
+    ///
+    /// Events received over the gateway don't include the [`user_count`], so
+    /// it's only present if the event was cached with it. The users that
+    /// subscribed while running are available through
+    /// [`scheduled_event_users`].
+    ///
+    /// This is an O(1) operation. This requires the [`GUILDS`] and
+    /// [`GUILD_SCHEDULED_EVENTS`] intents.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_SCHEDULED_EVENTS`]: ::twilight_model::gateway::Intents::GUILD_SCHEDULED_EVENTS
+    /// [`scheduled_event_users`]: Self::scheduled_event_users
+    /// [`user_count`]: GuildScheduledEvent::user_count
+    pub fn scheduled_event(
+        &self,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Option<Arc<GuildScheduledEvent>> {
+        self.0
+            .scheduled_events
+            .get(&scheduled_event_id)
+            .map(|scheduled_event| Arc::clone(&scheduled_event.data))
+    }
+
+    /// Gets the set of users subscribed to a scheduled event.
+    ///
+    /// The gateway only notifies when users subscribe or unsubscribe, so only
+    /// the users that subscribed while running are cached. This is a O(m)
+    /// operation, where m is the amount of cached users subscribed to the
+    /// event. This requires the [`GUILD_SCHEDULED_EVENTS`] intent.
+    ///
+    /// [`GUILD_SCHEDULED_EVENTS`]: ::twilight_model::gateway::Intents::GUILD_SCHEDULED_EVENTS
+    pub fn scheduled_event_users(
+        &self,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Option<HashSet<UserId>> {
+        self.0
+            .scheduled_event_users
+            .get(&scheduled_event_id)
+            .map(|r| r.value().clone())
+    }
+
+    /// Gets a stage instance by ID.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn stage_instance(&self, stage_id: StageId) -> Option<Arc<StageInstance>> {
+        self.0
+            .stage_instances
+            .get(&stage_id)
+            .map(|stage_instance| Arc::clone(&stage_instance.data))
+    }
+
     /// Gets a user by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_MEMBERS`] intent.
@@ -433,6 +945,23 @@ impl InMemoryCache {
         self.0.users.get(&user_id).map(|r| Arc::clone(&r.0))
     }
 
+    /// Gets a user by their tag, such as `twilight#0001`.
+    ///
+    /// Returns `None` if the tag is invalid or no cached user has it.
+    ///
+    /// This is a O(n) operation, where n is the amount of cached users. This
+    /// requires the [`GUILD_MEMBERS`] intent.
+    ///
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn user_by_tag(&self, tag: &str) -> Option<Arc<User>> {
+        let idx = tag.rfind('#')?;
+        let (name, discriminator) = (&tag[..idx], &tag[idx + 1..]);
+
+        self.iter()
+            .users()
+            .find(|user| user.name == name && user.discriminator == discriminator)
+    }
+
     /// Gets the voice states within a voice channel.
     ///
     /// This requires both the [`GUILDS`] and [`GUILD_VOICE_STATES`] intents.
@@ -464,10 +993,57 @@ impl InMemoryCache {
             .map(|r| Arc::clone(r.value()))
     }
 
+    /// Gets a webhook by ID.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_WEBHOOKS`] intent.
+    ///
+    /// [`GUILD_WEBHOOKS`]: ::twilight_model::gateway::Intents::GUILD_WEBHOOKS
+    pub fn webhook(&self, webhook_id: WebhookId) -> Option<Arc<Webhook>> {
+        self.0
+            .webhooks
+            .get(&webhook_id)
+            .map(|r| Arc::clone(r.value()))
+    }
+
+    /// Write a snapshot of the state of the cache, which can be restored via
+    /// [`InMemoryCacheBuilder::snapshot`].
+    ///
+    /// The state isn't locked while the snapshot is created, so the cache
+    /// shouldn't be updated at the same time: bring the shards down before
+    /// creating a snapshot for the snapshot to be consistent with their
+    /// sessions.
+    ///
+    /// # Examples
+    ///
+    /// Write a snapshot to a file:
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use twilight_cache_inmemory::InMemoryCache;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cache = InMemoryCache::new();
+    ///
+    /// cache.snapshot(File::create("cache.snapshot")?)?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::Serializing`] if the state couldn't be
+    /// serialized.
+    ///
+    /// Returns [`SnapshotError::Io`] if writing failed.
+    pub fn snapshot(&self, writer: impl Write) -> Result<(), SnapshotError> {
+        Snapshot::new(self).write(writer)
+    }
+
     /// Clear the state of the Cache.
     ///
     /// This is equal to creating a new empty cache.
     pub fn clear(&self) {
+        self.0.bans.clear();
+        self.0.channel_webhooks.clear();
         self.0.channels_guild.clear();
         self.0.channels_private.clear();
         self.0
@@ -479,19 +1055,33 @@ impl InMemoryCache {
         self.0.groups.clear();
         self.0.guilds.clear();
         self.0.guild_channels.clear();
+        self.0.guild_bans.clear();
         self.0.guild_emojis.clear();
+        self.0.guild_integrations.clear();
+        self.0.guild_invites.clear();
         self.0.guild_members.clear();
         self.0.guild_presences.clear();
         self.0.guild_roles.clear();
+        self.0.guild_scheduled_events.clear();
+        self.0.guild_stage_instances.clear();
+        self.0.integrations.clear();
+        self.0.invites.clear();
         self.0.members.clear();
+        // Lock the order first so messages aren't cached in between.
+        let mut message_order = self.0.message_order.lock().expect("message order poisoned");
         self.0.messages.clear();
+        message_order.clear();
         self.0.presences.clear();
         self.0.roles.clear();
+        self.0.scheduled_events.clear();
+        self.0.scheduled_event_users.clear();
+        self.0.stage_instances.clear();
         self.0.unavailable_guilds.clear();
         self.0.users.clear();
         self.0.voice_state_channels.clear();
         self.0.voice_state_guilds.clear();
         self.0.voice_states.clear();
+        self.0.webhooks.clear();
     }
 
     fn cache_current_user(&self, mut current_user: CurrentUser) {
@@ -514,7 +1104,9 @@ impl InMemoryCache {
         guild_channels: impl IntoIterator<Item = GuildChannel>,
     ) {
         for channel in guild_channels {
-            self.cache_guild_channel(guild_id, channel);
+            if self.filter().wants_channel(guild_id, &channel) {
+                self.cache_guild_channel(guild_id, channel);
+            }
         }
     }
 
@@ -527,9 +1119,15 @@ impl InMemoryCache {
             GuildChannel::Category(ref mut c) => {
                 c.guild_id.replace(guild_id);
             }
+            GuildChannel::Stage(ref mut c) => {
+                c.guild_id.replace(guild_id);
+            }
             GuildChannel::Text(ref mut c) => {
                 c.guild_id.replace(guild_id);
             }
+            GuildChannel::Thread(ref mut c) => {
+                c.guild_id.replace(guild_id);
+            }
             GuildChannel::Voice(ref mut c) => {
                 c.guild_id.replace(guild_id);
             }
@@ -586,7 +1184,9 @@ impl InMemoryCache {
 
     fn cache_emojis(&self, guild_id: GuildId, emojis: impl IntoIterator<Item = Emoji>) {
         for emoji in emojis {
-            self.cache_emoji(guild_id, emoji);
+            if self.filter().wants_emoji(guild_id, &emoji) {
+                self.cache_emoji(guild_id, emoji);
+            }
         }
     }
 
@@ -594,76 +1194,59 @@ impl InMemoryCache {
         upsert_item(&self.0.groups, group.id, group)
     }
 
-    fn cache_guild(&self, guild: Guild) {
+    fn cache_guild(&self, mut guild: Guild) {
+        if !self.filter().wants_guild(guild.id) {
+            return;
+        }
+
         // The map and set creation needs to occur first, so caching states and
         // objects always has a place to put them.
         if self.wants(ResourceType::CHANNEL) {
             self.0.guild_channels.insert(guild.id, HashSet::new());
-            self.cache_guild_channels(guild.id, guild.channels);
+            self.cache_guild_channels(guild.id, mem::take(&mut guild.channels));
+            self.cache_guild_channels(guild.id, mem::take(&mut guild.threads));
         }
 
         if self.wants(ResourceType::EMOJI) {
             self.0.guild_emojis.insert(guild.id, HashSet::new());
-            self.cache_emojis(guild.id, guild.emojis);
+            self.cache_emojis(guild.id, mem::take(&mut guild.emojis));
         }
 
         if self.wants(ResourceType::MEMBER) {
             self.0.guild_members.insert(guild.id, HashSet::new());
-            self.cache_members(guild.id, guild.members);
+            self.cache_members(guild.id, mem::take(&mut guild.members));
         }
 
         if self.wants(ResourceType::PRESENCE) {
             self.0.guild_presences.insert(guild.id, HashSet::new());
-            self.cache_presences(guild.id, guild.presences);
+            self.cache_presences(guild.id, mem::take(&mut guild.presences));
         }
 
         if self.wants(ResourceType::ROLE) {
             self.0.guild_roles.insert(guild.id, HashSet::new());
-            self.cache_roles(guild.id, guild.roles);
+            self.cache_roles(guild.id, mem::take(&mut guild.roles));
+        }
+
+        if self.wants(ResourceType::SCHEDULED_EVENT) {
+            self.0
+                .guild_scheduled_events
+                .insert(guild.id, HashSet::new());
+            self.cache_scheduled_events(guild.id, mem::take(&mut guild.guild_scheduled_events));
+        }
+
+        if self.wants(ResourceType::STAGE_INSTANCE) {
+            self.0
+                .guild_stage_instances
+                .insert(guild.id, HashSet::new());
+            self.cache_stage_instances(guild.id, mem::take(&mut guild.stage_instances));
         }
 
         if self.wants(ResourceType::VOICE_STATE) {
             self.0.voice_state_guilds.insert(guild.id, HashSet::new());
-            self.cache_voice_states(guild.voice_states);
-        }
-
-        let guild = CachedGuild {
-            id: guild.id,
-            afk_channel_id: guild.afk_channel_id,
-            afk_timeout: guild.afk_timeout,
-            application_id: guild.application_id,
-            banner: guild.banner,
-            default_message_notifications: guild.default_message_notifications,
-            description: guild.description,
-            discovery_splash: guild.discovery_splash,
-            explicit_content_filter: guild.explicit_content_filter,
-            features: guild.features,
-            icon: guild.icon,
-            joined_at: guild.joined_at,
-            large: guild.large,
-            lazy: guild.lazy,
-            max_members: guild.max_members,
-            max_presences: guild.max_presences,
-            member_count: guild.member_count,
-            mfa_level: guild.mfa_level,
-            name: guild.name,
-            owner: guild.owner,
-            owner_id: guild.owner_id,
-            permissions: guild.permissions,
-            preferred_locale: guild.preferred_locale,
-            premium_subscription_count: guild.premium_subscription_count,
-            premium_tier: guild.premium_tier,
-            region: guild.region,
-            rules_channel_id: guild.rules_channel_id,
-            splash: guild.splash,
-            system_channel_id: guild.system_channel_id,
-            system_channel_flags: guild.system_channel_flags,
-            unavailable: guild.unavailable,
-            verification_level: guild.verification_level,
-            vanity_url_code: guild.vanity_url_code,
-            widget_channel_id: guild.widget_channel_id,
-            widget_enabled: guild.widget_enabled,
-        };
+            self.cache_voice_states(mem::take(&mut guild.voice_states));
+        }
+
+        let guild = CachedGuild::from(guild);
 
         self.0.unavailable_guilds.remove(&guild.id);
         self.0.guilds.insert(guild.id, Arc::new(guild));
@@ -733,14 +1316,23 @@ impl InMemoryCache {
     }
 
     fn cache_members(&self, guild_id: GuildId, members: impl IntoIterator<Item = Member>) {
+        let current_user_id = self.current_user_id();
+
         for member in members {
-            self.cache_member(guild_id, member);
+            if self
+                .filter()
+                .wants_member(guild_id, &member, current_user_id)
+            {
+                self.cache_member(guild_id, member);
+            }
         }
     }
 
     fn cache_presences(&self, guild_id: GuildId, presences: impl IntoIterator<Item = Presence>) {
         for presence in presences {
-            self.cache_presence(guild_id, presence);
+            if self.filter().wants_presence(guild_id, &presence) {
+                self.cache_presence(guild_id, presence);
+            }
         }
     }
 
@@ -774,7 +1366,9 @@ impl InMemoryCache {
 
     fn cache_roles(&self, guild_id: GuildId, roles: impl IntoIterator<Item = Role>) {
         for role in roles {
-            self.cache_role(guild_id, role);
+            if self.filter().wants_role(guild_id, &role) {
+                self.cache_role(guild_id, role);
+            }
         }
     }
 
@@ -790,6 +1384,72 @@ impl InMemoryCache {
         upsert_guild_item(&self.0.roles, guild_id, role.id, role)
     }
 
+    fn cache_scheduled_events(
+        &self,
+        guild_id: GuildId,
+        scheduled_events: impl IntoIterator<Item = GuildScheduledEvent>,
+    ) {
+        for scheduled_event in scheduled_events {
+            self.cache_scheduled_event(guild_id, scheduled_event);
+        }
+    }
+
+    fn cache_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        mut scheduled_event: GuildScheduledEvent,
+    ) -> Arc<GuildScheduledEvent> {
+        // Events received over the gateway don't include the number of
+        // subscribed users, so keep the cached one.
+        if scheduled_event.user_count.is_none() {
+            if let Some(cached) = self.0.scheduled_events.get(&scheduled_event.id) {
+                scheduled_event.user_count = cached.data.user_count;
+            }
+        }
+
+        self.0
+            .guild_scheduled_events
+            .entry(guild_id)
+            .or_default()
+            .insert(scheduled_event.id);
+
+        upsert_guild_item(
+            &self.0.scheduled_events,
+            guild_id,
+            scheduled_event.id,
+            scheduled_event,
+        )
+    }
+
+    fn cache_stage_instances(
+        &self,
+        guild_id: GuildId,
+        stage_instances: impl IntoIterator<Item = StageInstance>,
+    ) {
+        for stage_instance in stage_instances {
+            self.cache_stage_instance(guild_id, stage_instance);
+        }
+    }
+
+    fn cache_stage_instance(
+        &self,
+        guild_id: GuildId,
+        stage_instance: StageInstance,
+    ) -> Arc<StageInstance> {
+        self.0
+            .guild_stage_instances
+            .entry(guild_id)
+            .or_default()
+            .insert(stage_instance.id);
+
+        upsert_guild_item(
+            &self.0.stage_instances,
+            guild_id,
+            stage_instance.id,
+            stage_instance,
+        )
+    }
+
     fn cache_user(&self, user: Cow<'_, User>, guild_id: Option<GuildId>) -> Arc<User> {
         match self.0.users.get_mut(&user.id) {
             Some(mut u) if *u.0 == *user => {
@@ -815,7 +1475,9 @@ impl InMemoryCache {
 
     fn cache_voice_states(&self, voice_states: impl IntoIterator<Item = VoiceState>) {
         for voice_state in voice_states {
-            self.cache_voice_state(voice_state);
+            if self.filter().wants_voice_state(&voice_state) {
+                self.cache_voice_state(voice_state);
+            }
         }
     }
 
@@ -900,6 +1562,10 @@ impl InMemoryCache {
     }
 
     fn unavailable_guild(&self, guild_id: GuildId) {
+        if !self.filter().wants_guild(guild_id) {
+            return;
+        }
+
         self.0.unavailable_guilds.insert(guild_id);
         self.0.guilds.remove(&guild_id);
     }
@@ -928,6 +1594,52 @@ impl InMemoryCache {
         Some(role.data)
     }
 
+    fn delete_scheduled_event(
+        &self,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Option<Arc<GuildScheduledEvent>> {
+        let scheduled_event = self
+            .0
+            .scheduled_events
+            .remove(&scheduled_event_id)
+            .map(|(_, v)| v)?;
+        self.0.scheduled_event_users.remove(&scheduled_event_id);
+
+        if let Some(mut scheduled_events) = self
+            .0
+            .guild_scheduled_events
+            .get_mut(&scheduled_event.guild_id)
+        {
+            scheduled_events.remove(&scheduled_event_id);
+        }
+
+        Some(scheduled_event.data)
+    }
+
+    fn delete_stage_instance(&self, stage_id: StageId) -> Option<Arc<StageInstance>> {
+        let stage_instance = self.0.stage_instances.remove(&stage_id).map(|(_, v)| v)?;
+
+        if let Some(mut stage_instances) = self
+            .0
+            .guild_stage_instances
+            .get_mut(&stage_instance.guild_id)
+        {
+            stage_instances.remove(&stage_id);
+        }
+
+        Some(stage_instance.data)
+    }
+
+    /// Filters of which guilds and resources are cached.
+    fn filter(&self) -> &Filter {
+        &self.0.config.filter
+    }
+
+    /// ID of the current user, used for filtering members.
+    fn current_user_id(&self) -> Option<UserId> {
+        self.current_user().map(|user| user.id)
+    }
+
     /// Determine whether the configured cache wants a specific resource to be
     /// processed.
     fn wants(&self, resource_type: ResourceType) -> bool {
@@ -945,15 +1657,16 @@ fn presence_user_id(presence: &Presence) -> UserId {
 #[cfg(test)]
 mod tests {
     use crate::InMemoryCache;
-    use std::borrow::Cow;
+    use std::{borrow::Cow, collections::HashSet};
     use twilight_model::{
-        channel::{ChannelType, GuildChannel, TextChannel},
+        channel::{ChannelType, GuildChannel, TextChannel, VoiceChannel, Webhook, WebhookType},
         gateway::payload::{MemberRemove, RoleDelete},
         guild::{
             DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, Guild, Member, MfaLevel,
             Permissions, PremiumTier, Role, SystemChannelFlags, VerificationLevel,
         },
-        id::{ChannelId, EmojiId, GuildId, RoleId, UserId},
+        id::{ChannelId, EmojiId, GuildId, RoleId, UserId, WebhookId},
+        invite::{Invite, InviteChannel},
         user::{CurrentUser, User},
         voice::VoiceState,
     };
@@ -1046,6 +1759,7 @@ mod tests {
             guild_id: Some(guild_id),
             member: None,
             mute: true,
+            request_to_speak_timestamp: None,
             self_deaf: false,
             self_mute: true,
             self_stream: false,
@@ -1098,6 +1812,7 @@ mod tests {
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::AllMembers,
             features: vec![],
+            guild_scheduled_events: Vec::new(),
             icon: None,
             joined_at: Some("".to_owned()),
             large: false,
@@ -1118,9 +1833,11 @@ mod tests {
             region: "us-east".to_owned(),
             roles: Vec::new(),
             splash: None,
+            stage_instances: Vec::new(),
             system_channel_id: None,
             system_channel_flags: SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS,
             rules_channel_id: None,
+            threads: Vec::new(),
             unavailable: false,
             verification_level: VerificationLevel::VeryHigh,
             voice_states: Vec::new(),
@@ -1463,6 +2180,106 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_members_with_role() {
+        let cache = InMemoryCache::new();
+
+        for id in 1..=3 {
+            let mut member = member(UserId(id), GuildId(1));
+
+            if id != 2 {
+                member.roles.push(RoleId(4));
+            }
+
+            cache.cache_member(GuildId(1), member);
+        }
+
+        let mut ids = cache
+            .members_with_role(GuildId(1), RoleId(4))
+            .iter()
+            .map(|member| member.user.id)
+            .collect::<Vec<_>>();
+        ids.sort();
+
+        assert_eq!(vec![UserId(1), UserId(3)], ids);
+        assert!(cache.members_with_role(GuildId(2), RoleId(4)).is_empty());
+    }
+
+    #[test]
+    fn test_guild_channels_of_kind() {
+        let cache = InMemoryCache::new();
+
+        let text = |id| {
+            GuildChannel::Text(TextChannel {
+                guild_id: None,
+                id: ChannelId(id),
+                kind: ChannelType::GuildText,
+                last_message_id: None,
+                last_pin_timestamp: None,
+                name: "text".to_owned(),
+                nsfw: false,
+                permission_overwrites: Vec::new(),
+                parent_id: None,
+                position: 0,
+                rate_limit_per_user: None,
+                topic: None,
+            })
+        };
+
+        cache.cache_guild_channel(GuildId(4), text(1));
+        cache.cache_guild_channel(
+            GuildId(4),
+            GuildChannel::Voice(VoiceChannel {
+                bitrate: 64_000,
+                guild_id: None,
+                id: ChannelId(2),
+                kind: ChannelType::GuildVoice,
+                name: "voice".to_owned(),
+                permission_overwrites: Vec::new(),
+                parent_id: None,
+                position: 0,
+                user_limit: None,
+            }),
+        );
+        cache.cache_guild_channel(GuildId(4), text(3));
+
+        let mut ids = cache
+            .guild_channels_of_kind(GuildId(4), ChannelType::GuildText)
+            .iter()
+            .map(|channel| channel.id())
+            .collect::<Vec<_>>();
+        ids.sort();
+
+        assert_eq!(vec![ChannelId(1), ChannelId(3)], ids);
+        assert_eq!(
+            1,
+            cache
+                .guild_channels_of_kind(GuildId(4), ChannelType::GuildVoice)
+                .len()
+        );
+        assert!(cache
+            .guild_channels_of_kind(GuildId(4), ChannelType::GuildCategory)
+            .is_empty());
+        assert!(cache
+            .guild_channels_of_kind(GuildId(5), ChannelType::GuildText)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_user_by_tag() {
+        let cache = InMemoryCache::new();
+        let mut user = user(UserId(1));
+        user.name = "with#hash".to_owned();
+        cache.cache_user(Cow::Owned(user), Some(GuildId(1)));
+
+        assert_eq!(
+            Some(UserId(1)),
+            cache.user_by_tag("with#hash#0001").map(|user| user.id)
+        );
+        assert!(cache.user_by_tag("with#hash#0002").is_none());
+        assert!(cache.user_by_tag("with").is_none());
+    }
+
     #[test]
     fn test_cache_emoji() {
         let cache = InMemoryCache::new();
@@ -1529,6 +2346,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_set_guild_invites() {
+        let cache = InMemoryCache::new();
+        let invite = Invite {
+            approximate_member_count: None,
+            approximate_presence_count: None,
+            channel: InviteChannel {
+                id: ChannelId(2),
+                kind: ChannelType::GuildText,
+                name: None,
+            },
+            code: "twilight".to_owned(),
+            created_at: None,
+            guild: None,
+            inviter: None,
+            max_age: Some(0),
+            max_uses: Some(0),
+            target_user_type: None,
+            target_user: None,
+            temporary: Some(false),
+            uses: Some(3),
+        };
+
+        cache.set_guild_invites(GuildId(1), vec![invite]);
+        assert_eq!(3, cache.invite("twilight").unwrap().uses);
+
+        let used = cache.update_invite_uses(GuildId(1), vec![("twilight".to_owned(), 4)]);
+        assert_eq!(1, used.len());
+
+        cache.set_guild_invites(GuildId(1), Vec::new());
+        assert!(cache.invite("twilight").is_none());
+        assert_eq!(Some(HashSet::new()), cache.guild_invites(GuildId(1)));
+    }
+
+    #[test]
+    fn test_set_channel_webhooks() {
+        let cache = InMemoryCache::new();
+        let webhook = Webhook {
+            avatar: None,
+            channel_id: ChannelId(2),
+            guild_id: Some(GuildId(1)),
+            id: WebhookId(3),
+            kind: WebhookType::Incoming,
+            name: None,
+            token: None,
+            user: None,
+        };
+
+        cache.set_channel_webhooks(ChannelId(2), vec![webhook]);
+        assert!(cache.webhook(WebhookId(3)).is_some());
+
+        cache.set_channel_webhooks(ChannelId(2), Vec::new());
+        assert!(cache.webhook(WebhookId(3)).is_none());
+        assert_eq!(Some(HashSet::new()), cache.channel_webhooks(ChannelId(2)));
+    }
+
     #[test]
     fn test_clear() {
         let cache = InMemoryCache::new();
@@ -1,13 +1,13 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use twilight_model::{
     guild::{
-        DefaultMessageNotificationLevel, ExplicitContentFilter, MfaLevel, Permissions, PremiumTier,
-        SystemChannelFlags, VerificationLevel,
+        DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, MfaLevel, Permissions,
+        PremiumTier, SystemChannelFlags, VerificationLevel,
     },
     id::{ApplicationId, ChannelId, GuildId, UserId},
 };
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct CachedGuild {
     pub id: GuildId,
     pub afk_channel_id: Option<ChannelId>,
@@ -45,3 +45,45 @@ pub struct CachedGuild {
     pub widget_channel_id: Option<ChannelId>,
     pub widget_enabled: Option<bool>,
 }
+
+impl From<Guild> for CachedGuild {
+    fn from(guild: Guild) -> Self {
+        Self {
+            id: guild.id,
+            afk_channel_id: guild.afk_channel_id,
+            afk_timeout: guild.afk_timeout,
+            application_id: guild.application_id,
+            banner: guild.banner,
+            default_message_notifications: guild.default_message_notifications,
+            description: guild.description,
+            discovery_splash: guild.discovery_splash,
+            explicit_content_filter: guild.explicit_content_filter,
+            features: guild.features,
+            icon: guild.icon,
+            joined_at: guild.joined_at,
+            large: guild.large,
+            lazy: guild.lazy,
+            max_members: guild.max_members,
+            max_presences: guild.max_presences,
+            member_count: guild.member_count,
+            mfa_level: guild.mfa_level,
+            name: guild.name,
+            owner: guild.owner,
+            owner_id: guild.owner_id,
+            permissions: guild.permissions,
+            preferred_locale: guild.preferred_locale,
+            premium_subscription_count: guild.premium_subscription_count,
+            premium_tier: guild.premium_tier,
+            region: guild.region,
+            rules_channel_id: guild.rules_channel_id,
+            splash: guild.splash,
+            system_channel_id: guild.system_channel_id,
+            system_channel_flags: guild.system_channel_flags,
+            unavailable: guild.unavailable,
+            verification_level: guild.verification_level,
+            vanity_url_code: guild.vanity_url_code,
+            widget_channel_id: guild.widget_channel_id,
+            widget_enabled: guild.widget_enabled,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use twilight_model::{
+    gateway::payload::InviteCreate,
+    id::{ChannelId, GuildId, UserId},
+    invite::Invite,
+};
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CachedInvite {
+    pub channel_id: ChannelId,
+    pub code: String,
+    pub created_at: String,
+    pub guild_id: GuildId,
+    pub inviter_id: Option<UserId>,
+    pub max_age: u64,
+    pub max_uses: u64,
+    pub temporary: bool,
+    pub uses: u64,
+}
+
+impl CachedInvite {
+    /// Create a cached invite from an invite of a guild fetched over HTTP.
+    ///
+    /// Metadata missing from the invite defaults to zero values.
+    pub(crate) fn from_invite(guild_id: GuildId, invite: Invite) -> Self {
+        Self {
+            channel_id: invite.channel.id,
+            code: invite.code,
+            created_at: invite.created_at.unwrap_or_default(),
+            guild_id,
+            inviter_id: invite.inviter.map(|user| user.id),
+            max_age: invite.max_age.unwrap_or_default(),
+            max_uses: invite.max_uses.unwrap_or_default(),
+            temporary: invite.temporary.unwrap_or_default(),
+            uses: invite.uses.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<InviteCreate> for CachedInvite {
+    fn from(invite: InviteCreate) -> Self {
+        Self {
+            channel_id: invite.channel_id,
+            code: invite.code,
+            created_at: invite.created_at,
+            guild_id: invite.guild_id,
+            inviter_id: invite.inviter.map(|user| user.id),
+            max_age: invite.max_age,
+            max_uses: invite.max_uses,
+            temporary: invite.temporary,
+            uses: invite.uses.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CachedInvite;
+    use twilight_model::{
+        gateway::payload::InviteCreate,
+        id::{ChannelId, GuildId},
+    };
+
+    #[test]
+    fn test_from_invite_create() {
+        let invite = InviteCreate {
+            channel_id: ChannelId(1),
+            code: "twilight".to_owned(),
+            created_at: "2021-01-01T00:00:00+00:00".to_owned(),
+            guild_id: GuildId(2),
+            inviter: None,
+            max_age: 3600,
+            max_uses: 5,
+            target_user_type: None,
+            target_user: None,
+            temporary: false,
+            uses: 0,
+        };
+
+        let cached = CachedInvite::from(invite);
+
+        assert_eq!("twilight", cached.code);
+        assert_eq!(GuildId(2), cached.guild_id);
+        assert_eq!(0, cached.uses);
+    }
+}
@@ -1,10 +1,10 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use twilight_model::{
     channel::{
         embed::Embed,
         message::{
-            Message, MessageActivity, MessageApplication, MessageFlags, MessageReaction,
-            MessageReference, MessageType, Sticker,
+            component::Component, Message, MessageActivity, MessageApplication, MessageFlags,
+            MessageReaction, MessageReference, MessageType, Sticker,
         },
         Attachment, ChannelMention,
     },
@@ -12,7 +12,7 @@ use twilight_model::{
     id::{ChannelId, GuildId, MessageId, RoleId, UserId, WebhookId},
 };
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct CachedMessage {
     pub id: MessageId,
     pub activity: Option<MessageActivity>,
@@ -20,6 +20,8 @@ pub struct CachedMessage {
     pub attachments: Vec<Attachment>,
     pub author: UserId,
     pub channel_id: ChannelId,
+    #[serde(default)]
+    pub components: Vec<Component>,
     pub content: String,
     pub edited_timestamp: Option<String>,
     pub embeds: Vec<Embed>,
@@ -49,6 +51,7 @@ impl From<Message> for CachedMessage {
             attachments: msg.attachments,
             author: msg.author.id,
             channel_id: msg.channel_id,
+            components: msg.components,
             content: msg.content,
             edited_timestamp: msg.edited_timestamp,
             embeds: msg.embeds,
@@ -2,14 +2,15 @@
 
 mod emoji;
 mod guild;
+mod invite;
 mod member;
 mod message;
 mod presence;
 mod voice_state;
 
 pub use self::{
-    emoji::CachedEmoji, guild::CachedGuild, member::CachedMember, message::CachedMessage,
-    presence::CachedPresence, voice_state::CachedVoiceState,
+    emoji::CachedEmoji, guild::CachedGuild, invite::CachedInvite, member::CachedMember,
+    message::CachedMessage, presence::CachedPresence, voice_state::CachedVoiceState,
 };
 
 #[cfg(tests)]
@@ -1,15 +1,16 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use twilight_model::{
     id::{ChannelId, GuildId, UserId},
     voice::VoiceState,
 };
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct CachedVoiceState {
     pub channel_id: Option<ChannelId>,
     pub deaf: bool,
     pub guild_id: Option<GuildId>,
     pub mute: bool,
+    pub request_to_speak_timestamp: Option<String>,
     pub self_deaf: bool,
     pub self_mute: bool,
     pub self_stream: bool,
@@ -25,6 +26,7 @@ impl PartialEq<VoiceState> for CachedVoiceState {
             && self.deaf == other.deaf
             && self.guild_id == other.guild_id
             && self.mute == other.mute
+            && self.request_to_speak_timestamp == other.request_to_speak_timestamp
             && self.self_deaf == other.self_deaf
             && self.self_mute == other.self_mute
             && self.self_stream == other.self_stream
@@ -0,0 +1,179 @@
+use super::InMemoryCache;
+use twilight_http::{Client, Error};
+use twilight_model::id::{ChannelId, GuildId};
+
+impl InMemoryCache {
+    /// Fetch the webhooks of a channel and replace the cached ones.
+    ///
+    /// Call this when receiving a [`WebhooksUpdate`] event, which only
+    /// notifies that the webhooks of a channel changed.
+    ///
+    /// This requires the `twilight-http` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::env;
+    /// use twilight_cache_inmemory::InMemoryCache;
+    /// use twilight_http::Client;
+    /// use twilight_model::gateway::event::Event;
+    ///
+    /// # async fn handle(cache: &InMemoryCache, event: Event) -> Result<(), Box<dyn std::error::Error>> {
+    /// let http = Client::new(env::var("DISCORD_TOKEN")?);
+    ///
+    /// cache.update(&event);
+    ///
+    /// if let Event::WebhooksUpdate(update) = event {
+    ///     cache.refresh_webhooks(&http, update.channel_id).await?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the webhooks couldn't be fetched. The cached
+    /// webhooks are left unchanged.
+    ///
+    /// [`WebhooksUpdate`]: twilight_model::gateway::payload::WebhooksUpdate
+    pub async fn refresh_webhooks(
+        &self,
+        http: &Client,
+        channel_id: ChannelId,
+    ) -> Result<(), Error> {
+        let webhooks = http.channel_webhooks(channel_id).await?.model().await?;
+        self.set_channel_webhooks(channel_id, webhooks);
+
+        Ok(())
+    }
+
+    /// Fetch the integrations of a guild and replace the cached ones.
+    ///
+    /// Call this when receiving a [`GuildIntegrationsUpdate`] event, which
+    /// only notifies that the integrations of a guild changed.
+    ///
+    /// This requires the `twilight-http` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the integrations couldn't be fetched. The cached
+    /// integrations are left unchanged.
+    ///
+    /// [`GuildIntegrationsUpdate`]: twilight_model::gateway::payload::GuildIntegrationsUpdate
+    pub async fn refresh_integrations(
+        &self,
+        http: &Client,
+        guild_id: GuildId,
+    ) -> Result<(), Error> {
+        let integrations = http.guild_integrations(guild_id).await?.model().await?;
+        self.set_guild_integrations(guild_id, integrations);
+
+        Ok(())
+    }
+    /// Fetch the invites of a guild and replace the cached ones.
+    ///
+    /// Call this when the guild becomes available to track the uses of
+    /// invites that were created before the cache started, since only new
+    /// invites are received over the gateway.
+    ///
+    /// This requires the `twilight-http` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the invites couldn't be fetched. The cached invites
+    /// are left unchanged.
+    pub async fn refresh_invites(&self, http: &Client, guild_id: GuildId) -> Result<(), Error> {
+        let invites = http.guild_invites(guild_id).await?.model().await?;
+        self.set_guild_invites(guild_id, invites);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::InMemoryCache;
+    use hyper::{
+        service::{make_service_fn, service_fn},
+        Body, Request, Response, Server, StatusCode,
+    };
+    use serde_json::json;
+    use std::{collections::HashSet, convert::Infallible, net::SocketAddr};
+    use twilight_http::Client;
+    use twilight_model::id::{ChannelId, GuildId, WebhookId};
+
+    /// Client of a mocked API serving the invites of guild 1 and the
+    /// webhooks of channel 2, responding with 404 to other requests.
+    fn client() -> Client {
+        let make_service = make_service_fn(|_| async {
+            Ok::<_, Infallible>(service_fn(|request: Request<Body>| async move {
+                let body = match request.uri().path() {
+                    "/api/v9/guilds/1/invites" => json!([{
+                        "channel": {"id": "2", "name": "general", "type": 0},
+                        "code": "twilight",
+                        "created_at": "2021-01-01T00:00:00+00:00",
+                        "max_age": 0,
+                        "max_uses": 0,
+                        "temporary": false,
+                        "uses": 5
+                    }]),
+                    "/api/v9/channels/2/webhooks" => json!([{
+                        "channel_id": "2",
+                        "guild_id": "1",
+                        "id": "3",
+                        "type": 1
+                    }]),
+                    _ => {
+                        let mut response = Response::new(Body::from(
+                            json!({"code": 10003, "message": "Unknown Channel"}).to_string(),
+                        ));
+                        *response.status_mut() = StatusCode::NOT_FOUND;
+
+                        return Ok::<_, Infallible>(response);
+                    }
+                };
+
+                Ok::<_, Infallible>(Response::new(Body::from(body.to_string())))
+            }))
+        });
+
+        let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+        let addr = server.local_addr();
+        tokio::spawn(server);
+
+        Client::builder()
+            .proxy(addr.to_string(), true)
+            .ratelimiter(None)
+            .token("token")
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_refresh_invites() {
+        let cache = InMemoryCache::new();
+        cache.refresh_invites(&client(), GuildId(1)).await.unwrap();
+
+        let invite = cache.invite("twilight").unwrap();
+        assert_eq!(ChannelId(2), invite.channel_id);
+        assert_eq!(GuildId(1), invite.guild_id);
+        assert_eq!(5, invite.uses);
+
+        // Invites that existed before the cache was seeded can be tracked.
+        let used = cache.update_invite_uses(GuildId(1), vec![("twilight".to_owned(), 6)]);
+        assert_eq!(1, used.len());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_webhooks() {
+        let cache = InMemoryCache::new();
+        let client = client();
+        cache.refresh_webhooks(&client, ChannelId(2)).await.unwrap();
+
+        let expected = vec![WebhookId(3)].into_iter().collect::<HashSet<_>>();
+        assert_eq!(Some(expected.clone()), cache.channel_webhooks(ChannelId(2)));
+
+        // A failed refresh leaves the cached webhooks unchanged.
+        assert!(cache.refresh_webhooks(&client, ChannelId(4)).await.is_err());
+        assert_eq!(Some(expected), cache.channel_webhooks(ChannelId(2)));
+        assert!(cache.channel_webhooks(ChannelId(4)).is_none());
+    }
+}
@@ -0,0 +1,377 @@
+use super::{
+    model::{CachedEmoji, CachedGuild, CachedInvite, CachedMember, CachedMessage, CachedPresence},
+    GuildItem, InMemoryCache,
+};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Error as JsonError;
+use std::{
+    collections::{BTreeSet, HashSet},
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::Hash,
+    io::{BufRead, BufReader, Error as IoError, Read, Write},
+    sync::Arc,
+};
+use twilight_model::{
+    channel::{stage_instance::StageInstance, Group, GuildChannel, PrivateChannel, Webhook},
+    guild::{scheduled_event::GuildScheduledEvent, GuildIntegration, Role},
+    id::{
+        ChannelId, EmojiId, GuildId, IntegrationId, RoleId, ScheduledEventId, StageId, UserId,
+        WebhookId,
+    },
+    user::{CurrentUser, User},
+    voice::VoiceState,
+};
+
+/// Header written before the data of a snapshot, followed by the version.
+const HEADER: &str = "twilight-cache-inmemory snapshot v";
+
+/// Creating or reading a [`Snapshot`] failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SnapshotError {
+    /// The data of the snapshot couldn't be deserialized.
+    Deserializing {
+        /// Reason for the error.
+        source: JsonError,
+    },
+    /// The snapshot doesn't start with a snapshot header.
+    HeaderInvalid,
+    /// Reading from or writing to the snapshot failed.
+    Io {
+        /// Reason for the error.
+        source: IoError,
+    },
+    /// The cache couldn't be serialized.
+    Serializing {
+        /// Reason for the error.
+        source: JsonError,
+    },
+    /// The snapshot was created by an incompatible version of the cache.
+    VersionUnsupported {
+        /// Version of the snapshot.
+        version: u32,
+    },
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Deserializing { .. } => f.write_str("the snapshot couldn't be deserialized"),
+            Self::HeaderInvalid => f.write_str("the snapshot header is invalid"),
+            Self::Io { .. } => f.write_str("the snapshot couldn't be read or written"),
+            Self::Serializing { .. } => f.write_str("the cache couldn't be serialized"),
+            Self::VersionUnsupported { version } => write!(
+                f,
+                "the snapshot version {} is unsupported, expected {}",
+                version,
+                Snapshot::VERSION,
+            ),
+        }
+    }
+}
+
+impl Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Deserializing { source } => Some(source),
+            Self::HeaderInvalid => None,
+            Self::Io { source } => Some(source),
+            Self::Serializing { source } => Some(source),
+            Self::VersionUnsupported { .. } => None,
+        }
+    }
+}
+
+/// Serialized state of an [`InMemoryCache`].
+///
+/// Snapshots are created with [`InMemoryCache::snapshot`] and restored when
+/// building a cache with [`InMemoryCacheBuilder::snapshot`]. This allows the
+/// cache to survive a process restart, such as when shards are resumed from
+/// the sessions returned by bringing a cluster down.
+///
+/// [`InMemoryCacheBuilder::snapshot`]: crate::InMemoryCacheBuilder::snapshot
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Snapshot {
+    #[serde(default)]
+    bans: Vec<((GuildId, UserId), Arc<User>)>,
+    #[serde(default)]
+    channel_webhooks: Vec<(ChannelId, HashSet<WebhookId>)>,
+    channels_guild: Vec<(ChannelId, GuildItem<GuildChannel>)>,
+    channels_private: Vec<(ChannelId, Arc<PrivateChannel>)>,
+    current_user: Option<Arc<CurrentUser>>,
+    emojis: Vec<(EmojiId, GuildItem<CachedEmoji>)>,
+    groups: Vec<(ChannelId, Arc<Group>)>,
+    guilds: Vec<(GuildId, Arc<CachedGuild>)>,
+    guild_channels: Vec<(GuildId, HashSet<ChannelId>)>,
+    #[serde(default)]
+    guild_bans: Vec<(GuildId, HashSet<UserId>)>,
+    guild_emojis: Vec<(GuildId, HashSet<EmojiId>)>,
+    #[serde(default)]
+    guild_integrations: Vec<(GuildId, HashSet<IntegrationId>)>,
+    #[serde(default)]
+    guild_invites: Vec<(GuildId, HashSet<String>)>,
+    guild_members: Vec<(GuildId, HashSet<UserId>)>,
+    guild_presences: Vec<(GuildId, HashSet<UserId>)>,
+    guild_roles: Vec<(GuildId, HashSet<RoleId>)>,
+    #[serde(default)]
+    guild_scheduled_events: Vec<(GuildId, HashSet<ScheduledEventId>)>,
+    #[serde(default)]
+    guild_stage_instances: Vec<(GuildId, HashSet<StageId>)>,
+    #[serde(default)]
+    integrations: Vec<(IntegrationId, GuildItem<GuildIntegration>)>,
+    #[serde(default)]
+    invites: Vec<(String, Arc<CachedInvite>)>,
+    members: Vec<((GuildId, UserId), Arc<CachedMember>)>,
+    messages: Vec<(ChannelId, Vec<Arc<CachedMessage>>)>,
+    presences: Vec<((GuildId, UserId), Arc<CachedPresence>)>,
+    roles: Vec<(RoleId, GuildItem<Role>)>,
+    #[serde(default)]
+    scheduled_events: Vec<(ScheduledEventId, GuildItem<GuildScheduledEvent>)>,
+    #[serde(default)]
+    scheduled_event_users: Vec<(ScheduledEventId, HashSet<UserId>)>,
+    #[serde(default)]
+    stage_instances: Vec<(StageId, GuildItem<StageInstance>)>,
+    unavailable_guilds: Vec<GuildId>,
+    users: Vec<(UserId, (Arc<User>, BTreeSet<GuildId>))>,
+    voice_state_channels: Vec<(ChannelId, HashSet<(GuildId, UserId)>)>,
+    voice_state_guilds: Vec<(GuildId, HashSet<UserId>)>,
+    voice_states: Vec<((GuildId, UserId), Arc<VoiceState>)>,
+    #[serde(default)]
+    webhooks: Vec<(WebhookId, Arc<Webhook>)>,
+}
+
+impl Snapshot {
+    /// Version of the snapshot format.
+    ///
+    /// Snapshots of other versions are rejected when read.
+    pub const VERSION: u32 = 1;
+
+    /// Read a snapshot written by [`InMemoryCache::snapshot`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::HeaderInvalid`] if the reader doesn't start
+    /// with a snapshot header.
+    ///
+    /// Returns [`SnapshotError::VersionUnsupported`] if the snapshot was
+    /// written in a format other than the current [`VERSION`].
+    ///
+    /// Returns [`SnapshotError::Io`] if reading failed.
+    ///
+    /// Returns [`SnapshotError::Deserializing`] if the data of the snapshot
+    /// is invalid.
+    ///
+    /// [`VERSION`]: Self::VERSION
+    pub fn from_reader(reader: impl Read) -> Result<Self, SnapshotError> {
+        let mut reader = BufReader::new(reader);
+        let mut header = String::new();
+
+        reader
+            .read_line(&mut header)
+            .map_err(|source| SnapshotError::Io { source })?;
+
+        let version = header
+            .trim_end()
+            .strip_prefix(HEADER)
+            .and_then(|version| version.parse().ok())
+            .ok_or(SnapshotError::HeaderInvalid)?;
+
+        if version != Self::VERSION {
+            return Err(SnapshotError::VersionUnsupported { version });
+        }
+
+        serde_json::from_reader(reader).map_err(|source| SnapshotError::Deserializing { source })
+    }
+
+    /// Create a snapshot of the current state of a cache.
+    pub(super) fn new(cache: &InMemoryCache) -> Self {
+        let cache = &cache.0;
+
+        Self {
+            bans: entries(&cache.bans),
+            channel_webhooks: entries(&cache.channel_webhooks),
+            channels_guild: entries(&cache.channels_guild),
+            channels_private: entries(&cache.channels_private),
+            current_user: cache
+                .current_user
+                .lock()
+                .expect("current user poisoned")
+                .clone(),
+            emojis: entries(&cache.emojis),
+            groups: entries(&cache.groups),
+            guilds: entries(&cache.guilds),
+            guild_channels: entries(&cache.guild_channels),
+            guild_bans: entries(&cache.guild_bans),
+            guild_emojis: entries(&cache.guild_emojis),
+            guild_integrations: entries(&cache.guild_integrations),
+            guild_invites: entries(&cache.guild_invites),
+            guild_members: entries(&cache.guild_members),
+            guild_presences: entries(&cache.guild_presences),
+            guild_roles: entries(&cache.guild_roles),
+            guild_scheduled_events: entries(&cache.guild_scheduled_events),
+            guild_stage_instances: entries(&cache.guild_stage_instances),
+            integrations: entries(&cache.integrations),
+            invites: entries(&cache.invites),
+            members: entries(&cache.members),
+            messages: cache
+                .messages
+                .iter()
+                .map(|r| (*r.key(), r.value().values().cloned().collect()))
+                .collect(),
+            presences: entries(&cache.presences),
+            roles: entries(&cache.roles),
+            scheduled_events: entries(&cache.scheduled_events),
+            scheduled_event_users: entries(&cache.scheduled_event_users),
+            stage_instances: entries(&cache.stage_instances),
+            unavailable_guilds: cache.unavailable_guilds.iter().map(|r| *r.key()).collect(),
+            users: entries(&cache.users),
+            voice_state_channels: entries(&cache.voice_state_channels),
+            voice_state_guilds: entries(&cache.voice_state_guilds),
+            voice_states: entries(&cache.voice_states),
+            webhooks: entries(&cache.webhooks),
+        }
+    }
+
+    /// Write the snapshot, preceded by its header.
+    pub(super) fn write(&self, mut writer: impl Write) -> Result<(), SnapshotError> {
+        writeln!(writer, "{}{}", HEADER, Self::VERSION)
+            .map_err(|source| SnapshotError::Io { source })?;
+
+        serde_json::to_writer(&mut writer, self)
+            .map_err(|source| SnapshotError::Serializing { source })?;
+
+        writer
+            .flush()
+            .map_err(|source| SnapshotError::Io { source })
+    }
+
+    /// Restore the state of the snapshot into a cache.
+    pub(super) fn restore(self, cache: &InMemoryCache) {
+        let cache = &cache.0;
+
+        insert(&cache.bans, self.bans);
+        insert(&cache.channel_webhooks, self.channel_webhooks);
+        insert(&cache.channels_guild, self.channels_guild);
+        insert(&cache.channels_private, self.channels_private);
+        *cache.current_user.lock().expect("current user poisoned") = self.current_user;
+        insert(&cache.emojis, self.emojis);
+        insert(&cache.groups, self.groups);
+        insert(&cache.guilds, self.guilds);
+        insert(&cache.guild_channels, self.guild_channels);
+        insert(&cache.guild_bans, self.guild_bans);
+        insert(&cache.guild_emojis, self.guild_emojis);
+        insert(&cache.guild_integrations, self.guild_integrations);
+        insert(&cache.guild_invites, self.guild_invites);
+        insert(&cache.guild_members, self.guild_members);
+        insert(&cache.guild_presences, self.guild_presences);
+        insert(&cache.guild_roles, self.guild_roles);
+        insert(&cache.guild_scheduled_events, self.guild_scheduled_events);
+        insert(&cache.guild_stage_instances, self.guild_stage_instances);
+        insert(&cache.integrations, self.integrations);
+        insert(&cache.invites, self.invites);
+        insert(&cache.members, self.members);
+
+        for (channel_id, messages) in self.messages {
+            let messages = messages
+                .into_iter()
+                .map(|message| (message.id, message))
+                .collect();
+
+            cache.messages.insert(channel_id, messages);
+        }
+
+        insert(&cache.presences, self.presences);
+        insert(&cache.roles, self.roles);
+        insert(&cache.scheduled_events, self.scheduled_events);
+        insert(&cache.scheduled_event_users, self.scheduled_event_users);
+        insert(&cache.stage_instances, self.stage_instances);
+
+        for guild_id in self.unavailable_guilds {
+            cache.unavailable_guilds.insert(guild_id);
+        }
+
+        insert(&cache.users, self.users);
+        insert(&cache.voice_state_channels, self.voice_state_channels);
+        insert(&cache.voice_state_guilds, self.voice_state_guilds);
+        insert(&cache.voice_states, self.voice_states);
+        insert(&cache.webhooks, self.webhooks);
+    }
+}
+
+fn entries<K: Clone + Eq + Hash, V: Clone>(map: &DashMap<K, V>) -> Vec<(K, V)> {
+    map.iter()
+        .map(|r| (r.key().clone(), r.value().clone()))
+        .collect()
+}
+
+fn insert<K: Eq + Hash, V>(map: &DashMap<K, V>, entries: Vec<(K, V)>) {
+    for (key, value) in entries {
+        map.insert(key, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Snapshot, SnapshotError};
+    use crate::InMemoryCache;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        guild::{Permissions, Role},
+        id::{GuildId, RoleId},
+    };
+
+    assert_fields!(SnapshotError::Deserializing: source);
+    assert_fields!(SnapshotError::Io: source);
+    assert_fields!(SnapshotError::Serializing: source);
+    assert_fields!(SnapshotError::VersionUnsupported: version);
+    assert_impl_all!(Snapshot: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(SnapshotError: Debug, Error, Send, Sync);
+
+    #[test]
+    fn test_snapshot_restore() -> Result<(), SnapshotError> {
+        let cache = InMemoryCache::new();
+        cache.cache_role(
+            GuildId(1),
+            Role {
+                color: 0,
+                hoist: false,
+                id: RoleId(2),
+                managed: false,
+                mentionable: false,
+                name: "test".to_owned(),
+                permissions: Permissions::empty(),
+                position: 0,
+                tags: None,
+            },
+        );
+
+        let mut buf = Vec::new();
+        cache.snapshot(&mut buf)?;
+
+        let snapshot = Snapshot::from_reader(buf.as_slice())?;
+        let restored = InMemoryCache::builder().snapshot(snapshot).build();
+
+        assert_eq!(cache.role(RoleId(2)), restored.role(RoleId(2)));
+        assert_eq!(
+            cache.guild_roles(GuildId(1)),
+            restored.guild_roles(GuildId(1))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot_rejected() {
+        assert!(matches!(
+            Snapshot::from_reader(&b"twilight-cache-inmemory snapshot v0\n{}"[..]),
+            Err(SnapshotError::VersionUnsupported { version: 0 })
+        ));
+        assert!(matches!(
+            Snapshot::from_reader(&b"{}"[..]),
+            Err(SnapshotError::HeaderInvalid)
+        ));
+    }
+}
@@ -0,0 +1,569 @@
+use super::{
+    model::{CachedEmoji, CachedGuild, CachedInvite, CachedMember, CachedMessage, CachedPresence},
+    InMemoryCache,
+};
+use dashmap::DashMap;
+use std::{collections::HashSet, hash::Hash, mem};
+use twilight_model::{
+    channel::{stage_instance::StageInstance, Group, GuildChannel, PrivateChannel, Webhook},
+    guild::{scheduled_event::GuildScheduledEvent, GuildIntegration, Role},
+    id::GuildId,
+    user::User,
+    voice::VoiceState,
+};
+
+/// Statistics about the resources in an [`InMemoryCache`].
+///
+/// This is obtained through [`InMemoryCache::stats`]. Counts are read from the
+/// cache when called, so they're always up to date.
+#[derive(Debug)]
+pub struct InMemoryCacheStats<'a>(&'a InMemoryCache);
+
+impl<'a> InMemoryCacheStats<'a> {
+    pub(super) fn new(cache: &'a InMemoryCache) -> Self {
+        Self(cache)
+    }
+
+    /// Number of guild bans.
+    pub fn bans(&self) -> usize {
+        self.0 .0.bans.len()
+    }
+
+    /// Number of guild channels.
+    pub fn channels(&self) -> usize {
+        self.0 .0.channels_guild.len()
+    }
+
+    /// Number of emojis.
+    pub fn emojis(&self) -> usize {
+        self.0 .0.emojis.len()
+    }
+
+    /// Number of groups.
+    pub fn groups(&self) -> usize {
+        self.0 .0.groups.len()
+    }
+
+    /// Number of guilds.
+    pub fn guilds(&self) -> usize {
+        self.0 .0.guilds.len()
+    }
+
+    /// Number of integrations.
+    pub fn integrations(&self) -> usize {
+        self.0 .0.integrations.len()
+    }
+
+    /// Number of invites.
+    pub fn invites(&self) -> usize {
+        self.0 .0.invites.len()
+    }
+
+    /// Number of members across all guilds.
+    pub fn members(&self) -> usize {
+        self.0 .0.members.len()
+    }
+
+    /// Number of messages across all channels.
+    ///
+    /// This is a O(n) operation, where n is the amount of channels with
+    /// cached messages.
+    pub fn messages(&self) -> usize {
+        self.0 .0.messages.iter().map(|r| r.value().len()).sum()
+    }
+
+    /// Number of presences across all guilds.
+    pub fn presences(&self) -> usize {
+        self.0 .0.presences.len()
+    }
+
+    /// Number of private channels.
+    pub fn private_channels(&self) -> usize {
+        self.0 .0.channels_private.len()
+    }
+
+    /// Number of roles.
+    pub fn roles(&self) -> usize {
+        self.0 .0.roles.len()
+    }
+
+    /// Number of scheduled events.
+    pub fn scheduled_events(&self) -> usize {
+        self.0 .0.scheduled_events.len()
+    }
+
+    /// Number of live stage instances.
+    pub fn stage_instances(&self) -> usize {
+        self.0 .0.stage_instances.len()
+    }
+
+    /// Number of unavailable guilds.
+    pub fn unavailable_guilds(&self) -> usize {
+        self.0 .0.unavailable_guilds.len()
+    }
+
+    /// Number of users.
+    pub fn users(&self) -> usize {
+        self.0 .0.users.len()
+    }
+
+    /// Number of voice states across all guilds.
+    pub fn voice_states(&self) -> usize {
+        self.0 .0.voice_states.len()
+    }
+
+    /// Number of webhooks.
+    pub fn webhooks(&self) -> usize {
+        self.0 .0.webhooks.len()
+    }
+
+    /// Number of resources cached for a guild.
+    pub fn guild(&self, guild_id: GuildId) -> GuildStats {
+        let cache = &self.0 .0;
+
+        GuildStats {
+            channels: set_len(&cache.guild_channels, guild_id),
+            emojis: set_len(&cache.guild_emojis, guild_id),
+            members: set_len(&cache.guild_members, guild_id),
+            presences: set_len(&cache.guild_presences, guild_id),
+            roles: set_len(&cache.guild_roles, guild_id),
+            voice_states: set_len(&cache.voice_state_guilds, guild_id),
+        }
+    }
+
+    /// Estimate the number of bytes used by the cached resources.
+    ///
+    /// The estimate adds the size of each cached model to the capacity of its
+    /// strings and vectors. Deeply nested data such as the contents of
+    /// message embeds and the bookkeeping of the maps themselves aren't
+    /// accounted for, so the actual usage is higher.
+    ///
+    /// This is a O(n) operation, where n is the amount of cached resources.
+    pub fn memory_estimate(&self) -> usize {
+        let cache = &self.0 .0;
+
+        let messages = cache
+            .messages
+            .iter()
+            .map(|r| {
+                r.value()
+                    .values()
+                    .map(|message| footprint(&**message))
+                    .sum::<usize>()
+            })
+            .sum::<usize>();
+
+        map_footprint(&cache.bans, |user| footprint(&**user))
+            + map_footprint(&cache.channels_guild, |item| footprint(&*item.data))
+            + map_footprint(&cache.channels_private, |channel| footprint(&**channel))
+            + map_footprint(&cache.emojis, |item| footprint(&*item.data))
+            + map_footprint(&cache.groups, |group| footprint(&**group))
+            + map_footprint(&cache.guilds, |guild| footprint(&**guild))
+            + map_footprint(&cache.integrations, |item| footprint(&*item.data))
+            + map_footprint(&cache.invites, |invite| footprint(&**invite))
+            + map_footprint(&cache.members, |member| footprint(&**member))
+            + messages
+            + map_footprint(&cache.presences, |presence| footprint(&**presence))
+            + map_footprint(&cache.roles, |item| footprint(&*item.data))
+            + map_footprint(&cache.scheduled_events, |item| footprint(&*item.data))
+            + map_footprint(&cache.stage_instances, |item| footprint(&*item.data))
+            + map_footprint(&cache.users, |user| footprint(&*user.0))
+            + map_footprint(&cache.voice_states, |state| footprint(&**state))
+            + map_footprint(&cache.webhooks, |webhook| footprint(&**webhook))
+    }
+
+    /// Emit the statistics as gauges via the `metrics` crate.
+    ///
+    /// Counts are emitted as `Cache-<Resource>`, such as `Cache-Guilds`, and
+    /// the estimated memory as `Cache-MemoryEstimate`. Call this periodically,
+    /// such as on an interval, to keep the gauges up to date.
+    #[cfg(feature = "metrics")]
+    pub fn emit_metrics(&self) {
+        use std::convert::TryInto;
+
+        let gauges = [
+            ("Cache-Bans", self.bans()),
+            ("Cache-Channels", self.channels()),
+            ("Cache-Emojis", self.emojis()),
+            ("Cache-Groups", self.groups()),
+            ("Cache-Guilds", self.guilds()),
+            ("Cache-Integrations", self.integrations()),
+            ("Cache-Invites", self.invites()),
+            ("Cache-Members", self.members()),
+            ("Cache-MemoryEstimate", self.memory_estimate()),
+            ("Cache-Messages", self.messages()),
+            ("Cache-Presences", self.presences()),
+            ("Cache-PrivateChannels", self.private_channels()),
+            ("Cache-Roles", self.roles()),
+            ("Cache-ScheduledEvents", self.scheduled_events()),
+            ("Cache-StageInstances", self.stage_instances()),
+            ("Cache-UnavailableGuilds", self.unavailable_guilds()),
+            ("Cache-Users", self.users()),
+            ("Cache-VoiceStates", self.voice_states()),
+            ("Cache-Webhooks", self.webhooks()),
+        ];
+
+        for (name, value) in gauges.iter() {
+            metrics::gauge!(*name, (*value).try_into().unwrap_or(-1));
+        }
+    }
+}
+
+/// Number of resources cached in a guild.
+///
+/// This is obtained through [`InMemoryCacheStats::guild`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct GuildStats {
+    channels: usize,
+    emojis: usize,
+    members: usize,
+    presences: usize,
+    roles: usize,
+    voice_states: usize,
+}
+
+impl GuildStats {
+    /// Number of channels in the guild.
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Number of emojis in the guild.
+    pub fn emojis(&self) -> usize {
+        self.emojis
+    }
+
+    /// Number of members in the guild.
+    pub fn members(&self) -> usize {
+        self.members
+    }
+
+    /// Number of presences in the guild.
+    pub fn presences(&self) -> usize {
+        self.presences
+    }
+
+    /// Number of roles in the guild.
+    pub fn roles(&self) -> usize {
+        self.roles
+    }
+
+    /// Number of voice states in the guild.
+    pub fn voice_states(&self) -> usize {
+        self.voice_states
+    }
+}
+
+/// Estimated number of bytes used by a model.
+trait Footprint {
+    /// Capacity of the data owned by the model on the heap.
+    fn heap_size(&self) -> usize;
+}
+
+fn footprint<T: Footprint>(value: &T) -> usize {
+    mem::size_of::<T>() + value.heap_size()
+}
+
+fn map_footprint<K: Eq + Hash, V>(map: &DashMap<K, V>, f: impl Fn(&V) -> usize) -> usize {
+    map.iter().map(|r| f(r.value())).sum()
+}
+
+fn set_len<T: Eq + Hash>(map: &DashMap<GuildId, HashSet<T>>, guild_id: GuildId) -> usize {
+    map.get(&guild_id)
+        .map(|r| r.value().len())
+        .unwrap_or_default()
+}
+
+fn option_string(value: &Option<String>) -> usize {
+    value.as_ref().map(String::capacity).unwrap_or_default()
+}
+
+fn option_user(value: &Option<User>) -> usize {
+    value.as_ref().map(User::heap_size).unwrap_or_default()
+}
+
+// The capacity of a vector is what's allocated, which a slice doesn't have.
+#[allow(clippy::ptr_arg)]
+fn vec<T>(value: &Vec<T>) -> usize {
+    value.capacity() * mem::size_of::<T>()
+}
+
+impl Footprint for CachedEmoji {
+    fn heap_size(&self) -> usize {
+        self.name.capacity() + vec(&self.roles)
+    }
+}
+
+impl Footprint for CachedGuild {
+    fn heap_size(&self) -> usize {
+        option_string(&self.banner)
+            + option_string(&self.description)
+            + option_string(&self.discovery_splash)
+            + self
+                .features
+                .iter()
+                .map(|feature| mem::size_of::<String>() + feature.capacity())
+                .sum::<usize>()
+            + option_string(&self.icon)
+            + option_string(&self.joined_at)
+            + self.name.capacity()
+            + self.preferred_locale.capacity()
+            + self.region.capacity()
+            + option_string(&self.splash)
+            + option_string(&self.vanity_url_code)
+    }
+}
+
+impl Footprint for CachedInvite {
+    fn heap_size(&self) -> usize {
+        self.code.capacity() + self.created_at.capacity()
+    }
+}
+
+impl Footprint for CachedMember {
+    fn heap_size(&self) -> usize {
+        option_string(&self.joined_at)
+            + option_string(&self.nick)
+            + option_string(&self.premium_since)
+            + vec(&self.roles)
+    }
+}
+
+impl Footprint for CachedMessage {
+    fn heap_size(&self) -> usize {
+        self.attachments
+            .iter()
+            .map(|attachment| {
+                attachment.filename.capacity()
+                    + attachment.proxy_url.capacity()
+                    + attachment.url.capacity()
+            })
+            .sum::<usize>()
+            + vec(&self.attachments)
+            + vec(&self.components)
+            + self.content.capacity()
+            + option_string(&self.edited_timestamp)
+            + vec(&self.embeds)
+            + vec(&self.mention_channels)
+            + vec(&self.mention_roles)
+            + vec(&self.mentions)
+            + vec(&self.reactions)
+            + vec(&self.stickers)
+            + self.timestamp.capacity()
+    }
+}
+
+impl Footprint for CachedPresence {
+    fn heap_size(&self) -> usize {
+        self.activities
+            .iter()
+            .map(|activity| {
+                activity.name.capacity()
+                    + option_string(&activity.details)
+                    + option_string(&activity.state)
+            })
+            .sum::<usize>()
+            + vec(&self.activities)
+    }
+}
+
+impl Footprint for Group {
+    fn heap_size(&self) -> usize {
+        option_string(&self.icon)
+            + option_string(&self.last_pin_timestamp)
+            + option_string(&self.name)
+            + self.recipients.iter().map(User::heap_size).sum::<usize>()
+            + vec(&self.recipients)
+    }
+}
+
+impl Footprint for GuildChannel {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Category(c) => c.name.capacity() + vec(&c.permission_overwrites),
+            Self::Text(c) => {
+                option_string(&c.last_pin_timestamp)
+                    + c.name.capacity()
+                    + vec(&c.permission_overwrites)
+                    + option_string(&c.topic)
+            }
+            Self::Thread(c) => {
+                c.member
+                    .as_ref()
+                    .map_or(0, |member| member.join_timestamp.capacity())
+                    + c.name.capacity()
+                    + c.thread_metadata.archive_timestamp.capacity()
+            }
+            Self::Stage(c) | Self::Voice(c) => c.name.capacity() + vec(&c.permission_overwrites),
+        }
+    }
+}
+
+impl Footprint for GuildIntegration {
+    fn heap_size(&self) -> usize {
+        self.account.id.capacity()
+            + self.account.name.capacity()
+            + self.application.as_ref().map_or(0, |application| {
+                option_user(&application.bot)
+                    + application.description.capacity()
+                    + option_string(&application.icon)
+                    + application.name.capacity()
+                    + application.summary.capacity()
+            })
+            + self.kind.capacity()
+            + self.name.capacity()
+            + option_string(&self.synced_at)
+            + option_user(&self.user)
+    }
+}
+
+impl Footprint for GuildScheduledEvent {
+    fn heap_size(&self) -> usize {
+        option_user(&self.creator)
+            + option_string(&self.description)
+            + self
+                .entity_metadata
+                .as_ref()
+                .map_or(0, |metadata| option_string(&metadata.location))
+            + option_string(&self.image)
+            + self.name.capacity()
+            + self.recurrence_rule.as_ref().map_or(0, |rule| {
+                rule.by_month.as_ref().map_or(0, vec)
+                    + rule.by_month_day.as_ref().map_or(0, vec)
+                    + rule.by_n_weekday.as_ref().map_or(0, vec)
+                    + rule.by_weekday.as_ref().map_or(0, vec)
+                    + rule.by_year_day.as_ref().map_or(0, vec)
+                    + option_string(&rule.end)
+                    + rule.start.capacity()
+            })
+            + option_string(&self.scheduled_end_time)
+            + self.scheduled_start_time.capacity()
+    }
+}
+
+impl Footprint for PrivateChannel {
+    fn heap_size(&self) -> usize {
+        option_string(&self.last_pin_timestamp)
+            + self.recipients.iter().map(User::heap_size).sum::<usize>()
+            + vec(&self.recipients)
+    }
+}
+
+impl Footprint for Role {
+    fn heap_size(&self) -> usize {
+        self.name.capacity()
+    }
+}
+
+impl Footprint for StageInstance {
+    fn heap_size(&self) -> usize {
+        self.topic.capacity()
+    }
+}
+
+impl Footprint for User {
+    fn heap_size(&self) -> usize {
+        option_string(&self.avatar)
+            + self.discriminator.capacity()
+            + option_string(&self.email)
+            + option_string(&self.locale)
+            + self.name.capacity()
+    }
+}
+
+impl Footprint for VoiceState {
+    fn heap_size(&self) -> usize {
+        self.session_id.capacity() + option_string(&self.token)
+    }
+}
+
+impl Footprint for Webhook {
+    fn heap_size(&self) -> usize {
+        option_string(&self.avatar)
+            + option_string(&self.name)
+            + option_string(&self.token)
+            + option_user(&self.user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{vec, GuildStats, InMemoryCacheStats};
+    use crate::InMemoryCache;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash, mem};
+    use twilight_model::{
+        channel::{Webhook, WebhookType},
+        guild::{Permissions, Role},
+        id::{ChannelId, GuildId, RoleId, WebhookId},
+    };
+
+    assert_impl_all!(
+        GuildStats: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_impl_all!(InMemoryCacheStats<'_>: Debug, Send, Sync);
+
+    #[test]
+    fn test_stats() {
+        let cache = InMemoryCache::new();
+        assert_eq!(0, cache.stats().memory_estimate());
+
+        cache.cache_role(
+            GuildId(1),
+            Role {
+                color: 0,
+                hoist: false,
+                id: RoleId(2),
+                managed: false,
+                mentionable: false,
+                name: "test".to_owned(),
+                permissions: Permissions::empty(),
+                position: 0,
+                tags: None,
+            },
+        );
+
+        let stats = cache.stats();
+        assert_eq!(1, stats.roles());
+        assert_eq!(0, stats.guilds());
+        assert_eq!(1, stats.guild(GuildId(1)).roles());
+        assert_eq!(GuildStats::default(), stats.guild(GuildId(2)));
+        assert!(stats.memory_estimate() >= std::mem::size_of::<Role>() + "test".len());
+    }
+
+    #[test]
+    fn test_memory_estimate_webhooks() {
+        let cache = InMemoryCache::new();
+        cache.set_channel_webhooks(
+            ChannelId(1),
+            vec![Webhook {
+                avatar: None,
+                channel_id: ChannelId(1),
+                guild_id: Some(GuildId(2)),
+                id: WebhookId(3),
+                kind: WebhookType::Incoming,
+                name: Some("webhook".to_owned()),
+                token: None,
+                user: None,
+            }],
+        );
+
+        assert!(cache.stats().memory_estimate() >= mem::size_of::<Webhook>() + "webhook".len());
+    }
+
+    #[test]
+    fn test_vec_capacity() {
+        let mut value = Vec::<u64>::with_capacity(4);
+        value.push(1);
+
+        assert_eq!(4 * mem::size_of::<u64>(), vec(&value));
+    }
+}
@@ -1,4 +1,4 @@
-use super::{config::ResourceType, InMemoryCache};
+use super::{cache::Cache, config::ResourceType, eviction, InMemoryCache};
 use dashmap::DashMap;
 use std::{borrow::Cow, collections::HashSet, hash::Hash, ops::Deref, sync::Arc};
 use twilight_model::{
@@ -8,10 +8,18 @@ use twilight_model::{
     id::GuildId,
 };
 
-pub trait UpdateCache {
+/// Update a [`Cache`] with a value, such as an event received from the
+/// gateway.
+///
+/// This is implemented for the [`InMemoryCache`] by default. Other cache
+/// implementations implement this for the types they're updated with, where
+/// the [`Cache::Update`] type is returned from updating.
+pub trait UpdateCache<C: Cache + ?Sized = InMemoryCache> {
     // Allow this for presentation purposes in documentation.
     #[allow(unused_variables)]
-    fn update(&self, cache: &InMemoryCache) {}
+    fn update(&self, cache: &C) -> C::Update {
+        C::Update::default()
+    }
 }
 
 impl UpdateCache for Event {
@@ -20,8 +28,8 @@ impl UpdateCache for Event {
         use Event::*;
 
         match self {
-            BanAdd(_) => {}
-            BanRemove(_) => {}
+            BanAdd(v) => c.update(v),
+            BanRemove(v) => c.update(v),
             ChannelCreate(v) => c.update(v),
             ChannelDelete(v) => c.update(v),
             ChannelPinsUpdate(v) => c.update(v),
@@ -36,9 +44,14 @@ impl UpdateCache for Event {
             GuildDelete(v) => c.update(v.deref()),
             GuildEmojisUpdate(v) => c.update(v),
             GuildIntegrationsUpdate(v) => c.update(v),
+            GuildScheduledEventCreate(v) => c.update(v.deref()),
+            GuildScheduledEventDelete(v) => c.update(v.deref()),
+            GuildScheduledEventUpdate(v) => c.update(v.deref()),
+            GuildScheduledEventUserAdd(v) => c.update(v),
+            GuildScheduledEventUserRemove(v) => c.update(v),
             GuildUpdate(v) => c.update(v.deref()),
-            InviteCreate(_) => {}
-            InviteDelete(_) => {}
+            InviteCreate(v) => c.update(v.deref()),
+            InviteDelete(v) => c.update(v),
             MemberAdd(v) => c.update(v.deref()),
             MemberRemove(v) => c.update(v),
             MemberUpdate(v) => c.update(v.deref()),
@@ -65,6 +78,16 @@ impl UpdateCache for Event {
             ShardReconnecting(_) => {}
             ShardPayload(_) => {}
             ShardResuming(_) => {}
+            ShardZombied(_) => {}
+            StageInstanceCreate(v) => c.update(v),
+            StageInstanceDelete(v) => c.update(v),
+            StageInstanceUpdate(v) => c.update(v),
+            ThreadCreate(v) => c.update(v),
+            ThreadDelete(v) => c.update(v),
+            ThreadListSync(v) => c.update(v),
+            ThreadMemberUpdate(v) => c.update(v),
+            ThreadMembersUpdate(v) => c.update(v),
+            ThreadUpdate(v) => c.update(v),
             TypingStart(v) => c.update(v.deref()),
             UnavailableGuild(v) => c.update(v),
             UserUpdate(v) => c.update(v),
@@ -75,9 +98,38 @@ impl UpdateCache for Event {
     }
 }
 
-impl UpdateCache for BanAdd {}
+impl UpdateCache for BanAdd {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::BAN) || !cache.filter().wants_guild(self.guild_id) {
+            return;
+        }
+
+        cache
+            .0
+            .guild_bans
+            .entry(self.guild_id)
+            .or_default()
+            .insert(self.user.id);
+        cache
+            .0
+            .bans
+            .insert((self.guild_id, self.user.id), Arc::new(self.user.clone()));
+    }
+}
+
+impl UpdateCache for BanRemove {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::BAN) {
+            return;
+        }
 
-impl UpdateCache for BanRemove {}
+        cache.0.bans.remove(&(self.guild_id, self.user.id));
+
+        if let Some(mut bans) = cache.0.guild_bans.get_mut(&self.guild_id) {
+            bans.remove(&self.user.id);
+        }
+    }
+}
 
 impl UpdateCache for ChannelCreate {
     fn update(&self, cache: &InMemoryCache) {
@@ -91,7 +143,9 @@ impl UpdateCache for ChannelCreate {
             }
             Channel::Guild(c) => {
                 if let Some(gid) = c.guild_id() {
-                    cache.cache_guild_channel(gid, c.clone());
+                    if cache.filter().wants_channel(gid, c) {
+                        cache.cache_guild_channel(gid, c.clone());
+                    }
                 }
             }
             Channel::Private(c) => {
@@ -118,6 +172,12 @@ impl UpdateCache for ChannelDelete {
                 cache.0.channels_private.remove(&c.id);
             }
         }
+
+        if let Some((_, ids)) = cache.0.channel_webhooks.remove(&self.0.id()) {
+            for id in ids {
+                cache.0.webhooks.remove(&id);
+            }
+        }
     }
 }
 
@@ -161,7 +221,11 @@ impl UpdateCache for ChannelUpdate {
             }
             Channel::Guild(c) => {
                 if let Some(gid) = c.guild_id() {
-                    cache.cache_guild_channel(gid, c);
+                    if cache.filter().wants_channel(gid, &c) {
+                        cache.cache_guild_channel(gid, c);
+                    } else {
+                        cache.delete_guild_channel(c.id());
+                    }
                 }
             }
             Channel::Private(c) => {
@@ -215,6 +279,24 @@ impl UpdateCache for GuildDelete {
             remove_ids(&cache.0.guild_roles, &cache.0.roles, id);
         }
 
+        if cache.wants(ResourceType::SCHEDULED_EVENT) {
+            if let Some(ids) = cache.0.guild_scheduled_events.get(&id) {
+                for scheduled_event_id in ids.iter() {
+                    cache.0.scheduled_event_users.remove(scheduled_event_id);
+                }
+            }
+
+            remove_ids(
+                &cache.0.guild_scheduled_events,
+                &cache.0.scheduled_events,
+                id,
+            );
+        }
+
+        if cache.wants(ResourceType::STAGE_INSTANCE) {
+            remove_ids(&cache.0.guild_stage_instances, &cache.0.stage_instances, id);
+        }
+
         if cache.wants(ResourceType::VOICE_STATE) {
             // Clear out a guilds voice states when a guild leaves
             cache.0.voice_state_guilds.remove(&id);
@@ -235,6 +317,34 @@ impl UpdateCache for GuildDelete {
                 }
             }
         }
+
+        if cache.wants(ResourceType::BAN) {
+            if let Some((_, ids)) = cache.0.guild_bans.remove(&id) {
+                for user_id in ids {
+                    cache.0.bans.remove(&(id, user_id));
+                }
+            }
+        }
+
+        if cache.wants(ResourceType::INTEGRATION) {
+            remove_ids(&cache.0.guild_integrations, &cache.0.integrations, id);
+        }
+
+        if cache.wants(ResourceType::INVITE) {
+            remove_ids(&cache.0.guild_invites, &cache.0.invites, id);
+        }
+
+        if cache.wants(ResourceType::WEBHOOK) {
+            cache
+                .0
+                .webhooks
+                .retain(|_, webhook| webhook.guild_id != Some(id));
+            cache.0.channel_webhooks.retain(|_, ids| {
+                ids.retain(|id| cache.0.webhooks.contains_key(id));
+
+                !ids.is_empty()
+            });
+        }
     }
 }
 
@@ -248,8 +358,74 @@ impl UpdateCache for GuildEmojisUpdate {
     }
 }
 
+/// The event doesn't include the integrations, so they're refreshed through
+/// [`InMemoryCache::set_guild_integrations`].
 impl UpdateCache for GuildIntegrationsUpdate {}
 
+impl UpdateCache for GuildScheduledEventCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) || !cache.filter().wants_guild(self.guild_id)
+        {
+            return;
+        }
+
+        cache.cache_scheduled_event(self.guild_id, self.0.clone());
+    }
+}
+
+impl UpdateCache for GuildScheduledEventDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        cache.delete_scheduled_event(self.id);
+    }
+}
+
+impl UpdateCache for GuildScheduledEventUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) || !cache.filter().wants_guild(self.guild_id)
+        {
+            return;
+        }
+
+        cache.cache_scheduled_event(self.guild_id, self.0.clone());
+    }
+}
+
+impl UpdateCache for GuildScheduledEventUserAdd {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) || !cache.filter().wants_guild(self.guild_id)
+        {
+            return;
+        }
+
+        cache
+            .0
+            .scheduled_event_users
+            .entry(self.guild_scheduled_event_id)
+            .or_default()
+            .insert(self.user_id);
+    }
+}
+
+impl UpdateCache for GuildScheduledEventUserRemove {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        if let Some(mut users) = cache
+            .0
+            .scheduled_event_users
+            .get_mut(&self.guild_scheduled_event_id)
+        {
+            users.remove(&self.user_id);
+        }
+    }
+}
+
 impl UpdateCache for GuildUpdate {
     fn update(&self, cache: &InMemoryCache) {
         if !cache.wants(ResourceType::GUILD) {
@@ -288,20 +464,53 @@ impl UpdateCache for GuildUpdate {
     }
 }
 
-impl UpdateCache for MemberAdd {
+impl UpdateCache for InviteCreate {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::MEMBER) {
+        if !cache.wants(ResourceType::INVITE) || !cache.filter().wants_guild(self.guild_id) {
             return;
         }
 
-        cache.cache_member(self.guild_id, self.0.clone());
-
         cache
             .0
-            .guild_members
+            .guild_invites
             .entry(self.guild_id)
             .or_default()
-            .insert(self.0.user.id);
+            .insert(self.code.clone());
+        cache
+            .0
+            .invites
+            .insert(self.code.clone(), Arc::new(self.clone().into()));
+    }
+}
+
+impl UpdateCache for InviteDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::INVITE) {
+            return;
+        }
+
+        cache.0.invites.remove(&self.code);
+
+        if let Some(mut invites) = cache.0.guild_invites.get_mut(&self.guild_id) {
+            invites.remove(&self.code);
+        }
+    }
+}
+
+impl UpdateCache for MemberAdd {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::MEMBER) {
+            return;
+        }
+
+        if !cache
+            .filter()
+            .wants_member(self.guild_id, &self.0, cache.current_user_id())
+        {
+            return;
+        }
+
+        cache.cache_member(self.guild_id, self.0.clone());
     }
 }
 
@@ -316,8 +525,6 @@ impl UpdateCache for MemberChunk {
         }
 
         cache.cache_members(self.guild_id, self.members.clone());
-        let mut guild = cache.0.guild_members.entry(self.guild_id).or_default();
-        guild.extend(self.members.iter().map(|member| member.user.id));
     }
 }
 
@@ -377,16 +584,23 @@ impl UpdateCache for MessageCreate {
             return;
         }
 
-        let mut channel = cache.0.messages.entry(self.0.channel_id).or_default();
+        if cache.filter().wants_message(&self.0) {
+            eviction::cache_message(cache, From::from(self.0.clone()));
+        }
+
+        if let Some(guild_id) = self.guild_id {
+            let filter = cache.filter();
 
-        if channel.len() > cache.0.config.message_cache_size() {
-            if let Some(k) = channel.iter().next_back().map(|x| *x.0) {
-                channel.remove(&k);
+            // Partial members can't be checked against the member predicate,
+            // so only update members that passed it when they were cached.
+            if !filter.wants_member_id(guild_id, self.author.id, cache.current_user_id())
+                || (filter.member.is_some()
+                    && !cache.0.members.contains_key(&(guild_id, self.author.id)))
+            {
+                return;
             }
         }
 
-        channel.insert(self.0.id, Arc::new(From::from(self.0.clone())));
-
         let user = cache.cache_user(Cow::Borrowed(&self.author), self.guild_id);
 
         if let (Some(member), Some(guild_id)) = (&self.member, self.guild_id) {
@@ -401,8 +615,7 @@ impl UpdateCache for MessageDelete {
             return;
         }
 
-        let mut channel = cache.0.messages.entry(self.channel_id).or_default();
-        channel.remove(&self.id);
+        eviction::remove_message(cache, self.channel_id, self.id);
     }
 }
 
@@ -412,10 +625,8 @@ impl UpdateCache for MessageDeleteBulk {
             return;
         }
 
-        let mut channel = cache.0.messages.entry(self.channel_id).or_default();
-
         for id in &self.ids {
-            channel.remove(id);
+            eviction::remove_message(cache, self.channel_id, *id);
         }
     }
 }
@@ -426,7 +637,10 @@ impl UpdateCache for MessageUpdate {
             return;
         }
 
-        let mut channel = cache.0.messages.entry(self.channel_id).or_default();
+        let mut channel = match cache.0.messages.get_mut(&self.channel_id) {
+            Some(channel) => channel,
+            None => return,
+        };
 
         if let Some(mut message) = channel.get_mut(&self.id) {
             let mut msg = Arc::make_mut(&mut message);
@@ -435,6 +649,10 @@ impl UpdateCache for MessageUpdate {
                 msg.attachments = attachments.clone();
             }
 
+            if let Some(components) = &self.components {
+                msg.components = components.clone();
+            }
+
             if let Some(content) = &self.content {
                 msg.content = content.clone();
             }
@@ -488,7 +706,9 @@ impl UpdateCache for PresenceUpdate {
             user: self.user.clone(),
         };
 
-        cache.cache_presence(self.guild_id, presence);
+        if cache.filter().wants_presence(self.guild_id, &presence) {
+            cache.cache_presence(self.guild_id, presence);
+        }
     }
 }
 
@@ -498,7 +718,10 @@ impl UpdateCache for ReactionAdd {
             return;
         }
 
-        let mut channel = cache.0.messages.entry(self.0.channel_id).or_default();
+        let mut channel = match cache.0.messages.get_mut(&self.0.channel_id) {
+            Some(channel) => channel,
+            None => return,
+        };
 
         let mut message = match channel.get_mut(&self.0.message_id) {
             Some(message) => message,
@@ -538,7 +761,10 @@ impl UpdateCache for ReactionRemove {
             return;
         }
 
-        let mut channel = cache.0.messages.entry(self.0.channel_id).or_default();
+        let mut channel = match cache.0.messages.get_mut(&self.0.channel_id) {
+            Some(channel) => channel,
+            None => return,
+        };
 
         let mut message = match channel.get_mut(&self.0.message_id) {
             Some(message) => message,
@@ -571,7 +797,10 @@ impl UpdateCache for ReactionRemoveAll {
             return;
         }
 
-        let mut channel = cache.0.messages.entry(self.channel_id).or_default();
+        let mut channel = match cache.0.messages.get_mut(&self.channel_id) {
+            Some(channel) => channel,
+            None => return,
+        };
 
         let mut message = match channel.get_mut(&self.message_id) {
             Some(message) => message,
@@ -589,7 +818,10 @@ impl UpdateCache for ReactionRemoveEmoji {
             return;
         }
 
-        let mut channel = cache.0.messages.entry(self.channel_id).or_default();
+        let mut channel = match cache.0.messages.get_mut(&self.channel_id) {
+            Some(channel) => channel,
+            None => return,
+        };
 
         let mut message = match channel.get_mut(&self.message_id) {
             Some(message) => message,
@@ -638,6 +870,10 @@ impl UpdateCache for RoleCreate {
             return;
         }
 
+        if !cache.filter().wants_role(self.guild_id, &self.role) {
+            return;
+        }
+
         super::upsert_guild_item(
             &cache.0.roles,
             self.guild_id,
@@ -663,7 +899,204 @@ impl UpdateCache for RoleUpdate {
             return;
         }
 
-        cache.cache_role(self.guild_id, self.role.clone());
+        if cache.filter().wants_role(self.guild_id, &self.role) {
+            cache.cache_role(self.guild_id, self.role.clone());
+        } else {
+            cache.delete_role(self.role.id);
+        }
+    }
+}
+
+impl UpdateCache for StageInstanceCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::STAGE_INSTANCE) || !cache.filter().wants_guild(self.guild_id)
+        {
+            return;
+        }
+
+        cache.cache_stage_instance(self.guild_id, self.0.clone());
+    }
+}
+
+impl UpdateCache for StageInstanceDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::STAGE_INSTANCE) {
+            return;
+        }
+
+        cache.delete_stage_instance(self.id);
+    }
+}
+
+impl UpdateCache for StageInstanceUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::STAGE_INSTANCE) || !cache.filter().wants_guild(self.guild_id)
+        {
+            return;
+        }
+
+        cache.cache_stage_instance(self.guild_id, self.0.clone());
+    }
+}
+
+impl UpdateCache for ThreadCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        if let Some(guild_id) = self.0.guild_id() {
+            if cache.filter().wants_channel(guild_id, &self.0) {
+                cache.cache_guild_channel(guild_id, self.0.clone());
+            }
+        }
+    }
+}
+
+impl UpdateCache for ThreadDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        cache.delete_guild_channel(self.id);
+    }
+}
+
+/// Cached threads of the synced channels that aren't in the event are no
+/// longer active, so they're removed.
+impl UpdateCache for ThreadListSync {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) || !cache.filter().wants_guild(self.guild_id) {
+            return;
+        }
+
+        let stale = cache
+            .0
+            .guild_channels
+            .get(&self.guild_id)
+            .map(|ids| ids.iter().copied().collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|id| {
+                cache.0.channels_guild.get(id).map_or(false, |item| {
+                    if let GuildChannel::Thread(thread) = item.data.as_ref() {
+                        self.channel_ids.is_empty()
+                            || thread
+                                .parent_id
+                                .map_or(false, |id| self.channel_ids.contains(&id))
+                    } else {
+                        false
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for id in stale {
+            cache.delete_guild_channel(id);
+        }
+
+        for channel in &self.threads {
+            let mut channel = channel.clone();
+
+            if let GuildChannel::Thread(thread) = &mut channel {
+                if let Some(member) = self
+                    .members
+                    .iter()
+                    .find(|member| member.id == Some(thread.id))
+                {
+                    thread.member.replace(member.clone());
+                }
+            }
+
+            if cache.filter().wants_channel(self.guild_id, &channel) {
+                cache.cache_guild_channel(self.guild_id, channel);
+            }
+        }
+    }
+}
+
+impl UpdateCache for ThreadMemberUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        let thread_id = match self.id {
+            Some(thread_id) => thread_id,
+            None => return,
+        };
+
+        if let Some(mut item) = cache.0.channels_guild.get_mut(&thread_id) {
+            if let GuildChannel::Thread(thread) = Arc::make_mut(&mut item.data) {
+                thread.member.replace(self.0.clone());
+            }
+        }
+    }
+}
+
+impl UpdateCache for ThreadMembersUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        let current_user_id = cache.current_user_id();
+
+        if let Some(mut item) = cache.0.channels_guild.get_mut(&self.id) {
+            if let GuildChannel::Thread(thread) = Arc::make_mut(&mut item.data) {
+                thread.member_count = self.member_count;
+
+                if let Some(user_id) = current_user_id {
+                    if let Some(member) = self
+                        .added_members
+                        .iter()
+                        .find(|member| member.user_id == Some(user_id))
+                    {
+                        thread.member.replace(member.clone());
+                    }
+
+                    if self.removed_member_ids.contains(&user_id) {
+                        thread.member.take();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Thread updates don't include the thread member of the current user, so
+/// the cached one is kept.
+impl UpdateCache for ThreadUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        let guild_id = match self.0.guild_id() {
+            Some(guild_id) => guild_id,
+            None => return,
+        };
+
+        if !cache.filter().wants_channel(guild_id, &self.0) {
+            cache.delete_guild_channel(self.0.id());
+
+            return;
+        }
+
+        let mut channel = self.0.clone();
+
+        if let GuildChannel::Thread(thread) = &mut channel {
+            if thread.member.is_none() {
+                if let Some(item) = cache.0.channels_guild.get(&thread.id) {
+                    if let GuildChannel::Thread(cached) = item.data.as_ref() {
+                        thread.member = cached.member.clone();
+                    }
+                }
+            }
+        }
+
+        cache.cache_guild_channel(guild_id, channel);
     }
 }
 
@@ -675,8 +1108,7 @@ impl UpdateCache for UnavailableGuild {
             return;
         }
 
-        cache.0.guilds.remove(&self.id);
-        cache.0.unavailable_guilds.insert(self.id);
+        cache.unavailable_guild(self.id);
     }
 }
 
@@ -700,14 +1132,28 @@ impl UpdateCache for VoiceStateUpdate {
             return;
         }
 
-        cache.cache_voice_state(self.0.clone());
+        let mut voice_state = self.0.clone();
+
+        // Remove the voice state if it no longer matches the filter.
+        if !cache.filter().wants_voice_state(&voice_state) {
+            voice_state.channel_id.take();
+        }
+
+        cache.cache_voice_state(voice_state);
 
         if let (Some(guild_id), Some(member)) = (self.0.guild_id, &self.0.member) {
-            cache.cache_member(guild_id, member.clone());
+            if cache
+                .filter()
+                .wants_member(guild_id, member, cache.current_user_id())
+            {
+                cache.cache_member(guild_id, member.clone());
+            }
         }
     }
 }
 
+/// The event doesn't include the webhooks, so they're refreshed through
+/// [`InMemoryCache::set_channel_webhooks`].
 impl UpdateCache for WebhooksUpdate {}
 
 #[cfg(test)]
@@ -717,15 +1163,24 @@ mod tests {
     use twilight_model::{
         channel::{
             message::{MessageFlags, MessageType},
-            ChannelType, GuildChannel, Message, Reaction, TextChannel,
+            stage_instance::{PrivacyLevel, StageInstance},
+            thread::{AutoArchiveDuration, ThreadMember, ThreadMetadata},
+            ChannelType, GuildChannel, Message, Reaction, TextChannel, ThreadChannel,
+        },
+        gateway::payload::{
+            reaction_remove_emoji::PartialEmoji, ChannelDelete, GuildScheduledEventCreate,
+            GuildScheduledEventDelete, GuildScheduledEventUpdate, GuildScheduledEventUserAdd,
+            GuildScheduledEventUserRemove, StageInstanceCreate, StageInstanceDelete,
         },
-        gateway::payload::{reaction_remove_emoji::PartialEmoji, ChannelDelete},
         guild::{
+            scheduled_event::{
+                EntityType, GuildScheduledEvent, PrivacyLevel as EventPrivacyLevel, Status,
+            },
             DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, Member, MfaLevel,
             PartialGuild, PartialMember, PremiumTier, SystemChannelFlags, VerificationLevel,
         },
-        id::{ChannelId, GuildId, MessageId, UserId},
-        user::User,
+        id::{ChannelId, GuildId, MessageId, ScheduledEventId, StageId, UserId},
+        user::{CurrentUser, User},
         voice::VoiceState,
     };
 
@@ -750,6 +1205,45 @@ mod tests {
         (guild_id, channel_id, channel)
     }
 
+    fn thread(id: u64, parent_id: u64) -> GuildChannel {
+        GuildChannel::Thread(ThreadChannel {
+            guild_id: Some(GuildId(1)),
+            id: ChannelId(id),
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            member: None,
+            member_count: 1,
+            message_count: 0,
+            name: "thread".to_owned(),
+            owner_id: None,
+            parent_id: Some(ChannelId(parent_id)),
+            rate_limit_per_user: None,
+            thread_metadata: ThreadMetadata {
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Day,
+                archive_timestamp: "2021-01-01T00:00:00+00:00".to_owned(),
+                invitable: None,
+                locked: false,
+            },
+        })
+    }
+
+    fn thread_member(thread_id: u64, user_id: u64) -> ThreadMember {
+        ThreadMember {
+            flags: 0,
+            id: Some(ChannelId(thread_id)),
+            join_timestamp: "2021-01-01T00:00:00+00:00".to_owned(),
+            user_id: Some(UserId(user_id)),
+        }
+    }
+
+    fn cached_thread_member(cache: &InMemoryCache, thread_id: u64) -> Option<ThreadMember> {
+        match cache.guild_channel(ChannelId(thread_id))?.as_ref() {
+            GuildChannel::Thread(thread) => thread.member.clone(),
+            _ => None,
+        }
+    }
+
     fn cache_with_message_and_reactions() -> InMemoryCache {
         let cache = InMemoryCache::new();
 
@@ -773,6 +1267,7 @@ mod tests {
                 verified: None,
             },
             channel_id: ChannelId(2),
+            components: Vec::new(),
             content: "ping".to_owned(),
             edited_timestamp: None,
             embeds: Vec::new(),
@@ -898,6 +1393,7 @@ mod tests {
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::None,
             features: Vec::new(),
+            guild_scheduled_events: Vec::new(),
             icon: None,
             id: GuildId(1),
             joined_at: None,
@@ -921,8 +1417,10 @@ mod tests {
             roles: Vec::new(),
             rules_channel_id: None,
             splash: None,
+            stage_instances: Vec::new(),
             system_channel_flags: SystemChannelFlags::empty(),
             system_channel_id: None,
+            threads: Vec::new(),
             unavailable: false,
             vanity_url_code: None,
             verification_level: VerificationLevel::VeryHigh,
@@ -1010,6 +1508,221 @@ mod tests {
             .contains(&channel_id));
     }
 
+    #[test]
+    fn test_thread_list_sync_prunes_channels() {
+        let cache = InMemoryCache::new();
+        cache.update(&ThreadCreate(thread(10, 2)));
+        cache.update(&ThreadCreate(thread(11, 3)));
+
+        // Only threads of the synced channels are pruned.
+        cache.update(&ThreadListSync {
+            channel_ids: vec![ChannelId(2)],
+            guild_id: GuildId(1),
+            members: vec![thread_member(12, 5)],
+            threads: vec![thread(12, 2)],
+        });
+        assert!(cache.guild_channel(ChannelId(10)).is_none());
+        assert!(cache.guild_channel(ChannelId(11)).is_some());
+        assert_eq!(Some(thread_member(12, 5)), cached_thread_member(&cache, 12));
+
+        // Syncing without channel IDs syncs the whole guild.
+        cache.update(&ThreadListSync {
+            channel_ids: Vec::new(),
+            guild_id: GuildId(1),
+            members: Vec::new(),
+            threads: vec![thread(13, 3)],
+        });
+        assert!(cache.guild_channel(ChannelId(11)).is_none());
+        assert!(cache.guild_channel(ChannelId(12)).is_none());
+        assert!(cache.guild_channel(ChannelId(13)).is_some());
+        assert_eq!(
+            Some(&ChannelId(13)),
+            cache
+                .0
+                .guild_channels
+                .get(&GuildId(1))
+                .unwrap()
+                .iter()
+                .next()
+        );
+    }
+
+    #[test]
+    fn test_thread_members_update_current_user() {
+        let cache = InMemoryCache::new();
+        cache.cache_current_user(CurrentUser {
+            avatar: None,
+            bot: true,
+            discriminator: "0001".to_owned(),
+            email: None,
+            flags: None,
+            id: UserId(5),
+            locale: None,
+            mfa_enabled: false,
+            name: "bot".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            verified: None,
+        });
+        cache.update(&ThreadCreate(thread(10, 2)));
+
+        // Members other than the current user aren't cached.
+        cache.update(&ThreadMembersUpdate {
+            added_members: vec![thread_member(10, 6)],
+            guild_id: GuildId(1),
+            id: ChannelId(10),
+            member_count: 2,
+            removed_member_ids: Vec::new(),
+        });
+        assert!(cached_thread_member(&cache, 10).is_none());
+
+        cache.update(&ThreadMembersUpdate {
+            added_members: vec![thread_member(10, 5)],
+            guild_id: GuildId(1),
+            id: ChannelId(10),
+            member_count: 3,
+            removed_member_ids: Vec::new(),
+        });
+        assert_eq!(Some(thread_member(10, 5)), cached_thread_member(&cache, 10));
+
+        cache.update(&ThreadMembersUpdate {
+            added_members: Vec::new(),
+            guild_id: GuildId(1),
+            id: ChannelId(10),
+            member_count: 2,
+            removed_member_ids: vec![UserId(5)],
+        });
+        assert!(cached_thread_member(&cache, 10).is_none());
+
+        match cache.guild_channel(ChannelId(10)).unwrap().as_ref() {
+            GuildChannel::Thread(thread) => assert_eq!(2, thread.member_count),
+            _ => panic!("expected a thread"),
+        }
+    }
+
+    #[test]
+    fn test_thread_update_keeps_member() {
+        let cache = InMemoryCache::new();
+        cache.update(&ThreadCreate(thread(10, 2)));
+        cache.update(&ThreadMemberUpdate(thread_member(10, 5)));
+        assert_eq!(Some(thread_member(10, 5)), cached_thread_member(&cache, 10));
+
+        let mut updated = thread(10, 2);
+
+        if let GuildChannel::Thread(thread) = &mut updated {
+            thread.name = "renamed".to_owned();
+        }
+
+        cache.update(&ThreadUpdate(updated));
+        assert_eq!(Some(thread_member(10, 5)), cached_thread_member(&cache, 10));
+        assert_eq!(
+            "renamed",
+            cache.guild_channel(ChannelId(10)).unwrap().name()
+        );
+    }
+
+    #[test]
+    fn test_scheduled_events() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        let scheduled_event_id = ScheduledEventId(2);
+        let scheduled_event = GuildScheduledEvent {
+            channel_id: Some(ChannelId(3)),
+            creator: None,
+            creator_id: None,
+            description: None,
+            entity_id: None,
+            entity_metadata: None,
+            entity_type: EntityType::Voice,
+            guild_id,
+            id: scheduled_event_id,
+            image: None,
+            name: "an event".to_owned(),
+            privacy_level: EventPrivacyLevel::GuildOnly,
+            recurrence_rule: None,
+            scheduled_end_time: None,
+            scheduled_start_time: "2021-10-22T18:00:00+00:00".to_owned(),
+            status: Status::Scheduled,
+            user_count: Some(5),
+        };
+
+        cache.update(&GuildScheduledEventCreate(scheduled_event.clone()));
+        assert_eq!(
+            Some(scheduled_event.clone()),
+            cache
+                .scheduled_event(scheduled_event_id)
+                .map(|s| (*s).clone())
+        );
+        assert!(cache
+            .guild_scheduled_events(guild_id)
+            .unwrap()
+            .contains(&scheduled_event_id));
+
+        cache.update(&GuildScheduledEventUserAdd {
+            guild_id,
+            guild_scheduled_event_id: scheduled_event_id,
+            user_id: UserId(4),
+        });
+        cache.update(&GuildScheduledEventUserAdd {
+            guild_id,
+            guild_scheduled_event_id: scheduled_event_id,
+            user_id: UserId(5),
+        });
+        cache.update(&GuildScheduledEventUserRemove {
+            guild_id,
+            guild_scheduled_event_id: scheduled_event_id,
+            user_id: UserId(5),
+        });
+        assert_eq!(
+            Some(vec![UserId(4)].into_iter().collect()),
+            cache.scheduled_event_users(scheduled_event_id)
+        );
+
+        // Gateway updates don't include the user count, so the cached one is
+        // kept.
+        let mut updated = scheduled_event.clone();
+        updated.name = "a renamed event".to_owned();
+        updated.user_count = None;
+        cache.update(&GuildScheduledEventUpdate(updated));
+        let cached = cache.scheduled_event(scheduled_event_id).unwrap();
+        assert_eq!("a renamed event", cached.name);
+        assert_eq!(Some(5), cached.user_count);
+
+        cache.update(&GuildScheduledEventDelete(scheduled_event));
+        assert!(cache.scheduled_event(scheduled_event_id).is_none());
+        assert!(cache.scheduled_event_users(scheduled_event_id).is_none());
+        assert!(cache.guild_scheduled_events(guild_id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_stage_instances() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId(1);
+        let stage_id = StageId(3);
+        let stage_instance = StageInstance {
+            channel_id: ChannelId(2),
+            discoverable_disabled: false,
+            guild_id,
+            id: stage_id,
+            privacy_level: PrivacyLevel::GuildOnly,
+            topic: "a topic".to_owned(),
+        };
+
+        cache.update(&StageInstanceCreate(stage_instance.clone()));
+        assert_eq!(
+            Some(stage_instance.clone()),
+            cache.stage_instance(stage_id).map(|s| (*s).clone())
+        );
+        assert!(cache
+            .guild_stage_instances(guild_id)
+            .unwrap()
+            .contains(&stage_id));
+
+        cache.update(&StageInstanceDelete(stage_instance));
+        assert!(cache.stage_instance(stage_id).is_none());
+        assert!(cache.guild_stage_instances(guild_id).unwrap().is_empty());
+    }
+
     #[test]
     fn test_voice_states_with_no_cached_guilds() {
         let cache = InMemoryCache::builder()
@@ -1022,6 +1735,7 @@ mod tests {
             guild_id: Some(GuildId(1)),
             member: None,
             mute: false,
+            request_to_speak_timestamp: None,
             self_deaf: false,
             self_mute: false,
             self_stream: false,
@@ -1069,6 +1783,7 @@ mod tests {
                 },
             }),
             mute: false,
+            request_to_speak_timestamp: None,
             self_deaf: false,
             self_mute: false,
             self_stream: false,
@@ -1117,6 +1832,7 @@ mod tests {
                 verified: None,
             },
             channel_id: ChannelId(2),
+            components: Vec::new(),
             content: "ping".to_owned(),
             edited_timestamp: None,
             embeds: Vec::new(),
@@ -1261,4 +1977,142 @@ mod tests {
 
         assert_eq!(msg.reactions.len(), 0);
     }
+
+    #[test]
+    fn test_filter_guilds_and_channel_types() {
+        let cache = InMemoryCache::builder()
+            .guilds(vec![GuildId(1)])
+            .channel_types(vec![ChannelType::GuildVoice])
+            .build();
+        let (guild_id, channel_id, channel) = guild_channel_text();
+        cache.update(&ChannelCreate(Channel::Guild(channel.clone())));
+        assert!(cache.guild_channel(channel_id).is_none());
+
+        let cache = InMemoryCache::builder().guilds(vec![GuildId(3)]).build();
+        cache.update(&ChannelCreate(Channel::Guild(channel.clone())));
+        assert!(cache.guild_channel(channel_id).is_none());
+
+        let cache = InMemoryCache::builder().guilds(vec![guild_id]).build();
+        cache.update(&ChannelCreate(Channel::Guild(channel)));
+        assert!(cache.guild_channel(channel_id).is_some());
+    }
+
+    #[test]
+    fn test_filter_members() {
+        fn member(id: u64, nick: Option<&str>) -> MemberAdd {
+            MemberAdd(Member {
+                deaf: false,
+                guild_id: GuildId(1),
+                hoisted_role: None,
+                joined_at: None,
+                mute: false,
+                nick: nick.map(ToOwned::to_owned),
+                pending: false,
+                premium_since: None,
+                roles: Vec::new(),
+                user: User {
+                    avatar: None,
+                    bot: false,
+                    discriminator: "0001".to_owned(),
+                    email: None,
+                    flags: None,
+                    id: UserId(id),
+                    locale: None,
+                    mfa_enabled: None,
+                    name: "test".to_owned(),
+                    premium_type: None,
+                    public_flags: None,
+                    system: None,
+                    verified: None,
+                },
+            })
+        }
+
+        let cache = InMemoryCache::builder()
+            .filter_member(|member| member.nick.is_some())
+            .build();
+        cache.update(&member(2, None));
+        cache.update(&member(3, Some("nick")));
+        assert!(cache.member(GuildId(1), UserId(2)).is_none());
+        assert!(cache.user(UserId(2)).is_none());
+        assert!(cache.member(GuildId(1), UserId(3)).is_some());
+
+        // Without a current user no member is the current member.
+        let cache = InMemoryCache::builder().current_member_only(true).build();
+        cache.update(&member(3, Some("nick")));
+        assert!(cache.member(GuildId(1), UserId(3)).is_none());
+        assert!(cache.guild_members(GuildId(1)).is_none());
+    }
+
+    #[test]
+    fn test_invite_uses() {
+        let cache = InMemoryCache::new();
+        cache.update(&InviteCreate {
+            channel_id: ChannelId(2),
+            code: "twilight".to_owned(),
+            created_at: "2021-01-01T00:00:00+00:00".to_owned(),
+            guild_id: GuildId(1),
+            inviter: None,
+            max_age: 0,
+            max_uses: 0,
+            target_user_type: None,
+            target_user: None,
+            temporary: false,
+            uses: 0,
+        });
+        assert!(cache
+            .guild_invites(GuildId(1))
+            .unwrap()
+            .contains("twilight"));
+
+        let uses = vec![("twilight".to_owned(), 1), ("unknown".to_owned(), 1)];
+        let used = cache.update_invite_uses(GuildId(1), uses.clone());
+        assert_eq!(1, used.len());
+        assert_eq!(1, cache.invite("twilight").unwrap().uses);
+        assert!(cache.update_invite_uses(GuildId(1), uses).is_empty());
+
+        cache.update(&InviteDelete {
+            channel_id: ChannelId(2),
+            code: "twilight".to_owned(),
+            guild_id: GuildId(1),
+        });
+        assert!(cache.invite("twilight").is_none());
+        assert!(cache.guild_invites(GuildId(1)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_bans() {
+        let cache = InMemoryCache::new();
+        let user = User {
+            avatar: None,
+            bot: false,
+            discriminator: "0001".to_owned(),
+            email: None,
+            flags: None,
+            id: UserId(2),
+            locale: None,
+            mfa_enabled: None,
+            name: "test".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        };
+
+        cache.update(&BanAdd {
+            guild_id: GuildId(1),
+            user: user.clone(),
+        });
+        assert_eq!(
+            Some(UserId(2)),
+            cache.ban(GuildId(1), UserId(2)).map(|u| u.id)
+        );
+
+        cache.update(&BanRemove {
+            guild_id: GuildId(1),
+            user,
+        });
+        assert!(cache.ban(GuildId(1), UserId(2)).is_none());
+        assert!(cache.guild_bans(GuildId(1)).unwrap().is_empty());
+    }
 }
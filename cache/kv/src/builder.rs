@@ -0,0 +1,64 @@
+use super::{store::Store, KvCache, KvCacheRef};
+use std::sync::Arc;
+use twilight_cache_inmemory::ResourceType;
+
+/// Builder to configure and construct a [`KvCache`].
+#[derive(Debug)]
+pub struct KvCacheBuilder {
+    key_prefix: String,
+    resource_types: ResourceType,
+    store: Box<dyn Store>,
+}
+
+impl KvCacheBuilder {
+    /// Creates a builder to configure and construct a [`KvCache`] backed by a
+    /// store.
+    pub fn new(store: impl Store + 'static) -> Self {
+        Self {
+            key_prefix: "twilight".to_owned(),
+            resource_types: ResourceType::all(),
+            store: Box::new(store),
+        }
+    }
+
+    /// Consume the builder, returning a configured cache.
+    pub fn build(self) -> KvCache {
+        KvCache(Arc::new(KvCacheRef {
+            key_prefix: self.key_prefix,
+            resource_types: self.resource_types,
+            store: self.store,
+        }))
+    }
+
+    /// Sets the prefix of all keys written to and read from the store.
+    ///
+    /// This allows multiple caches, such as the caches of different bots, to
+    /// share a store.
+    ///
+    /// Defaults to "twilight".
+    pub fn key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.key_prefix = key_prefix.into();
+
+        self
+    }
+
+    /// Sets the list of resource types for the cache to handle.
+    ///
+    /// [`ResourceType::MESSAGE`] and [`ResourceType::REACTION`] are ignored.
+    ///
+    /// Defaults to all types.
+    pub fn resource_types(mut self, resource_types: ResourceType) -> Self {
+        self.resource_types = resource_types;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KvCacheBuilder;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(KvCacheBuilder: Debug, Send, Sync);
+}
@@ -0,0 +1,953 @@
+//! # twilight-cache-kv
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-cache-kv` is a cache for the [`twilight-rs`] ecosystem backed by
+//! a key-value store, such as Redis. Since its data lives outside of the
+//! process, multiple processes - such as one receiving events from the gateway
+//! and others handling commands - can share the same cached guilds, channels,
+//! members, roles, and more.
+//!
+//! The storage backend is abstracted by the [`Store`] trait, and cached
+//! resources are serialized as JSON. A [`MemoryStore`] is provided for
+//! testing.
+//!
+//! The cache implements the [`Cache`] trait of `twilight-cache-inmemory`, so
+//! code can be written to work with either cache.
+//!
+//! Messages aren't cached since there's no bound on their number in a store.
+//!
+//! ## Examples
+//!
+//! Update the cache with an event and look up the guild afterwards:
+//!
+//! ```rust,no_run
+//! use twilight_cache_inmemory::Cache;
+//! use twilight_cache_kv::{KvCache, MemoryStore};
+//! use twilight_model::{gateway::event::Event, id::GuildId};
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let event = Event::GatewayHeartbeatAck;
+//! let cache = KvCache::builder(MemoryStore::new())
+//!     .key_prefix("my-bot")
+//!     .build();
+//!
+//! // Updates are asynchronous and need to be awaited to be performed.
+//! cache.update(&event).await?;
+//!
+//! if let Some(guild) = cache.guild(GuildId(1)).await? {
+//!     println!("guild name: {}", guild.name);
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! ## License
+//!
+//! All first-party crates are licensed under [ISC][LICENSE.md]
+//!
+//! [LICENSE.md]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.48+-93450a.svg?style=for-the-badge&logo=rust
+//! [`Cache`]: twilight_cache_inmemory::Cache
+//! [`twilight-rs`]: https://github.com/twilight-rs/twilight
+
+#![deny(rust_2018_idioms, broken_intra_doc_links, unused, warnings)]
+
+mod builder;
+mod store;
+mod updates;
+
+pub use self::{
+    builder::KvCacheBuilder,
+    store::{MemoryStore, Store, StoreError, StoreFuture},
+    updates::KvUpdate,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Error as JsonError;
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::Hash,
+    sync::Arc,
+};
+use twilight_cache_inmemory::{
+    model::{CachedEmoji, CachedGuild, CachedMember, CachedMessage, CachedPresence},
+    Cache, CacheFuture, ResourceType, UpdateCache,
+};
+use twilight_model::{
+    channel::{Channel, Group, GuildChannel, PrivateChannel},
+    gateway::presence::Presence,
+    guild::{Emoji, Guild, Member, Role},
+    id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, UserId},
+    user::{CurrentUser, User},
+    voice::VoiceState,
+};
+
+/// Reading from or writing to the cache failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum KvCacheError {
+    /// A value in the store couldn't be deserialized.
+    Deserializing {
+        /// Key of the value.
+        key: String,
+        /// Reason for the error.
+        source: JsonError,
+    },
+    /// A resource couldn't be serialized.
+    Serializing {
+        /// Reason for the error.
+        source: JsonError,
+    },
+    /// The store failed to perform an operation.
+    Store {
+        /// Reason for the error.
+        source: StoreError,
+    },
+}
+
+impl Display for KvCacheError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Deserializing { key, .. } => {
+                write!(f, "the value of key {} couldn't be deserialized", key)
+            }
+            Self::Serializing { .. } => f.write_str("a resource couldn't be serialized"),
+            Self::Store { .. } => f.write_str("the store failed to perform an operation"),
+        }
+    }
+}
+
+impl Error for KvCacheError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Deserializing { source, .. } => Some(source),
+            Self::Serializing { source } => Some(source),
+            Self::Store { source } => Some(source),
+        }
+    }
+}
+
+impl From<StoreError> for KvCacheError {
+    fn from(source: StoreError) -> Self {
+        Self::Store { source }
+    }
+}
+
+#[derive(Debug)]
+struct KvCacheRef {
+    key_prefix: String,
+    resource_types: ResourceType,
+    store: Box<dyn Store>,
+}
+
+/// A cache of Discord data stored in a key-value store. It can be cloned and
+/// sent to other threads.
+///
+/// Each resource is serialized as JSON and stored under its own key, while the
+/// IDs of the resources of a guild - such as its channels or members - are
+/// stored in sets. All keys are prefixed with the
+/// [configured key prefix][`KvCacheBuilder::key_prefix`].
+///
+/// Multiple processes may use a cache backed by the same store, but only one
+/// of them should update it with events received from the gateway.
+///
+/// # Updating
+///
+/// Updating the cache returns a [`KvUpdate`], which needs to be awaited for
+/// the update to be written to the store. Updates are made of multiple store
+/// operations and aren't atomic, so updates should be awaited in the order in
+/// which events are received.
+///
+/// # Cloning
+///
+/// The cache internally wraps its data within an Arc. This means that the cache
+/// can be cloned and passed around tasks and threads cheaply.
+#[derive(Clone, Debug)]
+pub struct KvCache(Arc<KvCacheRef>);
+
+impl KvCache {
+    /// Creates a new cache backed by a store with the default configuration.
+    pub fn new(store: impl Store + 'static) -> Self {
+        Self::builder(store).build()
+    }
+
+    /// Create a new builder to configure and construct a cache backed by a
+    /// store.
+    pub fn builder(store: impl Store + 'static) -> KvCacheBuilder {
+        KvCacheBuilder::new(store)
+    }
+
+    /// Return an immutable reference to the store backing the cache.
+    pub fn store(&self) -> &dyn Store {
+        self.0.store.as_ref()
+    }
+
+    /// Update the cache with an event from the gateway.
+    ///
+    /// The returned future needs to be awaited for the update to be
+    /// performed.
+    pub fn update(&self, value: &impl UpdateCache<Self>) -> KvUpdate {
+        value.update(self)
+    }
+
+    fn key(&self, key: impl Display) -> String {
+        format!("{}:{}", self.0.key_prefix, key)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, KvCacheError> {
+        let bytes = match self.0.store.get(key).await? {
+            Some(bytes) => bytes,
+            None => return Ok(None),
+        };
+
+        serde_json::from_slice(&bytes)
+            .map(Some)
+            .map_err(|source| KvCacheError::Deserializing {
+                key: key.to_owned(),
+                source,
+            })
+    }
+
+    async fn put<T: Serialize>(&self, key: &str, value: &T) -> Result<(), KvCacheError> {
+        let bytes =
+            serde_json::to_vec(value).map_err(|source| KvCacheError::Serializing { source })?;
+
+        self.0.store.put(key, bytes).await.map_err(From::from)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), KvCacheError> {
+        self.0.store.delete(key).await.map_err(From::from)
+    }
+
+    async fn set_add(&self, key: &str, members: Vec<String>) -> Result<(), KvCacheError> {
+        if members.is_empty() {
+            return Ok(());
+        }
+
+        self.0.store.set_add(key, members).await.map_err(From::from)
+    }
+
+    async fn set_remove(&self, key: &str, member: String) -> Result<(), KvCacheError> {
+        self.0
+            .store
+            .set_remove(key, vec![member])
+            .await
+            .map_err(From::from)
+    }
+
+    /// Get the members of a set of IDs, converting them with the ID type's
+    /// constructor.
+    async fn set_ids<T: Eq + Hash>(
+        &self,
+        key: &str,
+        id: fn(u64) -> T,
+    ) -> Result<Option<HashSet<T>>, KvCacheError> {
+        let members = match self.0.store.set_members(key).await? {
+            Some(members) => members,
+            None => return Ok(None),
+        };
+
+        members
+            .iter()
+            .map(|member| {
+                serde_json::from_str(member)
+                    .map(id)
+                    .map_err(|source| KvCacheError::Deserializing {
+                        key: key.to_owned(),
+                        source,
+                    })
+            })
+            .collect::<Result<_, _>>()
+            .map(Some)
+    }
+
+    /// Delete the set of the IDs of a guild's resources and the resources
+    /// themselves.
+    async fn delete_set<T: Eq + Hash>(
+        &self,
+        key: &str,
+        id: fn(u64) -> T,
+        item_key: impl Fn(T) -> String,
+    ) -> Result<(), KvCacheError> {
+        if let Some(ids) = self.set_ids(key, id).await? {
+            for id in ids {
+                self.delete(&self.key(item_key(id))).await?;
+            }
+        }
+
+        self.delete(key).await
+    }
+
+    async fn cache_channel(&self, channel: Channel) -> Result<(), KvCacheError> {
+        match channel {
+            Channel::Group(group) => self.put(&self.key(group_key(group.id)), &group).await,
+            Channel::Guild(channel) => match channel.guild_id() {
+                Some(guild_id) => self.cache_guild_channel(guild_id, channel).await,
+                None => Ok(()),
+            },
+            Channel::Private(channel) => {
+                self.put(&self.key(private_channel_key(channel.id)), &channel)
+                    .await
+            }
+        }
+    }
+
+    async fn cache_guild_channel(
+        &self,
+        guild_id: GuildId,
+        mut channel: GuildChannel,
+    ) -> Result<(), KvCacheError> {
+        match channel {
+            GuildChannel::Category(ref mut c) => {
+                c.guild_id.replace(guild_id);
+            }
+            GuildChannel::Stage(ref mut c) => {
+                c.guild_id.replace(guild_id);
+            }
+            GuildChannel::Text(ref mut c) => {
+                c.guild_id.replace(guild_id);
+            }
+            GuildChannel::Thread(ref mut c) => {
+                c.guild_id.replace(guild_id);
+            }
+            GuildChannel::Voice(ref mut c) => {
+                c.guild_id.replace(guild_id);
+            }
+        }
+
+        let id = channel.id();
+        self.put(&self.key(channel_key(id)), &channel).await?;
+        self.set_add(
+            &self.key(guild_set_key(guild_id, "channels")),
+            vec![id.to_string()],
+        )
+        .await
+    }
+
+    async fn delete_channel(&self, channel: &Channel) -> Result<(), KvCacheError> {
+        match channel {
+            Channel::Group(group) => self.delete(&self.key(group_key(group.id))).await,
+            Channel::Guild(channel) => {
+                self.delete_guild_channel(channel.guild_id(), channel.id())
+                    .await
+            }
+            Channel::Private(channel) => {
+                self.delete(&self.key(private_channel_key(channel.id)))
+                    .await
+            }
+        }
+    }
+
+    async fn delete_guild_channel(
+        &self,
+        guild_id: Option<GuildId>,
+        channel_id: ChannelId,
+    ) -> Result<(), KvCacheError> {
+        self.delete(&self.key(channel_key(channel_id))).await?;
+
+        match guild_id {
+            Some(guild_id) => {
+                self.set_remove(
+                    &self.key(guild_set_key(guild_id, "channels")),
+                    channel_id.to_string(),
+                )
+                .await
+            }
+            None => Ok(()),
+        }
+    }
+
+    async fn cache_current_user(&self, current_user: &CurrentUser) -> Result<(), KvCacheError> {
+        self.put(&self.key("current_user"), current_user).await
+    }
+
+    /// Cache the emojis of a guild, replacing all of its existing emojis.
+    async fn cache_emojis(
+        &self,
+        guild_id: GuildId,
+        emojis: Vec<Emoji>,
+    ) -> Result<(), KvCacheError> {
+        let set_key = self.key(guild_set_key(guild_id, "emojis"));
+
+        if let Some(existing) = self.set_ids(&set_key, EmojiId).await? {
+            for id in existing {
+                if !emojis.iter().any(|emoji| emoji.id == id) {
+                    self.delete(&self.key(emoji_key(id))).await?;
+                    self.set_remove(&set_key, id.to_string()).await?;
+                }
+            }
+        }
+
+        let ids = emojis.iter().map(|emoji| emoji.id.to_string()).collect();
+
+        for emoji in emojis {
+            let user = match emoji.user {
+                Some(user) => {
+                    self.put(&self.key(user_key(user.id)), &user).await?;
+
+                    Some(Arc::new(user))
+                }
+                None => None,
+            };
+
+            let cached = CachedEmoji {
+                id: emoji.id,
+                animated: emoji.animated,
+                name: emoji.name,
+                managed: emoji.managed,
+                require_colons: emoji.require_colons,
+                roles: emoji.roles,
+                user,
+                available: emoji.available,
+            };
+
+            self.put(&self.key(emoji_key(cached.id)), &cached).await?;
+        }
+
+        self.set_add(&set_key, ids).await
+    }
+
+    async fn cache_guild(&self, guild: Guild) -> Result<(), KvCacheError> {
+        let guild_id = guild.id;
+
+        // Remove the guild's existing resources first, since the guild may
+        // have changed while it was unavailable.
+        self.delete_guild(guild_id).await?;
+
+        if self.wants(ResourceType::CHANNEL) {
+            for channel in guild.channels.into_iter().chain(guild.threads) {
+                self.cache_guild_channel(guild_id, channel).await?;
+            }
+        }
+
+        if self.wants(ResourceType::EMOJI) {
+            self.cache_emojis(guild_id, guild.emojis).await?;
+        }
+
+        if self.wants(ResourceType::MEMBER) {
+            for member in guild.members {
+                self.cache_member(guild_id, member).await?;
+            }
+        }
+
+        if self.wants(ResourceType::PRESENCE) {
+            for presence in &guild.presences {
+                self.cache_presence(guild_id, presence).await?;
+            }
+        }
+
+        if self.wants(ResourceType::ROLE) {
+            for role in &guild.roles {
+                self.cache_role(guild_id, role).await?;
+            }
+        }
+
+        if self.wants(ResourceType::VOICE_STATE) {
+            for voice_state in &guild.voice_states {
+                self.cache_voice_state(voice_state).await?;
+            }
+        }
+
+        let guild = CachedGuild {
+            id: guild.id,
+            afk_channel_id: guild.afk_channel_id,
+            afk_timeout: guild.afk_timeout,
+            application_id: guild.application_id,
+            banner: guild.banner,
+            default_message_notifications: guild.default_message_notifications,
+            description: guild.description,
+            discovery_splash: guild.discovery_splash,
+            explicit_content_filter: guild.explicit_content_filter,
+            features: guild.features,
+            icon: guild.icon,
+            joined_at: guild.joined_at,
+            large: guild.large,
+            lazy: guild.lazy,
+            max_members: guild.max_members,
+            max_presences: guild.max_presences,
+            member_count: guild.member_count,
+            mfa_level: guild.mfa_level,
+            name: guild.name,
+            owner: guild.owner,
+            owner_id: guild.owner_id,
+            permissions: guild.permissions,
+            preferred_locale: guild.preferred_locale,
+            premium_subscription_count: guild.premium_subscription_count,
+            premium_tier: guild.premium_tier,
+            region: guild.region,
+            rules_channel_id: guild.rules_channel_id,
+            splash: guild.splash,
+            system_channel_id: guild.system_channel_id,
+            system_channel_flags: guild.system_channel_flags,
+            unavailable: guild.unavailable,
+            verification_level: guild.verification_level,
+            vanity_url_code: guild.vanity_url_code,
+            widget_channel_id: guild.widget_channel_id,
+            widget_enabled: guild.widget_enabled,
+        };
+
+        self.put(&self.key(guild_key(guild_id)), &guild).await
+    }
+
+    /// Delete a guild and all of its resources.
+    async fn delete_guild(&self, guild_id: GuildId) -> Result<(), KvCacheError> {
+        self.delete(&self.key(guild_key(guild_id))).await?;
+
+        if self.wants(ResourceType::CHANNEL) {
+            self.delete_set(
+                &self.key(guild_set_key(guild_id, "channels")),
+                ChannelId,
+                channel_key,
+            )
+            .await?;
+        }
+
+        if self.wants(ResourceType::EMOJI) {
+            self.delete_set(
+                &self.key(guild_set_key(guild_id, "emojis")),
+                EmojiId,
+                emoji_key,
+            )
+            .await?;
+        }
+
+        if self.wants(ResourceType::MEMBER) {
+            self.delete_set(
+                &self.key(guild_set_key(guild_id, "members")),
+                UserId,
+                |id| member_key(guild_id, id),
+            )
+            .await?;
+        }
+
+        if self.wants(ResourceType::PRESENCE) {
+            self.delete_set(
+                &self.key(guild_set_key(guild_id, "presences")),
+                UserId,
+                |id| presence_key(guild_id, id),
+            )
+            .await?;
+        }
+
+        if self.wants(ResourceType::ROLE) {
+            self.delete_set(
+                &self.key(guild_set_key(guild_id, "roles")),
+                RoleId,
+                role_key,
+            )
+            .await?;
+        }
+
+        if self.wants(ResourceType::VOICE_STATE) {
+            let set_key = self.key(guild_set_key(guild_id, "voice_states"));
+
+            if let Some(user_ids) = self.set_ids(&set_key, UserId).await? {
+                for user_id in user_ids {
+                    self.delete_voice_state(guild_id, user_id).await?;
+                }
+            }
+
+            self.delete(&set_key).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn cache_member(&self, guild_id: GuildId, member: Member) -> Result<(), KvCacheError> {
+        let user_id = member.user.id;
+        self.put(&self.key(user_key(user_id)), &member.user).await?;
+
+        let cached = CachedMember {
+            deaf: member.deaf,
+            guild_id,
+            joined_at: member.joined_at,
+            mute: member.mute,
+            nick: member.nick,
+            pending: member.pending,
+            premium_since: member.premium_since,
+            roles: member.roles,
+            user: Arc::new(member.user),
+        };
+
+        self.put(&self.key(member_key(guild_id, user_id)), &cached)
+            .await?;
+        self.set_add(
+            &self.key(guild_set_key(guild_id, "members")),
+            vec![user_id.to_string()],
+        )
+        .await
+    }
+
+    async fn delete_member(&self, guild_id: GuildId, user_id: UserId) -> Result<(), KvCacheError> {
+        self.delete(&self.key(member_key(guild_id, user_id)))
+            .await?;
+        self.set_remove(
+            &self.key(guild_set_key(guild_id, "members")),
+            user_id.to_string(),
+        )
+        .await
+    }
+
+    async fn cache_presence(
+        &self,
+        guild_id: GuildId,
+        presence: &Presence,
+    ) -> Result<(), KvCacheError> {
+        let cached = CachedPresence::from(presence);
+
+        self.put(&self.key(presence_key(guild_id, cached.user_id)), &cached)
+            .await?;
+        self.set_add(
+            &self.key(guild_set_key(guild_id, "presences")),
+            vec![cached.user_id.to_string()],
+        )
+        .await
+    }
+
+    async fn cache_role(&self, guild_id: GuildId, role: &Role) -> Result<(), KvCacheError> {
+        self.put(&self.key(role_key(role.id)), role).await?;
+        self.set_add(
+            &self.key(guild_set_key(guild_id, "roles")),
+            vec![role.id.to_string()],
+        )
+        .await
+    }
+
+    async fn delete_role(&self, guild_id: GuildId, role_id: RoleId) -> Result<(), KvCacheError> {
+        self.delete(&self.key(role_key(role_id))).await?;
+        self.set_remove(
+            &self.key(guild_set_key(guild_id, "roles")),
+            role_id.to_string(),
+        )
+        .await
+    }
+
+    async fn cache_voice_state(&self, voice_state: &VoiceState) -> Result<(), KvCacheError> {
+        let guild_id = match voice_state.guild_id {
+            Some(guild_id) => guild_id,
+            None => return Ok(()),
+        };
+
+        // Remove the user from the channel they were previously connected to.
+        self.delete_voice_state(guild_id, voice_state.user_id)
+            .await?;
+
+        let channel_id = match voice_state.channel_id {
+            Some(channel_id) => channel_id,
+            None => return Ok(()),
+        };
+
+        self.put(
+            &self.key(voice_state_key(guild_id, voice_state.user_id)),
+            voice_state,
+        )
+        .await?;
+        self.set_add(
+            &self.key(guild_set_key(guild_id, "voice_states")),
+            vec![voice_state.user_id.to_string()],
+        )
+        .await?;
+        self.set_add(
+            &self.key(channel_voice_states_key(channel_id)),
+            vec![format!("{}:{}", guild_id, voice_state.user_id)],
+        )
+        .await
+    }
+
+    async fn delete_voice_state(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<(), KvCacheError> {
+        let key = self.key(voice_state_key(guild_id, user_id));
+
+        let voice_state = match self.get::<VoiceState>(&key).await? {
+            Some(voice_state) => voice_state,
+            None => return Ok(()),
+        };
+
+        if let Some(channel_id) = voice_state.channel_id {
+            self.set_remove(
+                &self.key(channel_voice_states_key(channel_id)),
+                format!("{}:{}", guild_id, user_id),
+            )
+            .await?;
+        }
+
+        self.set_remove(
+            &self.key(guild_set_key(guild_id, "voice_states")),
+            user_id.to_string(),
+        )
+        .await?;
+        self.delete(&key).await
+    }
+
+    /// Determine whether the configured cache wants a specific resource to be
+    /// processed.
+    fn wants(&self, resource_type: ResourceType) -> bool {
+        self.0.resource_types.contains(resource_type)
+    }
+}
+
+impl Cache for KvCache {
+    type Error = KvCacheError;
+    type Update = KvUpdate;
+
+    fn current_user(&self) -> CacheFuture<'_, Option<Arc<CurrentUser>>, Self::Error> {
+        Box::pin(async move { Ok(self.get(&self.key("current_user")).await?.map(Arc::new)) })
+    }
+
+    fn emoji(&self, emoji_id: EmojiId) -> CacheFuture<'_, Option<Arc<CachedEmoji>>, Self::Error> {
+        Box::pin(async move {
+            Ok(self
+                .get(&self.key(emoji_key(emoji_id)))
+                .await?
+                .map(Arc::new))
+        })
+    }
+
+    fn group(&self, channel_id: ChannelId) -> CacheFuture<'_, Option<Arc<Group>>, Self::Error> {
+        Box::pin(async move {
+            Ok(self
+                .get(&self.key(group_key(channel_id)))
+                .await?
+                .map(Arc::new))
+        })
+    }
+
+    fn guild(&self, guild_id: GuildId) -> CacheFuture<'_, Option<Arc<CachedGuild>>, Self::Error> {
+        Box::pin(async move {
+            Ok(self
+                .get(&self.key(guild_key(guild_id)))
+                .await?
+                .map(Arc::new))
+        })
+    }
+
+    fn guild_channel(
+        &self,
+        channel_id: ChannelId,
+    ) -> CacheFuture<'_, Option<Arc<GuildChannel>>, Self::Error> {
+        Box::pin(async move {
+            Ok(self
+                .get(&self.key(channel_key(channel_id)))
+                .await?
+                .map(Arc::new))
+        })
+    }
+
+    fn guild_channels(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<ChannelId>>, Self::Error> {
+        Box::pin(async move {
+            self.set_ids(&self.key(guild_set_key(guild_id, "channels")), ChannelId)
+                .await
+        })
+    }
+
+    fn guild_emojis(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<EmojiId>>, Self::Error> {
+        Box::pin(async move {
+            self.set_ids(&self.key(guild_set_key(guild_id, "emojis")), EmojiId)
+                .await
+        })
+    }
+
+    fn guild_members(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<UserId>>, Self::Error> {
+        Box::pin(async move {
+            self.set_ids(&self.key(guild_set_key(guild_id, "members")), UserId)
+                .await
+        })
+    }
+
+    fn guild_presences(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<UserId>>, Self::Error> {
+        Box::pin(async move {
+            self.set_ids(&self.key(guild_set_key(guild_id, "presences")), UserId)
+                .await
+        })
+    }
+
+    fn guild_roles(
+        &self,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<HashSet<RoleId>>, Self::Error> {
+        Box::pin(async move {
+            self.set_ids(&self.key(guild_set_key(guild_id, "roles")), RoleId)
+                .await
+        })
+    }
+
+    fn member(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> CacheFuture<'_, Option<Arc<CachedMember>>, Self::Error> {
+        Box::pin(async move {
+            Ok(self
+                .get(&self.key(member_key(guild_id, user_id)))
+                .await?
+                .map(Arc::new))
+        })
+    }
+
+    /// Messages aren't cached, so this always resolves to `None`.
+    fn message(
+        &self,
+        _: ChannelId,
+        _: MessageId,
+    ) -> CacheFuture<'_, Option<Arc<CachedMessage>>, Self::Error> {
+        Box::pin(async { Ok(None) })
+    }
+
+    fn presence(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> CacheFuture<'_, Option<Arc<CachedPresence>>, Self::Error> {
+        Box::pin(async move {
+            Ok(self
+                .get(&self.key(presence_key(guild_id, user_id)))
+                .await?
+                .map(Arc::new))
+        })
+    }
+
+    fn private_channel(
+        &self,
+        channel_id: ChannelId,
+    ) -> CacheFuture<'_, Option<Arc<PrivateChannel>>, Self::Error> {
+        Box::pin(async move {
+            Ok(self
+                .get(&self.key(private_channel_key(channel_id)))
+                .await?
+                .map(Arc::new))
+        })
+    }
+
+    fn role(&self, role_id: RoleId) -> CacheFuture<'_, Option<Arc<Role>>, Self::Error> {
+        Box::pin(async move { Ok(self.get(&self.key(role_key(role_id))).await?.map(Arc::new)) })
+    }
+
+    fn user(&self, user_id: UserId) -> CacheFuture<'_, Option<Arc<User>>, Self::Error> {
+        Box::pin(async move { Ok(self.get(&self.key(user_key(user_id))).await?.map(Arc::new)) })
+    }
+
+    fn voice_channel_states(
+        &self,
+        channel_id: ChannelId,
+    ) -> CacheFuture<'_, Option<Vec<Arc<VoiceState>>>, Self::Error> {
+        Box::pin(async move {
+            let key = self.key(channel_voice_states_key(channel_id));
+
+            let members = match self.0.store.set_members(&key).await? {
+                Some(members) => members,
+                None => return Ok(None),
+            };
+
+            let mut voice_states = Vec::with_capacity(members.len());
+
+            for member in members {
+                let key = self.key(format_args!("voice_state:{}", member));
+
+                if let Some(voice_state) = self.get(&key).await? {
+                    voice_states.push(Arc::new(voice_state));
+                }
+            }
+
+            Ok(Some(voice_states))
+        })
+    }
+
+    fn voice_state(
+        &self,
+        user_id: UserId,
+        guild_id: GuildId,
+    ) -> CacheFuture<'_, Option<Arc<VoiceState>>, Self::Error> {
+        Box::pin(async move {
+            Ok(self
+                .get(&self.key(voice_state_key(guild_id, user_id)))
+                .await?
+                .map(Arc::new))
+        })
+    }
+}
+
+fn channel_voice_states_key(channel_id: ChannelId) -> String {
+    format!("channel:{}:voice_states", channel_id)
+}
+
+fn channel_key(channel_id: ChannelId) -> String {
+    format!("channel:{}", channel_id)
+}
+
+fn emoji_key(emoji_id: EmojiId) -> String {
+    format!("emoji:{}", emoji_id)
+}
+
+fn group_key(channel_id: ChannelId) -> String {
+    format!("group:{}", channel_id)
+}
+
+fn guild_key(guild_id: GuildId) -> String {
+    format!("guild:{}", guild_id)
+}
+
+/// Key of the set of the IDs of a kind of resource in a guild.
+fn guild_set_key(guild_id: GuildId, kind: &str) -> String {
+    format!("guild:{}:{}", guild_id, kind)
+}
+
+fn member_key(guild_id: GuildId, user_id: UserId) -> String {
+    format!("member:{}:{}", guild_id, user_id)
+}
+
+fn presence_key(guild_id: GuildId, user_id: UserId) -> String {
+    format!("presence:{}:{}", guild_id, user_id)
+}
+
+fn private_channel_key(channel_id: ChannelId) -> String {
+    format!("private_channel:{}", channel_id)
+}
+
+fn role_key(role_id: RoleId) -> String {
+    format!("role:{}", role_id)
+}
+
+fn user_key(user_id: UserId) -> String {
+    format!("user:{}", user_id)
+}
+
+fn voice_state_key(guild_id: GuildId, user_id: UserId) -> String {
+    format!("voice_state:{}:{}", guild_id, user_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KvCache, KvCacheError};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_cache_inmemory::Cache;
+
+    assert_fields!(KvCacheError::Deserializing: key, source);
+    assert_fields!(KvCacheError::Serializing: source);
+    assert_fields!(KvCacheError::Store: source);
+    assert_impl_all!(KvCache: Cache, Clone, Debug, Send, Sync);
+    assert_impl_all!(KvCacheError: Debug, Error, Send, Sync);
+}
@@ -0,0 +1,174 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    future::{self, Future},
+    pin::Pin,
+    sync::Mutex,
+};
+
+/// Future returned by the operations of a [`Store`].
+pub type StoreFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, StoreError>> + Send + 'a>>;
+
+/// Performing an operation on a [`Store`] failed.
+#[derive(Debug)]
+pub struct StoreError {
+    source: Box<dyn Error + Send + Sync>,
+}
+
+impl StoreError {
+    /// Create a new error from the error of a storage backend.
+    pub fn new(source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
+}
+
+impl Display for StoreError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("the store failed to perform an operation")
+    }
+}
+
+impl Error for StoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// Asynchronous key-value storage backing a [`KvCache`].
+///
+/// Keys hold either a value or a set of strings, which maps directly to the
+/// string and set types of stores like Redis.
+///
+/// [`KvCache`]: crate::KvCache
+pub trait Store: Debug + Send + Sync {
+    /// Get the value of a key, if it exists.
+    fn get<'a>(&'a self, key: &'a str) -> StoreFuture<'a, Option<Vec<u8>>>;
+
+    /// Set the value of a key, replacing any existing value.
+    fn put<'a>(&'a self, key: &'a str, value: Vec<u8>) -> StoreFuture<'a, ()>;
+
+    /// Delete a key, whether it holds a value or a set.
+    fn delete<'a>(&'a self, key: &'a str) -> StoreFuture<'a, ()>;
+
+    /// Add members to the set of a key, creating the set if it doesn't exist.
+    fn set_add<'a>(&'a self, key: &'a str, members: Vec<String>) -> StoreFuture<'a, ()>;
+
+    /// Remove members from the set of a key.
+    fn set_remove<'a>(&'a self, key: &'a str, members: Vec<String>) -> StoreFuture<'a, ()>;
+
+    /// Get the members of the set of a key, if it exists.
+    ///
+    /// Stores may treat empty sets as nonexistent.
+    fn set_members<'a>(&'a self, key: &'a str) -> StoreFuture<'a, Option<HashSet<String>>>;
+}
+
+/// Store keeping its data in the memory of the current process.
+///
+/// This is mostly useful for testing code using a [`KvCache`] without an
+/// external store.
+///
+/// [`KvCache`]: crate::KvCache
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    sets: Mutex<HashMap<String, HashSet<String>>>,
+    values: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl MemoryStore {
+    /// Create a new, empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Store for MemoryStore {
+    fn get<'a>(&'a self, key: &'a str) -> StoreFuture<'a, Option<Vec<u8>>> {
+        let value = self
+            .values
+            .lock()
+            .expect("values poisoned")
+            .get(key)
+            .cloned();
+
+        Box::pin(future::ready(Ok(value)))
+    }
+
+    fn put<'a>(&'a self, key: &'a str, value: Vec<u8>) -> StoreFuture<'a, ()> {
+        self.values
+            .lock()
+            .expect("values poisoned")
+            .insert(key.to_owned(), value);
+
+        Box::pin(future::ready(Ok(())))
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> StoreFuture<'a, ()> {
+        self.sets.lock().expect("sets poisoned").remove(key);
+        self.values.lock().expect("values poisoned").remove(key);
+
+        Box::pin(future::ready(Ok(())))
+    }
+
+    fn set_add<'a>(&'a self, key: &'a str, members: Vec<String>) -> StoreFuture<'a, ()> {
+        self.sets
+            .lock()
+            .expect("sets poisoned")
+            .entry(key.to_owned())
+            .or_default()
+            .extend(members);
+
+        Box::pin(future::ready(Ok(())))
+    }
+
+    fn set_remove<'a>(&'a self, key: &'a str, members: Vec<String>) -> StoreFuture<'a, ()> {
+        if let Some(set) = self.sets.lock().expect("sets poisoned").get_mut(key) {
+            for member in &members {
+                set.remove(member);
+            }
+        }
+
+        Box::pin(future::ready(Ok(())))
+    }
+
+    fn set_members<'a>(&'a self, key: &'a str) -> StoreFuture<'a, Option<HashSet<String>>> {
+        let members = self.sets.lock().expect("sets poisoned").get(key).cloned();
+
+        Box::pin(future::ready(Ok(members)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemoryStore, Store, StoreError};
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(MemoryStore: Debug, Default, Send, Store, Sync);
+    assert_impl_all!(StoreError: Debug, Error, Send, Sync);
+    assert_obj_safe!(Store);
+
+    #[tokio::test]
+    async fn test_memory_store() -> Result<(), StoreError> {
+        let store = MemoryStore::new();
+        store.put("a", b"value".to_vec()).await?;
+        assert_eq!(Some(b"value".to_vec()), store.get("a").await?);
+
+        store
+            .set_add("b", vec!["1".to_owned(), "2".to_owned()])
+            .await?;
+        store.set_remove("b", vec!["1".to_owned()]).await?;
+        let members = store.set_members("b").await?.expect("set exists");
+        assert_eq!(1, members.len());
+        assert!(members.contains("2"));
+
+        store.delete("a").await?;
+        store.delete("b").await?;
+        assert!(store.get("a").await?.is_none());
+        assert!(store.set_members("b").await?.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,928 @@
+use super::{guild_key, member_key, KvCache, KvCacheError};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    ops::Deref,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use twilight_cache_inmemory::{
+    model::{CachedGuild, CachedMember},
+    ResourceType, UpdateCache,
+};
+use twilight_model::{
+    gateway::{event::Event, payload::*, presence::Presence},
+    guild::GuildStatus,
+};
+
+/// Future writing an update to a [`KvCache`].
+///
+/// This is returned when updating the cache, and resolves immediately if the
+/// cache doesn't handle the value it was updated with.
+#[derive(Default)]
+#[must_use = "updates aren't written to the store unless polled"]
+pub struct KvUpdate(Option<Pin<Box<dyn Future<Output = Result<(), KvCacheError>> + Send>>>);
+
+impl KvUpdate {
+    fn new(future: impl Future<Output = Result<(), KvCacheError>> + Send + 'static) -> Self {
+        Self(Some(Box::pin(future)))
+    }
+}
+
+impl Debug for KvUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("KvUpdate").finish()
+    }
+}
+
+impl Future for KvUpdate {
+    type Output = Result<(), KvCacheError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.0.as_mut() {
+            Some(future) => future.as_mut().poll(cx),
+            None => Poll::Ready(Ok(())),
+        }
+    }
+}
+
+impl UpdateCache<KvCache> for Event {
+    fn update(&self, c: &KvCache) -> KvUpdate {
+        use Event::*;
+
+        match self {
+            ChannelCreate(v) => c.update(v),
+            ChannelDelete(v) => c.update(v),
+            ChannelUpdate(v) => c.update(v),
+            GuildCreate(v) => c.update(v.deref()),
+            GuildDelete(v) => c.update(v.deref()),
+            GuildEmojisUpdate(v) => c.update(v),
+            GuildUpdate(v) => c.update(v.deref()),
+            MemberAdd(v) => c.update(v.deref()),
+            MemberChunk(v) => c.update(v),
+            MemberRemove(v) => c.update(v),
+            MemberUpdate(v) => c.update(v.deref()),
+            PresenceUpdate(v) => c.update(v.deref()),
+            Ready(v) => c.update(v.deref()),
+            RoleCreate(v) => c.update(v),
+            RoleDelete(v) => c.update(v),
+            RoleUpdate(v) => c.update(v),
+            ThreadCreate(v) => c.update(v),
+            ThreadDelete(v) => c.update(v),
+            ThreadUpdate(v) => c.update(v),
+            UnavailableGuild(v) => c.update(v),
+            UserUpdate(v) => c.update(v),
+            VoiceStateUpdate(v) => c.update(v.deref()),
+            _ => KvUpdate::default(),
+        }
+    }
+}
+
+impl UpdateCache<KvCache> for ChannelCreate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let channel = self.0.clone();
+
+        KvUpdate::new(async move { cache.cache_channel(channel).await })
+    }
+}
+
+impl UpdateCache<KvCache> for ChannelDelete {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let channel = self.0.clone();
+
+        KvUpdate::new(async move { cache.delete_channel(&channel).await })
+    }
+}
+
+impl UpdateCache<KvCache> for ChannelUpdate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let channel = self.0.clone();
+
+        KvUpdate::new(async move { cache.cache_channel(channel).await })
+    }
+}
+
+impl UpdateCache<KvCache> for GuildCreate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::GUILD) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let guild = self.0.clone();
+
+        KvUpdate::new(async move { cache.cache_guild(guild).await })
+    }
+}
+
+impl UpdateCache<KvCache> for GuildDelete {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::GUILD) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let guild_id = self.id;
+
+        KvUpdate::new(async move { cache.delete_guild(guild_id).await })
+    }
+}
+
+impl UpdateCache<KvCache> for GuildEmojisUpdate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::EMOJI) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let guild_id = self.guild_id;
+        let emojis = self.emojis.clone();
+
+        KvUpdate::new(async move { cache.cache_emojis(guild_id, emojis).await })
+    }
+}
+
+impl UpdateCache<KvCache> for GuildUpdate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::GUILD) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let update = self.0.clone();
+
+        KvUpdate::new(async move {
+            let key = cache.key(guild_key(update.id));
+
+            let mut guild = match cache.get::<CachedGuild>(&key).await? {
+                Some(guild) => guild,
+                None => return Ok(()),
+            };
+
+            guild.afk_channel_id = update.afk_channel_id;
+            guild.afk_timeout = update.afk_timeout;
+            guild.banner = update.banner;
+            guild.default_message_notifications = update.default_message_notifications;
+            guild.description = update.description;
+            guild.features = update.features;
+            guild.icon = update.icon;
+            guild.max_members = update.max_members;
+            guild.max_presences = Some(update.max_presences.unwrap_or(25000));
+            guild.mfa_level = update.mfa_level;
+            guild.name = update.name;
+            guild.owner = update.owner;
+            guild.owner_id = update.owner_id;
+            guild.permissions = update.permissions;
+            guild.preferred_locale = update.preferred_locale;
+            guild.premium_tier = update.premium_tier;
+            guild
+                .premium_subscription_count
+                .replace(update.premium_subscription_count.unwrap_or_default());
+            guild.region = update.region;
+            guild.splash = update.splash;
+            guild.system_channel_id = update.system_channel_id;
+            guild.verification_level = update.verification_level;
+            guild.vanity_url_code = update.vanity_url_code;
+            guild.widget_channel_id = update.widget_channel_id;
+            guild.widget_enabled = update.widget_enabled;
+
+            cache.put(&key, &guild).await
+        })
+    }
+}
+
+impl UpdateCache<KvCache> for MemberAdd {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::MEMBER) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let guild_id = self.guild_id;
+        let member = self.0.clone();
+
+        KvUpdate::new(async move { cache.cache_member(guild_id, member).await })
+    }
+}
+
+impl UpdateCache<KvCache> for MemberChunk {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::MEMBER) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let guild_id = self.guild_id;
+        let members = self.members.clone();
+        let presences = self.presences.clone();
+
+        KvUpdate::new(async move {
+            for member in members {
+                cache.cache_member(guild_id, member).await?;
+            }
+
+            if cache.wants(ResourceType::PRESENCE) {
+                for presence in &presences {
+                    cache.cache_presence(guild_id, presence).await?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl UpdateCache<KvCache> for MemberRemove {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::MEMBER) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let guild_id = self.guild_id;
+        let user_id = self.user.id;
+
+        KvUpdate::new(async move { cache.delete_member(guild_id, user_id).await })
+    }
+}
+
+impl UpdateCache<KvCache> for MemberUpdate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::MEMBER) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let update = self.clone();
+
+        KvUpdate::new(async move {
+            let key = cache.key(member_key(update.guild_id, update.user.id));
+
+            let mut member = match cache.get::<CachedMember>(&key).await? {
+                Some(member) => member,
+                None => return Ok(()),
+            };
+
+            member.nick = update.nick;
+            member.roles = update.roles;
+            member.joined_at.replace(update.joined_at);
+            member.pending = update.pending;
+
+            cache.put(&key, &member).await
+        })
+    }
+}
+
+impl UpdateCache<KvCache> for PresenceUpdate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::PRESENCE) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let presence = Presence {
+            activities: self.activities.clone(),
+            client_status: self.client_status.clone(),
+            guild_id: self.guild_id,
+            status: self.status,
+            user: self.user.clone(),
+        };
+
+        KvUpdate::new(async move { cache.cache_presence(presence.guild_id, &presence).await })
+    }
+}
+
+impl UpdateCache<KvCache> for Ready {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        let cache = cache.clone();
+        let current_user = self.user.clone();
+        let guilds = self.guilds.clone();
+
+        KvUpdate::new(async move {
+            if cache.wants(ResourceType::USER_CURRENT) {
+                cache.cache_current_user(&current_user).await?;
+            }
+
+            if cache.wants(ResourceType::GUILD) {
+                for status in guilds {
+                    match status {
+                        GuildStatus::Offline(guild) => {
+                            cache.delete(&cache.key(guild_key(guild.id))).await?;
+                        }
+                        GuildStatus::Online(guild) => {
+                            cache.cache_guild(guild).await?;
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl UpdateCache<KvCache> for RoleCreate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::ROLE) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let guild_id = self.guild_id;
+        let role = self.role.clone();
+
+        KvUpdate::new(async move { cache.cache_role(guild_id, &role).await })
+    }
+}
+
+impl UpdateCache<KvCache> for RoleDelete {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::ROLE) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let guild_id = self.guild_id;
+        let role_id = self.role_id;
+
+        KvUpdate::new(async move { cache.delete_role(guild_id, role_id).await })
+    }
+}
+
+impl UpdateCache<KvCache> for RoleUpdate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::ROLE) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let guild_id = self.guild_id;
+        let role = self.role.clone();
+
+        KvUpdate::new(async move { cache.cache_role(guild_id, &role).await })
+    }
+}
+
+impl UpdateCache<KvCache> for ThreadCreate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        let guild_id = match self.0.guild_id() {
+            Some(guild_id) if cache.wants(ResourceType::CHANNEL) => guild_id,
+            _ => return KvUpdate::default(),
+        };
+
+        let cache = cache.clone();
+        let channel = self.0.clone();
+
+        KvUpdate::new(async move { cache.cache_guild_channel(guild_id, channel).await })
+    }
+}
+
+impl UpdateCache<KvCache> for ThreadDelete {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let guild_id = self.guild_id;
+        let id = self.id;
+
+        KvUpdate::new(async move { cache.delete_guild_channel(Some(guild_id), id).await })
+    }
+}
+
+impl UpdateCache<KvCache> for ThreadUpdate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        let guild_id = match self.0.guild_id() {
+            Some(guild_id) if cache.wants(ResourceType::CHANNEL) => guild_id,
+            _ => return KvUpdate::default(),
+        };
+
+        let cache = cache.clone();
+        let channel = self.0.clone();
+
+        KvUpdate::new(async move { cache.cache_guild_channel(guild_id, channel).await })
+    }
+}
+
+impl UpdateCache<KvCache> for UnavailableGuild {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::GUILD) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let guild_id = self.id;
+
+        KvUpdate::new(async move { cache.delete(&cache.key(guild_key(guild_id))).await })
+    }
+}
+
+impl UpdateCache<KvCache> for UserUpdate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::USER_CURRENT) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let current_user = self.0.clone();
+
+        KvUpdate::new(async move { cache.cache_current_user(&current_user).await })
+    }
+}
+
+impl UpdateCache<KvCache> for VoiceStateUpdate {
+    fn update(&self, cache: &KvCache) -> KvUpdate {
+        if !cache.wants(ResourceType::VOICE_STATE) {
+            return KvUpdate::default();
+        }
+
+        let cache = cache.clone();
+        let voice_state = self.0.clone();
+
+        KvUpdate::new(async move {
+            cache.cache_voice_state(&voice_state).await?;
+
+            if let (Some(guild_id), Some(member)) = (voice_state.guild_id, voice_state.member) {
+                if cache.wants(ResourceType::MEMBER) {
+                    cache.cache_member(guild_id, member).await?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KvUpdate;
+    use crate::{KvCache, KvCacheError, MemoryStore, Store, StoreFuture};
+    use static_assertions::assert_impl_all;
+    use std::{collections::HashSet, fmt::Debug, future::Future, sync::Arc};
+    use twilight_cache_inmemory::Cache;
+    use twilight_model::{
+        channel::{Channel, ChannelType, GuildChannel, TextChannel},
+        gateway::payload::{
+            ChannelCreate, ChannelDelete, ChannelUpdate, GuildCreate, GuildDelete,
+            GuildEmojisUpdate, MemberAdd, MemberChunk, MemberRemove, RoleCreate, RoleDelete,
+            VoiceStateUpdate,
+        },
+        guild::{
+            DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, Guild, Member, MfaLevel,
+            Permissions, PremiumTier, Role, SystemChannelFlags, VerificationLevel,
+        },
+        id::{ChannelId, EmojiId, GuildId, RoleId, UserId},
+        user::User,
+        voice::VoiceState,
+    };
+
+    assert_impl_all!(KvUpdate: Debug, Default, Future, Send);
+
+    /// Store shared by multiple caches.
+    #[derive(Clone, Debug, Default)]
+    struct SharedStore(Arc<MemoryStore>);
+
+    impl Store for SharedStore {
+        fn get<'a>(&'a self, key: &'a str) -> StoreFuture<'a, Option<Vec<u8>>> {
+            self.0.get(key)
+        }
+
+        fn put<'a>(&'a self, key: &'a str, value: Vec<u8>) -> StoreFuture<'a, ()> {
+            self.0.put(key, value)
+        }
+
+        fn delete<'a>(&'a self, key: &'a str) -> StoreFuture<'a, ()> {
+            self.0.delete(key)
+        }
+
+        fn set_add<'a>(&'a self, key: &'a str, members: Vec<String>) -> StoreFuture<'a, ()> {
+            self.0.set_add(key, members)
+        }
+
+        fn set_remove<'a>(&'a self, key: &'a str, members: Vec<String>) -> StoreFuture<'a, ()> {
+            self.0.set_remove(key, members)
+        }
+
+        fn set_members<'a>(&'a self, key: &'a str) -> StoreFuture<'a, Option<HashSet<String>>> {
+            self.0.set_members(key)
+        }
+    }
+
+    fn channel(id: u64, name: &str) -> GuildChannel {
+        GuildChannel::Text(TextChannel {
+            guild_id: Some(GuildId(1)),
+            id: ChannelId(id),
+            kind: ChannelType::GuildText,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            name: name.to_owned(),
+            nsfw: false,
+            parent_id: None,
+            permission_overwrites: Vec::new(),
+            position: 3,
+            rate_limit_per_user: None,
+            topic: None,
+        })
+    }
+
+    fn emoji(id: u64, name: &str) -> Emoji {
+        Emoji {
+            animated: false,
+            available: true,
+            id: EmojiId(id),
+            managed: false,
+            name: name.to_owned(),
+            require_colons: true,
+            roles: Vec::new(),
+            user: None,
+        }
+    }
+
+    fn member(id: u64) -> Member {
+        Member {
+            deaf: false,
+            guild_id: GuildId(1),
+            hoisted_role: None,
+            joined_at: None,
+            mute: false,
+            nick: None,
+            pending: false,
+            premium_since: None,
+            roles: Vec::new(),
+            user: user(id),
+        }
+    }
+
+    fn role(id: u64) -> Role {
+        Role {
+            color: 0,
+            hoist: false,
+            id: RoleId(id),
+            managed: false,
+            mentionable: false,
+            name: "test".to_owned(),
+            permissions: Permissions::empty(),
+            position: 0,
+            tags: None,
+        }
+    }
+
+    fn user(id: u64) -> User {
+        User {
+            avatar: None,
+            bot: false,
+            discriminator: "0001".to_owned(),
+            email: None,
+            flags: None,
+            id: UserId(id),
+            locale: None,
+            mfa_enabled: None,
+            name: "test".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    fn guild() -> Guild {
+        Guild {
+            afk_channel_id: None,
+            afk_timeout: 0,
+            application_id: None,
+            approximate_member_count: None,
+            approximate_presence_count: None,
+            banner: None,
+            channels: vec![channel(2, "test")],
+            default_message_notifications: DefaultMessageNotificationLevel::Mentions,
+            description: None,
+            discovery_splash: None,
+            emojis: Vec::new(),
+            explicit_content_filter: ExplicitContentFilter::None,
+            features: Vec::new(),
+            guild_scheduled_events: Vec::new(),
+            icon: None,
+            id: GuildId(1),
+            joined_at: None,
+            large: false,
+            lazy: None,
+            max_members: None,
+            max_presences: None,
+            max_video_channel_users: None,
+            member_count: None,
+            members: Vec::new(),
+            mfa_level: MfaLevel::None,
+            name: "test".to_owned(),
+            owner_id: UserId(1),
+            owner: None,
+            permissions: None,
+            preferred_locale: "en_us".to_owned(),
+            premium_subscription_count: None,
+            premium_tier: PremiumTier::None,
+            presences: Vec::new(),
+            region: "us".to_owned(),
+            roles: vec![role(3)],
+            rules_channel_id: None,
+            splash: None,
+            stage_instances: Vec::new(),
+            system_channel_flags: SystemChannelFlags::empty(),
+            system_channel_id: None,
+            threads: Vec::new(),
+            unavailable: false,
+            vanity_url_code: None,
+            verification_level: VerificationLevel::VeryHigh,
+            voice_states: Vec::new(),
+            widget_channel_id: None,
+            widget_enabled: None,
+        }
+    }
+
+    fn voice_state(channel_id: Option<ChannelId>) -> VoiceState {
+        VoiceState {
+            channel_id,
+            deaf: false,
+            guild_id: Some(GuildId(1)),
+            member: None,
+            mute: false,
+            request_to_speak_timestamp: None,
+            self_deaf: false,
+            self_mute: false,
+            self_stream: false,
+            session_id: "a".to_owned(),
+            suppress: false,
+            token: None,
+            user_id: UserId(4),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_guild_create_and_delete() -> Result<(), KvCacheError> {
+        let cache = KvCache::new(MemoryStore::new());
+        cache.update(&GuildCreate(guild())).await?;
+
+        let cached = cache.guild(GuildId(1)).await?.expect("guild cached");
+        assert_eq!("test", cached.name);
+        let channel = cache
+            .guild_channel(ChannelId(2))
+            .await?
+            .expect("channel cached");
+        assert_eq!(Some(GuildId(1)), channel.guild_id());
+        assert!(cache
+            .guild_channels(GuildId(1))
+            .await?
+            .expect("channels cached")
+            .contains(&ChannelId(2)));
+
+        cache
+            .update(&RoleDelete {
+                guild_id: GuildId(1),
+                role_id: RoleId(3),
+            })
+            .await?;
+        assert!(cache.role(RoleId(3)).await?.is_none());
+
+        cache
+            .update(&GuildDelete {
+                id: GuildId(1),
+                unavailable: false,
+            })
+            .await?;
+        assert!(cache.guild(GuildId(1)).await?.is_none());
+        assert!(cache.guild_channel(ChannelId(2)).await?.is_none());
+        assert!(cache.guild_channels(GuildId(1)).await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_voice_states() -> Result<(), KvCacheError> {
+        let cache = KvCache::builder(MemoryStore::new())
+            .key_prefix("test")
+            .build();
+
+        cache
+            .update(&VoiceStateUpdate(voice_state(Some(ChannelId(5)))))
+            .await?;
+        assert_eq!(
+            1,
+            cache
+                .voice_channel_states(ChannelId(5))
+                .await?
+                .expect("states cached")
+                .len()
+        );
+
+        // Moving to another channel removes the state from the previous one.
+        cache
+            .update(&VoiceStateUpdate(voice_state(Some(ChannelId(6)))))
+            .await?;
+        assert!(cache
+            .voice_channel_states(ChannelId(5))
+            .await?
+            .expect("set exists")
+            .is_empty());
+
+        cache.update(&VoiceStateUpdate(voice_state(None))).await?;
+        assert!(cache.voice_state(UserId(4), GuildId(1)).await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_channels() -> Result<(), KvCacheError> {
+        let cache = KvCache::new(MemoryStore::new());
+
+        cache
+            .update(&ChannelCreate(Channel::Guild(channel(2, "a"))))
+            .await?;
+        cache
+            .update(&ChannelCreate(Channel::Guild(channel(3, "b"))))
+            .await?;
+        let channels = cache.guild_channels(GuildId(1)).await?.expect("set exists");
+        assert_eq!(2, channels.len());
+        assert!(channels.contains(&ChannelId(2)) && channels.contains(&ChannelId(3)));
+
+        cache
+            .update(&ChannelUpdate(Channel::Guild(channel(2, "c"))))
+            .await?;
+        let updated = cache.guild_channel(ChannelId(2)).await?.expect("cached");
+        assert_eq!("c", updated.name());
+        assert_eq!(
+            2,
+            cache
+                .guild_channels(GuildId(1))
+                .await?
+                .expect("set exists")
+                .len()
+        );
+
+        cache
+            .update(&ChannelDelete(Channel::Guild(channel(2, "c"))))
+            .await?;
+        assert!(cache.guild_channel(ChannelId(2)).await?.is_none());
+        let channels = cache.guild_channels(GuildId(1)).await?.expect("set exists");
+        assert_eq!(1, channels.len());
+        assert!(channels.contains(&ChannelId(3)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_members() -> Result<(), KvCacheError> {
+        let cache = KvCache::new(MemoryStore::new());
+
+        cache.update(&MemberAdd(member(4))).await?;
+        assert_eq!(
+            GuildId(1),
+            cache
+                .member(GuildId(1), UserId(4))
+                .await?
+                .expect("cached")
+                .guild_id
+        );
+        assert!(cache.user(UserId(4)).await?.is_some());
+
+        cache
+            .update(&MemberChunk {
+                chunk_count: 1,
+                chunk_index: 0,
+                guild_id: GuildId(1),
+                members: vec![member(5), member(6)],
+                nonce: None,
+                not_found: Vec::new(),
+                presences: Vec::new(),
+            })
+            .await?;
+        assert_eq!(
+            3,
+            cache
+                .guild_members(GuildId(1))
+                .await?
+                .expect("set exists")
+                .len()
+        );
+        assert!(cache.member(GuildId(1), UserId(6)).await?.is_some());
+
+        cache
+            .update(&MemberRemove {
+                guild_id: GuildId(1),
+                user: user(4),
+            })
+            .await?;
+        assert!(cache.member(GuildId(1), UserId(4)).await?.is_none());
+        let members = cache.guild_members(GuildId(1)).await?.expect("set exists");
+        assert_eq!(2, members.len());
+        assert!(!members.contains(&UserId(4)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_roles() -> Result<(), KvCacheError> {
+        let cache = KvCache::new(MemoryStore::new());
+
+        cache
+            .update(&RoleCreate {
+                guild_id: GuildId(1),
+                role: role(3),
+            })
+            .await?;
+        assert_eq!(RoleId(3), cache.role(RoleId(3)).await?.expect("cached").id);
+        assert!(cache
+            .guild_roles(GuildId(1))
+            .await?
+            .expect("set exists")
+            .contains(&RoleId(3)));
+
+        cache
+            .update(&RoleDelete {
+                guild_id: GuildId(1),
+                role_id: RoleId(3),
+            })
+            .await?;
+        assert!(cache.role(RoleId(3)).await?.is_none());
+        assert!(!cache
+            .guild_roles(GuildId(1))
+            .await?
+            .unwrap_or_default()
+            .contains(&RoleId(3)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_emojis_replaced() -> Result<(), KvCacheError> {
+        let cache = KvCache::new(MemoryStore::new());
+
+        cache
+            .update(&GuildEmojisUpdate {
+                emojis: vec![emoji(7, "a"), emoji(8, "b")],
+                guild_id: GuildId(1),
+            })
+            .await?;
+        assert_eq!(
+            2,
+            cache
+                .guild_emojis(GuildId(1))
+                .await?
+                .expect("set exists")
+                .len()
+        );
+
+        // Emojis missing from the update are removed, the others replaced.
+        cache
+            .update(&GuildEmojisUpdate {
+                emojis: vec![emoji(8, "c"), emoji(9, "d")],
+                guild_id: GuildId(1),
+            })
+            .await?;
+        assert!(cache.emoji(EmojiId(7)).await?.is_none());
+        assert_eq!("c", cache.emoji(EmojiId(8)).await?.expect("cached").name);
+        assert!(cache.emoji(EmojiId(9)).await?.is_some());
+        let emojis = cache.guild_emojis(GuildId(1)).await?.expect("set exists");
+        assert_eq!(2, emojis.len());
+        assert!(!emojis.contains(&EmojiId(7)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_key_prefix_isolation() -> Result<(), KvCacheError> {
+        let store = SharedStore::default();
+        let first = KvCache::builder(store.clone()).key_prefix("first").build();
+        let second = KvCache::builder(store.clone()).key_prefix("second").build();
+
+        first.update(&GuildCreate(guild())).await?;
+        assert!(first.guild(GuildId(1)).await?.is_some());
+        assert!(second.guild(GuildId(1)).await?.is_none());
+        assert!(second.guild_channels(GuildId(1)).await?.is_none());
+        assert!(store.get("first:guild:1").await?.is_some());
+
+        second
+            .update(&GuildDelete {
+                id: GuildId(1),
+                unavailable: false,
+            })
+            .await?;
+        assert!(first.guild(GuildId(1)).await?.is_some());
+        assert!(first.guild_channel(ChannelId(2)).await?.is_some());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+//! Create action rows.
+
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::channel::message::component::{ActionRow, Component};
+
+/// Error building an action row.
+///
+/// This is returned from [`ActionRowBuilder::build`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ActionRowBuildError {
+    /// No components were provided.
+    Empty,
+    /// An action row was provided as a component of the action row.
+    ///
+    /// Action rows can't be nested.
+    NestedActionRow {
+        /// The provided components.
+        components: Vec<Component>,
+    },
+    /// A select menu was provided alongside other components.
+    ///
+    /// A select menu must be the only component in its action row.
+    SelectMenuNotAlone {
+        /// The provided components.
+        components: Vec<Component>,
+    },
+    /// Too many components were provided.
+    ///
+    /// Refer to [`ActionRowBuilder::COMPONENT_LIMIT`] for more information
+    /// about what the limit is.
+    TooManyComponents {
+        /// The provided components.
+        components: Vec<Component>,
+    },
+}
+
+impl Display for ActionRowBuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Empty => f.write_str("no components were provided"),
+            Self::NestedActionRow { .. } => {
+                f.write_str("an action row can't contain another action row")
+            }
+            Self::SelectMenuNotAlone { .. } => {
+                f.write_str("a select menu must be the only component in an action row")
+            }
+            Self::TooManyComponents { .. } => f.write_str("more than 5 components were provided"),
+        }
+    }
+}
+
+impl Error for ActionRowBuildError {}
+
+/// Create an action row with a builder.
+///
+/// Action rows are the top-level components of a message and contain either
+/// up to 5 buttons or a single select menu.
+///
+/// # Examples
+///
+/// Refer to the [crate-level documentation] for examples.
+///
+/// [crate-level documentation]: crate
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into an action row"]
+pub struct ActionRowBuilder(ActionRow);
+
+impl ActionRowBuilder {
+    /// The maximum number of components that can be in an action row.
+    ///
+    /// This is used by [`build`].
+    ///
+    /// [`build`]: Self::build
+    pub const COMPONENT_LIMIT: usize = 5;
+
+    /// Create a new default action row builder.
+    ///
+    /// This is equivalent to the [default implementation].
+    ///
+    /// [default implementation]: Self::default
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build this into an action row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActionRowBuildError::Empty`] if no components were added.
+    ///
+    /// Returns [`ActionRowBuildError::NestedActionRow`] if one of the
+    /// components is an action row.
+    ///
+    /// Returns [`ActionRowBuildError::SelectMenuNotAlone`] if a select menu
+    /// was added alongside other components.
+    ///
+    /// Returns [`ActionRowBuildError::TooManyComponents`] if there are too
+    /// many components in the action row. Refer to [`COMPONENT_LIMIT`] for
+    /// the limit value.
+    ///
+    /// [`COMPONENT_LIMIT`]: Self::COMPONENT_LIMIT
+    #[must_use = "should be used as part of a message"]
+    pub fn build(self) -> Result<ActionRow, ActionRowBuildError> {
+        let components = self.0.components;
+
+        if components.is_empty() {
+            return Err(ActionRowBuildError::Empty);
+        }
+
+        if components.len() > Self::COMPONENT_LIMIT {
+            return Err(ActionRowBuildError::TooManyComponents { components });
+        }
+
+        if components
+            .iter()
+            .any(|component| matches!(component, Component::ActionRow(_)))
+        {
+            return Err(ActionRowBuildError::NestedActionRow { components });
+        }
+
+        if components.len() > 1
+            && components
+                .iter()
+                .any(|component| matches!(component, Component::SelectMenu(_)))
+        {
+            return Err(ActionRowBuildError::SelectMenuNotAlone { components });
+        }
+
+        Ok(ActionRow { components })
+    }
+
+    /// Add a component.
+    ///
+    /// # Examples
+    ///
+    /// Create an action row with two buttons:
+    ///
+    /// ```rust
+    /// use twilight_component_builder::{ActionRowBuilder, ButtonBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let action_row = ActionRowBuilder::new()
+    ///     .component(ButtonBuilder::success("accept")?.label("Accept")?)
+    ///     .component(ButtonBuilder::danger("decline")?.label("Decline")?)
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn component(self, component: impl Into<Component>) -> Self {
+        self._component(component.into())
+    }
+
+    fn _component(mut self, component: Component) -> Self {
+        self.0.components.push(component);
+
+        self
+    }
+}
+
+impl Default for ActionRowBuilder {
+    /// Create an action row builder with no components.
+    fn default() -> Self {
+        Self(ActionRow {
+            components: Vec::new(),
+        })
+    }
+}
+
+impl TryFrom<ActionRowBuilder> for ActionRow {
+    type Error = ActionRowBuildError;
+
+    /// Convert an action row builder into an action row.
+    ///
+    /// This is equivalent to calling [`ActionRowBuilder::build`].
+    fn try_from(builder: ActionRowBuilder) -> Result<Self, Self::Error> {
+        builder.build()
+    }
+}
+
+impl TryFrom<ActionRowBuilder> for Component {
+    type Error = ActionRowBuildError;
+
+    /// Convert an action row builder into a component.
+    ///
+    /// This is equivalent to calling [`ActionRowBuilder::build`] and wrapping
+    /// the result in [`Component::ActionRow`].
+    fn try_from(builder: ActionRowBuilder) -> Result<Self, Self::Error> {
+        builder.build().map(Component::ActionRow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ActionRowBuildError, ActionRowBuilder};
+    use crate::{ButtonBuilder, SelectMenuBuilder, SelectMenuOptionBuilder};
+    use static_assertions::{assert_fields, assert_impl_all, const_assert};
+    use std::{convert::TryFrom, error::Error, fmt::Debug};
+    use twilight_model::channel::message::component::{ActionRow, Component};
+
+    assert_impl_all!(
+        ActionRowBuildError: Clone,
+        Debug,
+        Error,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_fields!(ActionRowBuildError::NestedActionRow: components);
+    assert_fields!(ActionRowBuildError::SelectMenuNotAlone: components);
+    assert_fields!(ActionRowBuildError::TooManyComponents: components);
+    assert_impl_all!(
+        ActionRowBuilder: Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    const_assert!(ActionRowBuilder::COMPONENT_LIMIT == 5);
+    assert_impl_all!(ActionRow: TryFrom<ActionRowBuilder>);
+    assert_impl_all!(Component: TryFrom<ActionRowBuilder>);
+
+    #[test]
+    fn test_build_errors() -> Result<(), Box<dyn Error>> {
+        assert!(matches!(
+            ActionRowBuilder::new().build(),
+            Err(ActionRowBuildError::Empty)
+        ));
+
+        let mut builder = ActionRowBuilder::new();
+
+        for _ in 0..=ActionRowBuilder::COMPONENT_LIMIT {
+            builder = builder.component(ButtonBuilder::primary("a")?);
+        }
+
+        assert!(matches!(
+            builder.build(),
+            Err(ActionRowBuildError::TooManyComponents { components })
+            if components.len() == 6
+        ));
+
+        let nested = ActionRowBuilder::new()
+            .component(ButtonBuilder::primary("a")?)
+            .build()?;
+
+        assert!(matches!(
+            ActionRowBuilder::new().component(nested).build(),
+            Err(ActionRowBuildError::NestedActionRow { .. })
+        ));
+
+        let select_menu = SelectMenuBuilder::new("b")?
+            .option(SelectMenuOptionBuilder::new("label", "value")?)
+            .build()?;
+
+        assert!(matches!(
+            ActionRowBuilder::new()
+                .component(ButtonBuilder::primary("a")?)
+                .component(select_menu)
+                .build(),
+            Err(ActionRowBuildError::SelectMenuNotAlone { components })
+            if components.len() == 2
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder() -> Result<(), Box<dyn Error>> {
+        let expected = ActionRow {
+            components: vec![
+                Component::Button(ButtonBuilder::primary("a")?.build()),
+                Component::Button(ButtonBuilder::link("https://twilight.rs").build()),
+            ],
+        };
+        let actual = ActionRowBuilder::new()
+            .component(ButtonBuilder::primary("a")?)
+            .component(ButtonBuilder::link("https://twilight.rs"))
+            .build()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
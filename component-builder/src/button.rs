@@ -0,0 +1,345 @@
+//! Create buttons.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::channel::{
+    message::component::{Button, ButtonStyle, Component},
+    ReactionType,
+};
+
+/// Error creating a button with a custom ID.
+///
+/// This is returned from [`ButtonBuilder::primary`],
+/// [`ButtonBuilder::secondary`], [`ButtonBuilder::success`], and
+/// [`ButtonBuilder::danger`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ButtonCustomIdError {
+    /// Custom ID is empty.
+    Empty {
+        /// Provided custom ID. Although empty, the same owned allocation is
+        /// included.
+        custom_id: String,
+    },
+    /// Custom ID is longer than 100 UTF-16 code points.
+    TooLong {
+        /// Provided custom ID.
+        custom_id: String,
+    },
+}
+
+impl Display for ButtonCustomIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Empty { .. } => f.write_str("the custom id is empty"),
+            Self::TooLong { .. } => f.write_str("the custom id is too long"),
+        }
+    }
+}
+
+impl Error for ButtonCustomIdError {}
+
+/// Error setting the label of a button.
+///
+/// This is returned from [`ButtonBuilder::label`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ButtonLabelError {
+    /// Label is longer than 80 UTF-16 code points.
+    TooLong {
+        /// Provided label.
+        label: String,
+    },
+}
+
+impl Display for ButtonLabelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::TooLong { .. } => f.write_str("the label is too long"),
+        }
+    }
+}
+
+impl Error for ButtonLabelError {}
+
+/// Create a button with a builder.
+///
+/// Buttons with a link style are created with [`link`] and navigate to a URL
+/// when clicked, while all other styles are created with a custom ID that is
+/// sent with the resulting interaction.
+///
+/// # Examples
+///
+/// Refer to the [crate-level documentation] for examples.
+///
+/// [`link`]: Self::link
+/// [crate-level documentation]: crate
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a button"]
+pub struct ButtonBuilder(Button);
+
+impl ButtonBuilder {
+    /// The maximum number of UTF-16 code points that can be in a custom ID.
+    ///
+    /// This is used by [`primary`], [`secondary`], [`success`], and
+    /// [`danger`].
+    ///
+    /// [`danger`]: Self::danger
+    /// [`primary`]: Self::primary
+    /// [`secondary`]: Self::secondary
+    /// [`success`]: Self::success
+    pub const CUSTOM_ID_LENGTH_LIMIT: usize = 100;
+
+    /// The maximum number of UTF-16 code points that can be in a label.
+    ///
+    /// This is used by [`label`].
+    ///
+    /// [`label`]: Self::label
+    pub const LABEL_LENGTH_LIMIT: usize = 80;
+
+    /// Create a new button builder with a [`ButtonStyle::Primary`] style.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ButtonCustomIdError::Empty`] if the provided custom ID is
+    /// empty.
+    ///
+    /// Returns [`ButtonCustomIdError::TooLong`] if the provided custom ID is
+    /// longer than 100 UTF-16 code points.
+    pub fn primary(custom_id: impl Into<String>) -> Result<Self, ButtonCustomIdError> {
+        Self::with_custom_id(ButtonStyle::Primary, custom_id.into())
+    }
+
+    /// Create a new button builder with a [`ButtonStyle::Secondary`] style.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ButtonCustomIdError::Empty`] if the provided custom ID is
+    /// empty.
+    ///
+    /// Returns [`ButtonCustomIdError::TooLong`] if the provided custom ID is
+    /// longer than 100 UTF-16 code points.
+    pub fn secondary(custom_id: impl Into<String>) -> Result<Self, ButtonCustomIdError> {
+        Self::with_custom_id(ButtonStyle::Secondary, custom_id.into())
+    }
+
+    /// Create a new button builder with a [`ButtonStyle::Success`] style.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ButtonCustomIdError::Empty`] if the provided custom ID is
+    /// empty.
+    ///
+    /// Returns [`ButtonCustomIdError::TooLong`] if the provided custom ID is
+    /// longer than 100 UTF-16 code points.
+    pub fn success(custom_id: impl Into<String>) -> Result<Self, ButtonCustomIdError> {
+        Self::with_custom_id(ButtonStyle::Success, custom_id.into())
+    }
+
+    /// Create a new button builder with a [`ButtonStyle::Danger`] style.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ButtonCustomIdError::Empty`] if the provided custom ID is
+    /// empty.
+    ///
+    /// Returns [`ButtonCustomIdError::TooLong`] if the provided custom ID is
+    /// longer than 100 UTF-16 code points.
+    pub fn danger(custom_id: impl Into<String>) -> Result<Self, ButtonCustomIdError> {
+        Self::with_custom_id(ButtonStyle::Danger, custom_id.into())
+    }
+
+    /// Create a new button builder with a [`ButtonStyle::Link`] style that
+    /// navigates to a URL.
+    pub fn link(url: impl Into<String>) -> Self {
+        Self(Button {
+            custom_id: None,
+            disabled: false,
+            emoji: None,
+            label: None,
+            style: ButtonStyle::Link,
+            url: Some(url.into()),
+        })
+    }
+
+    fn with_custom_id(style: ButtonStyle, custom_id: String) -> Result<Self, ButtonCustomIdError> {
+        if custom_id.is_empty() {
+            return Err(ButtonCustomIdError::Empty { custom_id });
+        }
+
+        if custom_id.chars().count() > Self::CUSTOM_ID_LENGTH_LIMIT {
+            return Err(ButtonCustomIdError::TooLong { custom_id });
+        }
+
+        Ok(Self(Button {
+            custom_id: Some(custom_id),
+            disabled: false,
+            emoji: None,
+            label: None,
+            style,
+            url: None,
+        }))
+    }
+
+    /// Build into a button.
+    #[must_use = "should be used as part of an action row"]
+    pub fn build(self) -> Button {
+        self.0
+    }
+
+    /// Disable the button.
+    pub fn disabled(mut self) -> Self {
+        self.0.disabled = true;
+
+        self
+    }
+
+    /// Set the emoji displayed with the button.
+    pub fn emoji(mut self, emoji: ReactionType) -> Self {
+        self.0.emoji.replace(emoji);
+
+        self
+    }
+
+    /// Set the label.
+    ///
+    /// Refer to [`LABEL_LENGTH_LIMIT`] for the maximum number of UTF-16 code
+    /// points that can be in a label.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ButtonLabelError::TooLong`] if the label is longer than 80
+    /// UTF-16 code points.
+    ///
+    /// [`LABEL_LENGTH_LIMIT`]: Self::LABEL_LENGTH_LIMIT
+    pub fn label(self, label: impl Into<String>) -> Result<Self, ButtonLabelError> {
+        self._label(label.into())
+    }
+
+    fn _label(mut self, label: String) -> Result<Self, ButtonLabelError> {
+        if label.chars().count() > Self::LABEL_LENGTH_LIMIT {
+            return Err(ButtonLabelError::TooLong { label });
+        }
+
+        self.0.label.replace(label);
+
+        Ok(self)
+    }
+}
+
+impl From<ButtonBuilder> for Button {
+    /// Convert a button builder into a button.
+    ///
+    /// This is equivalent to calling [`ButtonBuilder::build`].
+    fn from(builder: ButtonBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<ButtonBuilder> for Component {
+    /// Convert a button builder into a component.
+    ///
+    /// This is equivalent to calling [`ButtonBuilder::build`] and wrapping the
+    /// result in [`Component::Button`].
+    fn from(builder: ButtonBuilder) -> Self {
+        Component::Button(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ButtonBuilder, ButtonCustomIdError, ButtonLabelError};
+    use static_assertions::{assert_fields, assert_impl_all, const_assert};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::channel::message::component::{Button, ButtonStyle, Component};
+
+    assert_impl_all!(
+        ButtonCustomIdError: Clone,
+        Debug,
+        Error,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_fields!(ButtonCustomIdError::Empty: custom_id);
+    assert_fields!(ButtonCustomIdError::TooLong: custom_id);
+    assert_impl_all!(
+        ButtonLabelError: Clone,
+        Debug,
+        Error,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_fields!(ButtonLabelError::TooLong: label);
+    assert_impl_all!(ButtonBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    const_assert!(ButtonBuilder::CUSTOM_ID_LENGTH_LIMIT == 100);
+    const_assert!(ButtonBuilder::LABEL_LENGTH_LIMIT == 80);
+    assert_impl_all!(Button: From<ButtonBuilder>);
+    assert_impl_all!(Component: From<ButtonBuilder>);
+
+    #[test]
+    fn test_custom_id_errors() {
+        assert!(matches!(
+            ButtonBuilder::primary(""),
+            Err(ButtonCustomIdError::Empty { custom_id })
+            if custom_id.is_empty()
+        ));
+        assert!(matches!(
+            ButtonBuilder::danger("a".repeat(101)),
+            Err(ButtonCustomIdError::TooLong { custom_id })
+            if custom_id.len() == 101
+        ));
+    }
+
+    #[test]
+    fn test_label_errors() -> Result<(), Box<dyn Error>> {
+        assert!(matches!(
+            ButtonBuilder::primary("a")?.label("a".repeat(81)),
+            Err(ButtonLabelError::TooLong { label })
+            if label.len() == 81
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder() -> Result<(), Box<dyn Error>> {
+        let expected = Button {
+            custom_id: Some("id".to_owned()),
+            disabled: true,
+            emoji: None,
+            label: Some("label".to_owned()),
+            style: ButtonStyle::Success,
+            url: None,
+        };
+        let actual = ButtonBuilder::success("id")?
+            .label("label")?
+            .disabled()
+            .build();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_link() {
+        let expected = Button {
+            custom_id: None,
+            disabled: false,
+            emoji: None,
+            label: None,
+            style: ButtonStyle::Link,
+            url: Some("https://twilight.rs".to_owned()),
+        };
+        let actual = ButtonBuilder::link("https://twilight.rs").build();
+
+        assert_eq!(actual, expected);
+    }
+}
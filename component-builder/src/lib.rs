@@ -0,0 +1,81 @@
+//! # twilight-component-builder
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-component-builder` is a set of builders for the [`twilight-rs`]
+//! ecosystem to create message components, such as buttons and select menus,
+//! useful when creating or updating messages.
+//!
+//! Builders validate the limits Discord places on each component, such as the
+//! length of labels and custom IDs and the number of components in an action
+//! row.
+//!
+//! ## Examples
+//!
+//! Build an action row with two buttons:
+//!
+//! ```rust,no_run
+//! use twilight_component_builder::{ActionRowBuilder, ButtonBuilder};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let action_row = ActionRowBuilder::new()
+//!     .component(ButtonBuilder::success("accept")?.label("Accept")?)
+//!     .component(ButtonBuilder::link("https://twilight.rs").label("Documentation")?)
+//!     .build()?;
+//! # Ok(()) }
+//! ```
+//!
+//! Build an action row with a select menu:
+//!
+//! ```rust,no_run
+//! use twilight_component_builder::{ActionRowBuilder, SelectMenuBuilder, SelectMenuOptionBuilder};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let select_menu = SelectMenuBuilder::new("pony")?
+//!     .placeholder("Choose the best pony")?
+//!     .option(SelectMenuOptionBuilder::new("Twilight Sparkle", "twilight")?.default_selected())
+//!     .option(SelectMenuOptionBuilder::new("Rarity", "rarity")?)
+//!     .build()?;
+//!
+//! let action_row = ActionRowBuilder::new().component(select_menu).build()?;
+//! # Ok(()) }
+//! ```
+//!
+//! [`twilight-rs`]: https://github.com/twilight-rs/twilight
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.48+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    broken_intra_doc_links,
+    unsafe_code,
+    unused,
+    warnings
+)]
+
+pub mod action_row;
+pub mod button;
+pub mod select_menu;
+pub mod select_menu_option;
+
+pub use self::{
+    action_row::{ActionRowBuildError, ActionRowBuilder},
+    button::{ButtonBuilder, ButtonCustomIdError, ButtonLabelError},
+    select_menu::{
+        SelectMenuBuildError, SelectMenuBuilder, SelectMenuCustomIdError,
+        SelectMenuPlaceholderError,
+    },
+    select_menu_option::{
+        SelectMenuOptionBuilder, SelectMenuOptionDescriptionError, SelectMenuOptionError,
+    },
+};
@@ -0,0 +1,442 @@
+//! Create select menus.
+
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::channel::message::component::{SelectMenu, SelectMenuOption};
+
+/// Error building a select menu.
+///
+/// This is returned from [`SelectMenuBuilder::build`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum SelectMenuBuildError {
+    /// Maximum number of values to choose is either 0 or more than the
+    /// number of options.
+    MaxValuesInvalid {
+        /// Provided maximum number of values.
+        max_values: u8,
+    },
+    /// Minimum number of values to choose is more than the maximum number of
+    /// values or the number of options.
+    MinValuesInvalid {
+        /// Provided minimum number of values.
+        min_values: u8,
+    },
+    /// No options were provided.
+    NoOptions,
+    /// Too many options were provided.
+    ///
+    /// Refer to [`SelectMenuBuilder::OPTION_LIMIT`] for more information
+    /// about what the limit is.
+    TooManyOptions {
+        /// The provided options.
+        options: Vec<SelectMenuOption>,
+    },
+}
+
+impl Display for SelectMenuBuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::MaxValuesInvalid { .. } => f.write_str("the maximum number of values is invalid"),
+            Self::MinValuesInvalid { .. } => f.write_str("the minimum number of values is invalid"),
+            Self::NoOptions => f.write_str("no options were provided"),
+            Self::TooManyOptions { .. } => f.write_str("more than 25 options were provided"),
+        }
+    }
+}
+
+impl Error for SelectMenuBuildError {}
+
+/// Error creating a select menu.
+///
+/// This is returned from [`SelectMenuBuilder::new`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum SelectMenuCustomIdError {
+    /// Custom ID is empty.
+    Empty {
+        /// Provided custom ID. Although empty, the same owned allocation is
+        /// included.
+        custom_id: String,
+    },
+    /// Custom ID is longer than 100 UTF-16 code points.
+    TooLong {
+        /// Provided custom ID.
+        custom_id: String,
+    },
+}
+
+impl Display for SelectMenuCustomIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Empty { .. } => f.write_str("the custom id is empty"),
+            Self::TooLong { .. } => f.write_str("the custom id is too long"),
+        }
+    }
+}
+
+impl Error for SelectMenuCustomIdError {}
+
+/// Error setting the placeholder of a select menu.
+///
+/// This is returned from [`SelectMenuBuilder::placeholder`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum SelectMenuPlaceholderError {
+    /// Placeholder is longer than 100 UTF-16 code points.
+    TooLong {
+        /// Provided placeholder.
+        placeholder: String,
+    },
+}
+
+impl Display for SelectMenuPlaceholderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::TooLong { .. } => f.write_str("the placeholder is too long"),
+        }
+    }
+}
+
+impl Error for SelectMenuPlaceholderError {}
+
+/// Create a select menu with a builder.
+///
+/// # Examples
+///
+/// Refer to the [crate-level documentation] for examples.
+///
+/// [crate-level documentation]: crate
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a select menu"]
+pub struct SelectMenuBuilder(SelectMenu);
+
+impl SelectMenuBuilder {
+    /// The maximum number of UTF-16 code points that can be in a custom ID.
+    ///
+    /// This is used by [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub const CUSTOM_ID_LENGTH_LIMIT: usize = 100;
+
+    /// The maximum number of options that can be in a select menu.
+    ///
+    /// This is used by [`build`].
+    ///
+    /// [`build`]: Self::build
+    pub const OPTION_LIMIT: usize = 25;
+
+    /// The maximum number of UTF-16 code points that can be in a placeholder.
+    ///
+    /// This is used by [`placeholder`].
+    ///
+    /// [`placeholder`]: Self::placeholder
+    pub const PLACEHOLDER_LENGTH_LIMIT: usize = 100;
+
+    /// Create a new select menu builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SelectMenuCustomIdError::Empty`] if the provided custom ID is
+    /// empty.
+    ///
+    /// Returns [`SelectMenuCustomIdError::TooLong`] if the provided custom ID
+    /// is longer than 100 UTF-16 code points.
+    pub fn new(custom_id: impl Into<String>) -> Result<Self, SelectMenuCustomIdError> {
+        Self::_new(custom_id.into())
+    }
+
+    fn _new(custom_id: String) -> Result<Self, SelectMenuCustomIdError> {
+        if custom_id.is_empty() {
+            return Err(SelectMenuCustomIdError::Empty { custom_id });
+        }
+
+        if custom_id.chars().count() > Self::CUSTOM_ID_LENGTH_LIMIT {
+            return Err(SelectMenuCustomIdError::TooLong { custom_id });
+        }
+
+        Ok(Self(SelectMenu {
+            custom_id,
+            disabled: false,
+            max_values: None,
+            min_values: None,
+            options: Vec::new(),
+            placeholder: None,
+        }))
+    }
+
+    /// Build this into a select menu.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SelectMenuBuildError::NoOptions`] if no options were added.
+    ///
+    /// Returns [`SelectMenuBuildError::TooManyOptions`] if there are too many
+    /// options in the select menu. Refer to [`OPTION_LIMIT`] for the limit
+    /// value.
+    ///
+    /// Returns [`SelectMenuBuildError::MaxValuesInvalid`] if the maximum
+    /// number of values is 0 or more than the number of options.
+    ///
+    /// Returns [`SelectMenuBuildError::MinValuesInvalid`] if the minimum
+    /// number of values is more than the maximum number of values or the
+    /// number of options.
+    ///
+    /// [`OPTION_LIMIT`]: Self::OPTION_LIMIT
+    #[must_use = "should be used as part of an action row"]
+    pub fn build(self) -> Result<SelectMenu, SelectMenuBuildError> {
+        if self.0.options.is_empty() {
+            return Err(SelectMenuBuildError::NoOptions);
+        }
+
+        if self.0.options.len() > Self::OPTION_LIMIT {
+            return Err(SelectMenuBuildError::TooManyOptions {
+                options: self.0.options,
+            });
+        }
+
+        let option_count = self.0.options.len();
+
+        if let Some(max_values) = self.0.max_values {
+            if max_values == 0 || usize::from(max_values) > option_count {
+                return Err(SelectMenuBuildError::MaxValuesInvalid { max_values });
+            }
+        }
+
+        if let Some(min_values) = self.0.min_values {
+            let max_values = self.0.max_values.unwrap_or(1);
+
+            if min_values > max_values || usize::from(min_values) > option_count {
+                return Err(SelectMenuBuildError::MinValuesInvalid { min_values });
+            }
+        }
+
+        Ok(self.0)
+    }
+
+    /// Disable the select menu.
+    pub fn disabled(mut self) -> Self {
+        self.0.disabled = true;
+
+        self
+    }
+
+    /// Set the maximum number of options that may be chosen.
+    ///
+    /// Defaults to 1 if not set.
+    pub fn max_values(mut self, max_values: u8) -> Self {
+        self.0.max_values.replace(max_values);
+
+        self
+    }
+
+    /// Set the minimum number of options that must be chosen.
+    ///
+    /// Defaults to 1 if not set.
+    pub fn min_values(mut self, min_values: u8) -> Self {
+        self.0.min_values.replace(min_values);
+
+        self
+    }
+
+    /// Add an option.
+    ///
+    /// # Examples
+    ///
+    /// Create a select menu with two options:
+    ///
+    /// ```rust
+    /// use twilight_component_builder::{SelectMenuBuilder, SelectMenuOptionBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let select_menu = SelectMenuBuilder::new("class")?
+    ///     .option(SelectMenuOptionBuilder::new("Rogue", "rogue")?)
+    ///     .option(SelectMenuOptionBuilder::new("Mage", "mage")?)
+    ///     .build()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn option(self, option: impl Into<SelectMenuOption>) -> Self {
+        self._option(option.into())
+    }
+
+    fn _option(mut self, option: SelectMenuOption) -> Self {
+        self.0.options.push(option);
+
+        self
+    }
+
+    /// Set the placeholder shown when no option is selected.
+    ///
+    /// Refer to [`PLACEHOLDER_LENGTH_LIMIT`] for the maximum number of UTF-16
+    /// code points that can be in a placeholder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SelectMenuPlaceholderError::TooLong`] if the placeholder is
+    /// longer than 100 UTF-16 code points.
+    ///
+    /// [`PLACEHOLDER_LENGTH_LIMIT`]: Self::PLACEHOLDER_LENGTH_LIMIT
+    pub fn placeholder(
+        self,
+        placeholder: impl Into<String>,
+    ) -> Result<Self, SelectMenuPlaceholderError> {
+        self._placeholder(placeholder.into())
+    }
+
+    fn _placeholder(mut self, placeholder: String) -> Result<Self, SelectMenuPlaceholderError> {
+        if placeholder.chars().count() > Self::PLACEHOLDER_LENGTH_LIMIT {
+            return Err(SelectMenuPlaceholderError::TooLong { placeholder });
+        }
+
+        self.0.placeholder.replace(placeholder);
+
+        Ok(self)
+    }
+}
+
+impl TryFrom<SelectMenuBuilder> for SelectMenu {
+    type Error = SelectMenuBuildError;
+
+    /// Convert a select menu builder into a select menu.
+    ///
+    /// This is equivalent to calling [`SelectMenuBuilder::build`].
+    fn try_from(builder: SelectMenuBuilder) -> Result<Self, Self::Error> {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        SelectMenuBuildError, SelectMenuBuilder, SelectMenuCustomIdError,
+        SelectMenuPlaceholderError,
+    };
+    use crate::SelectMenuOptionBuilder;
+    use static_assertions::{assert_fields, assert_impl_all, const_assert};
+    use std::{convert::TryFrom, error::Error, fmt::Debug};
+    use twilight_model::channel::message::component::{SelectMenu, SelectMenuOption};
+
+    assert_impl_all!(
+        SelectMenuBuildError: Clone,
+        Debug,
+        Error,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_fields!(SelectMenuBuildError::MaxValuesInvalid: max_values);
+    assert_fields!(SelectMenuBuildError::MinValuesInvalid: min_values);
+    assert_fields!(SelectMenuBuildError::TooManyOptions: options);
+    assert_impl_all!(
+        SelectMenuCustomIdError: Clone,
+        Debug,
+        Error,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_fields!(SelectMenuCustomIdError::Empty: custom_id);
+    assert_fields!(SelectMenuCustomIdError::TooLong: custom_id);
+    assert_impl_all!(
+        SelectMenuPlaceholderError: Clone,
+        Debug,
+        Error,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_fields!(SelectMenuPlaceholderError::TooLong: placeholder);
+    assert_impl_all!(SelectMenuBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    const_assert!(SelectMenuBuilder::CUSTOM_ID_LENGTH_LIMIT == 100);
+    const_assert!(SelectMenuBuilder::OPTION_LIMIT == 25);
+    const_assert!(SelectMenuBuilder::PLACEHOLDER_LENGTH_LIMIT == 100);
+    assert_impl_all!(SelectMenu: TryFrom<SelectMenuBuilder>);
+
+    fn option(value: &str) -> SelectMenuOption {
+        SelectMenuOptionBuilder::new(value, value)
+            .expect("option is valid")
+            .build()
+    }
+
+    #[test]
+    fn test_build_errors() -> Result<(), Box<dyn Error>> {
+        assert!(matches!(
+            SelectMenuBuilder::new("id")?.build(),
+            Err(SelectMenuBuildError::NoOptions)
+        ));
+
+        let mut builder = SelectMenuBuilder::new("id")?;
+
+        for _ in 0..=SelectMenuBuilder::OPTION_LIMIT {
+            builder = builder.option(option("a"));
+        }
+
+        assert!(matches!(
+            builder.build(),
+            Err(SelectMenuBuildError::TooManyOptions { options })
+            if options.len() == 26
+        ));
+        assert!(matches!(
+            SelectMenuBuilder::new("id")?
+                .option(option("a"))
+                .max_values(2)
+                .build(),
+            Err(SelectMenuBuildError::MaxValuesInvalid { max_values: 2 })
+        ));
+        assert!(matches!(
+            SelectMenuBuilder::new("id")?
+                .option(option("a"))
+                .option(option("b"))
+                .max_values(1)
+                .min_values(2)
+                .build(),
+            Err(SelectMenuBuildError::MinValuesInvalid { min_values: 2 })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_errors() {
+        assert!(matches!(
+            SelectMenuBuilder::new(""),
+            Err(SelectMenuCustomIdError::Empty { custom_id })
+            if custom_id.is_empty()
+        ));
+        assert!(matches!(
+            SelectMenuBuilder::new("a".repeat(101)),
+            Err(SelectMenuCustomIdError::TooLong { custom_id })
+            if custom_id.len() == 101
+        ));
+    }
+
+    #[test]
+    fn test_builder() -> Result<(), Box<dyn Error>> {
+        let expected = SelectMenu {
+            custom_id: "id".to_owned(),
+            disabled: false,
+            max_values: Some(2),
+            min_values: Some(0),
+            options: vec![option("a"), option("b")],
+            placeholder: Some("placeholder".to_owned()),
+        };
+        let actual = SelectMenuBuilder::new("id")?
+            .option(option("a"))
+            .option(SelectMenuOptionBuilder::new("b", "b")?)
+            .max_values(2)
+            .min_values(0)
+            .placeholder("placeholder")?
+            .build()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
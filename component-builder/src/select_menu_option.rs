@@ -0,0 +1,314 @@
+//! Create select menu options.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::channel::{message::component::SelectMenuOption, ReactionType};
+
+/// Error creating a select menu option.
+///
+/// This is returned from [`SelectMenuOptionBuilder::new`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum SelectMenuOptionError {
+    /// Label is empty.
+    LabelEmpty {
+        /// Provided label. Although empty, the same owned allocation is
+        /// included.
+        label: String,
+        /// Provided value.
+        value: String,
+    },
+    /// Label is longer than 100 UTF-16 code points.
+    LabelTooLong {
+        /// Provided label.
+        label: String,
+        /// Provided value.
+        value: String,
+    },
+    /// Value is empty.
+    ValueEmpty {
+        /// Provided label.
+        label: String,
+        /// Provided value. Although empty, the same owned allocation is
+        /// included.
+        value: String,
+    },
+    /// Value is longer than 100 UTF-16 code points.
+    ValueTooLong {
+        /// Provided label.
+        label: String,
+        /// Provided value.
+        value: String,
+    },
+}
+
+impl Display for SelectMenuOptionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::LabelEmpty { .. } => f.write_str("the option label is empty"),
+            Self::LabelTooLong { .. } => f.write_str("the option label is too long"),
+            Self::ValueEmpty { .. } => f.write_str("the option value is empty"),
+            Self::ValueTooLong { .. } => f.write_str("the option value is too long"),
+        }
+    }
+}
+
+impl Error for SelectMenuOptionError {}
+
+/// Error setting the description of a select menu option.
+///
+/// This is returned from [`SelectMenuOptionBuilder::description`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum SelectMenuOptionDescriptionError {
+    /// Description is longer than 100 UTF-16 code points.
+    TooLong {
+        /// Provided description.
+        description: String,
+    },
+}
+
+impl Display for SelectMenuOptionDescriptionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::TooLong { .. } => f.write_str("the option description is too long"),
+        }
+    }
+}
+
+impl Error for SelectMenuOptionDescriptionError {}
+
+/// Create a select menu option with a builder.
+///
+/// This can be passed into [`SelectMenuBuilder::option`].
+///
+/// Options are not selected by default. Use [`default_selected`] to select an
+/// option by default.
+///
+/// [`SelectMenuBuilder::option`]: crate::SelectMenuBuilder::option
+/// [`default_selected`]: Self::default_selected
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a select menu option"]
+pub struct SelectMenuOptionBuilder(SelectMenuOption);
+
+impl SelectMenuOptionBuilder {
+    /// The maximum number of UTF-16 code points that can be in a description.
+    ///
+    /// This is used by [`description`].
+    ///
+    /// [`description`]: Self::description
+    pub const DESCRIPTION_LENGTH_LIMIT: usize = 100;
+
+    /// The maximum number of UTF-16 code points that can be in a label.
+    ///
+    /// This is used by [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub const LABEL_LENGTH_LIMIT: usize = 100;
+
+    /// The maximum number of UTF-16 code points that can be in a value.
+    ///
+    /// This is used by [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub const VALUE_LENGTH_LIMIT: usize = 100;
+
+    /// Create a new select menu option builder.
+    ///
+    /// The label and value are each limited to 100 UTF-16 code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SelectMenuOptionError::LabelEmpty`] if the provided label is
+    /// empty.
+    ///
+    /// Returns [`SelectMenuOptionError::LabelTooLong`] if the provided label
+    /// is longer than 100 UTF-16 code points.
+    ///
+    /// Returns [`SelectMenuOptionError::ValueEmpty`] if the provided value is
+    /// empty.
+    ///
+    /// Returns [`SelectMenuOptionError::ValueTooLong`] if the provided value
+    /// is longer than 100 UTF-16 code points.
+    pub fn new(
+        label: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<Self, SelectMenuOptionError> {
+        Self::_new(label.into(), value.into())
+    }
+
+    fn _new(label: String, value: String) -> Result<Self, SelectMenuOptionError> {
+        if label.is_empty() {
+            return Err(SelectMenuOptionError::LabelEmpty { label, value });
+        }
+
+        if label.chars().count() > Self::LABEL_LENGTH_LIMIT {
+            return Err(SelectMenuOptionError::LabelTooLong { label, value });
+        }
+
+        if value.is_empty() {
+            return Err(SelectMenuOptionError::ValueEmpty { label, value });
+        }
+
+        if value.chars().count() > Self::VALUE_LENGTH_LIMIT {
+            return Err(SelectMenuOptionError::ValueTooLong { label, value });
+        }
+
+        Ok(Self(SelectMenuOption {
+            default: false,
+            description: None,
+            emoji: None,
+            label,
+            value,
+        }))
+    }
+
+    /// Build into a select menu option.
+    #[must_use = "should be used as part of a select menu builder"]
+    pub fn build(self) -> SelectMenuOption {
+        self.0
+    }
+
+    /// Select the option by default.
+    pub fn default_selected(mut self) -> Self {
+        self.0.default = true;
+
+        self
+    }
+
+    /// Set the description.
+    ///
+    /// Refer to [`DESCRIPTION_LENGTH_LIMIT`] for the maximum number of UTF-16
+    /// code points that can be in a description.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SelectMenuOptionDescriptionError::TooLong`] if the
+    /// description is longer than 100 UTF-16 code points.
+    ///
+    /// [`DESCRIPTION_LENGTH_LIMIT`]: Self::DESCRIPTION_LENGTH_LIMIT
+    pub fn description(
+        self,
+        description: impl Into<String>,
+    ) -> Result<Self, SelectMenuOptionDescriptionError> {
+        self._description(description.into())
+    }
+
+    fn _description(
+        mut self,
+        description: String,
+    ) -> Result<Self, SelectMenuOptionDescriptionError> {
+        if description.chars().count() > Self::DESCRIPTION_LENGTH_LIMIT {
+            return Err(SelectMenuOptionDescriptionError::TooLong { description });
+        }
+
+        self.0.description.replace(description);
+
+        Ok(self)
+    }
+
+    /// Set the emoji displayed with the option.
+    pub fn emoji(mut self, emoji: ReactionType) -> Self {
+        self.0.emoji.replace(emoji);
+
+        self
+    }
+}
+
+impl From<SelectMenuOptionBuilder> for SelectMenuOption {
+    /// Convert a select menu option builder into a select menu option.
+    ///
+    /// This is equivalent to calling [`SelectMenuOptionBuilder::build`].
+    fn from(builder: SelectMenuOptionBuilder) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SelectMenuOptionBuilder, SelectMenuOptionDescriptionError, SelectMenuOptionError};
+    use static_assertions::{assert_fields, assert_impl_all, const_assert};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::channel::message::component::SelectMenuOption;
+
+    assert_impl_all!(
+        SelectMenuOptionError: Clone,
+        Debug,
+        Error,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_fields!(SelectMenuOptionError::LabelEmpty: label, value);
+    assert_fields!(SelectMenuOptionError::LabelTooLong: label, value);
+    assert_fields!(SelectMenuOptionError::ValueEmpty: label, value);
+    assert_fields!(SelectMenuOptionError::ValueTooLong: label, value);
+    assert_impl_all!(
+        SelectMenuOptionDescriptionError: Clone,
+        Debug,
+        Error,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_fields!(SelectMenuOptionDescriptionError::TooLong: description);
+    assert_impl_all!(
+        SelectMenuOptionBuilder: Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    const_assert!(SelectMenuOptionBuilder::DESCRIPTION_LENGTH_LIMIT == 100);
+    const_assert!(SelectMenuOptionBuilder::LABEL_LENGTH_LIMIT == 100);
+    const_assert!(SelectMenuOptionBuilder::VALUE_LENGTH_LIMIT == 100);
+    assert_impl_all!(SelectMenuOption: From<SelectMenuOptionBuilder>);
+
+    #[test]
+    fn test_new_errors() {
+        assert!(matches!(
+            SelectMenuOptionBuilder::new("", "a"),
+            Err(SelectMenuOptionError::LabelEmpty { label, value })
+            if label.is_empty() && value.len() == 1
+        ));
+        assert!(matches!(
+            SelectMenuOptionBuilder::new("a".repeat(101), "a"),
+            Err(SelectMenuOptionError::LabelTooLong { label, value })
+            if label.len() == 101 && value.len() == 1
+        ));
+        assert!(matches!(
+            SelectMenuOptionBuilder::new("a", ""),
+            Err(SelectMenuOptionError::ValueEmpty { label, value })
+            if label.len() == 1 && value.is_empty()
+        ));
+        assert!(matches!(
+            SelectMenuOptionBuilder::new("a", "a".repeat(101)),
+            Err(SelectMenuOptionError::ValueTooLong { label, value })
+            if label.len() == 1 && value.len() == 101
+        ));
+    }
+
+    #[test]
+    fn test_builder() -> Result<(), Box<dyn Error>> {
+        let expected = SelectMenuOption {
+            default: true,
+            description: Some("description".to_owned()),
+            emoji: None,
+            label: "label".to_owned(),
+            value: "value".to_owned(),
+        };
+        let actual = SelectMenuOptionBuilder::new("label", "value")?
+            .description("description")?
+            .default_selected()
+            .build();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
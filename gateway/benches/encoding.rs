@@ -0,0 +1,91 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use serde::de::DeserializeSeed;
+use serde_json::{Deserializer, Value};
+use twilight_gateway::shard::etf;
+use twilight_model::gateway::event::GatewayEventDeserializer;
+
+const ROLE_DELETE: &str = r#"{
+    "op": 0,
+    "s": 2,
+    "d": {
+        "guild_id": "1",
+        "role_id": "2"
+    },
+    "t": "GUILD_ROLE_DELETE"
+}"#;
+
+const MEMBER_CHUNK: &str = r#"{
+    "op": 0,
+    "s": 3,
+    "d": {
+        "chunk_count": 1,
+        "chunk_index": 0,
+        "guild_id": "1",
+        "members": [{
+            "deaf": false,
+            "hoisted_role": "6",
+            "joined_at": "2020-04-04T04:04:04.000000+00:00",
+            "mute": false,
+            "nick": "chunk",
+            "roles": ["6"],
+            "user": {
+                "avatar": "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                "discriminator": "0001",
+                "id": "5",
+                "public_flags": 131072,
+                "username": "test"
+            }
+        }, {
+            "deaf": false,
+            "hoisted_role": "6",
+            "joined_at": "2020-04-04T04:04:04.000000+00:00",
+            "mute": false,
+            "nick": "chunk",
+            "roles": ["6"],
+            "user": {
+                "avatar": "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb",
+                "discriminator": "0001",
+                "id": "6",
+                "public_flags": 131072,
+                "username": "test"
+            }
+        }],
+        "presences": []
+    },
+    "t": "GUILD_MEMBERS_CHUNK"
+}"#;
+
+fn json(input: &str) {
+    let mut json_deserializer = Deserializer::from_str(input);
+    let gateway_deserializer = GatewayEventDeserializer::from_json(input).unwrap();
+    gateway_deserializer
+        .deserialize(&mut json_deserializer)
+        .unwrap();
+}
+
+fn to_etf(input: &str) -> Vec<u8> {
+    let value = serde_json::from_str::<Value>(input).unwrap();
+
+    etf::to_vec(&value).unwrap()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let role_delete = to_etf(ROLE_DELETE);
+    let member_chunk = to_etf(MEMBER_CHUNK);
+
+    c.bench_function("json gateway event role delete", |b| {
+        b.iter(|| json(ROLE_DELETE))
+    });
+    c.bench_function("etf gateway event role delete", |b| {
+        b.iter(|| etf::parse_gateway_event(&role_delete).unwrap())
+    });
+    c.bench_function("json gateway event member chunk", |b| {
+        b.iter(|| json(MEMBER_CHUNK))
+    });
+    c.bench_function("etf gateway event member chunk", |b| {
+        b.iter(|| etf::parse_gateway_event(&member_chunk).unwrap())
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -56,6 +56,9 @@ impl DayLimiter {
             .gateway()
             .authed()
             .await
+            .map_err(|source| DayLimiterError::RetrievingSessionAvailability { source })?
+            .model()
+            .await
             .map_err(|source| DayLimiterError::RetrievingSessionAvailability { source })?;
 
         let last_check = Instant::now();
@@ -81,7 +84,12 @@ impl DayLimiter {
         } else {
             let wait = lock.last_check + lock.next_reset;
             time::sleep_until(wait).await;
-            if let Ok(info) = lock.http.gateway().authed().await {
+            let info = match lock.http.gateway().authed().await {
+                Ok(response) => response.model().await,
+                Err(source) => Err(source),
+            };
+
+            if let Ok(info) = info {
                 let last_check = Instant::now();
                 let next_reset = Duration::from_millis(info.session_start_limit.remaining);
                 tracing::info!("next session start limit reset in: {:.2?}", next_reset);
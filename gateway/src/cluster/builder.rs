@@ -2,7 +2,7 @@ use super::{
     config::Config as ClusterConfig,
     r#impl::{Cluster, ClusterStartError},
 };
-use crate::shard::{LargeThresholdError, ResumeSession, ShardBuilder};
+use crate::shard::{Compression, LargeThresholdError, ResumeSession, ShardBuilder};
 use std::{
     collections::HashMap,
     convert::TryFrom,
@@ -10,6 +10,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     ops::{Bound, RangeBounds},
     sync::Arc,
+    time::Duration,
 };
 use twilight_gateway_queue::{LocalQueue, Queue};
 use twilight_http::Client;
@@ -179,14 +180,10 @@ impl ClusterBuilder {
     /// HTTP error Retrieving the gateway information.
     pub async fn build(mut self) -> Result<Cluster, ClusterStartError> {
         if (self.1).0.gateway_url.is_none() {
-            let gateway_url = (self.1)
-                .0
-                .http_client
-                .gateway()
-                .authed()
-                .await
-                .ok()
-                .map(|s| s.url);
+            let gateway_url = match (self.1).0.http_client.gateway().authed().await {
+                Ok(response) => response.model().await.ok().map(|s| s.url),
+                Err(_) => None,
+            };
 
             self = self.gateway_url(gateway_url);
         }
@@ -196,6 +193,27 @@ impl ClusterBuilder {
         Cluster::new_with_config(self.0).await
     }
 
+    /// Set the compression of payloads sent by the gateway to shards.
+    ///
+    /// Refer to the shard's [`ShardBuilder::compression`] for more
+    /// information.
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.1 = self.1.compression(compression);
+
+        self
+    }
+
+    /// Set the duration without dispatch events after which a shard's
+    /// connection is considered a zombie.
+    ///
+    /// Refer to the shard's [`ShardBuilder::dispatch_timeout`] for more
+    /// information.
+    pub fn dispatch_timeout(mut self, dispatch_timeout: Duration) -> Self {
+        self.1 = self.1.dispatch_timeout(dispatch_timeout);
+
+        self
+    }
+
     /// Set the URL that will be used to connect to the gateway.
     pub fn gateway_url(mut self, gateway_url: Option<String>) -> Self {
         self.1 = self.1.gateway_url(gateway_url);
@@ -0,0 +1,483 @@
+use super::r#impl::{Cluster, ClusterCommandError};
+use crate::EventTypeFlags;
+use dashmap::DashMap;
+use futures_channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use futures_timer::Delay;
+use futures_util::{
+    future::FutureExt,
+    stream::{Stream, StreamExt},
+};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Weak,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+use twilight_model::{
+    gateway::{
+        event::Event,
+        payload::{MemberChunk, RequestGuildMembers},
+    },
+    guild::Member,
+    id::GuildId,
+};
+
+#[derive(Debug)]
+struct MemberChunkerRef {
+    cluster: Cluster,
+    large_guilds: bool,
+    nonce: AtomicU64,
+    /// Random prefix of the nonces of this chunker, so that they don't
+    /// collide with those of other chunkers or requests on the same shards.
+    nonce_prefix: String,
+    presences: bool,
+    requests: DashMap<String, UnboundedSender<MemberChunk>>,
+    timeout: Duration,
+}
+
+impl MemberChunkerRef {
+    /// Send a member chunk to the stream of the request with its nonce.
+    ///
+    /// The stream ends after the last chunk of the request.
+    fn route(&self, chunk: MemberChunk) {
+        let nonce = match chunk.nonce.as_ref() {
+            Some(nonce) => nonce.clone(),
+            None => return,
+        };
+
+        if chunk.chunk_index + 1 >= chunk.chunk_count {
+            if let Some((_, tx)) = self.requests.remove(&nonce) {
+                let _ = tx.unbounded_send(chunk);
+            }
+        } else if let Some(tx) = self.requests.get(&nonce) {
+            let _ = tx.unbounded_send(chunk);
+        }
+    }
+}
+
+/// Builder to configure and construct a [`MemberChunker`].
+#[derive(Debug)]
+pub struct MemberChunkerBuilder {
+    cluster: Cluster,
+    large_guilds: bool,
+    presences: bool,
+    timeout: Duration,
+}
+
+impl MemberChunkerBuilder {
+    /// Create a new builder to configure and construct a member chunker for a
+    /// cluster.
+    pub fn new(cluster: Cluster) -> Self {
+        Self {
+            cluster,
+            large_guilds: false,
+            presences: false,
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Consume the builder, constructing a member chunker.
+    ///
+    /// The chunker processes the events of the cluster in a task until the
+    /// cluster is brought [down] or the chunker is dropped, so this must be
+    /// called within a Tokio runtime.
+    ///
+    /// [down]: Cluster::down
+    pub fn build(self) -> MemberChunker {
+        let chunker = Arc::new(MemberChunkerRef {
+            cluster: self.cluster,
+            large_guilds: self.large_guilds,
+            nonce: AtomicU64::new(0),
+            nonce_prefix: nonce_prefix(),
+            presences: self.presences,
+            requests: DashMap::new(),
+            timeout: self.timeout,
+        });
+
+        let events = chunker
+            .cluster
+            .some_events(EventTypeFlags::GUILD_CREATE | EventTypeFlags::MEMBER_CHUNK);
+        tokio::spawn(MemberChunker::run(Arc::downgrade(&chunker), events));
+
+        MemberChunker(chunker)
+    }
+
+    /// Set whether to request all of the members of every large guild when
+    /// it's received.
+    ///
+    /// The chunks of these requests aren't returned by the chunker, but are
+    /// received through the event streams of the cluster, such as for
+    /// updating a cache.
+    ///
+    /// Defaults to false.
+    pub fn large_guilds(mut self, large_guilds: bool) -> Self {
+        self.large_guilds = large_guilds;
+
+        self
+    }
+
+    /// Set whether to request the presences of members of large guilds.
+    ///
+    /// This requires the `GUILD_PRESENCES` intent.
+    ///
+    /// Defaults to false.
+    pub fn presences(mut self, presences: bool) -> Self {
+        self.presences = presences;
+
+        self
+    }
+
+    /// Set how long to wait for each chunk of a request before ending its
+    /// stream.
+    ///
+    /// Chunks of a request may never be received, such as when the shard
+    /// reconnects after sending it.
+    ///
+    /// Defaults to 30 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+
+        self
+    }
+}
+
+/// Requests members of guilds through the shards of a cluster and collects
+/// the member chunks received in response.
+///
+/// Each request is sent through the shard that the guild is on, which waits
+/// for the shard's command ratelimit. Requests are given a nonce to identify
+/// the member chunks received in response, replacing any nonce that was set.
+///
+/// Requesting members requires the `GUILD_MEMBERS` intent.
+///
+/// # Cloning
+///
+/// The chunker internally wraps its data within an Arc. This means that the
+/// chunker can be cloned and passed around tasks and threads cheaply.
+///
+/// # Examples
+///
+/// Request the members of a guild whose names start with "tw":
+///
+/// ```no_run
+/// use twilight_gateway::{cluster::MemberChunker, Cluster, Intents};
+/// use twilight_model::{gateway::payload::RequestGuildMembers, id::GuildId};
+/// use std::env;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let cluster = Cluster::new(env::var("DISCORD_TOKEN")?, Intents::GUILD_MEMBERS).await?;
+/// cluster.up().await;
+///
+/// let chunker = MemberChunker::builder(cluster).large_guilds(true).build();
+///
+/// let request = RequestGuildMembers::builder(GuildId(1)).query("tw", None);
+/// let members = chunker.request(request).await?.members().await;
+///
+/// println!("found {} members", members.len());
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MemberChunker(Arc<MemberChunkerRef>);
+
+impl MemberChunker {
+    /// Create a new member chunker for a cluster with the default
+    /// configuration.
+    ///
+    /// Use [`builder`] to configure and construct a member chunker.
+    ///
+    /// [`builder`]: Self::builder
+    pub fn new(cluster: Cluster) -> Self {
+        Self::builder(cluster).build()
+    }
+
+    /// Create a builder to configure and construct a member chunker.
+    pub fn builder(cluster: Cluster) -> MemberChunkerBuilder {
+        MemberChunkerBuilder::new(cluster)
+    }
+
+    /// Return an immutable reference to the cluster of this chunker.
+    pub fn cluster(&self) -> &Cluster {
+        &self.0.cluster
+    }
+
+    /// Send a request for guild members, returning a stream of the member
+    /// chunks received in response.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClusterCommandError::Sending`] if the shard of the guild
+    /// exists, but sending the request failed.
+    ///
+    /// Returns [`ClusterCommandError::ShardNonexistent`] if the shard of the
+    /// guild isn't in the cluster.
+    pub async fn request(
+        &self,
+        mut request: RequestGuildMembers,
+    ) -> Result<MemberChunks, ClusterCommandError> {
+        let mut chunks = self.register();
+        request.d.nonce.replace(chunks.nonce.clone());
+
+        let shard_id = shard_id(request.d.guild_id, self.0.cluster.shard_total());
+        self.0.cluster.command(shard_id, &request).await?;
+
+        // Waiting for the ratelimit doesn't count towards the timeout.
+        chunks.delay.reset(self.0.timeout);
+
+        Ok(chunks)
+    }
+
+    /// Create the stream of a new request with a unique nonce.
+    fn register(&self) -> MemberChunks {
+        let nonce = format!(
+            "{}-{:x}",
+            self.0.nonce_prefix,
+            self.0.nonce.fetch_add(1, Ordering::Relaxed),
+        );
+
+        let (tx, rx) = mpsc::unbounded();
+        self.0.requests.insert(nonce.clone(), tx);
+
+        MemberChunks {
+            chunker: Arc::clone(&self.0),
+            delay: Delay::new(self.0.timeout),
+            nonce,
+            rx,
+        }
+    }
+
+    async fn run(weak: Weak<MemberChunkerRef>, events: impl Stream<Item = (u64, Event)>) {
+        futures_util::pin_mut!(events);
+
+        while let Some((shard_id, event)) = events.next().await {
+            let chunker = match weak.upgrade() {
+                Some(chunker) => chunker,
+                None => break,
+            };
+
+            match event {
+                Event::GuildCreate(guild) if chunker.large_guilds && guild.large => {
+                    let cluster = chunker.cluster.clone();
+                    let request = RequestGuildMembers::builder(guild.id)
+                        .presences(chunker.presences)
+                        .query("", None);
+
+                    tokio::spawn(async move {
+                        if let Err(source) = cluster.command(shard_id, &request).await {
+                            tracing::warn!(
+                                guild_id = request.d.guild_id.0,
+                                "requesting members of large guild failed: {}",
+                                source,
+                            );
+                        }
+                    });
+                }
+                Event::MemberChunk(chunk) => chunker.route(chunk),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Stream of the member chunks received in response to a request.
+///
+/// The stream ends after the last chunk, or if no chunk was received within
+/// the [timeout] of the chunker.
+///
+/// [timeout]: MemberChunkerBuilder::timeout
+#[derive(Debug)]
+pub struct MemberChunks {
+    chunker: Arc<MemberChunkerRef>,
+    delay: Delay,
+    nonce: String,
+    rx: UnboundedReceiver<MemberChunk>,
+}
+
+impl MemberChunks {
+    /// Return the nonce of the request.
+    pub fn nonce(&self) -> &str {
+        &self.nonce
+    }
+
+    /// Collect the members of all of the chunks.
+    pub async fn members(mut self) -> Vec<Member> {
+        let mut members = Vec::new();
+
+        while let Some(chunk) = self.next().await {
+            members.extend(chunk.members);
+        }
+
+        members
+    }
+}
+
+impl Drop for MemberChunks {
+    fn drop(&mut self) {
+        self.chunker.requests.remove(&self.nonce);
+    }
+}
+
+impl Stream for MemberChunks {
+    type Item = MemberChunk;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.rx.poll_next_unpin(cx) {
+            Poll::Ready(Some(chunk)) => {
+                let timeout = self.chunker.timeout;
+                self.delay.reset(timeout);
+
+                return Poll::Ready(Some(chunk));
+            }
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Pending => {}
+        }
+
+        if self.delay.poll_unpin(cx).is_pending() {
+            return Poll::Pending;
+        }
+
+        tracing::debug!(nonce = %self.nonce, "member chunks timed out");
+        self.chunker.requests.remove(&self.nonce);
+        self.rx.close();
+
+        Poll::Ready(None)
+    }
+}
+
+/// Generate a random prefix for the nonces of a chunker.
+///
+/// Nonces may be at most 32 characters long, which leaves room for the
+/// hexadecimal counter of the chunker after the prefix.
+fn nonce_prefix() -> String {
+    let random = RandomState::new().build_hasher().finish();
+
+    format!("{:08x}", random & 0xffff_ffff)
+}
+
+/// Calculate the ID of the shard that a guild is on.
+fn shard_id(guild_id: GuildId, total: u64) -> u64 {
+    (guild_id.0 >> 22) % total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{shard_id, MemberChunker, MemberChunkerBuilder, MemberChunks};
+    use crate::{
+        cluster::{Cluster, ShardScheme},
+        Intents,
+    };
+    use futures_util::{future::FutureExt, stream::StreamExt};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+    use twilight_model::{gateway::payload::MemberChunk, id::GuildId};
+
+    assert_impl_all!(MemberChunker: Clone, Debug, Send, Sync);
+    assert_impl_all!(MemberChunkerBuilder: Debug, Send, Sync);
+    assert_impl_all!(MemberChunks: Debug, Send, Sync, Unpin);
+
+    async fn cluster() -> Cluster {
+        Cluster::builder("token", Intents::GUILD_MEMBERS)
+            .gateway_url(Some("wss://gateway.discord.gg".to_owned()))
+            .shard_scheme(ShardScheme::Range {
+                from: 0,
+                to: 0,
+                total: 1,
+            })
+            .build()
+            .await
+            .unwrap()
+    }
+
+    fn chunk(nonce: &str, chunk_index: u32, chunk_count: u32) -> MemberChunk {
+        MemberChunk {
+            chunk_count,
+            chunk_index,
+            guild_id: GuildId(1),
+            members: Vec::new(),
+            nonce: Some(nonce.to_owned()),
+            not_found: Vec::new(),
+            presences: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_nonces_unique() {
+        let cluster = cluster().await;
+        let first = MemberChunker::new(cluster.clone());
+        let second = MemberChunker::new(cluster);
+
+        let a = first.register();
+        let b = first.register();
+        let c = second.register();
+
+        assert_ne!(a.nonce(), b.nonce());
+        assert!(a.nonce().starts_with(&first.0.nonce_prefix));
+        assert_ne!(first.0.nonce_prefix, second.0.nonce_prefix);
+        assert!(!c.nonce().starts_with(&first.0.nonce_prefix));
+        assert!(c.nonce().len() <= 32);
+    }
+
+    #[tokio::test]
+    async fn test_route_chunks() {
+        let chunker = MemberChunker::new(cluster().await);
+        let mut first = chunker.register();
+        let mut second = chunker.register();
+
+        chunker.0.route(chunk(second.nonce(), 0, 2));
+        assert!(first.next().now_or_never().is_none());
+        assert_eq!(
+            Some(0),
+            second
+                .next()
+                .now_or_never()
+                .flatten()
+                .map(|chunk| chunk.chunk_index)
+        );
+
+        // Chunks without a nonce or of other requests are ignored.
+        let mut unknown = chunk("unknown", 0, 1);
+        chunker.0.route(unknown.clone());
+        unknown.nonce = None;
+        chunker.0.route(unknown);
+        assert!(first.next().now_or_never().is_none());
+        assert!(second.next().now_or_never().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_end_on_last_chunk() {
+        let chunker = MemberChunker::new(cluster().await);
+        let mut chunks = chunker.register();
+
+        chunker.0.route(chunk(chunks.nonce(), 0, 2));
+        chunker.0.route(chunk(chunks.nonce(), 1, 2));
+        assert!(!chunker.0.requests.contains_key(chunks.nonce()));
+
+        assert_eq!(Some(0), chunks.next().await.map(|chunk| chunk.chunk_index));
+        assert_eq!(Some(1), chunks.next().await.map(|chunk| chunk.chunk_index));
+        assert!(chunks.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let chunker = MemberChunker::builder(cluster().await)
+            .timeout(Duration::from_millis(10))
+            .build();
+        let mut chunks = chunker.register();
+
+        chunker.0.route(chunk(chunks.nonce(), 0, 2));
+        assert!(chunks.next().await.is_some());
+        assert!(chunks.next().await.is_none());
+        assert!(!chunker.0.requests.contains_key(chunks.nonce()));
+    }
+
+    #[test]
+    fn test_shard_id() {
+        assert_eq!(0, shard_id(GuildId(81_384_788_765_712_384), 1));
+        assert_eq!(2, shard_id(GuildId(81_384_788_765_712_384), 4));
+    }
+}
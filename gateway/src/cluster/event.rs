@@ -0,0 +1,98 @@
+use crate::shard::Events;
+use futures_channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use futures_util::stream::{SelectAll, Stream, StreamExt};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use twilight_model::gateway::event::Event;
+
+/// Stream of events from a single shard, tagged with the shard's ID.
+pub struct ShardEvents {
+    events: Events,
+    id: u64,
+}
+
+impl ShardEvents {
+    pub fn new(id: u64, events: Events) -> Self {
+        Self { events, id }
+    }
+}
+
+impl Stream for ShardEvents {
+    type Item = (u64, Event);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let id = self.id;
+
+        self.events
+            .poll_next_unpin(cx)
+            .map(|maybe_event| maybe_event.map(|event| (id, event)))
+    }
+}
+
+/// Stream of events from all shards of a cluster.
+///
+/// Shards may be added to the cluster after the stream was created, such as
+/// when resharding. Their event streams are sent over a channel and merged into
+/// the stream, so the stream stays continuous while the cluster's shards
+/// change.
+///
+/// The stream ends once the cluster is brought down and all shard streams have
+/// ended.
+pub struct ClusterEvents {
+    new_streams: Option<UnboundedReceiver<ShardEvents>>,
+    streams: SelectAll<ShardEvents>,
+}
+
+impl ClusterEvents {
+    /// Create a new stream from the event streams of the current shards,
+    /// returning the sender to add future shards' event streams with.
+    pub fn new(streams: Vec<ShardEvents>) -> (Self, UnboundedSender<ShardEvents>) {
+        let (tx, rx) = mpsc::unbounded();
+        let mut select_all = SelectAll::new();
+
+        for stream in streams {
+            select_all.push(stream);
+        }
+
+        (
+            Self {
+                new_streams: Some(rx),
+                streams: select_all,
+            },
+            tx,
+        )
+    }
+}
+
+impl Stream for ClusterEvents {
+    type Item = (u64, Event);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        while let Some(new_streams) = self.new_streams.as_mut() {
+            match new_streams.poll_next_unpin(cx) {
+                Poll::Ready(Some(stream)) => self.streams.push(stream),
+                Poll::Ready(None) => self.new_streams = None,
+                Poll::Pending => break,
+            }
+        }
+
+        match self.streams.poll_next_unpin(cx) {
+            // An empty `SelectAll` is considered terminated, but more shard
+            // streams may be added while the cluster is up.
+            Poll::Ready(None) if self.new_streams.is_some() => Poll::Pending,
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClusterEvents, ShardEvents};
+    use futures_util::stream::Stream;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(ClusterEvents: Send, Stream, Sync);
+    assert_impl_all!(ShardEvents: Send, Stream, Sync);
+}
@@ -0,0 +1,160 @@
+use crate::shard::{Information, Stage};
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+/// Health of a shard, obtained through [`ClusterHealth`].
+#[derive(Clone, Debug)]
+pub struct ShardHealth {
+    id: u64,
+    last_event: Option<Instant>,
+    latency: Option<Duration>,
+    reconnects: u64,
+    stage: Stage,
+}
+
+impl ShardHealth {
+    /// Create the health of a shard from its information, if its session is
+    /// active.
+    pub(super) fn new(id: u64, info: Option<Information>) -> Self {
+        match info {
+            Some(info) => Self {
+                id,
+                last_event: info.last_event(),
+                latency: info.latency().average(),
+                reconnects: info.reconnects(),
+                stage: info.stage(),
+            },
+            None => Self {
+                id,
+                last_event: None,
+                latency: None,
+                reconnects: 0,
+                stage: Stage::Disconnected,
+            },
+        }
+    }
+
+    /// Return the ID of the shard.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// When the shard last received a payload from the gateway.
+    ///
+    /// Refer to [`Information::last_event`] for more information.
+    pub fn last_event(&self) -> Option<Instant> {
+        self.last_event
+    }
+
+    /// Average heartbeat latency of the shard.
+    ///
+    /// Refer to [`Latency::average`] for more information.
+    ///
+    /// [`Latency::average`]: crate::shard::Latency::average
+    pub fn latency(&self) -> Option<Duration> {
+        self.latency
+    }
+
+    /// Number of times the shard has reconnected or resumed since it was
+    /// started.
+    pub fn reconnects(&self) -> u64 {
+        self.reconnects
+    }
+
+    /// Current stage of the shard.
+    ///
+    /// Shards without an active session are [`Disconnected`].
+    ///
+    /// [`Disconnected`]: Stage::Disconnected
+    pub fn stage(&self) -> Stage {
+        self.stage
+    }
+
+    /// Whether the shard is connected and has received a payload within the
+    /// provided duration.
+    ///
+    /// Shards receive a heartbeat acknowledgement at least every heartbeat
+    /// interval, so a shard that has been silent for longer than a couple of
+    /// intervals is likely a zombie.
+    pub fn is_healthy(&self, timeout: Duration) -> bool {
+        self.stage == Stage::Connected
+            && self
+                .last_event
+                .map_or(false, |last_event| last_event.elapsed() <= timeout)
+    }
+}
+
+/// Health report of the shards of a cluster.
+///
+/// This is obtained through [`Cluster::health`].
+///
+/// [`Cluster::health`]: super::Cluster::health
+#[derive(Clone, Debug)]
+pub struct ClusterHealth {
+    shards: HashMap<u64, ShardHealth>,
+}
+
+impl ClusterHealth {
+    pub(super) fn new(shards: HashMap<u64, ShardHealth>) -> Self {
+        Self { shards }
+    }
+
+    /// Return the health of a shard by its ID.
+    pub fn shard(&self, id: u64) -> Option<&ShardHealth> {
+        self.shards.get(&id)
+    }
+
+    /// Return an immutable reference to the health of all shards, keyed by
+    /// their ID.
+    pub fn shards(&self) -> &HashMap<u64, ShardHealth> {
+        &self.shards
+    }
+
+    /// Return the number of shards that are [`Connected`].
+    ///
+    /// [`Connected`]: Stage::Connected
+    pub fn connected(&self) -> usize {
+        self.shards
+            .values()
+            .filter(|shard| shard.stage() == Stage::Connected)
+            .count()
+    }
+
+    /// Return an iterator over the shards that aren't [healthy] within the
+    /// provided duration.
+    ///
+    /// [healthy]: ShardHealth::is_healthy
+    pub fn unhealthy(&self, timeout: Duration) -> impl Iterator<Item = &ShardHealth> {
+        self.shards
+            .values()
+            .filter(move |shard| !shard.is_healthy(timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClusterHealth, ShardHealth};
+    use crate::shard::Stage;
+    use static_assertions::assert_impl_all;
+    use std::{collections::HashMap, fmt::Debug, time::Duration};
+
+    assert_impl_all!(ClusterHealth: Clone, Debug, Send, Sync);
+    assert_impl_all!(ShardHealth: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_inactive_shard() {
+        let shard = ShardHealth::new(3, None);
+        assert_eq!(3, shard.id());
+        assert_eq!(Stage::Disconnected, shard.stage());
+        assert!(!shard.is_healthy(Duration::from_secs(60)));
+
+        let mut shards = HashMap::new();
+        shards.insert(3, shard);
+        let health = ClusterHealth::new(shards);
+
+        assert_eq!(0, health.connected());
+        assert_eq!(1, health.unhealthy(Duration::from_secs(60)).count());
+    }
+}
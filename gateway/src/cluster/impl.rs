@@ -1,21 +1,30 @@
 use super::{
     builder::{ClusterBuilder, ShardScheme},
     config::Config,
+    event::{ClusterEvents, ShardEvents},
+    health::{ClusterHealth, ShardHealth},
 };
 use crate::{
-    shard::{raw_message::Message, CommandError, Information, ResumeSession, SendError, Shard},
+    shard::{
+        raw_message::Message, CommandError, Config as ShardConfig, Information, ResumeSession,
+        SendError, Shard, ShardStartError,
+    },
     EventTypeFlags, Intents,
 };
+use futures_channel::mpsc::UnboundedSender;
 use futures_util::{
     future,
-    stream::{SelectAll, Stream, StreamExt},
+    stream::{Stream, StreamExt},
 };
 use std::{
     collections::HashMap,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
-    iter::FromIterator,
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
 };
 use twilight_http::Error as HttpError;
 use twilight_model::gateway::event::Event;
@@ -138,12 +147,181 @@ impl Error for ClusterStartError {
     }
 }
 
+/// Adding or restarting a shard of the cluster failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ClusterShardError {
+    /// A shard with the provided ID is already in the cluster.
+    ShardExists {
+        /// ID of the shard.
+        id: u64,
+    },
+    /// Provided shard ID does not exist in the cluster.
+    ShardNonexistent {
+        /// Provided shard ID.
+        id: u64,
+    },
+    /// Provided shard ID is not less than the total number of shards.
+    ShardOutOfRange {
+        /// Provided shard ID.
+        id: u64,
+        /// Total number of shards used by the bot.
+        total: u64,
+    },
+    /// The shard failed to start.
+    ///
+    /// The shard has been removed from the cluster, unless it was replaced by
+    /// another shard with the same ID in the meantime. When restarting, the
+    /// previous shard has already been shutdown, so the cluster is left
+    /// without a shard for the ID; use [`Cluster::add_shard`] to add it again.
+    ShardStarting {
+        /// ID of the shard.
+        id: u64,
+        /// Reason for the error.
+        source: ShardStartError,
+    },
+}
+
+impl Display for ClusterShardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::ShardExists { id } => {
+                f.write_fmt(format_args!("shard {} is already in the cluster", id))
+            }
+            Self::ShardNonexistent { id } => {
+                f.write_fmt(format_args!("shard {} does not exist", id))
+            }
+            Self::ShardOutOfRange { id, total } => f.write_fmt(format_args!(
+                "shard {} is out of range of the {} total shards",
+                id, total
+            )),
+            Self::ShardStarting { id, .. } => {
+                f.write_fmt(format_args!("shard {} failed to start", id))
+            }
+        }
+    }
+}
+
+impl Error for ClusterShardError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::ShardExists { .. }
+            | Self::ShardNonexistent { .. }
+            | Self::ShardOutOfRange { .. } => None,
+            Self::ShardStarting { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Resharding a cluster failed.
+///
+/// The cluster continues running with its previous set of shards.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ClusterReshardError {
+    /// The cluster is already being resharded.
+    Resharding,
+    /// Retrieving the bot's recommended number of shards via the HTTP API
+    /// failed.
+    RetrievingGatewayInfo {
+        /// Reason for the error.
+        source: HttpError,
+    },
+    /// The cluster's [`ShardScheme`] only manages a range of the bot's
+    /// shards, so it can't be resharded into all of them.
+    ShardSchemeRange {
+        /// First shard ID managed by the cluster.
+        from: u64,
+        /// Last shard ID managed by the cluster.
+        to: u64,
+        /// Total number of shards used by the bot.
+        total: u64,
+    },
+    /// One of the new shards failed to start.
+    ShardStarting {
+        /// ID of the shard.
+        id: u64,
+        /// Reason for the error.
+        source: ShardStartError,
+    },
+    /// One of the new shards was shutdown before it became ready.
+    ShardShutdown {
+        /// ID of the shard.
+        id: u64,
+    },
+    /// One of the new shards didn't become ready in time after starting.
+    ShardTimedOut {
+        /// ID of the shard.
+        id: u64,
+    },
+}
+
+impl Display for ClusterReshardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Resharding => f.write_str("the cluster is already being resharded"),
+            Self::RetrievingGatewayInfo { .. } => {
+                f.write_str("getting the bot's gateway info failed")
+            }
+            Self::ShardSchemeRange { from, to, total } => f.write_fmt(format_args!(
+                "the cluster only manages shards {}-{} of {}",
+                from, to, total
+            )),
+            Self::ShardStarting { id, .. } => {
+                f.write_fmt(format_args!("new shard {} failed to start", id))
+            }
+            Self::ShardShutdown { id } => f.write_fmt(format_args!(
+                "new shard {} was shutdown before becoming ready",
+                id
+            )),
+            Self::ShardTimedOut { id } => {
+                f.write_fmt(format_args!("new shard {} didn't become ready in time", id))
+            }
+        }
+    }
+}
+
+impl Error for ClusterReshardError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::RetrievingGatewayInfo { source } => Some(source),
+            Self::ShardStarting { source, .. } => Some(source),
+            Self::Resharding
+            | Self::ShardSchemeRange { .. }
+            | Self::ShardShutdown { .. }
+            | Self::ShardTimedOut { .. } => None,
+        }
+    }
+}
+
 #[derive(Debug)]
 struct ClusterRef {
     config: Config,
-    shard_from: u64,
-    shard_to: u64,
+    /// Whether the cluster is currently being resharded.
+    resharding: AtomicBool,
+    shard_total: AtomicU64,
     shards: Mutex<HashMap<u64, Shard>>,
+    /// Event streams created via [`Cluster::some_events`], along with the
+    /// event types they're filtered by, which receive the event streams of
+    /// shards added to the cluster.
+    ///
+    /// Always lock `shards` before this to avoid deadlocks.
+    subscriptions: Mutex<Vec<(EventTypeFlags, UnboundedSender<ShardEvents>)>>,
+}
+
+impl ClusterRef {
+    /// Add the event stream of a shard to all event streams of the cluster.
+    ///
+    /// Event streams which have been dropped are removed.
+    fn subscribe(&self, id: u64, shard: &Shard) {
+        self.subscriptions
+            .lock()
+            .expect("subscriptions poisoned")
+            .retain(|(types, tx)| {
+                tx.unbounded_send(ShardEvents::new(id, shard.some_events(*types)))
+                    .is_ok()
+            });
+    }
 }
 
 /// A manager for multiple shards.
@@ -163,6 +341,10 @@ struct ClusterRef {
 pub struct Cluster(Arc<ClusterRef>);
 
 impl Cluster {
+    /// How long a new shard may take to become ready after starting when
+    /// resharding.
+    const RESHARD_READY_TIMEOUT: Duration = Duration::from_secs(60);
+
     /// Create a new unconfigured cluster.
     ///
     /// Use [`builder`] to configure and construct a cluster.
@@ -189,6 +371,9 @@ impl Cluster {
                     .gateway()
                     .authed()
                     .await
+                    .map_err(|source| ClusterStartError::RetrievingGatewayInfo { source })?
+                    .model()
+                    .await
                     .map_err(|source| ClusterStartError::RetrievingGatewayInfo { source })?;
 
                 [0, gateway.shards - 1, gateway.shards]
@@ -196,35 +381,48 @@ impl Cluster {
             ShardScheme::Range { from, to, total } => [*from, *to, *total],
         };
 
-        #[cfg(feature = "metrics")]
-        {
-            use std::convert::TryInto;
-
-            metrics::gauge!("Cluster-Shard-Count", total.try_into().unwrap_or(-1));
-        }
+        Self::shard_count_metrics(total);
 
         let shards = (from..=to)
             .map(|idx| {
-                let mut shard_config = config.shard_config().clone();
-                shard_config.shard = [idx, total];
+                let mut shard = Self::new_shard(&config, idx, total);
 
                 if let Some(data) = config.resume_sessions.remove(&idx) {
-                    shard_config.session_id = Some(data.session_id.into_boxed_str());
-                    shard_config.sequence = Some(data.sequence);
+                    shard.session_id = Some(data.session_id.into_boxed_str());
+                    shard.sequence = Some(data.sequence);
                 }
 
-                (idx, Shard::new_with_config(shard_config))
+                (idx, Shard::new_with_config(shard))
             })
             .collect();
 
         Ok(Self(Arc::new(ClusterRef {
             config,
-            shard_from: from,
-            shard_to: to,
+            resharding: AtomicBool::new(false),
+            shard_total: AtomicU64::new(total),
             shards: Mutex::new(shards),
+            subscriptions: Mutex::new(Vec::new()),
         })))
     }
 
+    /// Create the configuration of a shard with a given ID and total.
+    fn new_shard(config: &Config, id: u64, total: u64) -> ShardConfig {
+        let mut shard_config = config.shard_config().clone();
+        shard_config.shard = [id, total];
+
+        shard_config
+    }
+
+    #[allow(unused_variables)]
+    fn shard_count_metrics(total: u64) {
+        #[cfg(feature = "metrics")]
+        {
+            use std::convert::TryInto;
+
+            metrics::gauge!("Cluster-Shard-Count", total.try_into().unwrap_or(-1));
+        }
+    }
+
     /// Create a builder to configure and construct a cluster.
     pub fn builder(token: impl Into<String>, intents: Intents) -> ClusterBuilder {
         ClusterBuilder::new(token, intents)
@@ -263,17 +461,35 @@ impl Cluster {
     /// # Ok(()) }
     /// ```
     pub async fn up(&self) {
+        let ids = self
+            .0
+            .shards
+            .lock()
+            .expect("shards poisoned")
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+
         future::join_all(
-            (self.0.shard_from..=self.0.shard_to).map(|id| Self::start(Arc::clone(&self.0), id)),
+            ids.into_iter()
+                .map(|id| Self::start(Arc::clone(&self.0), id)),
         )
         .await;
     }
 
     /// Bring down the cluster, stopping all of the shards that it's managing.
+    ///
+    /// Event streams of the cluster end once the shards have stopped.
     pub fn down(&self) {
         for shard in self.0.shards.lock().expect("shards poisoned").values() {
             shard.shutdown();
         }
+
+        self.0
+            .subscriptions
+            .lock()
+            .expect("subscriptions poisoned")
+            .clear();
     }
 
     /// Bring down the cluster in a resumable way and returns all info needed
@@ -286,14 +502,335 @@ impl Cluster {
     /// disconnection. You may also not be able to resume if you missed too many
     /// events already.
     pub fn down_resumable(&self) -> HashMap<u64, ResumeSession> {
-        self.0
+        let sessions = self
+            .0
             .shards
             .lock()
             .expect("shards poisoned")
             .values()
             .map(Shard::shutdown_resumable)
             .filter_map(|(id, session)| session.map(|s| (id, s)))
-            .collect()
+            .collect();
+
+        self.0
+            .subscriptions
+            .lock()
+            .expect("subscriptions poisoned")
+            .clear();
+
+        sessions
+    }
+
+    /// Reshard the cluster to Discord's recommended number of shards without
+    /// downtime.
+    ///
+    /// The recommended number of shards is retrieved via the HTTP API, after
+    /// which a new set of shards is started alongside the current shards
+    /// through the cluster's [queue]. Once all of the new shards are ready,
+    /// the event streams of the cluster switch to the new shards and the
+    /// previous shards are shutdown.
+    ///
+    /// Event streams created via [`events`] or [`some_events`] are continuous
+    /// throughout: the previous shards emit events until the switch, after
+    /// which the new shards do. Events happening around the switch may be
+    /// emitted by both sets of shards.
+    ///
+    /// Only clusters managing all of the bot's shards can be resharded, which
+    /// is the case when the configured [`ShardScheme`] is [`Auto`] or a range
+    /// over all shards. The cluster may only be resharded once at a time.
+    ///
+    /// # Examples
+    ///
+    /// Reshard the cluster once it reports that it's too small:
+    ///
+    /// ```no_run
+    /// use twilight_gateway::{Cluster, Intents};
+    /// use std::env;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let cluster = Cluster::new(env::var("DISCORD_TOKEN")?, Intents::GUILDS).await?;
+    /// cluster.up().await;
+    ///
+    /// // some time later..
+    /// cluster.reshard().await?;
+    /// println!("cluster now has {} shards", cluster.shard_total());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClusterReshardError::Resharding`] if the cluster is already
+    /// being resharded.
+    ///
+    /// Returns [`ClusterReshardError::ShardSchemeRange`] if the configured
+    /// [`ShardScheme`] only manages a range of the bot's shards.
+    ///
+    /// Returns [`ClusterReshardError::RetrievingGatewayInfo`] if the
+    /// recommended number of shards couldn't be retrieved.
+    ///
+    /// Returns [`ClusterReshardError::ShardStarting`] if one of the new shards
+    /// failed to start.
+    ///
+    /// Returns [`ClusterReshardError::ShardShutdown`] if one of the new shards
+    /// was shutdown before it became ready.
+    ///
+    /// Returns [`ClusterReshardError::ShardTimedOut`] if one of the new shards
+    /// didn't become ready within a minute of starting.
+    ///
+    /// In all cases the new shards are shutdown and the cluster continues
+    /// with its current shards.
+    ///
+    /// [`Auto`]: ShardScheme::Auto
+    /// [`events`]: Self::events
+    /// [`some_events`]: Self::some_events
+    /// [queue]: crate::queue
+    pub async fn reshard(&self) -> Result<(), ClusterReshardError> {
+        if let ShardScheme::Range { from, to, total } = *self.0.config.shard_scheme() {
+            if from != 0 || to.saturating_add(1) < total {
+                return Err(ClusterReshardError::ShardSchemeRange { from, to, total });
+            }
+        }
+
+        if self
+            .0
+            .resharding
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Err(ClusterReshardError::Resharding);
+        }
+
+        // Reset the flag even if the future is dropped before completing.
+        let _resharding = ReshardingGuard(&self.0.resharding);
+
+        let total = self
+            .0
+            .config
+            .http_client()
+            .gateway()
+            .authed()
+            .await
+            .map_err(|source| ClusterReshardError::RetrievingGatewayInfo { source })?
+            .model()
+            .await
+            .map_err(|source| ClusterReshardError::RetrievingGatewayInfo { source })?
+            .shards;
+
+        tracing::info!(from = self.shard_total(), to = total, "resharding cluster");
+
+        let shards = (0..total)
+            .map(|id| {
+                let config = Self::new_shard(&self.0.config, id, total);
+
+                (id, Shard::new_with_config(config))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let started = future::join_all(shards.iter().map(|(id, shard)| {
+            let id = *id;
+            let mut shard = shard.clone();
+
+            // Subscribe before starting so that the ready event can't be
+            // missed.
+            let mut ready = shard.some_events(EventTypeFlags::READY);
+
+            async move {
+                shard
+                    .start()
+                    .await
+                    .map_err(|source| ClusterReshardError::ShardStarting { id, source })?;
+
+                tokio::time::timeout(Self::RESHARD_READY_TIMEOUT, ready.next())
+                    .await
+                    .map_err(|_| ClusterReshardError::ShardTimedOut { id })?
+                    .map(|_| ())
+                    .ok_or(ClusterReshardError::ShardShutdown { id })
+            }
+        }))
+        .await;
+
+        if let Some(Err(error)) = started.into_iter().find(Result::is_err) {
+            for shard in shards.values() {
+                shard.shutdown();
+            }
+
+            return Err(error);
+        }
+
+        self.swap_shards(total, shards);
+
+        Ok(())
+    }
+
+    /// Replace the shards of the cluster with a new set of shards, switching
+    /// the event streams of the cluster over to them and shutting down the
+    /// previous shards.
+    fn swap_shards(&self, total: u64, shards: HashMap<u64, Shard>) {
+        let old = {
+            let mut current = self.0.shards.lock().expect("shards poisoned");
+
+            for (id, shard) in &shards {
+                self.0.subscribe(*id, shard);
+            }
+
+            self.0.shard_total.store(total, Ordering::Release);
+
+            std::mem::replace(&mut *current, shards)
+        };
+
+        for shard in old.values() {
+            shard.shutdown();
+        }
+
+        Self::shard_count_metrics(total);
+    }
+
+    /// Return the total number of shards used by the bot.
+    ///
+    /// This is the total of the configured [`ShardScheme`] until the cluster
+    /// is [resharded].
+    ///
+    /// [resharded]: Self::reshard
+    pub fn shard_total(&self) -> u64 {
+        self.0.shard_total.load(Ordering::Acquire)
+    }
+
+    /// Restart a shard of the cluster without affecting the other shards.
+    ///
+    /// The shard is shutdown and replaced by a new shard with the same ID,
+    /// which resumes the previous session if possible. Event streams of the
+    /// cluster continue with the events of the new shard.
+    ///
+    /// This is useful for recovering a shard that is connected but no longer
+    /// receiving events, which can be detected via [`health`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClusterShardError::ShardNonexistent`] if the provided shard
+    /// ID does not exist in the cluster.
+    ///
+    /// Returns [`ClusterShardError::ShardStarting`] if the new shard failed to
+    /// start. The previous shard has been shutdown by then, so the shard is
+    /// removed from the cluster and may be added again via [`add_shard`].
+    ///
+    /// [`add_shard`]: Self::add_shard
+    /// [`health`]: Self::health
+    pub async fn restart_shard(&self, id: u64) -> Result<(), ClusterShardError> {
+        let shard = {
+            let mut shards = self.0.shards.lock().expect("shards poisoned");
+            let (_, session) = shards
+                .get(&id)
+                .ok_or(ClusterShardError::ShardNonexistent { id })?
+                .shutdown_resumable();
+
+            let mut config = Self::new_shard(&self.0.config, id, self.shard_total());
+
+            if let Some(data) = session {
+                config.session_id = Some(data.session_id.into_boxed_str());
+                config.sequence = Some(data.sequence);
+            }
+
+            let shard = Shard::new_with_config(config);
+            self.0.subscribe(id, &shard);
+            shards.insert(id, shard.clone());
+
+            shard
+        };
+
+        tracing::debug!(shard_id = id, "restarting shard");
+
+        self.start_shard(id, shard).await
+    }
+
+    /// Add a shard to the cluster and start it.
+    ///
+    /// Event streams of the cluster include the events of the new shard.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClusterShardError::ShardExists`] if a shard with the provided
+    /// ID is already in the cluster.
+    ///
+    /// Returns [`ClusterShardError::ShardOutOfRange`] if the provided shard ID
+    /// is not less than the [total number of shards].
+    ///
+    /// Returns [`ClusterShardError::ShardStarting`] if the shard failed to
+    /// start.
+    ///
+    /// [total number of shards]: Self::shard_total
+    pub async fn add_shard(&self, id: u64) -> Result<(), ClusterShardError> {
+        let shard = {
+            let mut shards = self.0.shards.lock().expect("shards poisoned");
+            let total = self.shard_total();
+
+            if id >= total {
+                return Err(ClusterShardError::ShardOutOfRange { id, total });
+            }
+
+            if shards.contains_key(&id) {
+                return Err(ClusterShardError::ShardExists { id });
+            }
+
+            let shard = Shard::new_with_config(Self::new_shard(&self.0.config, id, total));
+            self.0.subscribe(id, &shard);
+            shards.insert(id, shard.clone());
+
+            shard
+        };
+
+        self.start_shard(id, shard).await
+    }
+
+    /// Shutdown a shard and remove it from the cluster, returning it.
+    ///
+    /// Returns `None` if the provided shard ID does not exist in the cluster.
+    pub fn remove_shard(&self, id: u64) -> Option<Shard> {
+        let shard = self.0.shards.lock().expect("shards poisoned").remove(&id)?;
+        shard.shutdown();
+
+        Some(shard)
+    }
+
+    /// Return a health report of all shards.
+    ///
+    /// # Examples
+    ///
+    /// Restart shards that haven't received an event in over two minutes:
+    ///
+    /// ```no_run
+    /// use twilight_gateway::{Cluster, Intents};
+    /// use std::{env, time::Duration};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let cluster = Cluster::new(env::var("DISCORD_TOKEN")?, Intents::GUILDS).await?;
+    /// cluster.up().await;
+    ///
+    /// tokio::time::sleep(Duration::from_secs(120)).await;
+    ///
+    /// let unhealthy = cluster
+    ///     .health()
+    ///     .unhealthy(Duration::from_secs(120))
+    ///     .map(|shard| shard.id())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// for id in unhealthy {
+    ///     cluster.restart_shard(id).await?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn health(&self) -> ClusterHealth {
+        let shards = self
+            .0
+            .shards
+            .lock()
+            .expect("shards poisoned")
+            .iter()
+            .map(|(id, shard)| (*id, ShardHealth::new(*id, shard.info().ok())))
+            .collect();
+
+        ClusterHealth::new(shards)
     }
 
     /// Return a Shard by its ID.
@@ -494,22 +1031,51 @@ impl Cluster {
     ///
     /// [`events`]: Self::events
     pub fn some_events(&self, types: EventTypeFlags) -> impl Stream<Item = (u64, Event)> {
-        let shards = self.0.shards.lock().expect("shards poisoned").clone();
-        let stream = shards
-            .into_iter()
-            .map(|(id, shard)| shard.some_events(types).map(move |e| (id, e)));
+        let shards = self.0.shards.lock().expect("shards poisoned");
+        let streams = shards
+            .iter()
+            .map(|(id, shard)| ShardEvents::new(*id, shard.some_events(types)))
+            .collect();
 
-        // Clippy recommends using bad code here.
-        #[allow(clippy::from_iter_instead_of_collect)]
-        SelectAll::from_iter(stream)
+        let (events, tx) = ClusterEvents::new(streams);
+
+        let mut subscriptions = self.0.subscriptions.lock().expect("subscriptions poisoned");
+        // Event streams which have been dropped would otherwise only be
+        // removed once a shard is added.
+        subscriptions.retain(|(_, tx)| !tx.is_closed());
+        subscriptions.push((types, tx));
+
+        events
+    }
+
+    /// Start a shard that has been added to the cluster, removing it from the
+    /// cluster if it failed to start.
+    ///
+    /// The shard is only removed if it's still in the cluster, since a
+    /// concurrent call to [`add_shard`] or [`restart_shard`] may have replaced
+    /// it in the meantime.
+    ///
+    /// [`add_shard`]: Self::add_shard
+    /// [`restart_shard`]: Self::restart_shard
+    async fn start_shard(&self, id: u64, mut shard: Shard) -> Result<(), ClusterShardError> {
+        if let Err(source) = shard.start().await {
+            let mut shards = self.0.shards.lock().expect("shards poisoned");
+
+            if shards
+                .get(&id)
+                .map_or(false, |current| current.ptr_eq(&shard))
+            {
+                shards.remove(&id);
+            }
+
+            return Err(ClusterShardError::ShardStarting { id, source });
+        }
+
+        Ok(())
     }
 
     /// Queue a request to start a shard by ID and starts it once the queue
     /// accepts the request.
-    ///
-    /// Accepts weak references to the queue and map of shards, because by the
-    /// time the future is polled the cluster may have already dropped, bringing
-    /// down the queue and shards with it.
     async fn start(cluster: Arc<ClusterRef>, shard_id: u64) -> Option<Shard> {
         let mut shard = cluster
             .shards
@@ -524,19 +1090,212 @@ impl Cluster {
     }
 }
 
+/// Marks a cluster as no longer being resharded when dropped.
+struct ReshardingGuard<'a>(&'a AtomicBool);
+
+impl Drop for ReshardingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Cluster, ClusterCommandError, ClusterSendError, ClusterStartError};
+    use super::{
+        Cluster, ClusterCommandError, ClusterReshardError, ClusterSendError, ClusterShardError,
+        ClusterStartError,
+    };
+    use crate::{cluster::ShardScheme, shard::Shard, Intents};
+    use futures_util::{future::FutureExt, stream::StreamExt};
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::{error::Error, fmt::Debug};
+    use std::{
+        collections::HashMap, error::Error, fmt::Debug, net::TcpListener, sync::atomic::Ordering,
+    };
+    use twilight_model::gateway::event::Event;
 
     assert_fields!(ClusterCommandError::Sending: source);
     assert_fields!(ClusterCommandError::ShardNonexistent: id);
     assert_impl_all!(ClusterCommandError: Debug, Error, Send, Sync);
+    assert_fields!(ClusterReshardError::RetrievingGatewayInfo: source);
+    assert_fields!(ClusterReshardError::ShardSchemeRange: from, to, total);
+    assert_fields!(ClusterReshardError::ShardStarting: id, source);
+    assert_fields!(ClusterReshardError::ShardShutdown: id);
+    assert_fields!(ClusterReshardError::ShardTimedOut: id);
+    assert_impl_all!(ClusterReshardError: Debug, Error, Send, Sync);
     assert_fields!(ClusterSendError::Sending: source);
+    assert_fields!(ClusterShardError::ShardExists: id);
+    assert_fields!(ClusterShardError::ShardNonexistent: id);
+    assert_fields!(ClusterShardError::ShardOutOfRange: id, total);
+    assert_fields!(ClusterShardError::ShardStarting: id, source);
+    assert_impl_all!(ClusterShardError: Debug, Error, Send, Sync);
     assert_fields!(ClusterSendError::ShardNonexistent: id);
     assert_impl_all!(ClusterSendError: Debug, Error, Send, Sync);
     assert_fields!(ClusterStartError::RetrievingGatewayInfo: source);
     assert_impl_all!(ClusterStartError: Debug, Error, Send, Sync);
     assert_impl_all!(Cluster: Clone, Debug, Send, Sync);
+
+    /// Create a cluster managing shard 0 of 2 which connects to a local port
+    /// nothing listens on, so starting shards fails.
+    async fn cluster() -> Cluster {
+        cluster_with_scheme(ShardScheme::Range {
+            from: 0,
+            to: 0,
+            total: 2,
+        })
+        .await
+    }
+
+    async fn cluster_with_scheme(scheme: ShardScheme) -> Cluster {
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+
+        Cluster::builder("token", Intents::empty())
+            .gateway_url(Some(format!("ws://{}", addr)))
+            .shard_scheme(scheme)
+            .build()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_add_shard() {
+        let cluster = cluster().await;
+
+        assert!(matches!(
+            cluster.add_shard(2).await,
+            Err(ClusterShardError::ShardOutOfRange { id: 2, total: 2 })
+        ));
+        assert!(matches!(
+            cluster.add_shard(0).await,
+            Err(ClusterShardError::ShardExists { id: 0 })
+        ));
+        assert!(matches!(
+            cluster.add_shard(1).await,
+            Err(ClusterShardError::ShardStarting { id: 1, .. })
+        ));
+        assert!(cluster.shard(1).is_none());
+        assert!(cluster.shard(0).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_remove_shard() {
+        let cluster = cluster().await;
+        let shard = cluster.shard(0).unwrap();
+
+        assert!(cluster.remove_shard(0).unwrap().ptr_eq(&shard));
+        assert!(cluster.shard(0).is_none());
+        assert!(cluster.remove_shard(0).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_restart_shard() {
+        let cluster = cluster().await;
+
+        assert!(matches!(
+            cluster.restart_shard(1).await,
+            Err(ClusterShardError::ShardNonexistent { id: 1 })
+        ));
+
+        let previous = cluster.shard(0).unwrap();
+        assert!(matches!(
+            cluster.restart_shard(0).await,
+            Err(ClusterShardError::ShardStarting { id: 0, .. })
+        ));
+        assert!(cluster.shard(0).is_none());
+        assert!(previous.info().is_err());
+    }
+
+    /// A shard failing to start must not remove a shard which replaced it in
+    /// the meantime.
+    #[tokio::test]
+    async fn test_start_shard_replaced() {
+        let cluster = cluster().await;
+        let current = cluster.shard(0).unwrap();
+        let failing = Shard::new_with_config(Cluster::new_shard(&cluster.0.config, 0, 2));
+
+        assert!(matches!(
+            cluster.start_shard(0, failing).await,
+            Err(ClusterShardError::ShardStarting { id: 0, .. })
+        ));
+        assert!(cluster.shard(0).unwrap().ptr_eq(&current));
+    }
+
+    #[tokio::test]
+    async fn test_reshard_partial_scheme() {
+        let cluster = cluster().await;
+
+        assert!(matches!(
+            cluster.reshard().await,
+            Err(ClusterReshardError::ShardSchemeRange {
+                from: 0,
+                to: 0,
+                total: 2,
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reshard_concurrent() {
+        let cluster = cluster_with_scheme(ShardScheme::Range {
+            from: 0,
+            to: 0,
+            total: 1,
+        })
+        .await;
+        cluster.0.resharding.store(true, Ordering::Release);
+
+        assert!(matches!(
+            cluster.reshard().await,
+            Err(ClusterReshardError::Resharding)
+        ));
+    }
+
+    /// Event streams of the cluster must continue with the events of the new
+    /// shards once they're swapped in.
+    #[tokio::test]
+    async fn test_reshard_events_continuous() {
+        let cluster = cluster_with_scheme(ShardScheme::Range {
+            from: 0,
+            to: 0,
+            total: 1,
+        })
+        .await;
+        let mut events = cluster.events();
+
+        let old = cluster.shard(0).unwrap();
+        old.emit(Event::GatewayReconnect);
+        assert!(matches!(
+            events.next().await,
+            Some((0, Event::GatewayReconnect))
+        ));
+
+        let shards = (0..2)
+            .map(|id| {
+                let config = Cluster::new_shard(&cluster.0.config, id, 2);
+
+                (id, Shard::new_with_config(config))
+            })
+            .collect::<HashMap<_, _>>();
+        cluster.swap_shards(2, shards.clone());
+        assert_eq!(2, cluster.shard_total());
+        assert!(cluster.shard(1).unwrap().ptr_eq(&shards[&1]));
+
+        // The previous shard has been shutdown, so its events aren't received
+        // anymore.
+        old.emit(Event::GatewayReconnect);
+        assert!(events.next().now_or_never().is_none());
+
+        shards[&1].emit(Event::GatewayReconnect);
+        assert!(matches!(
+            events.next().await,
+            Some((1, Event::GatewayReconnect))
+        ));
+        shards[&0].emit(Event::GatewayReconnect);
+        assert!(matches!(
+            events.next().await,
+            Some((0, Event::GatewayReconnect))
+        ));
+    }
 }
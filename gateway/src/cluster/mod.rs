@@ -56,11 +56,19 @@
 //! ```
 
 mod builder;
+mod chunker;
 mod config;
+mod event;
+mod health;
 mod r#impl;
 
 pub use self::{
     builder::{ClusterBuilder, ShardScheme, ShardSchemeRangeError},
+    chunker::{MemberChunker, MemberChunkerBuilder, MemberChunks},
     config::Config,
-    r#impl::{Cluster, ClusterCommandError, ClusterSendError, ClusterStartError},
+    health::{ClusterHealth, ShardHealth},
+    r#impl::{
+        Cluster, ClusterCommandError, ClusterReshardError, ClusterSendError, ClusterShardError,
+        ClusterStartError,
+    },
 };
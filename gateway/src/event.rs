@@ -5,7 +5,7 @@ use twilight_model::gateway::event::EventType;
 bitflags! {
     /// Bitflags representing all of the possible types of events.
     #[non_exhaustive]
-    pub struct EventTypeFlags: u64 {
+    pub struct EventTypeFlags: u128 {
         /// User has been banned from a guild.
         const BAN_ADD = 1;
         /// User has been unbanned from a guild.
@@ -41,6 +41,16 @@ bitflags! {
         const GUILD_EMOJIS_UPDATE = 1 << 12;
         /// A guild's integrations have been updated.
         const GUILD_INTEGRATIONS_UPDATE = 1 << 13;
+        /// Event has been scheduled in a guild.
+        const GUILD_SCHEDULED_EVENT_CREATE = 1 << 60;
+        /// Scheduled event in a guild has been deleted.
+        const GUILD_SCHEDULED_EVENT_DELETE = 1 << 61;
+        /// Scheduled event in a guild has been updated.
+        const GUILD_SCHEDULED_EVENT_UPDATE = 1 << 62;
+        /// User has subscribed to a scheduled event.
+        const GUILD_SCHEDULED_EVENT_USER_ADD = 1 << 63;
+        /// User has unsubscribed from a scheduled event.
+        const GUILD_SCHEDULED_EVENT_USER_REMOVE = 1 << 64;
         /// A guild has been updated.
         const GUILD_UPDATE = 1 << 14;
         /// Invite for a channel has been created.
@@ -105,6 +115,28 @@ bitflags! {
         const SHARD_RECONNECTING = 1 << 37;
         /// Shard is resuming a session with the gateway.
         const SHARD_RESUMING = 1 << 38;
+        /// Shard's connection was determined to be a zombie.
+        const SHARD_ZOMBIED = 1 << 50;
+        /// Stage instance has been created in a stage channel.
+        const STAGE_INSTANCE_CREATE = 1 << 57;
+        /// Stage instance has been deleted.
+        const STAGE_INSTANCE_DELETE = 1 << 58;
+        /// Stage instance has been updated.
+        const STAGE_INSTANCE_UPDATE = 1 << 59;
+        /// Thread has been created, or the current user was added to a
+        /// private thread.
+        const THREAD_CREATE = 1 << 51;
+        /// Thread has been deleted.
+        const THREAD_DELETE = 1 << 52;
+        /// Current user has gained access to channels and their active
+        /// threads have been synced.
+        const THREAD_LIST_SYNC = 1 << 53;
+        /// Thread member of the current user has been updated.
+        const THREAD_MEMBER_UPDATE = 1 << 54;
+        /// Members have been added to or removed from a thread.
+        const THREAD_MEMBERS_UPDATE = 1 << 55;
+        /// Thread has been updated.
+        const THREAD_UPDATE = 1 << 56;
         /// User has begun typing in a channel.
         const TYPING_START = 1 << 39;
         /// Guild is unavailable, potentially due to an outage.
@@ -139,6 +171,13 @@ impl From<EventType> for EventTypeFlags {
             EventType::GuildDelete => EventTypeFlags::GUILD_DELETE,
             EventType::GuildEmojisUpdate => EventTypeFlags::GUILD_EMOJIS_UPDATE,
             EventType::GuildIntegrationsUpdate => EventTypeFlags::GUILD_INTEGRATIONS_UPDATE,
+            EventType::GuildScheduledEventCreate => EventTypeFlags::GUILD_SCHEDULED_EVENT_CREATE,
+            EventType::GuildScheduledEventDelete => EventTypeFlags::GUILD_SCHEDULED_EVENT_DELETE,
+            EventType::GuildScheduledEventUpdate => EventTypeFlags::GUILD_SCHEDULED_EVENT_UPDATE,
+            EventType::GuildScheduledEventUserAdd => EventTypeFlags::GUILD_SCHEDULED_EVENT_USER_ADD,
+            EventType::GuildScheduledEventUserRemove => {
+                EventTypeFlags::GUILD_SCHEDULED_EVENT_USER_REMOVE
+            }
             EventType::GuildUpdate => EventTypeFlags::GUILD_UPDATE,
             EventType::InviteCreate => EventTypeFlags::INVITE_CREATE,
             EventType::InviteDelete => EventTypeFlags::INVITE_DELETE,
@@ -168,6 +207,16 @@ impl From<EventType> for EventTypeFlags {
             EventType::ShardReconnecting => EventTypeFlags::SHARD_RECONNECTING,
             EventType::ShardPayload => EventTypeFlags::SHARD_PAYLOAD,
             EventType::ShardResuming => EventTypeFlags::SHARD_RESUMING,
+            EventType::ShardZombied => EventTypeFlags::SHARD_ZOMBIED,
+            EventType::StageInstanceCreate => EventTypeFlags::STAGE_INSTANCE_CREATE,
+            EventType::StageInstanceDelete => EventTypeFlags::STAGE_INSTANCE_DELETE,
+            EventType::StageInstanceUpdate => EventTypeFlags::STAGE_INSTANCE_UPDATE,
+            EventType::ThreadCreate => EventTypeFlags::THREAD_CREATE,
+            EventType::ThreadDelete => EventTypeFlags::THREAD_DELETE,
+            EventType::ThreadListSync => EventTypeFlags::THREAD_LIST_SYNC,
+            EventType::ThreadMemberUpdate => EventTypeFlags::THREAD_MEMBER_UPDATE,
+            EventType::ThreadMembersUpdate => EventTypeFlags::THREAD_MEMBERS_UPDATE,
+            EventType::ThreadUpdate => EventTypeFlags::THREAD_UPDATE,
             EventType::TypingStart => EventTypeFlags::TYPING_START,
             EventType::UnavailableGuild => EventTypeFlags::UNAVAILABLE_GUILD,
             EventType::UserUpdate => EventTypeFlags::USER_UPDATE,
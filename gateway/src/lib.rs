@@ -44,6 +44,22 @@
 //! twilight-gateway = { default-features = false, features = ["rustls", "simd-json"], version = "0.2" }
 //! ```
 //!
+//! ### ETF
+//!
+//! The `etf` feature makes shards connect to the gateway with Erlang's
+//! [External Term Format] encoding instead of JSON. ETF payloads are smaller on
+//! the wire and faster to decode, which is most noticeable with large guilds.
+//! Commands sent over the gateway are encoded as ETF as well. It is not enabled
+//! by default.
+//!
+//! When enabled, the bytes of [`EventTypeFlags::SHARD_PAYLOAD`] events are ETF
+//! encoded and can be decoded via [`shard::etf::parse_gateway_event`].
+//!
+//! ```toml
+//! [dependencies]
+//! twilight-gateway = { default-features = false, features = ["etf", "rustls"], version = "0.2" }
+//! ```
+//!
 //! ### TLS
 //!
 //! `twilight-gateway` has features to enable [`async-tungstenite`] and
@@ -90,6 +106,9 @@
 //! This is disabled by default.
 //!
 //! [`async-tungstenite`]: https://crates.io/crates/async-tungstenite
+//! [External Term Format]: https://erlang.org/doc/apps/erts/erl_ext_dist.html
+//! [`EventTypeFlags::SHARD_PAYLOAD`]: crate::EventTypeFlags::SHARD_PAYLOAD
+//! [`shard::etf::parse_gateway_event`]: crate::shard::etf::parse_gateway_event
 //! [`native-tls`]: https://crates.io/crates/native-tls
 //! [`rustls`]: https://crates.io/crates/rustls
 //! [`serde_json`]: https://crates.io/crates/serde_json
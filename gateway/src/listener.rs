@@ -3,7 +3,7 @@ use dashmap::DashMap;
 use futures_channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
 use std::sync::{
     atomic::{AtomicU64, Ordering},
-    Arc,
+    Arc, RwLock,
 };
 
 #[derive(Debug)]
@@ -25,7 +25,9 @@ struct ListenersRef<T> {
     //
     // If listener 1 wants message creates and listener 2 wants message deletes,
     // then this will contain the bits of both.
-    event_types: AtomicU64,
+    //
+    // There is no stable 128-bit atomic, so this is behind a lock.
+    event_types: RwLock<EventTypeFlags>,
     id: AtomicU64,
     listeners: DashMap<u64, Listener<T>>,
 }
@@ -33,7 +35,7 @@ struct ListenersRef<T> {
 impl<T> Default for ListenersRef<T> {
     fn default() -> Self {
         Self {
-            event_types: AtomicU64::new(0),
+            event_types: RwLock::new(EventTypeFlags::empty()),
             id: AtomicU64::new(0),
             listeners: DashMap::new(),
         }
@@ -63,9 +65,7 @@ impl<T> Listeners<T> {
     /// If listener 1 has requested message creates and listener 2 has requested
     /// message deletes, then this returns bitflags with both flipped on.
     pub fn event_types(&self) -> EventTypeFlags {
-        let bits = self.0.event_types.load(Ordering::SeqCst);
-
-        EventTypeFlags::from_bits_truncate(bits)
+        *self.0.event_types.read().expect("event types poisoned")
     }
 
     /// Return the length of the listeners map.
@@ -89,7 +89,7 @@ impl<T> Listeners<T> {
                 acc
             });
 
-        self.0.event_types.store(flags.bits(), Ordering::SeqCst);
+        *self.0.event_types.write().expect("event types poisoned") = flags;
     }
 }
 
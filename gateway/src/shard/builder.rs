@@ -1,8 +1,9 @@
-use super::{config::Config, Shard};
+use super::{compression::Compression, config::Config, Shard};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     sync::Arc,
+    time::Duration,
 };
 use twilight_gateway_queue::{LocalQueue, Queue};
 use twilight_http::Client as HttpClient;
@@ -106,6 +107,8 @@ impl ShardBuilder {
         }
 
         Self(Config {
+            compression: Compression::default(),
+            dispatch_timeout: None,
             gateway_url: None,
             http_client: HttpClient::new(token.clone()),
             intents,
@@ -124,6 +127,54 @@ impl ShardBuilder {
         Shard::new_with_config(self.0)
     }
 
+    /// Set the compression of payloads sent by the gateway.
+    ///
+    /// Default is [`Compression::ZlibStream`].
+    ///
+    /// # Examples
+    ///
+    /// Disable compression for a low traffic shard:
+    ///
+    /// ```rust,no_run
+    /// use std::env;
+    /// use twilight_gateway::{shard::Compression, Intents, Shard};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    ///
+    /// let shard = Shard::builder(token, Intents::GUILD_MESSAGES)
+    ///     .compression(Compression::None)
+    ///     .build();
+    /// # Ok(()) }
+    /// ```
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.0.compression = compression;
+
+        self
+    }
+
+    /// Set the duration without any dispatch events after which the shard's
+    /// connection is considered a zombie.
+    ///
+    /// Connections which don't acknowledge a heartbeat before the next is due
+    /// are always considered zombies. Some connections keep acknowledging
+    /// heartbeats while no longer sending events, which this detects. The
+    /// shard emits a [`Event::ShardZombied`] and resumes the session over a
+    /// new connection.
+    ///
+    /// This is checked every heartbeat interval, so detection may be delayed
+    /// by up to one interval. Shards of bots in few guilds may legitimately go
+    /// without events for a long time, so choose a window accordingly.
+    ///
+    /// Default is no timeout.
+    ///
+    /// [`Event::ShardZombied`]: crate::Event::ShardZombied
+    pub fn dispatch_timeout(mut self, dispatch_timeout: Duration) -> Self {
+        self.0.dispatch_timeout = Some(dispatch_timeout);
+
+        self
+    }
+
     /// Set the URL used for connecting to Discord's gateway
     pub fn gateway_url(mut self, gateway_url: Option<String>) -> Self {
         self.0.gateway_url = gateway_url.map(String::into_boxed_str);
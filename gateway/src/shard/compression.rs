@@ -0,0 +1,134 @@
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+
+/// Decompressing a payload received from the gateway failed.
+#[derive(Debug)]
+pub struct DecompressError {
+    source: Box<dyn Error + Send + Sync>,
+}
+
+impl DecompressError {
+    /// Create a new error from the error of a decompression algorithm.
+    pub fn new(source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
+}
+
+impl Display for DecompressError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a frame could not be decompressed")
+    }
+}
+
+impl Error for DecompressError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// Decompressor of binary frames received from the gateway.
+///
+/// Implement this to use a compression algorithm not built into the shard via
+/// [`Compression::Custom`].
+pub trait Decompressor: Debug + Send {
+    /// Value of the `compress` query parameter to connect to the gateway with,
+    /// if the algorithm is a transport compression.
+    fn compress_parameter(&self) -> Option<&str>;
+
+    /// Decompress a binary frame, extending the buffer with the decompressed
+    /// output.
+    ///
+    /// Returns whether the buffer contains a complete payload. If `false` is
+    /// returned then the next frame will be appended to the same buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DecompressError`] if the frame could not be decompressed,
+    /// after which the shard will reconnect.
+    fn decompress(&mut self, frame: &[u8], buffer: &mut Vec<u8>) -> Result<bool, DecompressError>;
+
+    /// Total number of compressed bytes taken in since the last reset.
+    fn total_in(&self) -> u64;
+
+    /// Total number of decompressed bytes put out since the last reset.
+    fn total_out(&self) -> u64;
+
+    /// Reset the state of the decompressor for a new connection.
+    fn reset(&mut self);
+}
+
+/// Compression of payloads sent by the gateway.
+///
+/// Turning compression off reduces CPU usage, which may benefit low traffic
+/// shards on constrained hosts at the cost of bandwidth.
+///
+/// Set via [`ShardBuilder::compression`].
+///
+/// [`ShardBuilder::compression`]: super::ShardBuilder::compression
+#[derive(Clone)]
+#[non_exhaustive]
+pub enum Compression {
+    /// Payloads aren't compressed.
+    None,
+    /// Large payloads are individually compressed with zlib, requested via the
+    /// `compress` field when identifying.
+    Payload,
+    /// The entire connection is compressed as a single zlib stream.
+    ///
+    /// This is the default.
+    ZlibStream,
+    /// Connection is compressed with a custom transport compression.
+    ///
+    /// The function is called to create a decompressor for each shard.
+    Custom(Arc<dyn Fn() -> Box<dyn Decompressor> + Send + Sync>),
+}
+
+impl Compression {
+    /// Name of the compression mode, used for logging and metrics.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Payload => "payload",
+            Self::ZlibStream => "zlib-stream",
+            Self::Custom(_) => "custom",
+        }
+    }
+}
+
+impl Debug for Compression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::None => f.write_str("None"),
+            Self::Payload => f.write_str("Payload"),
+            Self::ZlibStream => f.write_str("ZlibStream"),
+            Self::Custom(_) => f.debug_tuple("Custom").field(&"<fn>").finish(),
+        }
+    }
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Self::ZlibStream
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Compression, DecompressError, Decompressor};
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(Compression: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(DecompressError: Debug, Error, Send, Sync);
+    assert_obj_safe!(Decompressor);
+
+    #[test]
+    fn test_default() {
+        assert!(matches!(Compression::default(), Compression::ZlibStream));
+    }
+}
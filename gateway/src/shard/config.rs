@@ -1,4 +1,5 @@
-use std::sync::Arc;
+use super::compression::Compression;
+use std::{sync::Arc, time::Duration};
 use twilight_gateway_queue::Queue;
 use twilight_http::Client;
 use twilight_model::gateway::{payload::update_status::UpdateStatusInfo, Intents};
@@ -11,6 +12,8 @@ use twilight_model::gateway::{payload::update_status::UpdateStatusInfo, Intents}
 /// [`Shard::builder`]: super::Shard::builder
 #[derive(Clone, Debug)]
 pub struct Config {
+    pub(super) compression: Compression,
+    pub(super) dispatch_timeout: Option<Duration>,
     pub(crate) gateway_url: Option<Box<str>>,
     pub(crate) http_client: Client,
     pub(super) intents: Intents,
@@ -24,6 +27,18 @@ pub struct Config {
 }
 
 impl Config {
+    /// Return an immutable reference to the compression of payloads sent by
+    /// the gateway.
+    pub fn compression(&self) -> &Compression {
+        &self.compression
+    }
+
+    /// Return the duration without dispatch events after which the connection
+    /// is considered a zombie, if any.
+    pub fn dispatch_timeout(&self) -> Option<Duration> {
+        self.dispatch_timeout
+    }
+
     /// Return an immutable reference to the url used to connect to the gateway.
     pub fn gateway_url(&self) -> Option<&str> {
         self.gateway_url.as_deref()
@@ -0,0 +1,435 @@
+//! Encoding and decoding of gateway payloads in Erlang's [External Term
+//! Format].
+//!
+//! This module is available when the `etf` feature is enabled. With the
+//! feature enabled shards connect to the gateway with `encoding=etf`, which
+//! results in smaller payloads that are faster to decode than JSON, especially
+//! for large guilds.
+//!
+//! Terms are decoded into an intermediary [`serde_value::Value`] and then
+//! deserialized into the gateway event models, so the same models are used for
+//! both encodings.
+//!
+//! The functions in this module are public so that users subscribing to
+//! [`EventTypeFlags::SHARD_PAYLOAD`] can decode the raw payload bytes, which
+//! are ETF encoded when the feature is enabled.
+//!
+//! [External Term Format]: https://erlang.org/doc/apps/erts/erl_ext_dist.html
+//! [`EventTypeFlags::SHARD_PAYLOAD`]: crate::EventTypeFlags::SHARD_PAYLOAD
+
+use eetf::{
+    Atom, BigInteger, Binary, DecodeError, EncodeError, FixInteger, Float, List, Map, Term,
+};
+use serde::{de::DeserializeSeed, Serialize};
+use serde_value::{DeserializerError, SerializerError, Value, ValueDeserializer};
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Cursor,
+};
+use twilight_model::gateway::event::{GatewayEvent, GatewayEventDeserializerOwned};
+
+/// Encoding or decoding an ETF payload failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EtfError {
+    /// Decoding the bytes as an Erlang term failed.
+    Decoding {
+        /// Reason for the error.
+        source: DecodeError,
+    },
+    /// Deserializing the decoded term into a gateway event failed.
+    Deserializing {
+        /// Reason for the error.
+        source: DeserializerError,
+    },
+    /// Encoding the serialized value as an Erlang term failed.
+    Encoding {
+        /// Reason for the error.
+        source: EncodeError,
+    },
+    /// The payload received from Discord was an unrecognized or invalid
+    /// structure.
+    ///
+    /// The payload either wasn't a map or did not contain a valid "op" key.
+    PayloadInvalid,
+    /// Serializing the value to send into an intermediary value failed.
+    Serializing {
+        /// Reason for the error.
+        source: SerializerError,
+    },
+    /// A term was received that has no equivalent in the gateway models, such
+    /// as a PID or reference.
+    TermUnsupported {
+        /// The unsupported term.
+        term: Term,
+    },
+}
+
+impl Display for EtfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Decoding { source } => Display::fmt(source, f),
+            Self::Deserializing { source } => Display::fmt(source, f),
+            Self::Encoding { source } => Display::fmt(source, f),
+            Self::PayloadInvalid => f.write_str("payload is an invalid etf structure"),
+            Self::Serializing { source } => Display::fmt(source, f),
+            Self::TermUnsupported { term } => {
+                f.write_fmt(format_args!("the term {} is unsupported", term))
+            }
+        }
+    }
+}
+
+impl Error for EtfError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Decoding { source } => Some(source),
+            Self::Deserializing { source } => Some(source),
+            Self::Encoding { source } => Some(source),
+            Self::Serializing { source } => Some(source),
+            Self::PayloadInvalid | Self::TermUnsupported { .. } => None,
+        }
+    }
+}
+
+/// Decoded ETF payload with its headers extracted.
+///
+/// The body of the payload is only deserialized into a gateway event when
+/// requested, allowing the shard to skip deserializing events that no
+/// listener wants.
+#[derive(Debug)]
+pub(crate) struct Payload {
+    event_type: Option<String>,
+    op: u8,
+    sequence: Option<u64>,
+    value: Value,
+}
+
+impl Payload {
+    /// Decode a payload from ETF bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EtfError::Decoding`] if the bytes aren't a valid term.
+    ///
+    /// Returns [`EtfError::PayloadInvalid`] if the term isn't a map containing
+    /// an opcode.
+    ///
+    /// Returns [`EtfError::TermUnsupported`] if the payload contains a term
+    /// that can't be represented in the models.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, EtfError> {
+        let term =
+            Term::decode(Cursor::new(bytes)).map_err(|source| EtfError::Decoding { source })?;
+
+        let value = term_to_value(term)?;
+
+        let map = match &value {
+            Value::Map(map) => map,
+            _ => return Err(EtfError::PayloadInvalid),
+        };
+
+        let op = match map.get(&Value::String("op".to_owned())) {
+            Some(Value::U64(op)) => u8::try_from(*op).map_err(|_| EtfError::PayloadInvalid)?,
+            _ => return Err(EtfError::PayloadInvalid),
+        };
+
+        let sequence = match map.get(&Value::String("s".to_owned())) {
+            Some(Value::U64(sequence)) => Some(*sequence),
+            _ => None,
+        };
+
+        let event_type = match map.get(&Value::String("t".to_owned())) {
+            Some(Value::String(event_type)) => Some(event_type.clone()),
+            _ => None,
+        };
+
+        Ok(Self {
+            event_type,
+            op,
+            sequence,
+            value,
+        })
+    }
+
+    /// Return an immutable reference to the event type of the payload.
+    pub fn event_type(&self) -> Option<&str> {
+        self.event_type.as_deref()
+    }
+
+    /// Return the opcode of the payload.
+    pub fn op(&self) -> u8 {
+        self.op
+    }
+
+    /// Return the sequence of the payload.
+    pub fn sequence(&self) -> Option<u64> {
+        self.sequence
+    }
+
+    /// Consume the payload, deserializing it into a gateway event.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EtfError::Deserializing`] if the payload doesn't match the
+    /// structure of the event.
+    pub fn into_gateway_event(self) -> Result<GatewayEvent, EtfError> {
+        let deserializer =
+            GatewayEventDeserializerOwned::new(self.op, self.sequence, self.event_type);
+
+        deserializer
+            .deserialize(ValueDeserializer::<DeserializerError>::new(self.value))
+            .map_err(|source| EtfError::Deserializing { source })
+    }
+}
+
+/// Parse a gateway event from ETF encoded bytes.
+///
+/// # Errors
+///
+/// Returns [`EtfError::Decoding`] if the bytes aren't a valid term.
+///
+/// Returns [`EtfError::Deserializing`] if the payload failed to deserialize.
+///
+/// Returns [`EtfError::PayloadInvalid`] if the payload wasn't a valid
+/// `GatewayEvent` data structure.
+///
+/// Returns [`EtfError::TermUnsupported`] if the payload contains a term that
+/// can't be represented in the models.
+pub fn parse_gateway_event(bytes: &[u8]) -> Result<GatewayEvent, EtfError> {
+    Payload::from_slice(bytes)?.into_gateway_event()
+}
+
+/// Serialize a value into ETF encoded bytes.
+///
+/// Strings are encoded as binaries and `None` values as the `nil` atom, as
+/// expected by Discord.
+///
+/// # Errors
+///
+/// Returns [`EtfError::Serializing`] if the value failed to serialize.
+///
+/// Returns [`EtfError::Encoding`] if the serialized value couldn't be encoded.
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, EtfError> {
+    let value = serde_value::to_value(value).map_err(|source| EtfError::Serializing { source })?;
+    let mut bytes = Vec::new();
+
+    value_to_term(value)
+        .encode(&mut bytes)
+        .map_err(|source| EtfError::Encoding { source })?;
+
+    Ok(bytes)
+}
+
+fn atom(name: &str) -> Term {
+    Term::Atom(Atom::from(name))
+}
+
+fn integer(value: i64) -> Term {
+    match i32::try_from(value) {
+        Ok(value) => Term::FixInteger(FixInteger { value }),
+        Err(_) => Term::BigInteger(BigInteger::from(value)),
+    }
+}
+
+fn value_to_term(value: Value) -> Term {
+    match value {
+        Value::Bool(value) => atom(if value { "true" } else { "false" }),
+        Value::U8(value) => integer(i64::from(value)),
+        Value::U16(value) => integer(i64::from(value)),
+        Value::U32(value) => integer(i64::from(value)),
+        Value::U64(value) => match i64::try_from(value) {
+            Ok(value) => integer(value),
+            Err(_) => Term::BigInteger(BigInteger::from(value)),
+        },
+        Value::I8(value) => integer(i64::from(value)),
+        Value::I16(value) => integer(i64::from(value)),
+        Value::I32(value) => integer(i64::from(value)),
+        Value::I64(value) => integer(value),
+        Value::F32(value) => Term::Float(Float {
+            value: f64::from(value),
+        }),
+        Value::F64(value) => Term::Float(Float { value }),
+        Value::Char(value) => Term::Binary(Binary {
+            bytes: value.to_string().into_bytes(),
+        }),
+        Value::String(value) => Term::Binary(Binary {
+            bytes: value.into_bytes(),
+        }),
+        Value::Bytes(value) => Term::Binary(Binary { bytes: value }),
+        Value::Unit | Value::Option(None) => atom("nil"),
+        Value::Option(Some(value)) | Value::Newtype(value) => value_to_term(*value),
+        Value::Seq(values) => Term::List(List {
+            elements: values.into_iter().map(value_to_term).collect(),
+        }),
+        Value::Map(map) => Term::Map(Map {
+            entries: map
+                .into_iter()
+                .map(|(key, value)| (value_to_term(key), value_to_term(value)))
+                .collect(),
+        }),
+    }
+}
+
+fn term_to_value(term: Term) -> Result<Value, EtfError> {
+    Ok(match term {
+        Term::Atom(atom) => match atom.name.as_str() {
+            "nil" => Value::Unit,
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => Value::String(atom.name),
+        },
+        Term::FixInteger(integer) => match u64::try_from(integer.value) {
+            Ok(value) => Value::U64(value),
+            Err(_) => Value::I64(i64::from(integer.value)),
+        },
+        Term::BigInteger(integer) => {
+            // Snowflakes are sent as big integers, which don't fit in a
+            // fixed integer.
+            let digits = integer.value.to_string();
+
+            if let Ok(value) = digits.parse() {
+                Value::U64(value)
+            } else if let Ok(value) = digits.parse() {
+                Value::I64(value)
+            } else {
+                return Err(EtfError::TermUnsupported {
+                    term: Term::BigInteger(integer),
+                });
+            }
+        }
+        Term::Float(float) => Value::F64(float.value),
+        Term::Binary(binary) => match String::from_utf8(binary.bytes) {
+            Ok(string) => Value::String(string),
+            Err(source) => Value::Bytes(source.into_bytes()),
+        },
+        // Lists of small integers are encoded as byte lists.
+        Term::ByteList(list) => Value::Seq(
+            list.bytes
+                .into_iter()
+                .map(|byte| Value::U64(u64::from(byte)))
+                .collect(),
+        ),
+        Term::List(list) => Value::Seq(
+            list.elements
+                .into_iter()
+                .map(term_to_value)
+                .collect::<Result<_, _>>()?,
+        ),
+        Term::Tuple(tuple) => Value::Seq(
+            tuple
+                .elements
+                .into_iter()
+                .map(term_to_value)
+                .collect::<Result<_, _>>()?,
+        ),
+        Term::Map(map) => {
+            let mut values = BTreeMap::new();
+
+            for (key, value) in map.entries {
+                values.insert(term_to_value(key)?, term_to_value(value)?);
+            }
+
+            Value::Map(values)
+        }
+        other => return Err(EtfError::TermUnsupported { term: other }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_gateway_event, to_vec, EtfError, Payload};
+    use serde_json::json;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        gateway::event::{DispatchEvent, GatewayEvent},
+        id::{GuildId, RoleId},
+    };
+
+    assert_fields!(EtfError::Decoding: source);
+    assert_fields!(EtfError::Deserializing: source);
+    assert_fields!(EtfError::Encoding: source);
+    assert_fields!(EtfError::Serializing: source);
+    assert_fields!(EtfError::TermUnsupported: term);
+    assert_impl_all!(EtfError: Debug, Error, Send, Sync);
+
+    #[test]
+    fn test_payload_headers() -> Result<(), EtfError> {
+        let bytes = to_vec(&json!({
+            "op": 0,
+            "s": 2,
+            "d": {
+                "guild_id": "1",
+                "role_id": "2"
+            },
+            "t": "GUILD_ROLE_DELETE"
+        }))?;
+        let payload = Payload::from_slice(&bytes)?;
+
+        assert_eq!(0, payload.op());
+        assert_eq!(Some(2), payload.sequence());
+        assert_eq!(Some("GUILD_ROLE_DELETE"), payload.event_type());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_dispatch() -> Result<(), EtfError> {
+        let bytes = to_vec(&json!({
+            "op": 0,
+            "s": 2,
+            "d": {
+                "guild_id": 1,
+                "role_id": 2
+            },
+            "t": "GUILD_ROLE_DELETE"
+        }))?;
+
+        match parse_gateway_event(&bytes)? {
+            GatewayEvent::Dispatch(2, event) => match *event {
+                DispatchEvent::RoleDelete(delete) => {
+                    assert_eq!(GuildId(1), delete.guild_id);
+                    assert_eq!(RoleId(2), delete.role_id);
+                }
+                other => panic!("unexpected event: {:?}", other),
+            },
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_hello() -> Result<(), EtfError> {
+        let bytes = to_vec(&json!({
+            "op": 10,
+            "s": null,
+            "d": {
+                "heartbeat_interval": 41_250
+            },
+            "t": null
+        }))?;
+
+        assert!(matches!(
+            parse_gateway_event(&bytes)?,
+            GatewayEvent::Hello(41_250)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_payload_invalid() -> Result<(), EtfError> {
+        let bytes = to_vec(&json!([1, 2, 3]))?;
+
+        assert!(matches!(
+            Payload::from_slice(&bytes),
+            Err(EtfError::PayloadInvalid)
+        ));
+
+        Ok(())
+    }
+}
@@ -2,7 +2,6 @@ use super::{
     builder::ShardBuilder,
     config::Config,
     event::Events,
-    json,
     processor::{ConnectingError, Latency, Session, ShardProcessor},
     raw_message::Message,
     sink::ShardSink,
@@ -25,16 +24,17 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     sync::{atomic::Ordering, Arc},
+    time::Instant,
 };
 use tokio::sync::watch::Receiver as WatchReceiver;
 use twilight_http::Error as HttpError;
 use twilight_model::gateway::event::Event;
 use url::ParseError as UrlParseError;
 
-#[cfg(not(feature = "simd-json"))]
-use serde_json::Error as JsonError;
-#[cfg(feature = "simd-json")]
-use simd_json::Error as JsonError;
+#[cfg(feature = "etf")]
+use super::etf::{self as encoding, EtfError as EncodingError};
+#[cfg(not(feature = "etf"))]
+use super::json::{self as encoding, JsonError as EncodingError};
 
 /// Sending a command failed.
 #[derive(Debug)]
@@ -46,10 +46,11 @@ pub enum CommandError {
         /// Reason for the error.
         source: TrySendError<TungsteniteMessage>,
     },
-    /// Serializing the payload as JSON failed.
+    /// Serializing the payload as JSON, or ETF if the `etf` feature is
+    /// enabled, failed.
     Serializing {
         /// Reason for the error.
-        source: JsonError,
+        source: EncodingError,
     },
     /// Shard's session is inactive because the shard hasn't been started.
     SessionInactive {
@@ -192,11 +193,15 @@ impl Error for SendError {
 }
 
 /// Information about a shard, including its latency, current session sequence,
-/// and connection stage.
+/// connection stage, and activity.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Information {
     id: u64,
+    #[serde(skip)]
+    last_event: Option<Instant>,
     latency: Latency,
+    #[serde(default)]
+    reconnects: u64,
     session_id: Option<Box<str>>,
     seq: u64,
     stage: Stage,
@@ -208,6 +213,14 @@ impl Information {
         self.id
     }
 
+    /// When the shard last received a payload from the gateway.
+    ///
+    /// This is kept across reconnects. A shard that's connected but hasn't
+    /// received a payload in a long time may be a zombie.
+    pub fn last_event(&self) -> Option<Instant> {
+        self.last_event
+    }
+
     /// Return an immutable reference to the latency information for the shard.
     ///
     /// This includes the average latency over all time, and the latency
@@ -216,6 +229,12 @@ impl Information {
         &self.latency
     }
 
+    /// Number of times the shard has reconnected or resumed since it was
+    /// started.
+    pub fn reconnects(&self) -> u64 {
+        self.reconnects
+    }
+
     /// Return an immutable reference to the session ID of the shard.
     pub fn session_id(&self) -> Option<&str> {
         self.session_id.as_deref()
@@ -374,6 +393,24 @@ impl Shard {
         &self.0.config
     }
 
+    /// Whether this shard and another one are handles to the same shard.
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Send an event to the event streams of the shard as if it was received
+    /// from the gateway.
+    #[cfg(test)]
+    pub(crate) fn emit(&self, event: Event) {
+        let event_type = EventTypeFlags::from(event.kind());
+
+        for listener in self.0.listeners.all().iter() {
+            if listener.wants(event_type) {
+                let _ = listener.tx.unbounded_send(event.clone());
+            }
+        }
+    }
+
     /// Start the shard, connecting it to the gateway and starting the process
     /// of receiving and processing events.
     ///
@@ -398,6 +435,9 @@ impl Shard {
                 .authed()
                 .await
                 .map_err(|source| ShardStartError::RetrievingGatewayUrl { source })?
+                .model()
+                .await
+                .map_err(|source| ShardStartError::RetrievingGatewayUrl { source })?
                 .url
         };
 
@@ -498,7 +538,9 @@ impl Shard {
 
         Ok(Information {
             id: self.config().shard()[0],
+            last_event: session.activity.last_event(),
             latency: session.heartbeats.latency(),
+            reconnects: session.activity.reconnects(),
             session_id: session.id(),
             seq: session.seq(),
             stage: session.stage(),
@@ -530,14 +572,15 @@ impl Shard {
     /// over the websocket. This indicates the shard is currently restarting.
     ///
     /// Returns [`CommandError::Serializing`] if the provided value failed to
-    /// serialize into JSON.
+    /// serialize into JSON, or ETF if the `etf` feature is enabled.
     ///
     /// Returns [`CommandError::SessionInactive`] if the shard has not been
     /// started.
     pub async fn command(&self, value: &impl serde::Serialize) -> Result<(), CommandError> {
-        let json = json::to_vec(value).map_err(|source| CommandError::Serializing { source })?;
+        let bytes =
+            encoding::to_vec(value).map_err(|source| CommandError::Serializing { source })?;
 
-        self.send(Message::Binary(json))
+        self.send(Message::Binary(bytes))
             .await
             .map_err(CommandError::from_send)
     }
@@ -619,7 +662,7 @@ impl Shard {
     /// over the websocket. This indicates the shard is currently restarting.
     ///
     /// Returns [`CommandError::Serializing`] if the provided value failed to
-    /// serialize into JSON.
+    /// serialize into JSON, or ETF if the `etf` feature is enabled.
     ///
     /// Returns [`CommandError::SessionInactive`] if the shard has not been
     /// started.
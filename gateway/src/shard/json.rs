@@ -9,6 +9,9 @@ use std::{
 };
 use twilight_model::gateway::event::GatewayEvent;
 
+#[cfg(feature = "etf")]
+use super::etf::EtfError;
+
 #[derive(Debug)]
 pub enum GatewayEventParsingError {
     /// Deserializing the GatewayEvent payload from JSON failed.
@@ -16,6 +19,12 @@ pub enum GatewayEventParsingError {
         /// Reason for the error.
         source: JsonError,
     },
+    /// Decoding or deserializing the GatewayEvent payload from ETF failed.
+    #[cfg(feature = "etf")]
+    DeserializingEtf {
+        /// Reason for the error.
+        source: EtfError,
+    },
     /// The payload received from Discord was an unrecognized or invalid
     /// structure.
     ///
@@ -28,6 +37,8 @@ impl Display for GatewayEventParsingError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
             Self::Deserializing { source } => Display::fmt(source, f),
+            #[cfg(feature = "etf")]
+            Self::DeserializingEtf { source } => Display::fmt(source, f),
             Self::PayloadInvalid => f.write_str("payload is an invalid json structure"),
         }
     }
@@ -37,6 +48,8 @@ impl Error for GatewayEventParsingError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::Deserializing { source } => Some(source),
+            #[cfg(feature = "etf")]
+            Self::DeserializingEtf { source } => Some(source),
             Self::PayloadInvalid => None,
         }
     }
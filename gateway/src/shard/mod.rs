@@ -22,7 +22,11 @@
 pub mod raw_message;
 pub mod stage;
 
+#[cfg(feature = "etf")]
+pub mod etf;
+
 mod builder;
+mod compression;
 mod config;
 mod event;
 mod r#impl;
@@ -32,6 +36,7 @@ mod sink;
 
 pub use self::{
     builder::{LargeThresholdError, ShardBuilder, ShardIdError},
+    compression::{Compression, DecompressError, Decompressor},
     config::Config,
     event::Events,
     processor::heartbeat::Latency,
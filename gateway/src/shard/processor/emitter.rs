@@ -1,4 +1,4 @@
-use super::super::json::{self, GatewayEventParsingError};
+use super::super::json::GatewayEventParsingError;
 use crate::{listener::Listeners, EventTypeFlags};
 use std::{
     convert::TryFrom,
@@ -7,6 +7,11 @@ use std::{
 };
 use twilight_model::gateway::event::{shard::Payload, Event};
 
+#[cfg(feature = "etf")]
+use super::super::etf::Payload as EtfPayload;
+#[cfg(not(feature = "etf"))]
+use super::super::json;
+
 #[derive(Debug)]
 pub enum EmitJsonError {
     /// Provided event type and/or opcode combination doesn't match a known
@@ -118,6 +123,7 @@ impl Emitter {
 
     /// Emit a JSON payload that hasn't been deserialized yet, but only if at
     /// least one of the listeners wants the event type.
+    #[cfg(not(feature = "etf"))]
     pub fn json(
         &self,
         op: u8,
@@ -143,6 +149,32 @@ impl Emitter {
         Ok(())
     }
 
+    /// Emit an ETF payload that hasn't been deserialized yet, but only if at
+    /// least one of the listeners wants the event type.
+    #[cfg(feature = "etf")]
+    pub fn etf(&self, payload: EtfPayload) -> Result<(), EmitJsonError> {
+        let flag = EventTypeFlags::try_from((payload.op(), payload.event_type())).map_err(
+            |(op, event_type)| EmitJsonError::EventTypeUnknown {
+                event_type: event_type.map(ToOwned::to_owned),
+                op,
+            },
+        )?;
+
+        if !self.wants(flag) {
+            return Ok(());
+        }
+
+        let gateway_event =
+            payload
+                .into_gateway_event()
+                .map_err(|source| EmitJsonError::Parsing {
+                    source: GatewayEventParsingError::DeserializingEtf { source },
+                })?;
+        self.event(Event::from(gateway_event));
+
+        Ok(())
+    }
+
     fn send(&self, event_type: EventTypeFlags, mut f: impl FnMut(usize) -> Event) {
         let listener_count = self.listeners.len();
         let mut idx = 0;
@@ -1,8 +1,15 @@
-use super::{super::json, session::SessionSendError};
-use async_tungstenite::tungstenite::Message as TungsteniteMessage;
+use super::{
+    emitter::Emitter,
+    session::{Activity, SessionSendError},
+};
+use async_tungstenite::tungstenite::{
+    protocol::{frame::coding::CloseCode, CloseFrame},
+    Message as TungsteniteMessage,
+};
 use futures_channel::mpsc::UnboundedSender;
 use serde::{Deserialize, Serialize};
 use std::{
+    borrow::Cow,
     collections::VecDeque,
     convert::TryInto,
     sync::{
@@ -11,7 +18,18 @@ use std::{
     },
     time::{Duration, Instant},
 };
-use twilight_model::gateway::payload::Heartbeat;
+use twilight_model::gateway::{
+    event::{
+        shard::{ZombieReason, Zombied},
+        Event,
+    },
+    payload::Heartbeat,
+};
+
+#[cfg(feature = "etf")]
+use super::super::etf as encoding;
+#[cfg(not(feature = "etf"))]
+use super::super::json as encoding;
 
 /// Information about the latency of a [`Shard`]'s websocket connection.
 ///
@@ -171,25 +189,44 @@ impl Default for Heartbeats {
     }
 }
 
+/// Shard specific details used by the heartbeater to detect and report zombied
+/// connections.
+#[derive(Debug)]
+pub struct ZombieDetection {
+    pub activity: Arc<Activity>,
+    pub dispatch_timeout: Option<Duration>,
+    pub emitter: Emitter,
+    pub shard_id: u64,
+}
+
 pub struct Heartbeater {
     heartbeats: Arc<Heartbeats>,
     interval: u64,
     seq: Arc<AtomicU64>,
     tx: UnboundedSender<TungsteniteMessage>,
+    zombie: ZombieDetection,
 }
 
 impl Heartbeater {
+    /// Close code used when closing a zombied connection.
+    ///
+    /// Closing with a code other than 1000 or 1001 keeps the session valid, so
+    /// the shard can resume it.
+    const ZOMBIE_CLOSE_CODE: u16 = 4000;
+
     pub fn new(
         heartbeats: Arc<Heartbeats>,
         interval: u64,
         seq: Arc<AtomicU64>,
         tx: UnboundedSender<TungsteniteMessage>,
+        zombie: ZombieDetection,
     ) -> Self {
         Self {
             heartbeats,
             interval,
             seq,
             tx,
+            zombie,
         }
     }
 
@@ -205,30 +242,33 @@ impl Heartbeater {
     // programmatic error.
     async fn try_run(self) -> Result<(), SessionSendError> {
         let duration = Duration::from_millis(self.interval);
-
-        let mut last = true;
+        let started = Instant::now();
 
         loop {
             tokio::time::sleep(duration).await;
 
-            // Check if a heartbeat acknowledgement was received.
-            //
-            // If so, then check if one was received last time.
-            //
-            // - if so, then mark that we didn't get one this time
-            // - if not, then end the heartbeater because something is off
-            // (connecting closed?)
-            if self.heartbeats.last_acked() {
-                last = true;
-            } else if last {
-                last = false;
-            } else {
-                return Ok(());
+            // If the previous heartbeat wasn't acknowledged before the next is
+            // due then the connection is a zombie, since Discord always
+            // acknowledges heartbeats on a healthy connection.
+            if self.heartbeats.sent().is_some() && !self.heartbeats.last_acked() {
+                return self.zombied(ZombieReason::HeartbeatAckMissed);
+            }
+
+            if let Some(timeout) = self.zombie.dispatch_timeout {
+                let last_dispatch = self
+                    .zombie
+                    .activity
+                    .last_dispatch()
+                    .map_or(started, |last_dispatch| last_dispatch.max(started));
+
+                if last_dispatch.elapsed() > timeout {
+                    return self.zombied(ZombieReason::DispatchSilence);
+                }
             }
 
             let seq = self.seq.load(Ordering::Acquire);
             let heartbeat = Heartbeat::new(seq);
-            let bytes = json::to_vec(&heartbeat)
+            let bytes = encoding::to_vec(&heartbeat)
                 .map_err(|source| SessionSendError::Serializing { source })?;
 
             tracing::debug!(seq, "sending heartbeat");
@@ -239,13 +279,141 @@ impl Heartbeater {
             self.heartbeats.send();
         }
     }
+
+    /// Report that the connection is a zombie and close it, which makes the
+    /// shard resume the session over a new connection.
+    fn zombied(&self, reason: ZombieReason) -> Result<(), SessionSendError> {
+        tracing::warn!(
+            shard_id = self.zombie.shard_id,
+            ?reason,
+            "connection is zombied, closing",
+        );
+
+        #[cfg(feature = "metrics")]
+        {
+            let reason = match reason {
+                ZombieReason::DispatchSilence => "DispatchSilence",
+                ZombieReason::HeartbeatAckMissed => "HeartbeatAckMissed",
+            };
+
+            metrics::counter!("Shard-Zombied", 1, "reason" => reason);
+        }
+
+        self.zombie.emitter.event(Event::ShardZombied(Zombied {
+            reason,
+            shard_id: self.zombie.shard_id,
+        }));
+
+        let frame = CloseFrame {
+            code: CloseCode::Library(Self::ZOMBIE_CLOSE_CODE),
+            reason: Cow::Borrowed("Zombied"),
+        };
+
+        self.tx
+            .unbounded_send(TungsteniteMessage::Close(Some(frame)))
+            .map_err(|source| SessionSendError::Sending { source })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Latency;
+    use super::{
+        super::{emitter::Emitter, session::Activity},
+        Heartbeater, Heartbeats, Latency, ZombieDetection,
+    };
+    use crate::{listener::Listeners, EventTypeFlags};
+    use async_tungstenite::tungstenite::{
+        protocol::frame::coding::CloseCode, Message as TungsteniteMessage,
+    };
+    use futures_channel::mpsc::{self, UnboundedReceiver};
+    use futures_util::stream::StreamExt;
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{
+        fmt::Debug,
+        sync::{atomic::AtomicU64, Arc},
+        time::Duration,
+    };
+    use twilight_model::gateway::event::{
+        shard::{ZombieReason, Zombied},
+        Event,
+    };
 
     assert_impl_all!(Latency: Clone, Debug, Send, Sync);
+
+    /// Run a heartbeater that never receives heartbeat acknowledgements until
+    /// it detects that the connection is zombied.
+    async fn run(
+        dispatch_timeout: Option<Duration>,
+    ) -> (
+        UnboundedReceiver<TungsteniteMessage>,
+        UnboundedReceiver<Event>,
+    ) {
+        let listeners = Listeners::default();
+        let events = listeners.add(EventTypeFlags::SHARD_ZOMBIED);
+        let (tx, rx) = mpsc::unbounded();
+
+        let heartbeater = Heartbeater::new(
+            Arc::new(Heartbeats::default()),
+            10,
+            Arc::new(AtomicU64::new(1)),
+            tx,
+            ZombieDetection {
+                activity: Arc::new(Activity::default()),
+                dispatch_timeout,
+                emitter: Emitter::new(listeners),
+                shard_id: 3,
+            },
+        );
+        heartbeater.run().await;
+
+        (rx, events)
+    }
+
+    async fn assert_closed(rx: &mut UnboundedReceiver<TungsteniteMessage>) {
+        match rx.next().await {
+            Some(TungsteniteMessage::Close(Some(frame))) => {
+                assert_eq!(
+                    CloseCode::Library(Heartbeater::ZOMBIE_CLOSE_CODE),
+                    frame.code
+                );
+            }
+            other => panic!("expected close, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zombied_heartbeat_ack_missed() {
+        let (mut rx, mut events) = run(None).await;
+
+        // The first heartbeat is sent, but not acknowledged before the next.
+        assert!(matches!(
+            rx.next().await,
+            Some(TungsteniteMessage::Binary(_))
+        ));
+        assert_closed(&mut rx).await;
+
+        assert_eq!(
+            Some(Event::ShardZombied(Zombied {
+                reason: ZombieReason::HeartbeatAckMissed,
+                shard_id: 3,
+            })),
+            events.next().await
+        );
+    }
+
+    #[tokio::test]
+    async fn test_zombied_dispatch_silence() {
+        let (mut rx, mut events) = run(Some(Duration::from_millis(5))).await;
+
+        // No dispatch was received since starting, so no heartbeat is sent.
+        assert_closed(&mut rx).await;
+
+        assert_eq!(
+            Some(Event::ShardZombied(Zombied {
+                reason: ZombieReason::DispatchSilence,
+                shard_id: 3,
+            })),
+            events.next().await
+        );
+    }
 }
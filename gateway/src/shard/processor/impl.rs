@@ -1,11 +1,13 @@
 use super::{
     super::{
+        compression::{Compression, DecompressError},
         config::Config,
-        json::{self, GatewayEventParsingError},
+        json::GatewayEventParsingError,
         stage::Stage,
         ShardStream,
     },
     emitter::{EmitJsonError, Emitter},
+    heartbeat::ZombieDetection,
     inflater::Inflater,
     session::{Session, SessionSendError},
     socket_forwarder::SocketForwarder,
@@ -15,16 +17,14 @@ use async_tungstenite::tungstenite::{
     protocol::{frame::coding::CloseCode, CloseFrame},
     Error as TungsteniteError, Message,
 };
-use flate2::DecompressError;
 use futures_channel::mpsc::{TrySendError, UnboundedReceiver};
 use futures_util::stream::StreamExt;
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use std::{
     borrow::Cow,
     env::consts::OS,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
-    str::{self, Utf8Error},
     sync::{atomic::Ordering, Arc},
     time::Duration,
 };
@@ -34,7 +34,7 @@ use tokio::sync::watch::{
 use twilight_model::gateway::{
     event::{
         shard::{Connected, Connecting, Disconnected, Identifying, Reconnecting, Resuming},
-        DispatchEvent, Event, GatewayEvent, GatewayEventDeserializer,
+        DispatchEvent, Event, GatewayEvent,
     },
     payload::{
         identify::{Identify, IdentifyInfo, IdentifyProperties},
@@ -45,6 +45,17 @@ use twilight_model::gateway::{
 };
 use url::{ParseError as UrlParseError, Url};
 
+#[cfg(feature = "etf")]
+use super::super::etf;
+#[cfg(not(feature = "etf"))]
+use super::super::json;
+#[cfg(not(feature = "etf"))]
+use serde::Deserialize;
+#[cfg(not(feature = "etf"))]
+use std::str::{self, Utf8Error};
+#[cfg(not(feature = "etf"))]
+use twilight_model::gateway::event::GatewayEventDeserializer;
+
 /// Connecting to the gateway failed.
 #[derive(Debug)]
 #[non_exhaustive]
@@ -91,6 +102,7 @@ enum ProcessError {
     },
     /// The binary payload received from Discord wasn't validly encoded as
     /// UTF-8.
+    #[cfg(not(feature = "etf"))]
     PayloadNotUtf8 {
         /// Source error when converting to a UTF-8 valid string.
         source: Utf8Error,
@@ -124,6 +136,7 @@ impl Display for ProcessError {
                 event_type, op,
             )),
             Self::ParsingPayload { source } => Display::fmt(source, f),
+            #[cfg(not(feature = "etf"))]
             Self::PayloadNotUtf8 { .. } => {
                 f.write_str("the payload from Discord wasn't UTF-8 valid")
             }
@@ -138,6 +151,7 @@ impl Error for ProcessError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::ParsingPayload { source } => Some(source),
+            #[cfg(not(feature = "etf"))]
             Self::PayloadNotUtf8 { source } => Some(source),
             Self::SendingClose { source } => Some(source),
             Self::SessionSend { source } => Some(source),
@@ -205,7 +219,7 @@ impl Display for ReceivingEventError {
                 "the authorization token for shard {} is invalid",
                 shard_id
             )),
-            Self::Decompressing { .. } => f.write_str("a frame could not be decompressed"),
+            Self::Decompressing { source } => Display::fmt(source, f),
             Self::IntentsDisallowed { intents, shard_id } => f.write_fmt(format_args!(
                 "at least one of the intents ({:?}) for shard {} are disallowed",
                 intents, shard_id
@@ -221,6 +235,7 @@ impl Display for ReceivingEventError {
 
 impl Error for ReceivingEventError {}
 
+#[cfg(not(feature = "etf"))]
 #[derive(Deserialize)]
 struct ReadyMinimal {
     d: Ready,
@@ -262,7 +277,17 @@ impl ShardProcessor {
 
         let properties = IdentifyProperties::new("twilight.rs", "twilight.rs", OS, "", "");
 
-        url.push_str("?v=8&compress=zlib-stream");
+        let inflater = Inflater::new(shard_id, config.compression());
+
+        #[cfg(not(feature = "etf"))]
+        url.push_str("?v=9");
+        #[cfg(feature = "etf")]
+        url.push_str("?v=9&encoding=etf");
+
+        if let Some(compress) = inflater.compress_parameter() {
+            url.push_str("&compress=");
+            url.push_str(compress);
+        }
 
         let emitter = Emitter::new(listeners);
         emitter.event(Event::ShardConnecting(Connecting {
@@ -275,7 +300,7 @@ impl ShardProcessor {
             forwarder.run().await;
         });
 
-        let session = Arc::new(Session::new(tx));
+        let session = Arc::new(Session::new(tx, Arc::default()));
         if resumable {
             session.set_id(config.session_id.clone().unwrap());
             session
@@ -291,7 +316,7 @@ impl ShardProcessor {
             properties,
             rx,
             session,
-            inflater: Inflater::new(shard_id),
+            inflater,
             url: url.into_boxed_str(),
             resume: None,
             wtx,
@@ -308,7 +333,7 @@ impl ShardProcessor {
     pub async fn run(mut self) {
         loop {
             match self.next_payload().await {
-                Ok(v) => v,
+                Ok(()) => self.session.activity.receive(),
                 Err(source) => {
                     tracing::warn!("{}", source);
 
@@ -347,6 +372,7 @@ impl ShardProcessor {
         self.emitter.into_listeners().remove_all();
     }
 
+    #[cfg(not(feature = "etf"))]
     async fn process(&mut self) -> Result<(), ProcessError> {
         let (op, seq, event_type) = {
             let json = str::from_utf8_mut(self.inflater.buffer_mut())
@@ -413,6 +439,7 @@ impl ShardProcessor {
             }
 
             let seq = seq.ok_or(ProcessError::SequenceMissing)?;
+            self.session.activity.dispatch();
 
             if event_type.as_deref() == Some("RESUMED") {
                 self.process_resumed(seq);
@@ -456,6 +483,97 @@ impl ShardProcessor {
             })
     }
 
+    #[cfg(feature = "etf")]
+    async fn process(&mut self) -> Result<(), ProcessError> {
+        let payload = etf::Payload::from_slice(self.inflater.buffer_ref()).map_err(|source| {
+            tracing::warn!(
+                shard_id = self.config.shard()[0],
+                shard_total = self.config.shard()[1],
+                seq = self.session.seq(),
+                stage = ?self.session.stage(),
+                "received invalid etf payload",
+            );
+
+            ProcessError::ParsingPayload {
+                source: GatewayEventParsingError::DeserializingEtf { source },
+            }
+        })?;
+
+        let op = payload.op();
+        let seq = payload.sequence();
+
+        tracing::trace!(op, ?seq, event_type = ?payload.event_type(), "Received ETF");
+
+        // See the JSON implementation for the reasoning behind handling these
+        // opcodes separately.
+        if matches!(op, 1 | 7 | 9 | 10 | 11) {
+            let gateway_event = if op == OpCode::HeartbeatAck as u8 {
+                GatewayEvent::HeartbeatAck
+            } else if op == OpCode::Reconnect as u8 {
+                GatewayEvent::Reconnect
+            } else {
+                payload
+                    .into_gateway_event()
+                    .map_err(|source| ProcessError::ParsingPayload {
+                        source: GatewayEventParsingError::DeserializingEtf { source },
+                    })?
+            };
+
+            self.process_gateway_event(&gateway_event).await?;
+            self.emitter.event(Event::from(gateway_event));
+
+            if let Some(seq) = seq {
+                self.session.set_seq(seq);
+            }
+
+            return Ok(());
+        }
+
+        let seq = seq.ok_or(ProcessError::SequenceMissing)?;
+        self.session.activity.dispatch();
+
+        match payload.event_type() {
+            Some("RESUMED") => {
+                self.process_resumed(seq);
+
+                if self.emitter.wants(EventTypeFlags::RESUMED) {
+                    let gateway_event =
+                        GatewayEvent::Dispatch(seq, Box::new(DispatchEvent::Resumed));
+
+                    self.emitter.event(Event::from(gateway_event));
+                }
+
+                return Ok(());
+            }
+            Some("READY") => {
+                let gateway_event = payload.into_gateway_event().map_err(|source| {
+                    ProcessError::ParsingPayload {
+                        source: GatewayEventParsingError::DeserializingEtf { source },
+                    }
+                })?;
+
+                if let GatewayEvent::Dispatch(_, event) = gateway_event {
+                    if let DispatchEvent::Ready(ready) = *event {
+                        self.process_ready(&ready);
+                        self.emitter.event(Event::Ready(ready));
+                    }
+                }
+
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        self.session.set_seq(seq);
+
+        self.emitter.etf(payload).map_err(|source| match source {
+            EmitJsonError::Parsing { source } => ProcessError::ParsingPayload { source },
+            EmitJsonError::EventTypeUnknown { event_type, op } => {
+                ProcessError::EventTypeUnknown { event_type, op }
+            }
+        })
+    }
+
     fn process_ready(&mut self, ready: &Ready) {
         #[cfg(feature = "metrics")]
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "Dispatch");
@@ -538,7 +656,7 @@ impl ShardProcessor {
 
             if interval > 0 {
                 self.session.set_heartbeat_interval(interval);
-                self.session.start_heartbeater();
+                self.session.start_heartbeater(self.zombie_detection());
             }
 
             self.send(payload)
@@ -549,7 +667,7 @@ impl ShardProcessor {
 
             if interval > 0 {
                 self.session.set_heartbeat_interval(interval);
-                self.session.start_heartbeater();
+                self.session.start_heartbeater(self.zombie_detection());
             }
 
             self.identify()
@@ -642,21 +760,21 @@ impl ShardProcessor {
     /// then `true` is returned. The buffer can then be accessed via
     /// `self.inflater.buffer_ref()` or `buffer_mut()`.
     ///
+    /// Text messages are only sent by Discord when transport compression is
+    /// disabled. They are never compressed and are added to the inflater
+    /// buffer as-is.
+    ///
     /// If a close message is received then an error may be returned if fatal,
     /// or the connection may be resumed.
     ///
     /// If a ping or pong are received, then they are ignored.
-    ///
-    /// Text messages aren't sent by Discord, so they are left unhandled.
     async fn handle_message<'a>(
         &'a mut self,
         msg: &'a mut Message,
     ) -> Result<bool, ReceivingEventError> {
         match msg {
             Message::Binary(bin) => {
-                self.inflater.extend(&bin[..]);
-
-                let bytes = match self.inflater.msg() {
+                let bytes = match self.inflater.frame(&bin[..]) {
                     Ok(Some(bytes)) => bytes,
                     Ok(None) => return Ok(false),
                     Err(source) => return Err(ReceivingEventError::Decompressing { source }),
@@ -666,14 +784,19 @@ impl ShardProcessor {
 
                 Ok(true)
             }
+            Message::Text(text) => {
+                let bytes = self.inflater.uncompressed(text.as_bytes());
+
+                self.emitter.bytes(bytes);
+
+                Ok(true)
+            }
             Message::Close(close_frame) => {
                 self.handle_close(close_frame.as_ref()).await?;
 
                 Ok(false)
             }
-            // Discord doesn't appear to send Text messages, so we can ignore
-            // these.
-            Message::Ping(_) | Message::Pong(_) | Message::Text(_) => Ok(false),
+            Message::Ping(_) | Message::Pong(_) => Ok(false),
         }
     }
 
@@ -740,7 +863,7 @@ impl ShardProcessor {
         self.session.set_stage(Stage::Identifying);
 
         let identify = Identify::new(IdentifyInfo {
-            compress: false,
+            compress: matches!(self.config.compression(), Compression::Payload),
             large_threshold: self.config.large_threshold(),
             intents: self.config.intents(),
             properties: self.properties.clone(),
@@ -757,6 +880,16 @@ impl ShardProcessor {
         self.send(identify).await
     }
 
+    /// Details for the heartbeater to detect zombied connections with.
+    fn zombie_detection(&self) -> ZombieDetection {
+        ZombieDetection {
+            activity: Arc::clone(&self.session.activity),
+            dispatch_timeout: self.config.dispatch_timeout(),
+            emitter: self.emitter.clone(),
+            shard_id: self.config.shard()[0],
+        }
+    }
+
     /// Perform a full reconnect to the gateway, instantiating a new session.
     async fn reconnect(&mut self) {
         tracing::info!("reconnection started");
@@ -858,8 +991,11 @@ impl ShardProcessor {
 
         tokio::spawn(forwarder.run());
 
+        let activity = Arc::clone(&self.session.activity);
+        activity.reconnect();
+
         self.rx = rx;
-        self.session = Arc::new(Session::new(tx));
+        self.session = Arc::new(Session::new(tx, activity));
 
         if let Err(why) = self.wtx.send(Arc::clone(&self.session)) {
             tracing::error!("failed to broadcast new session: {:?}", why);
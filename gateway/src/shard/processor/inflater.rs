@@ -1,64 +1,51 @@
-use flate2::{Decompress, DecompressError, FlushDecompress};
+use super::super::compression::{Compression, DecompressError, Decompressor};
+use flate2::{Decompress, FlushDecompress, Status};
 use std::{convert::TryInto, mem, time::Instant};
 
 const ZLIB_SUFFIX: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
 const INTERNAL_BUFFER_SIZE: usize = 32 * 1024;
 
+/// Decompressor for a connection compressed as a single zlib stream.
 #[derive(Debug)]
-pub struct Inflater {
+pub struct ZlibStream {
     decompress: Decompress,
     compressed: Vec<u8>,
     internal_buffer: Vec<u8>,
-    buffer: Vec<u8>,
-    last_resize: Instant,
-    shard: [u64; 2],
 }
 
-impl Inflater {
-    /// Create a new inflater for a shard.
-    pub fn new(shard: [u64; 2]) -> Self {
+impl ZlibStream {
+    pub fn new() -> Self {
         Self {
-            buffer: Vec::with_capacity(INTERNAL_BUFFER_SIZE),
             compressed: Vec::new(),
             decompress: Decompress::new(true),
             internal_buffer: Vec::with_capacity(INTERNAL_BUFFER_SIZE),
-            last_resize: Instant::now(),
-            shard,
         }
     }
+}
 
-    /// Return an immutable reference to the buffer.
-    pub fn buffer_ref(&self) -> &[u8] {
-        self.buffer.as_slice()
-    }
-
-    /// Return a mutable reference to the buffer.
-    pub fn buffer_mut(&mut self) -> &mut [u8] {
-        self.buffer.as_mut_slice()
-    }
-
-    /// Extend the internal compressed buffer with bytes.
-    pub fn extend(&mut self, slice: &[u8]) {
-        self.compressed.extend_from_slice(&slice);
+impl Decompressor for ZlibStream {
+    fn compress_parameter(&self) -> Option<&str> {
+        Some("zlib-stream")
     }
 
     /// Decompress the next message if a complete payload was received.
     ///
-    /// Returns `None` if an incomplete payload was received.
+    /// Returns `false` if an incomplete payload was received.
     ///
     /// # Errors
     ///
     /// This returns `flate2`'s `DecompressError` as its method's type signature
     /// indicates it can return an error, however in reality in versions up to
     /// 1.0.17 it won't.
-    #[tracing::instrument(level = "trace")]
-    pub fn msg(&mut self) -> Result<Option<&mut [u8]>, DecompressError> {
+    fn decompress(&mut self, frame: &[u8], buffer: &mut Vec<u8>) -> Result<bool, DecompressError> {
+        self.compressed.extend_from_slice(frame);
+
         let length = self.compressed.len();
 
         // Check if a partial payload was received. If it was, we can just
         // return that no decompressed message is available.
         if length < 4 || self.compressed[(length - 4)..] != ZLIB_SUFFIX {
-            return Ok(None);
+            return Ok(false);
         }
 
         let before = self.decompress.total_in();
@@ -67,16 +54,18 @@ impl Inflater {
         loop {
             self.internal_buffer.clear();
 
-            self.decompress.decompress_vec(
-                &self.compressed[offset..],
-                &mut self.internal_buffer,
-                FlushDecompress::Sync,
-            )?;
+            self.decompress
+                .decompress_vec(
+                    &self.compressed[offset..],
+                    &mut self.internal_buffer,
+                    FlushDecompress::Sync,
+                )
+                .map_err(DecompressError::new)?;
 
             offset = (self.decompress.total_in() - before)
                 .try_into()
                 .unwrap_or_default();
-            self.buffer.extend_from_slice(&self.internal_buffer[..]);
+            buffer.extend_from_slice(&self.internal_buffer[..]);
 
             let not_at_capacity = self.internal_buffer.len() < self.internal_buffer.capacity();
 
@@ -87,28 +76,181 @@ impl Inflater {
 
         tracing::trace!(
             bytes_in = self.compressed.len(),
-            bytes_out = self.buffer.len(),
-            shard_id = self.shard[0],
-            shard_total = self.shard[1],
+            bytes_out = buffer.len(),
             "payload lengths",
         );
         self.compressed.clear();
 
+        Ok(true)
+    }
+
+    fn total_in(&self) -> u64 {
+        self.decompress.total_in()
+    }
+
+    fn total_out(&self) -> u64 {
+        self.decompress.total_out()
+    }
+
+    fn reset(&mut self) {
+        let _ = mem::replace(self, Self::new());
+    }
+}
+
+/// Decompressor for payloads individually compressed with zlib.
+///
+/// Discord only compresses large payloads, smaller ones are sent as text
+/// frames and don't go through the decompressor.
+#[derive(Debug)]
+pub struct ZlibPayload {
+    total_in: u64,
+    total_out: u64,
+}
+
+impl ZlibPayload {
+    pub fn new() -> Self {
+        Self {
+            total_in: 0,
+            total_out: 0,
+        }
+    }
+}
+
+impl Decompressor for ZlibPayload {
+    fn compress_parameter(&self) -> Option<&str> {
+        None
+    }
+
+    fn decompress(&mut self, frame: &[u8], buffer: &mut Vec<u8>) -> Result<bool, DecompressError> {
+        let mut decompress = Decompress::new(true);
+        buffer.reserve(frame.len() * 4);
+
+        loop {
+            let total_in = decompress.total_in();
+            let total_out = decompress.total_out();
+            let offset = total_in.try_into().unwrap_or_default();
+            let status = decompress
+                .decompress_vec(&frame[offset..], buffer, FlushDecompress::None)
+                .map_err(DecompressError::new)?;
+
+            if status == Status::StreamEnd {
+                break;
+            }
+
+            // A truncated or corrupt frame never reaches the end of the
+            // stream. A `BufError` alone only means that the buffer is full,
+            // so bail once the decompressor stops making progress.
+            if decompress.total_in() == total_in && decompress.total_out() == total_out {
+                return Err(DecompressError::new("the payload is truncated"));
+            }
+
+            buffer.reserve(INTERNAL_BUFFER_SIZE);
+        }
+
+        self.total_in += decompress.total_in();
+        self.total_out += decompress.total_out();
+
+        Ok(true)
+    }
+
+    fn total_in(&self) -> u64 {
+        self.total_in
+    }
+
+    fn total_out(&self) -> u64 {
+        self.total_out
+    }
+
+    fn reset(&mut self) {
+        let _ = mem::replace(self, Self::new());
+    }
+}
+
+/// Buffer of payloads received from the gateway, decompressing them according
+/// to the configured [`Compression`].
+#[derive(Debug)]
+pub struct Inflater {
+    buffer: Vec<u8>,
+    compression: &'static str,
+    decompressor: Option<Box<dyn Decompressor>>,
+    last_resize: Instant,
+    shard: [u64; 2],
+}
+
+impl Inflater {
+    /// Create a new inflater for a shard.
+    pub fn new(shard: [u64; 2], compression: &Compression) -> Self {
+        let decompressor: Option<Box<dyn Decompressor>> = match compression {
+            Compression::None => None,
+            Compression::Payload => Some(Box::new(ZlibPayload::new())),
+            Compression::ZlibStream => Some(Box::new(ZlibStream::new())),
+            Compression::Custom(new) => Some(new()),
+        };
+
+        Self {
+            buffer: Vec::with_capacity(INTERNAL_BUFFER_SIZE),
+            compression: compression.name(),
+            decompressor,
+            last_resize: Instant::now(),
+            shard,
+        }
+    }
+
+    /// Value of the `compress` query parameter to connect to the gateway with.
+    pub fn compress_parameter(&self) -> Option<&str> {
+        self.decompressor
+            .as_ref()
+            .and_then(|decompressor| decompressor.compress_parameter())
+    }
+
+    /// Return an immutable reference to the buffer.
+    pub fn buffer_ref(&self) -> &[u8] {
+        self.buffer.as_slice()
+    }
+
+    /// Return a mutable reference to the buffer.
+    pub fn buffer_mut(&mut self) -> &mut [u8] {
+        self.buffer.as_mut_slice()
+    }
+
+    /// Add a binary frame to the buffer, decompressing it if a decompressor is
+    /// configured.
+    ///
+    /// Returns `None` if an incomplete payload was received.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DecompressError`] if the decompressor failed to decompress
+    /// the frame.
+    #[tracing::instrument(level = "trace", skip(frame))]
+    pub fn frame(&mut self, frame: &[u8]) -> Result<Option<&mut [u8]>, DecompressError> {
+        let decompressor = match self.decompressor.as_mut() {
+            Some(decompressor) => decompressor,
+            None => return Ok(Some(self.uncompressed(frame))),
+        };
+
+        if !decompressor.decompress(frame, &mut self.buffer)? {
+            return Ok(None);
+        }
+
         // It doesn't matter if we lose precision for logging.
         #[allow(clippy::cast_precision_loss)]
-        let saved_percentage =
-            self.decompress.total_in() as f64 / self.decompress.total_out() as f64;
+        let saved_percentage = decompressor.total_in() as f64 / decompressor.total_out() as f64;
         let saved_percentage_readable = saved_percentage * 100.0;
 
-        let saved_kib = (self.decompress.total_out() - self.decompress.total_in()) / 1_024;
+        let saved_kib = decompressor
+            .total_out()
+            .saturating_sub(decompressor.total_in())
+            / 1_024;
 
         tracing::trace!(
+            compression = self.compression,
             saved_kib = saved_kib,
             saved_percentage = %saved_percentage_readable,
             shard_id = self.shard[0],
             shard_total = self.shard[1],
-            total_in = self.decompress.total_in(),
-            total_out = self.decompress.total_out(),
+            total_in = decompressor.total_in(),
+            total_out = decompressor.total_out(),
             "data saved",
         );
 
@@ -119,6 +261,16 @@ impl Inflater {
         Ok(Some(&mut self.buffer))
     }
 
+    /// Add an uncompressed payload to the buffer.
+    ///
+    /// This is used for text frames, which are never compressed, and binary
+    /// frames when compression is disabled.
+    pub fn uncompressed(&mut self, payload: &[u8]) -> &mut [u8] {
+        self.buffer.extend_from_slice(payload);
+
+        &mut self.buffer
+    }
+
     /// Clear the buffer and shrink it if the capacity is too large.
     ///
     /// If the capacity is 4 times larger than the buffer length then the
@@ -127,14 +279,17 @@ impl Inflater {
     pub fn clear(&mut self) {
         self.shrink();
 
-        self.compressed.clear();
-        self.internal_buffer.clear();
         self.buffer.clear();
     }
 
     /// Reset the state of the inflater back to its default state.
     pub fn reset(&mut self) {
-        let _ = mem::replace(self, Self::new(self.shard));
+        if let Some(decompressor) = self.decompressor.as_mut() {
+            decompressor.reset();
+        }
+
+        self.buffer = Vec::with_capacity(INTERNAL_BUFFER_SIZE);
+        self.last_resize = Instant::now();
     }
 
     /// Log metrics about the inflater.
@@ -144,32 +299,42 @@ impl Inflater {
             format!("Inflater-Capacity-{}", self.shard[0]),
             self.buffer.capacity().try_into().unwrap_or(-1)
         );
-        metrics::gauge!(
-            format!("Inflater-In-{}", self.shard[0]),
-            self.decompress.total_in().try_into().unwrap_or(-1)
-        );
-        metrics::gauge!(
-            format!("Inflater-Out-{}", self.shard[0]),
-            self.decompress.total_out().try_into().unwrap_or(-1)
-        );
+
+        if let Some(decompressor) = self.decompressor.as_ref() {
+            let total_in = decompressor.total_in();
+            let total_out = decompressor.total_out();
+
+            metrics::gauge!(
+                format!("Inflater-In-{}", self.shard[0]),
+                total_in.try_into().unwrap_or(-1),
+                "compression" => self.compression
+            );
+            metrics::gauge!(
+                format!("Inflater-Out-{}", self.shard[0]),
+                total_out.try_into().unwrap_or(-1),
+                "compression" => self.compression
+            );
+
+            // Ratio of compressed to decompressed bytes as a percentage.
+            if let Some(ratio) = (total_in * 100).checked_div(total_out) {
+                metrics::gauge!(
+                    format!("Inflater-Ratio-{}", self.shard[0]),
+                    ratio.try_into().unwrap_or(-1),
+                    "compression" => self.compression
+                );
+            }
+        }
     }
 
-    /// Shrink the capacity of the compressed buffer and payload buffer if at
-    /// least 60 seconds have passed since the last shrink.
+    /// Shrink the capacity of the payload buffer if at least 60 seconds have
+    /// passed since the last shrink.
     fn shrink(&mut self) {
         if self.last_resize.elapsed().as_secs() < 60 {
             return;
         }
 
-        self.compressed.shrink_to_fit();
         self.buffer.shrink_to_fit();
 
-        tracing::trace!(
-            capacity = self.compressed.capacity(),
-            shard_id = self.shard[0],
-            shard_total = self.shard[1],
-            "compressed capacity",
-        );
         tracing::trace!(
             capacity = self.buffer.capacity(),
             shard_id = self.shard[0],
@@ -180,3 +345,77 @@ impl Inflater {
         self.last_resize = Instant::now();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Compression, Inflater};
+    use flate2::{write::ZlibEncoder, Compression as Level};
+    use std::io::Write;
+
+    const PAYLOAD: &[u8] = br#"{"op":11,"d":null,"s":null,"t":null}"#;
+
+    #[test]
+    fn test_none() {
+        let mut inflater = Inflater::new([0, 1], &Compression::None);
+        assert!(inflater.compress_parameter().is_none());
+
+        assert_eq!(Some(PAYLOAD), inflater.frame(PAYLOAD).unwrap().as_deref());
+    }
+
+    #[test]
+    fn test_payload() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Level::default());
+        encoder.write_all(PAYLOAD).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut inflater = Inflater::new([0, 1], &Compression::Payload);
+        assert!(inflater.compress_parameter().is_none());
+
+        assert_eq!(
+            Some(PAYLOAD),
+            inflater.frame(&compressed).unwrap().as_deref()
+        );
+    }
+
+    #[test]
+    fn test_payload_larger_than_buffer() {
+        let payload = vec![b'a'; 1024 * 1024];
+        let mut encoder = ZlibEncoder::new(Vec::new(), Level::default());
+        encoder.write_all(&payload).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut inflater = Inflater::new([0, 1], &Compression::Payload);
+
+        assert_eq!(
+            Some(payload.as_slice()),
+            inflater.frame(&compressed).unwrap().as_deref()
+        );
+    }
+
+    #[test]
+    fn test_payload_truncated() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Level::default());
+        encoder.write_all(PAYLOAD).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut inflater = Inflater::new([0, 1], &Compression::Payload);
+
+        assert!(inflater.frame(&compressed[..compressed.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn test_zlib_stream() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Level::default());
+        encoder.write_all(PAYLOAD).unwrap();
+        encoder.flush().unwrap();
+        let compressed = encoder.get_ref().clone();
+
+        let mut inflater = Inflater::new([0, 1], &Compression::ZlibStream);
+        assert_eq!(Some("zlib-stream"), inflater.compress_parameter());
+
+        // A partial frame doesn't produce a payload.
+        let (first, second) = compressed.split_at(compressed.len() / 2);
+        assert!(inflater.frame(first).unwrap().is_none());
+        assert_eq!(Some(PAYLOAD), inflater.frame(second).unwrap().as_deref());
+    }
+}
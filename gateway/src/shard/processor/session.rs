@@ -1,6 +1,6 @@
 use super::{
-    super::{json, stage::Stage},
-    heartbeat::{Heartbeater, Heartbeats},
+    super::stage::Stage,
+    heartbeat::{Heartbeater, Heartbeats, ZombieDetection},
     throttle::Throttle,
 };
 use async_tungstenite::tungstenite::{protocol::CloseFrame, Message as TungsteniteMessage};
@@ -18,14 +18,14 @@ use std::{
         atomic::{AtomicU64, AtomicU8, Ordering},
         Arc, Mutex as MutexSync,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 use twilight_model::gateway::payload::Heartbeat;
 
-#[cfg(not(feature = "simd-json"))]
-use serde_json::Error as JsonError;
-#[cfg(feature = "simd-json")]
-use simd_json::Error as JsonError;
+#[cfg(feature = "etf")]
+use super::super::etf::{self as encoding, EtfError as EncodingError};
+#[cfg(not(feature = "etf"))]
+use super::super::json::{self as encoding, JsonError as EncodingError};
 
 #[derive(Debug)]
 pub enum SessionSendError {
@@ -33,7 +33,7 @@ pub enum SessionSendError {
         source: TrySendError<TungsteniteMessage>,
     },
     Serializing {
-        source: JsonError,
+        source: EncodingError,
     },
 }
 
@@ -55,8 +55,55 @@ impl Error for SessionSendError {
     }
 }
 
+/// Activity of a shard, kept across sessions.
+#[derive(Debug, Default)]
+pub struct Activity {
+    last_dispatch: MutexSync<Option<Instant>>,
+    last_event: MutexSync<Option<Instant>>,
+    reconnects: AtomicU64,
+}
+
+impl Activity {
+    /// Returns when the last dispatch event was received.
+    pub fn last_dispatch(&self) -> Option<Instant> {
+        *self.last_dispatch.lock().expect("last dispatch poisoned")
+    }
+
+    /// Records that a dispatch event was received.
+    pub fn dispatch(&self) {
+        self.last_dispatch
+            .lock()
+            .expect("last dispatch poisoned")
+            .replace(Instant::now());
+    }
+
+    /// Returns when the last payload was received.
+    pub fn last_event(&self) -> Option<Instant> {
+        *self.last_event.lock().expect("last event poisoned")
+    }
+
+    /// Records that a payload was received.
+    pub fn receive(&self) {
+        self.last_event
+            .lock()
+            .expect("last event poisoned")
+            .replace(Instant::now());
+    }
+
+    /// Returns the number of times a new connection was made after the first.
+    pub fn reconnects(&self) -> u64 {
+        self.reconnects.load(Ordering::Relaxed)
+    }
+
+    /// Records that a new connection was made.
+    pub fn reconnect(&self) {
+        self.reconnects.fetch_add(1, Ordering::Release);
+    }
+}
+
 #[derive(Debug)]
 pub struct Session {
+    pub activity: Arc<Activity>,
     // Needs to be Arc so it can be cloned in the `Drop` impl when spawned on
     // the runtime.
     pub heartbeater_handle: Arc<MutexSync<Option<AbortHandle>>>,
@@ -70,8 +117,9 @@ pub struct Session {
 }
 
 impl Session {
-    pub fn new(tx: UnboundedSender<TungsteniteMessage>) -> Self {
+    pub fn new(tx: UnboundedSender<TungsteniteMessage>, activity: Arc<Activity>) -> Self {
         Self {
+            activity,
             heartbeater_handle: Arc::new(MutexSync::new(None)),
             heartbeats: Arc::new(Heartbeats::default()),
             heartbeat_interval: AtomicU64::new(0),
@@ -95,8 +143,8 @@ impl Session {
     /// up. This will only happen when the shard has either not started or has
     /// already shutdown.
     pub fn send(&self, payload: impl Serialize) -> Result<(), SessionSendError> {
-        let bytes =
-            json::to_vec(&payload).map_err(|source| SessionSendError::Serializing { source })?;
+        let bytes = encoding::to_vec(&payload)
+            .map_err(|source| SessionSendError::Serializing { source })?;
 
         self.tx
             .unbounded_send(TungsteniteMessage::Binary(bytes))
@@ -165,12 +213,13 @@ impl Session {
         }
     }
 
-    pub fn start_heartbeater(&self) {
+    pub fn start_heartbeater(&self, zombie: ZombieDetection) {
         let interval = self.heartbeat_interval();
         let seq = Arc::clone(&self.seq);
         let heartbeats = Arc::clone(&self.heartbeats);
 
-        let heartbeater = Heartbeater::new(heartbeats, interval, seq, self.tx.clone()).run();
+        let heartbeater =
+            Heartbeater::new(heartbeats, interval, seq, self.tx.clone(), zombie).run();
         let (fut, handle) = future::abortable(heartbeater);
 
         tokio::spawn(fut);
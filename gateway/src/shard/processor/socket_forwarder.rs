@@ -6,6 +6,7 @@ use futures_util::{future::FutureExt, sink::SinkExt, stream::StreamExt};
 use std::time::Duration;
 
 pub struct SocketForwarder {
+    close_timeout: Duration,
     rx: UnboundedReceiver<Message>,
     pub stream: ShardStream,
     tx: UnboundedSender<Message>,
@@ -14,6 +15,9 @@ pub struct SocketForwarder {
 impl SocketForwarder {
     const TIMEOUT: Duration = Duration::from_secs(90);
 
+    /// How long to wait for the gateway to respond to a close sent by us.
+    const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
     pub fn new(
         stream: ShardStream,
     ) -> (Self, UnboundedReceiver<Message>, UnboundedSender<Message>) {
@@ -22,6 +26,7 @@ impl SocketForwarder {
 
         (
             Self {
+                close_timeout: Self::CLOSE_TIMEOUT,
                 rx: from_user,
                 stream,
                 tx: to_user,
@@ -34,18 +39,28 @@ impl SocketForwarder {
     pub async fn run(mut self) {
         tracing::debug!("starting driving loop");
 
+        let mut closing = false;
+
         // This seems to come from the `if let` in the macro and may be a false
         // positive.
         #[allow(clippy::mut_mut)]
         loop {
             let mut rx = self.rx.next();
             let mut stream = self.stream.next().fuse();
-            let mut timeout = Delay::new(Self::TIMEOUT).fuse();
+            // The gateway may not respond to a close initiated by us, such as
+            // when the connection is zombied, so don't wait as long for it.
+            let mut timeout = Delay::new(if closing {
+                self.close_timeout
+            } else {
+                Self::TIMEOUT
+            })
+            .fuse();
 
             futures_util::select! {
                 maybe_msg = rx => {
                     if let Some(msg) = maybe_msg {
                         tracing::trace!("sending message: {}", msg);
+                        closing |= msg.is_close();
 
                         if let Err(err) = self.stream.send(msg).await {
                             tracing::warn!("sending failed: {}", err);
@@ -88,3 +103,105 @@ impl SocketForwarder {
         tracing::debug!("Leaving loop");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{super::super::ShardStream, SocketForwarder};
+    use async_tungstenite::{
+        tokio::TokioAdapter,
+        tungstenite::{
+            protocol::{frame::coding::CloseCode, CloseFrame},
+            Message,
+        },
+        WebSocketStream,
+    };
+    use futures_util::stream::StreamExt;
+    use std::time::Duration;
+    use tokio::net::{TcpListener, TcpStream};
+
+    /// Connect to a local websocket server, returning both ends of the
+    /// connection.
+    async fn connect() -> (ShardStream, WebSocketStream<TokioAdapter<TcpStream>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+
+            async_tungstenite::tokio::accept_async(stream)
+                .await
+                .unwrap()
+        });
+
+        let (client, _) = async_tungstenite::tokio::connect_async(url).await.unwrap();
+
+        (client, server.await.unwrap())
+    }
+
+    fn close() -> Message {
+        Message::Close(Some(CloseFrame {
+            code: CloseCode::Restart,
+            reason: "".into(),
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_close_waits_for_gateway() {
+        let (client, mut server) = connect().await;
+        let (forwarder, mut rx, tx) = SocketForwarder::new(client);
+        tokio::spawn(forwarder.run());
+
+        // Reading the close makes the server respond to it.
+        let server = tokio::spawn(async move {
+            let mut received = Vec::new();
+
+            while let Some(Ok(message)) = server.next().await {
+                received.push(message);
+            }
+
+            received
+        });
+
+        tx.unbounded_send(close()).unwrap();
+
+        // The response of the gateway is still forwarded before the stream
+        // ends.
+        assert!(
+            matches!(rx.next().await, Some(Message::Close(Some(frame))) if frame.code == CloseCode::Normal)
+        );
+        assert!(rx.next().await.is_none());
+        assert_eq!(vec![close()], server.await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_close_times_out() {
+        let (client, _server) = connect().await;
+        let (mut forwarder, mut rx, tx) = SocketForwarder::new(client);
+        forwarder.close_timeout = Duration::from_millis(10);
+        tokio::spawn(forwarder.run());
+
+        // The server never responds to the close, such as when the connection
+        // is zombied.
+        tx.unbounded_send(close()).unwrap();
+
+        assert!(rx.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_forwards_messages() {
+        let (client, mut server) = connect().await;
+        let (forwarder, mut rx, tx) = SocketForwarder::new(client);
+        tokio::spawn(forwarder.run());
+
+        tx.unbounded_send(Message::Text("ping".to_owned())).unwrap();
+        assert_eq!(
+            Some(Message::Text("ping".to_owned())),
+            server.next().await.map(Result::unwrap)
+        );
+
+        futures_util::SinkExt::send(&mut server, Message::Text("pong".to_owned()))
+            .await
+            .unwrap();
+        assert_eq!(Some(Message::Text("pong".to_owned())), rx.next().await);
+    }
+}
@@ -435,6 +435,8 @@ impl Serialize for ErrorCode {
 #[serde(untagged)]
 pub enum ApiError {
     General(GeneralApiError),
+    /// An OAuth2 token request failed.
+    OAuth(OAuthApiError),
     /// Something was wrong with the input when sending a message.
     Message(MessageApiError),
     Ratelimited(RatelimitedApiError),
@@ -445,6 +447,7 @@ impl Display for ApiError {
         match self {
             Self::General(inner) => Display::fmt(inner, f),
             Self::Message(inner) => Display::fmt(inner, f),
+            Self::OAuth(inner) => Display::fmt(inner, f),
             Self::Ratelimited(inner) => Display::fmt(inner, f),
         }
     }
@@ -467,6 +470,31 @@ impl Display for GeneralApiError {
     }
 }
 
+/// An OAuth2 token request failed, such as because the code or refresh token
+/// was invalid.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct OAuthApiError {
+    /// Type of the error, such as `invalid_grant`.
+    pub error: String,
+    /// Human readable description of the error.
+    pub error_description: Option<String>,
+}
+
+impl Display for OAuthApiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("OAuth error ")?;
+        f.write_str(&self.error)?;
+
+        if let Some(description) = &self.error_description {
+            f.write_str(": ")?;
+            f.write_str(description)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// Sending a message failed because the provided fields contained invalid
 /// input.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -557,7 +585,7 @@ impl PartialEq for RatelimitedApiError {
 mod tests {
     use super::{
         ApiError, ErrorCode, GeneralApiError, MessageApiError, MessageApiErrorEmbedField,
-        RatelimitedApiError,
+        OAuthApiError, RatelimitedApiError,
     };
     use serde_test::Token;
 
@@ -620,6 +648,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_api_error_oauth() {
+        let expected = ApiError::OAuth(OAuthApiError {
+            error: "invalid_grant".to_owned(),
+            error_description: Some("Invalid \"code\" in request.".to_owned()),
+        });
+
+        serde_test::assert_tokens(
+            &expected,
+            &[
+                Token::Struct {
+                    name: "OAuthApiError",
+                    len: 2,
+                },
+                Token::Str("error"),
+                Token::Str("invalid_grant"),
+                Token::Str("error_description"),
+                Token::Some,
+                Token::Str("Invalid \"code\" in request."),
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn test_api_error_ratelimited() {
         let expected = RatelimitedApiError {
@@ -1,5 +1,8 @@
 use super::{Client, HttpsConnector, State};
-use crate::{ratelimiting::Ratelimiter, request::channel::allowed_mentions::AllowedMentions};
+use crate::{
+    middleware::Middleware, ratelimiting::Ratelimiter,
+    request::channel::allowed_mentions::AllowedMentions,
+};
 use hyper::{
     client::{Client as HyperClient, HttpConnector},
     header::HeaderMap,
@@ -17,6 +20,7 @@ pub struct ClientBuilder {
     pub(crate) ratelimiter: Option<Ratelimiter>,
     pub(crate) hyper_client: Option<HyperClient<HttpsConnector<HttpConnector>>>,
     pub(crate) default_headers: Option<HeaderMap>,
+    pub(crate) middleware: Vec<Box<dyn Middleware>>,
     pub(crate) timeout: Duration,
     pub(crate) token: Option<Box<str>>,
     pub(crate) use_http: bool,
@@ -52,6 +56,7 @@ impl ClientBuilder {
             state: Arc::new(State {
                 http,
                 default_headers: self.default_headers,
+                middleware: self.middleware,
                 proxy: self.proxy,
                 ratelimiter: self.ratelimiter,
                 timeout: self.timeout,
@@ -83,6 +88,19 @@ impl ClientBuilder {
         self
     }
 
+    /// Add a middleware to the client.
+    ///
+    /// The request hooks of middleware are called in the order that they are
+    /// added, and the response hooks in the reverse order. Refer to the
+    /// [`middleware`] module for more information.
+    ///
+    /// [`middleware`]: crate::middleware
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Box::new(middleware));
+
+        self
+    }
+
     /// Set the proxy to use for all HTTP(S) requests.
     ///
     /// **Note** that this isn't currently a traditional proxy, but is for
@@ -164,6 +182,7 @@ impl Default for ClientBuilder {
             default_allowed_mentions: None,
             hyper_client: None,
             default_headers: None,
+            middleware: Vec::new(),
             proxy: None,
             ratelimiter: Some(Ratelimiter::new()),
             timeout: Duration::from_secs(10),
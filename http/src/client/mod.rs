@@ -5,37 +5,55 @@ pub use self::builder::ClientBuilder;
 use crate::{
     api_error::{ApiError, ErrorCode},
     error::{Error, Result},
+    middleware::{Middleware, ResponseInfo},
     ratelimiting::{RatelimitHeaders, Ratelimiter},
     request::{
         channel::allowed_mentions::AllowedMentions,
-        guild::{create_guild::CreateGuildError, create_guild_channel::CreateGuildChannelError},
+        channel::stage::create_stage_instance::CreateStageInstanceError,
+        channel::thread::{
+            create_thread::CreateThreadError,
+            create_thread_from_message::CreateThreadFromMessageError,
+        },
+        guild::{
+            create_guild::CreateGuildError, create_guild_channel::CreateGuildChannelError,
+            scheduled_event::create_guild_scheduled_event::CreateGuildScheduledEventError,
+        },
         prelude::*,
+        template::{
+            create_guild_from_template::CreateGuildFromTemplateError,
+            create_template::CreateTemplateError,
+        },
         GetUserApplicationInfo, Request,
     },
+    response::Response as TypedResponse,
     API_VERSION,
 };
-use bytes::Bytes;
 use hyper::{
-    body::{self, Buf},
+    body::Buf,
     client::{Client as HyperClient, HttpConnector},
     header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
     Body, Method, Response, StatusCode,
 };
-use serde::de::DeserializeOwned;
 use std::{
     convert::TryFrom,
     fmt::{Debug, Formatter, Result as FmtResult},
+    mem,
     result::Result as StdResult,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::time;
 use twilight_model::{
-    guild::Permissions,
-    id::{ChannelId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, UserId, WebhookId},
+    channel::ChannelType,
+    guild::{scheduled_event::EntityType, Permissions},
+    id::{
+        ChannelId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, ScheduledEventId, UserId,
+        WebhookId,
+    },
+    oauth::AccessTokenResponse,
 };
 
 #[cfg(feature = "hyper-rustls")]
@@ -46,6 +64,7 @@ type HttpsConnector<T> = hyper_tls::HttpsConnector<T>;
 struct State {
     http: HyperClient<HttpsConnector<HttpConnector>, Body>,
     default_headers: Option<HeaderMap>,
+    middleware: Vec<Box<dyn Middleware>>,
     proxy: Option<Box<str>>,
     ratelimiter: Option<Ratelimiter>,
     timeout: Duration,
@@ -60,6 +79,7 @@ impl Debug for State {
         f.debug_struct("State")
             .field("http", &self.http)
             .field("default_headers", &self.default_headers)
+            .field("middleware", &self.middleware)
             .field("proxy", &self.proxy)
             .field("ratelimiter", &self.ratelimiter)
             .field("token", &self.token)
@@ -152,6 +172,19 @@ impl Client {
         ClientBuilder::new()
     }
 
+    /// Create a new client that makes requests on behalf of a user with an
+    /// OAuth2 access token.
+    ///
+    /// Refer to the [`oauth`] module for how to get an access token.
+    ///
+    /// [`oauth`]: crate::oauth
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "hyper-rustls", feature = "hyper-tls"))))]
+    pub fn from_access_token(token: &AccessTokenResponse) -> Self {
+        ClientBuilder::default()
+            .token(format!("Bearer {}", token.access_token))
+            .build()
+    }
+
     /// Retrieve an immutable reference to the token used by the client.
     ///
     /// If the initial token provided is not prefixed with `Bot `, it will be, and this method
@@ -192,6 +225,8 @@ impl Client {
     /// let audit_log = client
     /// // not done
     ///     .audit_log(guild_id)
+    ///     .await?
+    ///     .model()
     ///     .await?;
     /// # Ok(()) }
     /// ```
@@ -215,7 +250,7 @@ impl Client {
     /// #
     /// let guild_id = GuildId(1);
     ///
-    /// let bans = client.bans(guild_id).await?;
+    /// let bans = client.bans(guild_id).await?.model().await?;
     /// # Ok(()) }
     /// ```
     pub fn bans(&self, guild_id: GuildId) -> GetBans<'_> {
@@ -376,6 +411,8 @@ impl Client {
     ///     .channel_messages(channel_id)
     ///     .before(message_id)
     ///     .limit(limit)?
+    ///     .await?
+    ///     .model()
     ///     .await?;
     ///
     /// # Ok(()) }
@@ -484,6 +521,8 @@ impl Client {
     ///     .after(after)
     ///     .before(before)
     ///     .limit(25)?
+    ///     .await?
+    ///     .model()
     ///     .await?;
     /// # Ok(()) }
     /// ```
@@ -599,7 +638,7 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let info = client.gateway().await?;
+    /// let info = client.gateway().await?.model().await?;
     /// # Ok(()) }
     /// ```
     ///
@@ -613,7 +652,7 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     /// # let client = Client::new("my token");
     /// #
-    /// let info = client.gateway().authed().await?;
+    /// let info = client.gateway().authed().await?.model().await?;
     ///
     /// println!("URL: {}", info.url);
     /// println!("Recommended shards to use: {}", info.shards);
@@ -796,7 +835,7 @@ impl Client {
     /// #
     /// let guild_id = GuildId(100);
     /// let user_id = UserId(3000);
-    /// let members = client.guild_members(guild_id).after(user_id).await?;
+    /// let members = client.guild_members(guild_id).after(user_id).await?.model().await?;
     /// # Ok(()) }
     /// ```
     ///
@@ -936,6 +975,104 @@ impl Client {
         GetGuildWebhooks::new(self, guild_id)
     }
 
+    /// Get a template by its code.
+    pub fn get_template(&self, template_code: impl Into<String>) -> GetTemplate<'_> {
+        GetTemplate::new(self, template_code)
+    }
+
+    /// Create a new guild based on a template.
+    ///
+    /// The minimum length of the name is 2 UTF-16 characters and the maximum is 100 UTF-16
+    /// characters. This endpoint can only be used by bots in less than 10 guilds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use twilight_http::Client;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// # let client = Client::new("my token");
+    /// #
+    /// let template = client
+    ///     .get_template("hgM48av5Q69A")
+    ///     .await?
+    ///     .expect("template exists")
+    ///     .model()
+    ///     .await?;
+    ///
+    /// let guild = client
+    ///     .create_guild_from_template(template.code, "family guild")?
+    ///     .await?
+    ///     .model()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateGuildFromTemplateError::NameInvalid`] if the name length is too short or
+    /// too long.
+    ///
+    /// [`CreateGuildFromTemplateError::NameInvalid`]: crate::request::template::create_guild_from_template::CreateGuildFromTemplateError::NameInvalid
+    pub fn create_guild_from_template(
+        &self,
+        template_code: impl Into<String>,
+        name: impl Into<String>,
+    ) -> StdResult<CreateGuildFromTemplate<'_>, CreateGuildFromTemplateError> {
+        CreateGuildFromTemplate::new(self, template_code, name)
+    }
+
+    /// Get a list of the templates of a guild.
+    pub fn get_templates(&self, guild_id: GuildId) -> GetTemplates<'_> {
+        GetTemplates::new(self, guild_id)
+    }
+
+    /// Create a template from the current state of a guild.
+    ///
+    /// The minimum length of the name is 1 UTF-16 character and the maximum is 100 UTF-16
+    /// characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateTemplateError::NameInvalid`] if the name length is too short or too long.
+    ///
+    /// [`CreateTemplateError::NameInvalid`]: crate::request::template::create_template::CreateTemplateError::NameInvalid
+    pub fn create_template(
+        &self,
+        guild_id: GuildId,
+        name: impl Into<String>,
+    ) -> StdResult<CreateTemplate<'_>, CreateTemplateError> {
+        CreateTemplate::new(self, guild_id, name)
+    }
+
+    /// Sync a template to the current state of its guild.
+    pub fn sync_template(
+        &self,
+        guild_id: GuildId,
+        template_code: impl Into<String>,
+    ) -> SyncTemplate<'_> {
+        SyncTemplate::new(self, guild_id, template_code)
+    }
+
+    /// Update the name or description of a template.
+    pub fn update_template(
+        &self,
+        guild_id: GuildId,
+        template_code: impl Into<String>,
+    ) -> UpdateTemplate<'_> {
+        UpdateTemplate::new(self, guild_id, template_code)
+    }
+
+    /// Delete a template by its code.
+    pub fn delete_template(
+        &self,
+        guild_id: GuildId,
+        template_code: impl Into<String>,
+    ) -> DeleteTemplate<'_> {
+        DeleteTemplate::new(self, guild_id, template_code)
+    }
+
     /// Get information about an invite by its code.
     ///
     /// If [`with_counts`] is called, the returned invite will contain approximate member counts.
@@ -1011,6 +1148,8 @@ impl Client {
     ///     .create_message(channel_id)
     ///     .content("Twilight is best pony")?
     ///     .tts(true)
+    ///     .await?
+    ///     .model()
     ///     .await?;
     /// # Ok(()) }
     /// ```
@@ -1219,6 +1358,240 @@ impl Client {
         CreateTypingTrigger::new(self, channel_id)
     }
 
+    /// Start a thread that is not connected to a message.
+    ///
+    /// The minimum length of the name is 1 UTF-16 character and the maximum is
+    /// 100 UTF-16 characters. The kind must be one of the thread channel
+    /// types.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateThreadError::NameInvalid`] if the name length is too
+    /// short or too long.
+    ///
+    /// Returns [`CreateThreadError::TypeInvalid`] if the kind is not a thread.
+    ///
+    /// [`CreateThreadError::NameInvalid`]: crate::request::channel::thread::create_thread::CreateThreadError::NameInvalid
+    /// [`CreateThreadError::TypeInvalid`]: crate::request::channel::thread::create_thread::CreateThreadError::TypeInvalid
+    pub fn create_thread(
+        &self,
+        channel_id: ChannelId,
+        name: impl Into<String>,
+        kind: ChannelType,
+    ) -> StdResult<CreateThread<'_>, CreateThreadError> {
+        CreateThread::new(self, channel_id, name, kind)
+    }
+
+    /// Start a thread from an existing message.
+    ///
+    /// The minimum length of the name is 1 UTF-16 character and the maximum is
+    /// 100 UTF-16 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateThreadFromMessageError::NameInvalid`] if the name
+    /// length is too short or too long.
+    ///
+    /// [`CreateThreadFromMessageError::NameInvalid`]: crate::request::channel::thread::create_thread_from_message::CreateThreadFromMessageError::NameInvalid
+    pub fn create_thread_from_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: impl Into<String>,
+    ) -> StdResult<CreateThreadFromMessage<'_>, CreateThreadFromMessageError> {
+        CreateThreadFromMessage::new(self, channel_id, message_id, name)
+    }
+
+    /// Update a thread.
+    ///
+    /// All fields are optional.
+    pub fn update_thread(&self, channel_id: ChannelId) -> UpdateThread<'_> {
+        UpdateThread::new(self, channel_id)
+    }
+
+    /// Get the active threads of a guild.
+    pub fn active_threads(&self, guild_id: GuildId) -> GetActiveThreads<'_> {
+        GetActiveThreads::new(self, guild_id)
+    }
+
+    /// Get the archived public threads of a channel.
+    pub fn public_archived_threads(&self, channel_id: ChannelId) -> GetPublicArchivedThreads<'_> {
+        GetPublicArchivedThreads::new(self, channel_id)
+    }
+
+    /// Get the archived private threads of a channel.
+    pub fn private_archived_threads(&self, channel_id: ChannelId) -> GetPrivateArchivedThreads<'_> {
+        GetPrivateArchivedThreads::new(self, channel_id)
+    }
+
+    /// Get the archived private threads of a channel that the current user
+    /// has joined.
+    pub fn joined_private_archived_threads(
+        &self,
+        channel_id: ChannelId,
+    ) -> GetJoinedPrivateArchivedThreads<'_> {
+        GetJoinedPrivateArchivedThreads::new(self, channel_id)
+    }
+
+    /// Get the members of a thread.
+    pub fn thread_members(&self, channel_id: ChannelId) -> GetThreadMembers<'_> {
+        GetThreadMembers::new(self, channel_id)
+    }
+
+    /// Add a user to a thread.
+    pub fn add_thread_member(&self, channel_id: ChannelId, user_id: UserId) -> AddThreadMember<'_> {
+        AddThreadMember::new(self, channel_id, user_id)
+    }
+
+    /// Remove a user from a thread.
+    pub fn remove_thread_member(
+        &self,
+        channel_id: ChannelId,
+        user_id: UserId,
+    ) -> RemoveThreadMember<'_> {
+        RemoveThreadMember::new(self, channel_id, user_id)
+    }
+
+    /// Add the current user to a thread.
+    pub fn join_thread(&self, channel_id: ChannelId) -> JoinThread<'_> {
+        JoinThread::new(self, channel_id)
+    }
+
+    /// Remove the current user from a thread.
+    pub fn leave_thread(&self, channel_id: ChannelId) -> LeaveThread<'_> {
+        LeaveThread::new(self, channel_id)
+    }
+
+    /// Create a stage instance in a stage channel, starting a live stage.
+    ///
+    /// The minimum length of the topic is 1 UTF-16 character and the maximum
+    /// is 120 UTF-16 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateStageInstanceError::TopicInvalid`] if the topic length
+    /// is too short or too long.
+    ///
+    /// [`CreateStageInstanceError::TopicInvalid`]: crate::request::channel::stage::create_stage_instance::CreateStageInstanceError::TopicInvalid
+    pub fn create_stage_instance(
+        &self,
+        channel_id: ChannelId,
+        topic: impl Into<String>,
+    ) -> StdResult<CreateStageInstance<'_>, CreateStageInstanceError> {
+        CreateStageInstance::new(self, channel_id, topic)
+    }
+
+    /// Get the stage instance of a stage channel.
+    pub fn stage_instance(&self, channel_id: ChannelId) -> GetStageInstance<'_> {
+        GetStageInstance::new(self, channel_id)
+    }
+
+    /// Update the stage instance of a stage channel.
+    ///
+    /// All fields are optional. Refer to [the discord docs] for more
+    /// information.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/resources/stage-instance#update-stage-instance
+    pub fn update_stage_instance(&self, channel_id: ChannelId) -> UpdateStageInstance<'_> {
+        UpdateStageInstance::new(self, channel_id)
+    }
+
+    /// Delete the stage instance of a stage channel, ending the live stage.
+    pub fn delete_stage_instance(&self, channel_id: ChannelId) -> DeleteStageInstance<'_> {
+        DeleteStageInstance::new(self, channel_id)
+    }
+
+    /// Update the current user's voice state in a stage channel, such as to
+    /// request to speak or to move to the audience.
+    pub fn update_current_user_voice_state(
+        &self,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+    ) -> UpdateCurrentUserVoiceState<'_> {
+        UpdateCurrentUserVoiceState::new(self, guild_id, channel_id)
+    }
+
+    /// Update another user's voice state in a stage channel, such as to invite
+    /// them to speak or to move them to the audience.
+    pub fn update_user_voice_state(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        channel_id: ChannelId,
+    ) -> UpdateUserVoiceState<'_> {
+        UpdateUserVoiceState::new(self, guild_id, user_id, channel_id)
+    }
+
+    /// Create a scheduled event in a guild.
+    ///
+    /// The minimum length of the name is 1 UTF-16 character and the maximum
+    /// is 100 UTF-16 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateGuildScheduledEventError::NameInvalid`] if the name
+    /// length is too short or too long.
+    ///
+    /// [`CreateGuildScheduledEventError::NameInvalid`]: crate::request::guild::scheduled_event::create_guild_scheduled_event::CreateGuildScheduledEventError::NameInvalid
+    pub fn create_guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        entity_type: EntityType,
+        name: impl Into<String>,
+        scheduled_start_time: impl Into<String>,
+    ) -> StdResult<CreateGuildScheduledEvent<'_>, CreateGuildScheduledEventError> {
+        CreateGuildScheduledEvent::new(self, guild_id, entity_type, name, scheduled_start_time)
+    }
+
+    /// Get the scheduled events of a guild.
+    pub fn guild_scheduled_events(&self, guild_id: GuildId) -> GetGuildScheduledEvents<'_> {
+        GetGuildScheduledEvents::new(self, guild_id)
+    }
+
+    /// Get a scheduled event in a guild.
+    pub fn guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> GetGuildScheduledEvent<'_> {
+        GetGuildScheduledEvent::new(self, guild_id, scheduled_event_id)
+    }
+
+    /// Update a scheduled event in a guild.
+    ///
+    /// All fields are optional. Refer to [the discord docs] for more
+    /// information.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/resources/guild-scheduled-event#modify-guild-scheduled-event
+    pub fn update_guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> UpdateGuildScheduledEvent<'_> {
+        UpdateGuildScheduledEvent::new(self, guild_id, scheduled_event_id)
+    }
+
+    /// Delete a scheduled event in a guild.
+    pub fn delete_guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> DeleteGuildScheduledEvent<'_> {
+        DeleteGuildScheduledEvent::new(self, guild_id, scheduled_event_id)
+    }
+
+    /// Get the users subscribed to a scheduled event in a guild.
+    ///
+    /// The upper limit of users returned is 100; further users can be fetched
+    /// with [`GetGuildScheduledEventUsers::after`].
+    pub fn guild_scheduled_event_users(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> GetGuildScheduledEventUsers<'_> {
+        GetGuildScheduledEventUsers::new(self, guild_id, scheduled_event_id)
+    }
+
     /// Create a group DM.
     ///
     /// This endpoint is limited to 10 active group DMs.
@@ -1305,6 +1678,8 @@ impl Client {
     ///
     /// let webhook = client
     ///     .create_webhook(channel_id, "Twily Bot")
+    ///     .await?
+    ///     .model()
     ///     .await?;
     /// # Ok(()) }
     /// ```
@@ -1426,10 +1801,6 @@ impl Client {
     /// invalid due to expiration, revokation, etc.
     #[allow(clippy::too_many_lines)]
     pub async fn raw(&self, request: Request) -> Result<Response<Body>> {
-        if self.state.token_invalid.load(Ordering::Relaxed) {
-            return Err(Error::Unauthorized);
-        }
-
         let Request {
             body,
             form,
@@ -1439,6 +1810,16 @@ impl Client {
             path_str: path,
         } = request;
 
+        // Requests that authenticate themselves, such as OAuth2 token
+        // requests, don't use the client's token.
+        let self_authenticated = req_headers
+            .as_ref()
+            .map_or(false, |headers| headers.contains_key(AUTHORIZATION));
+
+        if !self_authenticated && self.state.token_invalid.load(Ordering::Relaxed) {
+            return Err(Error::Unauthorized);
+        }
+
         let protocol = if self.state.use_http { "http" } else { "https" };
         let host = self.state.proxy.as_deref().unwrap_or("discord.com");
 
@@ -1447,7 +1828,7 @@ impl Client {
 
         let mut builder = hyper::Request::builder().method(method.clone()).uri(&url);
 
-        if let Some(ref token) = self.state.token {
+        if let (false, Some(token)) = (self_authenticated, self.state.token.as_ref()) {
             let value = HeaderValue::from_str(&token).map_err(|source| {
                 #[allow(clippy::borrow_interior_mutable_const)]
                 let name = AUTHORIZATION.to_string();
@@ -1488,25 +1869,26 @@ impl Client {
             }
         }
 
-        let req = if let Some(form) = form {
+        let mut req = if let Some(form) = form {
             let content_type = HeaderValue::try_from(form.content_type());
-            let form_bytes = form.build();
+            let (form_body, len) = form.build();
             if let Some(headers) = builder.headers_mut() {
                 if let Ok(content_type) = content_type {
                     headers.insert(CONTENT_TYPE, content_type);
                 }
-                headers.insert(CONTENT_LENGTH, form_bytes.len().into());
+                headers.insert(CONTENT_LENGTH, len.into());
             };
             builder
-                .body(Body::from(form_bytes))
+                .body(form_body)
                 .map_err(|source| Error::BuildingRequest { source })?
         } else if let Some(bytes) = body {
             let len = bytes.len();
 
             if let Some(headers) = builder.headers_mut() {
                 headers.insert(CONTENT_LENGTH, len.into());
-                let content_type = HeaderValue::from_static("application/json");
-                headers.insert(CONTENT_TYPE, content_type);
+                headers
+                    .entry(CONTENT_TYPE)
+                    .or_insert_with(|| HeaderValue::from_static("application/json"));
             }
 
             builder
@@ -1526,33 +1908,57 @@ impl Client {
                 .map_err(|source| Error::BuildingRequest { source })?
         };
 
-        let inner = self.state.http.request(req);
-        let fut = time::timeout(self.state.timeout, inner);
+        for middleware in &self.state.middleware {
+            middleware
+                .request(&mut req, &bucket)
+                .map_err(|source| Error::Middleware { source })?;
+        }
+
+        let extensions = mem::take(req.extensions_mut());
+
+        let tx = match self.state.ratelimiter.as_ref() {
+            Some(ratelimiter) => {
+                let rx = ratelimiter.get(bucket.clone()).await;
 
-        let ratelimiter = match self.state.ratelimiter.as_ref() {
-            Some(ratelimiter) => ratelimiter,
-            None => {
-                return fut
-                    .await
-                    .map_err(|source| Error::RequestTimedOut { source })?
-                    .map_err(|source| Error::RequestError { source })
+                Some(
+                    rx.await
+                        .map_err(|source| Error::RequestCanceled { source })?,
+                )
             }
+            None => None,
         };
 
-        let rx = ratelimiter.get(bucket).await;
-        let tx = rx
+        let started = Instant::now();
+        let result = time::timeout(self.state.timeout, self.state.http.request(req))
             .await
-            .map_err(|source| Error::RequestCanceled { source })?;
+            .map_err(|source| Error::RequestTimedOut { source })
+            .and_then(|result| result.map_err(|source| Error::RequestError { source }));
+
+        if !self.state.middleware.is_empty() {
+            let info = ResponseInfo {
+                elapsed: started.elapsed(),
+                extensions: &extensions,
+                method: &method,
+                path: &bucket,
+                response: result.as_ref().ok(),
+            };
 
-        let resp = fut
-            .await
-            .map_err(|source| Error::RequestTimedOut { source })?
-            .map_err(|source| Error::RequestError { source })?;
+            for middleware in self.state.middleware.iter().rev() {
+                middleware.response(&info);
+            }
+        }
+
+        let resp = result?;
+
+        let tx = match tx {
+            Some(tx) => tx,
+            None => return Ok(resp),
+        };
 
         // If the API sent back an Unauthorized response, then the client's
         // configured token is permanently invalid and future requests must be
         // ignored to avoid API bans.
-        if resp.status() == StatusCode::UNAUTHORIZED {
+        if !self_authenticated && resp.status() == StatusCode::UNAUTHORIZED {
             self.state.token_invalid.store(true, Ordering::Relaxed);
         }
 
@@ -1570,50 +1976,38 @@ impl Client {
         Ok(resp)
     }
 
-    /// Execute a request, chunking and deserializing the response.
+    /// Execute a request, returning the response once its status and headers
+    /// are received.
+    ///
+    /// The body of the response can be deserialized into `T` with
+    /// [`Response::model`].
     ///
     /// # Errors
     ///
     /// Returns [`Error::Unauthorized`] if the configured token has become
     /// invalid due to expiration, revokation, etc.
-    pub async fn request<T: DeserializeOwned>(&self, request: Request) -> Result<T> {
-        let resp = self.make_request(request).await?;
-
-        let mut buf = body::aggregate(resp.into_body())
-            .await
-            .map_err(|source| Error::ChunkingResponse { source })?;
-
-        let mut bytes = vec![0; buf.remaining()];
-        buf.copy_to_slice(&mut bytes);
-
-        let result = crate::json_from_slice(&mut bytes);
-
-        result.map_err(|source| Error::Parsing {
-            body: bytes.to_vec(),
-            source,
-        })
-    }
-
-    pub(crate) async fn request_bytes(&self, request: Request) -> Result<Bytes> {
+    ///
+    /// Returns [`Error::Response`] if the response wasn't a success.
+    ///
+    /// [`Response::model`]: crate::response::Response::model
+    pub async fn request<T>(&self, request: Request) -> Result<TypedResponse<T>> {
         let resp = self.make_request(request).await?;
 
-        hyper::body::to_bytes(resp.into_body())
-            .await
-            .map_err(|source| Error::ChunkingResponse { source })
+        Ok(TypedResponse::new(resp))
     }
 
     /// Execute a request, checking only that the response was a success.
     ///
-    /// This will not chunk and deserialize the body of the response.
+    /// The body of the response is expected to be empty.
     ///
     /// # Errors
     ///
     /// Returns [`Error::Unauthorized`] if the configured token has become
     /// invalid due to expiration, revokation, etc.
-    pub async fn verify(&self, request: Request) -> Result<()> {
-        self.make_request(request).await?;
-
-        Ok(())
+    ///
+    /// Returns [`Error::Response`] if the response wasn't a success.
+    pub async fn verify(&self, request: Request) -> Result<TypedResponse<()>> {
+        self.request(request).await
     }
 
     async fn make_request(&self, request: Request) -> Result<Response<Body>> {
@@ -1669,6 +2063,7 @@ impl From<HyperClient<HttpsConnector<HttpConnector>>> for Client {
             state: Arc::new(State {
                 http: hyper_client,
                 default_headers: None,
+                middleware: Vec::new(),
                 proxy: None,
                 ratelimiter: Some(Ratelimiter::new()),
                 timeout: Duration::from_secs(10),
@@ -37,6 +37,10 @@ pub enum Error {
     Json {
         source: JsonError,
     },
+    /// A middleware stopped the request from being sent.
+    Middleware {
+        source: Box<dyn StdError + Send + Sync>,
+    },
     Parsing {
         body: Vec<u8>,
         source: JsonError,
@@ -95,6 +99,7 @@ impl Display for Error {
             }
             Self::Formatting { .. } => f.write_str("Formatting a string failed"),
             Self::Json { .. } => f.write_str("Given value couldn't be serialized"),
+            Self::Middleware { .. } => f.write_str("a middleware stopped the request"),
             Self::Parsing { body, .. } => {
                 write!(f, "Response body couldn't be deserialized: {:?}", body)
             }
@@ -124,6 +129,7 @@ impl StdError for Error {
             Self::CreatingHeader { source, .. } => Some(source),
             Self::Formatting { source } => Some(source),
             Self::Json { source } | Self::Parsing { source, .. } => Some(source),
+            Self::Middleware { source } => Some(&**source),
             Self::Ratelimiting { source } => Some(source),
             Self::RequestCanceled { source } => Some(source),
             Self::ChunkingResponse { source } | Self::RequestError { source } => Some(source),
@@ -36,6 +36,15 @@
 //! twilight-http = { default-features = false, features = ["rustls", "simd-json"], version = "0.2" }
 //! ```
 //!
+//! ### Metrics
+//!
+//! The `metrics` feature provides the [`Metrics`] middleware, which provides
+//! metrics information via the `metrics` crate. The metrics logged are a counter
+//! of requests and a histogram of their durations, labelled with their methods
+//! and status codes.
+//!
+//! This is disabled by default.
+//!
 //! ### TLS
 //!
 //! `twilight-http` has features to enable [`hyper`]'s TLS features. These
@@ -60,6 +69,7 @@
 //!
 //! This is enabled by default.
 //!
+//! [`Metrics`]: https://docs.rs/twilight-http/*/twilight_http/middleware/struct.Metrics.html
 //! [`native-tls`]: https://crates.io/crates/native-tls
 //! [`hyper`]: https://crates.io/crates/hyper
 //! [`rustls`]: https://crates.io/crates/rustls
@@ -94,16 +104,20 @@
 pub mod api_error;
 pub mod client;
 pub mod error;
+pub mod middleware;
+pub mod oauth;
 pub mod ratelimiting;
 pub mod request;
+pub mod response;
 pub mod routing;
 
 /// Discord API version used by this crate.
-pub const API_VERSION: u8 = 8;
+pub const API_VERSION: u8 = 9;
 
 pub use crate::{
     client::Client,
     error::{Error, Result},
+    response::Response,
 };
 
 #[cfg(not(feature = "simd-json"))]
@@ -0,0 +1,421 @@
+//! Hooks for inspecting and modifying the requests of a [`Client`] and
+//! observing their responses.
+//!
+//! Middleware is configured through [`ClientBuilder::middleware`]. The
+//! [`Middleware::request`] hooks are called in the order that the middleware
+//! was added to the builder, and the [`Middleware::response`] hooks are called
+//! in the reverse order.
+//!
+//! # Examples
+//!
+//! Add a header to every request and count the failed responses:
+//!
+//! ```rust
+//! use hyper::{header::HeaderValue, Body, Request};
+//! use std::{
+//!     error::Error,
+//!     sync::atomic::{AtomicU64, Ordering},
+//! };
+//! use twilight_http::{
+//!     middleware::{Middleware, ResponseInfo, Tracing},
+//!     routing::Path,
+//!     Client,
+//! };
+//!
+//! #[derive(Debug, Default)]
+//! struct Failures(AtomicU64);
+//!
+//! impl Middleware for Failures {
+//!     fn request(
+//!         &self,
+//!         request: &mut Request<Body>,
+//!         _: &Path,
+//!     ) -> Result<(), Box<dyn Error + Send + Sync>> {
+//!         request
+//!             .headers_mut()
+//!             .insert("x-proxy-tenant", HeaderValue::from_static("twilight"));
+//!
+//!         Ok(())
+//!     }
+//!
+//!     fn response(&self, info: &ResponseInfo<'_>) {
+//!         if !info.status().map_or(false, |status| status.is_success()) {
+//!             self.0.fetch_add(1, Ordering::Relaxed);
+//!         }
+//!     }
+//! }
+//!
+//! let client = Client::builder()
+//!     .token("my token")
+//!     .middleware(Tracing)
+//!     .middleware(Failures::default())
+//!     .build();
+//! ```
+//!
+//! [`Client`]: crate::Client
+//! [`ClientBuilder::middleware`]: crate::client::ClientBuilder::middleware
+
+use crate::routing::Path;
+use hyper::{http::Extensions, Body, Method, Request, Response, StatusCode};
+use std::{error::Error, fmt::Debug, time::Duration};
+use tracing::{field, Span};
+
+/// Hook into the requests of a client and their responses.
+///
+/// Both methods do nothing by default.
+pub trait Middleware: Debug + Send + Sync {
+    /// Inspect or modify a request before it's sent.
+    ///
+    /// This is called before waiting for the ratelimiter. Values inserted
+    /// into the extensions of the request are available to
+    /// [`response`] through [`ResponseInfo::extensions`].
+    ///
+    /// # Errors
+    ///
+    /// Returning an error stops the request from being sent, and the client
+    /// returns [`Error::Middleware`] with the error as its source.
+    ///
+    /// [`Error::Middleware`]: crate::Error::Middleware
+    /// [`response`]: Self::response
+    fn request(
+        &self,
+        request: &mut Request<Body>,
+        path: &Path,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let _ = (request, path);
+
+        Ok(())
+    }
+
+    /// Observe the response of a request, or that it failed to be sent.
+    fn response(&self, info: &ResponseInfo<'_>) {
+        let _ = info;
+    }
+}
+
+/// Information about the response of a request.
+#[derive(Debug)]
+pub struct ResponseInfo<'a> {
+    pub(crate) elapsed: Duration,
+    pub(crate) extensions: &'a Extensions,
+    pub(crate) method: &'a Method,
+    pub(crate) path: &'a Path,
+    pub(crate) response: Option<&'a Response<Body>>,
+}
+
+impl<'a> ResponseInfo<'a> {
+    /// Return how long the request took, excluding waiting for the
+    /// ratelimiter.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Return the extensions of the request, inserted by the middleware.
+    pub fn extensions(&self) -> &'a Extensions {
+        self.extensions
+    }
+
+    /// Return the method of the request.
+    pub fn method(&self) -> &'a Method {
+        self.method
+    }
+
+    /// Return the ratelimiting bucket path of the request.
+    pub fn path(&self) -> &'a Path {
+        self.path
+    }
+
+    /// Return the response, if the request didn't fail or time out.
+    pub fn response(&self) -> Option<&'a Response<Body>> {
+        self.response
+    }
+
+    /// Return the status code of the response, if the request didn't fail or
+    /// time out.
+    pub fn status(&self) -> Option<StatusCode> {
+        self.response.map(Response::status)
+    }
+}
+
+/// Middleware creating a `tracing` span for each request.
+///
+/// The span is created at the debug level when the request is sent and is
+/// closed when the response is received, recording its status code and
+/// duration.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Tracing;
+
+impl Middleware for Tracing {
+    fn request(
+        &self,
+        request: &mut Request<Body>,
+        path: &Path,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let span = tracing::debug_span!(
+            "http request",
+            method = %request.method(),
+            path = ?path,
+            status = field::Empty,
+            elapsed_ms = field::Empty,
+        );
+        span.in_scope(|| tracing::debug!(uri = %request.uri(), "sending request"));
+        request.extensions_mut().insert(span);
+
+        Ok(())
+    }
+
+    fn response(&self, info: &ResponseInfo<'_>) {
+        let span = match info.extensions().get::<Span>() {
+            Some(span) => span,
+            None => return,
+        };
+
+        span.record("elapsed_ms", &(info.elapsed().as_millis() as u64));
+
+        match info.status() {
+            Some(status) => {
+                span.record("status", &status.as_u16());
+                span.in_scope(|| tracing::debug!("received response"));
+            }
+            None => span.in_scope(|| tracing::debug!("request failed")),
+        }
+    }
+}
+
+/// Middleware providing metrics of requests via the `metrics` crate.
+///
+/// Counts requests as `Http-Request` and records their duration as the timing
+/// `Http-Request-Duration`, both labelled with the method and status code of
+/// the request. The status code of failed requests is "error".
+///
+/// This requires the `metrics` feature.
+#[cfg(feature = "metrics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Metrics;
+
+#[cfg(feature = "metrics")]
+impl Middleware for Metrics {
+    fn response(&self, info: &ResponseInfo<'_>) {
+        let method = info.method().to_string();
+        let status = info
+            .status()
+            .map_or_else(|| "error".to_owned(), |status| status.as_u16().to_string());
+
+        metrics::counter!(
+            "Http-Request",
+            1,
+            "method" => method.clone(),
+            "status" => status.clone()
+        );
+        metrics::timing!(
+            "Http-Request-Duration",
+            info.elapsed(),
+            "method" => method,
+            "status" => status
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Middleware, ResponseInfo, Tracing};
+    use crate::{
+        request::Request as TwilightRequest,
+        routing::{Path, Route},
+        Client, Error,
+    };
+    use hyper::{
+        http::Extensions,
+        service::{make_service_fn, service_fn},
+        Body, Method, Request, Response, Server, StatusCode,
+    };
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{
+        convert::Infallible,
+        error::Error as StdError,
+        fmt::Debug,
+        net::{SocketAddr, TcpListener},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        time::Duration,
+    };
+    use tracing::Span;
+
+    /// Middleware recording when its hooks are called into a shared log.
+    #[derive(Debug)]
+    struct Record {
+        fail: bool,
+        log: Arc<Mutex<Vec<String>>>,
+        name: &'static str,
+    }
+
+    impl Middleware for Record {
+        fn request(
+            &self,
+            _: &mut Request<Body>,
+            _: &Path,
+        ) -> Result<(), Box<dyn StdError + Send + Sync>> {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} request", self.name));
+
+            if self.fail {
+                return Err("injected failure".into());
+            }
+
+            Ok(())
+        }
+
+        fn response(&self, info: &ResponseInfo<'_>) {
+            self.log.lock().unwrap().push(format!(
+                "{} response {:?}",
+                self.name,
+                info.status().map(|status| status.as_u16())
+            ));
+        }
+    }
+
+    /// Address of a mocked API responding with 204 to every request, along
+    /// with the number of requests it received.
+    fn server() -> (SocketAddr, Arc<AtomicUsize>) {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+
+        let make_service = make_service_fn(move |_| {
+            let counter = Arc::clone(&counter);
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |_: Request<Body>| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+
+                    async {
+                        let mut response = Response::new(Body::empty());
+                        *response.status_mut() = StatusCode::NO_CONTENT;
+
+                        Ok::<_, Infallible>(response)
+                    }
+                }))
+            }
+        });
+
+        let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+        let addr = server.local_addr();
+        tokio::spawn(server);
+
+        (addr, requests)
+    }
+
+    fn client(addr: SocketAddr, log: &Arc<Mutex<Vec<String>>>, fail: bool) -> Client {
+        Client::builder()
+            .proxy(addr.to_string(), true)
+            .ratelimiter(None)
+            .token("token")
+            .middleware(Record {
+                fail: false,
+                log: Arc::clone(log),
+                name: "first",
+            })
+            .middleware(Record {
+                fail,
+                log: Arc::clone(log),
+                name: "second",
+            })
+            .build()
+    }
+
+    assert_obj_safe!(Middleware);
+    assert_impl_all!(ResponseInfo<'_>: Debug, Send, Sync);
+    assert_impl_all!(Tracing: Clone, Debug, Default, Middleware, Send, Sync);
+
+    #[test]
+    fn test_tracing_span() {
+        let mut request = Request::new(Body::empty());
+        Tracing.request(&mut request, &Path::Gateway).unwrap();
+
+        let extensions: Extensions = std::mem::take(request.extensions_mut());
+        assert!(extensions.get::<Span>().is_some());
+
+        let response = Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .body(Body::empty())
+            .unwrap();
+        let info = ResponseInfo {
+            elapsed: Duration::from_millis(5),
+            extensions: &extensions,
+            method: &Method::GET,
+            path: &Path::Gateway,
+            response: Some(&response),
+        };
+
+        Tracing.response(&info);
+        assert_eq!(Some(StatusCode::NO_CONTENT), info.status());
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_order() {
+        let (addr, requests) = server();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let client = client(addr, &log, false);
+
+        let response = client
+            .raw(TwilightRequest::from(Route::GetGateway))
+            .await
+            .unwrap();
+
+        assert_eq!(StatusCode::NO_CONTENT, response.status());
+        assert_eq!(1, requests.load(Ordering::SeqCst));
+        assert_eq!(
+            vec![
+                "first request",
+                "second request",
+                "second response Some(204)",
+                "first response Some(204)",
+            ],
+            *log.lock().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_request_error() {
+        let (addr, requests) = server();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let client = client(addr, &log, true);
+
+        let result = client.raw(TwilightRequest::from(Route::GetGateway)).await;
+
+        assert!(matches!(result, Err(Error::Middleware { .. })));
+        assert_eq!(0, requests.load(Ordering::SeqCst));
+        assert_eq!(
+            vec!["first request", "second request"],
+            *log.lock().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_request_failed() {
+        // Bind and drop a listener to get an address nothing listens on.
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let client = client(addr, &log, false);
+
+        let result = client.raw(TwilightRequest::from(Route::GetGateway)).await;
+
+        assert!(matches!(result, Err(Error::RequestError { .. })));
+        assert_eq!(
+            vec![
+                "first request",
+                "second request",
+                "second response None",
+                "first response None",
+            ],
+            *log.lock().unwrap()
+        );
+    }
+}
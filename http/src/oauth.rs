@@ -0,0 +1,403 @@
+//! OAuth2 authorization of users and applications.
+//!
+//! [`OAuthClient`] builds the URLs that users are sent to to authorize an
+//! application, and creates and revokes access tokens with the credentials
+//! of the application. Access tokens are used to make requests on behalf of
+//! users through a [`Client`] created with [`Client::from_access_token`].
+//!
+//! Refer to [the discord docs] for more information.
+//!
+//! # Examples
+//!
+//! Exchange the code that a user was redirected with for an access token,
+//! and get the guilds that they are in:
+//!
+//! ```rust,no_run
+//! use twilight_http::{oauth::OAuthClient, Client};
+//! use twilight_model::{id::ApplicationId, oauth::Scope};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let oauth = OAuthClient::new(ApplicationId(1), "client secret");
+//!
+//! let url = oauth
+//!     .authorization_url()
+//!     .redirect_uri("https://twilight.rs/callback")
+//!     .scopes(&[Scope::Identify, Scope::Guilds])
+//!     .state("random state")
+//!     .build();
+//! println!("authorize at {}", url);
+//!
+//! // Once the user is redirected back with a code:
+//! let token = oauth
+//!     .exchange_code("code", "https://twilight.rs/callback")
+//!     .await?
+//!     .model()
+//!     .await?;
+//!
+//! let client = Client::from_access_token(&token);
+//! let guilds = client.current_user_guilds().await?.model().await?;
+//! println!("user is in {} guilds", guilds.len());
+//! # Ok(()) }
+//! ```
+//!
+//! [`Client`]: crate::Client
+//! [`Client::from_access_token`]: crate::Client::from_access_token
+//! [the discord docs]: https://discord.com/developers/docs/topics/oauth2
+
+use crate::{
+    client::Client,
+    request::oauth::{CreateAccessToken, RevokeAccessToken},
+};
+use hyper::header::HeaderValue;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use std::fmt::Write;
+use twilight_model::{
+    guild::Permissions,
+    id::{ApplicationId, GuildId},
+    oauth::Scope,
+};
+
+/// Base URL of the page that users authorize applications on.
+const AUTHORIZE_URL: &str = "https://discord.com/api/oauth2/authorize";
+
+/// Whether the user is prompted to authorize an application they have
+/// already authorized.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Prompt {
+    /// Always prompt the user, granting a new access token.
+    Consent,
+    /// Skip prompting the user if they already authorized the scopes.
+    None,
+}
+
+impl Prompt {
+    /// Return the name of the prompt, as used in authorization URLs.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Consent => "consent",
+            Self::None => "none",
+        }
+    }
+}
+
+/// Builder for the URL that users authorize an application on.
+///
+/// This is created by [`OAuthClient::authorization_url`].
+#[derive(Clone, Debug)]
+pub struct AuthorizationUrl {
+    client_id: ApplicationId,
+    disable_guild_select: bool,
+    guild_id: Option<GuildId>,
+    permissions: Option<Permissions>,
+    prompt: Option<Prompt>,
+    redirect_uri: Option<String>,
+    scopes: Vec<Scope>,
+    state: Option<String>,
+}
+
+impl AuthorizationUrl {
+    fn new(client_id: ApplicationId) -> Self {
+        Self {
+            client_id,
+            disable_guild_select: false,
+            guild_id: None,
+            permissions: None,
+            prompt: None,
+            redirect_uri: None,
+            scopes: Vec::new(),
+            state: None,
+        }
+    }
+
+    /// Consume the builder, returning the URL.
+    pub fn build(self) -> String {
+        let mut url = format!("{}?client_id={}", AUTHORIZE_URL, self.client_id);
+
+        if let Some(redirect_uri) = &self.redirect_uri {
+            url.push_str("&response_type=code&redirect_uri=");
+            url.extend(utf8_percent_encode(redirect_uri, NON_ALPHANUMERIC));
+        }
+
+        if !self.scopes.is_empty() {
+            url.push_str("&scope=");
+            url.extend(utf8_percent_encode(
+                &scope_list(&self.scopes),
+                NON_ALPHANUMERIC,
+            ));
+        }
+
+        if let Some(state) = &self.state {
+            url.push_str("&state=");
+            url.extend(utf8_percent_encode(state, NON_ALPHANUMERIC));
+        }
+
+        if let Some(permissions) = self.permissions {
+            let _ = write!(url, "&permissions={}", permissions.bits());
+        }
+
+        if let Some(guild_id) = self.guild_id {
+            let _ = write!(url, "&guild_id={}", guild_id);
+        }
+
+        if self.disable_guild_select {
+            url.push_str("&disable_guild_select=true");
+        }
+
+        if let Some(prompt) = self.prompt {
+            url.push_str("&prompt=");
+            url.push_str(prompt.name());
+        }
+
+        url
+    }
+
+    /// Set whether the user can't choose a guild other than the one set by
+    /// [`guild_id`].
+    ///
+    /// Defaults to false.
+    ///
+    /// [`guild_id`]: Self::guild_id
+    pub fn disable_guild_select(mut self, disable_guild_select: bool) -> Self {
+        self.disable_guild_select = disable_guild_select;
+
+        self
+    }
+
+    /// Set the guild that is selected for adding a bot or webhook to.
+    pub fn guild_id(mut self, guild_id: GuildId) -> Self {
+        self.guild_id.replace(guild_id);
+
+        self
+    }
+
+    /// Set the permissions to request for a bot added to a guild.
+    ///
+    /// This requires the [`Scope::Bot`] scope.
+    pub fn permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions.replace(permissions);
+
+        self
+    }
+
+    /// Set whether to prompt a user who already authorized the application.
+    pub fn prompt(mut self, prompt: Prompt) -> Self {
+        self.prompt.replace(prompt);
+
+        self
+    }
+
+    /// Set the URI to redirect the user to with a code to exchange for an
+    /// access token.
+    ///
+    /// This must be one of the redirect URIs of the application, and is
+    /// required for scopes other than [`Scope::Bot`] and
+    /// [`Scope::WebhookIncoming`].
+    pub fn redirect_uri(mut self, redirect_uri: impl Into<String>) -> Self {
+        self.redirect_uri.replace(redirect_uri.into());
+
+        self
+    }
+
+    /// Add scopes to request.
+    pub fn scopes(mut self, scopes: &[Scope]) -> Self {
+        self.scopes.extend_from_slice(scopes);
+
+        self
+    }
+
+    /// Set the state that the user is redirected with, which should be unique
+    /// to the request to protect against CSRF.
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state.replace(state.into());
+
+        self
+    }
+}
+
+/// Client for the OAuth2 endpoints of an application.
+///
+/// Token requests are authenticated with the ID and secret of the
+/// application instead of the token of the [`Client`] used to send them, so
+/// the client of a bot can be reused.
+///
+/// # Cloning
+///
+/// The OAuth client wraps a [`Client`], which wraps its data within an Arc.
+/// This means that it can be cloned and passed around tasks and threads
+/// cheaply.
+#[derive(Clone, Debug)]
+pub struct OAuthClient {
+    authorization: HeaderValue,
+    client_id: ApplicationId,
+    http: Client,
+}
+
+impl OAuthClient {
+    /// Create a new OAuth client for an application with its client ID and
+    /// client secret.
+    ///
+    /// Use [`with_http`] to send requests through an existing client.
+    ///
+    /// [`with_http`]: Self::with_http
+    pub fn new(client_id: ApplicationId, client_secret: impl AsRef<str>) -> Self {
+        Self::with_http(Client::builder().build(), client_id, client_secret)
+    }
+
+    /// Create a new OAuth client for an application which sends requests
+    /// through an existing client.
+    pub fn with_http(
+        http: Client,
+        client_id: ApplicationId,
+        client_secret: impl AsRef<str>,
+    ) -> Self {
+        let credentials = format!("{}:{}", client_id, client_secret.as_ref());
+        let mut authorization =
+            HeaderValue::from_str(&format!("Basic {}", base64::encode(credentials)))
+                .expect("base64 is a valid header value");
+        authorization.set_sensitive(true);
+
+        Self {
+            authorization,
+            client_id,
+            http,
+        }
+    }
+
+    /// Return the client ID of the application.
+    pub fn client_id(&self) -> ApplicationId {
+        self.client_id
+    }
+
+    /// Return an immutable reference to the client used to send requests.
+    pub fn http(&self) -> &Client {
+        &self.http
+    }
+
+    /// Create a builder for the URL that users authorize the application on.
+    pub fn authorization_url(&self) -> AuthorizationUrl {
+        AuthorizationUrl::new(self.client_id)
+    }
+
+    /// Exchange the code that a user was redirected with for an access token.
+    ///
+    /// The redirect URI must be the same as the one in the authorization URL.
+    pub fn exchange_code(
+        &self,
+        code: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> CreateAccessToken<'_> {
+        CreateAccessToken::new(
+            &self.http,
+            self.authorization.clone(),
+            vec![
+                ("grant_type", "authorization_code".to_owned()),
+                ("code", code.into()),
+                ("redirect_uri", redirect_uri.into()),
+            ],
+        )
+    }
+
+    /// Get a new access token with the refresh token of an expiring one.
+    pub fn refresh_token(&self, refresh_token: impl Into<String>) -> CreateAccessToken<'_> {
+        CreateAccessToken::new(
+            &self.http,
+            self.authorization.clone(),
+            vec![
+                ("grant_type", "refresh_token".to_owned()),
+                ("refresh_token", refresh_token.into()),
+            ],
+        )
+    }
+
+    /// Get an access token for the owner of the application, without a
+    /// refresh token.
+    ///
+    /// Teams own applications that belong to them, in which case only some
+    /// scopes are available.
+    pub fn client_credentials(&self, scopes: &[Scope]) -> CreateAccessToken<'_> {
+        CreateAccessToken::new(
+            &self.http,
+            self.authorization.clone(),
+            vec![
+                ("grant_type", "client_credentials".to_owned()),
+                ("scope", scope_list(scopes)),
+            ],
+        )
+    }
+
+    /// Revoke an access token or refresh token.
+    pub fn revoke_token(&self, token: impl Into<String>) -> RevokeAccessToken<'_> {
+        RevokeAccessToken::new(&self.http, self.authorization.clone(), token.into())
+    }
+}
+
+/// Join the names of scopes with spaces.
+fn scope_list(scopes: &[Scope]) -> String {
+    let mut list = String::new();
+
+    for scope in scopes {
+        if !list.is_empty() {
+            list.push(' ');
+        }
+
+        list.push_str(scope.name());
+    }
+
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuthorizationUrl, OAuthClient, Prompt};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        guild::Permissions,
+        id::{ApplicationId, GuildId},
+        oauth::Scope,
+    };
+
+    assert_impl_all!(AuthorizationUrl: Clone, Debug, Send, Sync);
+    assert_impl_all!(OAuthClient: Clone, Debug, Send, Sync);
+    assert_impl_all!(Prompt: Clone, Copy, Debug, Eq, Send, Sync);
+
+    #[test]
+    fn test_authorization_url() {
+        let oauth = OAuthClient::new(ApplicationId(1), "secret");
+
+        assert_eq!(
+            "https://discord.com/api/oauth2/authorize?client_id=1\
+             &response_type=code&redirect_uri=https%3A%2F%2Ftwilight%2Ers\
+             &scope=identify%20guilds%2Ejoin&state=abc&prompt=consent",
+            oauth
+                .authorization_url()
+                .redirect_uri("https://twilight.rs")
+                .scopes(&[Scope::Identify, Scope::GuildsJoin])
+                .state("abc")
+                .prompt(Prompt::Consent)
+                .build(),
+        );
+
+        assert_eq!(
+            "https://discord.com/api/oauth2/authorize?client_id=1&scope=bot\
+             &permissions=8&guild_id=2&disable_guild_select=true",
+            oauth
+                .authorization_url()
+                .scopes(&[Scope::Bot])
+                .permissions(Permissions::ADMINISTRATOR)
+                .guild_id(GuildId(2))
+                .disable_guild_select(true)
+                .build(),
+        );
+    }
+
+    #[test]
+    fn test_credentials_sensitive() {
+        let oauth = OAuthClient::new(ApplicationId(1), "secret");
+
+        assert_eq!("Basic MTpzZWNyZXQ=", oauth.authorization.to_str().unwrap());
+        assert!(oauth.authorization.is_sensitive());
+    }
+}
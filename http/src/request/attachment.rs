@@ -0,0 +1,142 @@
+use bytes::Bytes;
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    io::Result as IoResult,
+    path::Path,
+};
+use tokio::{fs::File, io::AsyncRead};
+
+pub(super) type Reader = Box<dyn AsyncRead + Send + Sync + Unpin>;
+
+pub(super) enum Source {
+    Bytes(Bytes),
+    Reader { len: u64, reader: Reader },
+}
+
+/// File to attach to a message.
+///
+/// Files are streamed when sending the request instead of being copied into
+/// it, so files on disk or from readers don't need to be read into memory
+/// first.
+///
+/// # Examples
+///
+/// Attach a file on disk and an in-memory file to a message:
+///
+/// ```rust,no_run
+/// use twilight_http::{request::AttachmentFile, Client};
+/// use twilight_model::id::ChannelId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::new("my token");
+///
+/// let image = AttachmentFile::open("twilight.png").await?;
+///
+/// client
+///     .create_message(ChannelId(1))
+///     .attachment("twilight.png", image)
+///     .attachment("notes.txt", "Twilight is best pony")
+///     .await?;
+/// # Ok(()) }
+/// ```
+pub struct AttachmentFile(pub(super) Source);
+
+impl AttachmentFile {
+    /// Create a file from in-memory data.
+    pub fn from_bytes(bytes: impl Into<Bytes>) -> Self {
+        Self(Source::Bytes(bytes.into()))
+    }
+
+    /// Create a file from a reader of a known length.
+    ///
+    /// Only the provided length is read from the reader. Sending the request
+    /// fails if the reader ends before the provided length.
+    pub fn from_reader(reader: impl AsyncRead + Send + Sync + Unpin + 'static, len: u64) -> Self {
+        Self(Source::Reader {
+            len,
+            reader: Box::new(reader),
+        })
+    }
+
+    /// Open a file on disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file couldn't be opened or its length couldn't
+    /// be read.
+    pub async fn open(path: impl AsRef<Path>) -> IoResult<Self> {
+        let file = File::open(path).await?;
+        let len = file.metadata().await?.len();
+
+        Ok(Self::from_reader(file, len))
+    }
+
+    /// Return the length of the file in bytes.
+    pub fn len(&self) -> u64 {
+        match &self.0 {
+            Source::Bytes(bytes) => bytes.len() as u64,
+            Source::Reader { len, .. } => *len,
+        }
+    }
+
+    /// Return whether the file is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Debug for AttachmentFile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.0 {
+            Source::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            Source::Reader { len, .. } => f.debug_tuple("Reader").field(len).finish(),
+        }
+    }
+}
+
+impl From<Bytes> for AttachmentFile {
+    fn from(bytes: Bytes) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<Vec<u8>> for AttachmentFile {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<&[u8]> for AttachmentFile {
+    fn from(bytes: &[u8]) -> Self {
+        Self::from_bytes(Bytes::copy_from_slice(bytes))
+    }
+}
+
+impl From<String> for AttachmentFile {
+    fn from(text: String) -> Self {
+        Self::from_bytes(text)
+    }
+}
+
+impl From<&str> for AttachmentFile {
+    fn from(text: &str) -> Self {
+        Self::from(text.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AttachmentFile;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(AttachmentFile: Debug, From<Vec<u8>>, From<&'static str>, Send, Sync);
+
+    #[test]
+    fn test_len() {
+        assert_eq!(3, AttachmentFile::from("abc").len());
+        assert_eq!(5, AttachmentFile::from_reader(&b"abc"[..], 5).len());
+        assert!(AttachmentFile::from(Vec::new()).is_empty());
+    }
+}
@@ -19,6 +19,7 @@ mod private {
     impl<'a> Sealed for DeleteMessage<'a> {}
     impl<'a> Sealed for DeleteMessages<'a> {}
     impl<'a> Sealed for UpdateChannel<'a> {}
+    impl Sealed for UpdateThread<'_> {}
     impl<'a> Sealed for CreateWebhook<'a> {}
     impl Sealed for DeleteWebhookMessage<'_> {}
     impl<'a> Sealed for DeleteWebhook<'a> {}
@@ -96,6 +97,7 @@ mod test {
     assert_impl_all!(DeleteMessage<'_>: AuditLogReason);
     assert_impl_all!(DeleteMessages<'_>: AuditLogReason);
     assert_impl_all!(UpdateChannel<'_>: AuditLogReason);
+    assert_impl_all!(UpdateThread<'_>: AuditLogReason);
     assert_impl_all!(CreateWebhook<'_>: AuditLogReason);
     assert_impl_all!(DeleteWebhook<'_>: AuditLogReason);
     assert_impl_all!(UpdateWebhook<'_>: AuditLogReason);
@@ -36,6 +36,8 @@ struct CreateInviteFields {
 /// let invite = client
 ///     .create_invite(channel_id)
 ///     .max_uses(3)
+///     .await?
+///     .model()
 ///     .await?;
 /// # Ok(()) }
 /// ```
@@ -1,12 +1,16 @@
 use super::super::allowed_mentions::{AllowedMentions, AllowedMentionsBuilder, Unspecified};
-use crate::request::{multipart::Form, prelude::*};
+use crate::request::{multipart::Form, prelude::*, AttachmentFile};
 use std::{
     collections::HashMap,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    channel::{embed::Embed, message::MessageReference, Message},
+    channel::{
+        embed::Embed,
+        message::{Component, MessageReference},
+        Message,
+    },
     id::{ChannelId, MessageId},
 };
 
@@ -26,6 +30,11 @@ pub enum CreateMessageError {
         /// The source of the error.
         source: EmbedValidationError,
     },
+    /// Returned when more than 5 action rows of components are provided.
+    TooManyComponents {
+        /// Provided components.
+        components: Vec<Component>,
+    },
 }
 
 impl Display for CreateMessageError {
@@ -33,6 +42,7 @@ impl Display for CreateMessageError {
         match self {
             Self::ContentInvalid { .. } => f.write_str("the message content is invalid"),
             Self::EmbedTooLarge { .. } => f.write_str("the embed's contents are too long"),
+            Self::TooManyComponents { .. } => f.write_str("more than 5 components were provided"),
         }
     }
 }
@@ -40,7 +50,7 @@ impl Display for CreateMessageError {
 impl Error for CreateMessageError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::ContentInvalid { .. } => None,
+            Self::ContentInvalid { .. } | Self::TooManyComponents { .. } => None,
             Self::EmbedTooLarge { source, .. } => Some(source),
         }
     }
@@ -48,6 +58,8 @@ impl Error for CreateMessageError {
 
 #[derive(Default, Serialize)]
 pub(crate) struct CreateMessageFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -81,11 +93,13 @@ pub(crate) struct CreateMessageFields {
 ///     .create_message(channel_id)
 ///     .content("Twilight is best pony")?
 ///     .tts(true)
+///     .await?
+///     .model()
 ///     .await?;
 /// # Ok(()) }
 /// ```
 pub struct CreateMessage<'a> {
-    attachments: HashMap<String, Vec<u8>>,
+    attachments: HashMap<String, AttachmentFile>,
     channel_id: ChannelId,
     pub(crate) fields: CreateMessageFields,
     fut: Option<Pending<'a, Message>>,
@@ -115,15 +129,17 @@ impl<'a> CreateMessage<'a> {
 
     /// Attach a new file to the message.
     ///
-    /// The file is raw binary data. It can be an image, or any other kind of file.
-    pub fn attachment(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
+    /// The file can be raw binary data, or a file on disk or reader that is
+    /// streamed when sending the message. Refer to [`AttachmentFile`] for more
+    /// information.
+    pub fn attachment(mut self, name: impl Into<String>, file: impl Into<AttachmentFile>) -> Self {
         self.attachments.insert(name.into(), file.into());
 
         self
     }
 
     /// Insert multiple attachments into the message.
-    pub fn attachments<N: Into<String>, F: Into<Vec<u8>>>(
+    pub fn attachments<N: Into<String>, F: Into<AttachmentFile>>(
         mut self,
         attachments: impl IntoIterator<Item = (N, F)>,
     ) -> Self {
@@ -134,6 +150,27 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Set the message's list of [`Component`]s.
+    ///
+    /// Top-level components must be action rows, of which there may be up to
+    /// 5. Refer to [the discord docs] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateMessageError::TooManyComponents`] if more than 5
+    /// components are provided.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/message-components
+    pub fn components(mut self, components: Vec<Component>) -> Result<Self, CreateMessageError> {
+        if !validate::components(&components) {
+            return Err(CreateMessageError::TooManyComponents { components });
+        }
+
+        self.fields.components.replace(components);
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// The maximum length is 2000 UTF-16 characters.
@@ -234,7 +271,7 @@ impl<'a> CreateMessage<'a> {
                 let mut multipart = Form::new();
 
                 for (index, (name, file)) in self.attachments.drain().enumerate() {
-                    multipart.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
+                    multipart.file(format!("{}", index).as_bytes(), name.as_bytes(), file);
                 }
 
                 let body = crate::json_to_vec(&self.fields)?;
@@ -58,6 +58,8 @@ struct GetChannelMessagesFields {
 ///     .channel_messages(channel_id)
 ///     .before(message_id)
 ///     .limit(6u64)?
+///     .await?
+///     .model()
 ///     .await?;
 ///
 /// # Ok(()) }
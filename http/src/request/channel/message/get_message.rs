@@ -7,7 +7,7 @@ use twilight_model::{
 /// Get a message by [`ChannelId`] and [`MessageId`].
 pub struct GetMessage<'a> {
     channel_id: ChannelId,
-    fut: Option<PendingOption<'a>>,
+    fut: Option<Pending<'a, Message>>,
     http: &'a Client,
     message_id: MessageId,
 }
@@ -23,13 +23,12 @@ impl<'a> GetMessage<'a> {
     }
 
     fn start(&mut self) -> Result<()> {
-        self.fut
-            .replace(Box::pin(self.http.request_bytes(Request::from(
-                Route::GetMessage {
-                    channel_id: self.channel_id.0,
-                    message_id: self.message_id.0,
-                },
-            ))));
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetMessage {
+                channel_id: self.channel_id.0,
+                message_id: self.message_id.0,
+            },
+        ))));
 
         Ok(())
     }
@@ -4,7 +4,11 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    channel::{embed::Embed, message::MessageFlags, Message},
+    channel::{
+        embed::Embed,
+        message::{Component, MessageFlags},
+        Message,
+    },
     id::{ChannelId, MessageId},
 };
 
@@ -24,6 +28,11 @@ pub enum UpdateMessageError {
         /// The source of the error.
         source: EmbedValidationError,
     },
+    /// Returned when more than 5 action rows of components are provided.
+    TooManyComponents {
+        /// Provided components.
+        components: Vec<Component>,
+    },
 }
 
 impl Display for UpdateMessageError {
@@ -31,6 +40,7 @@ impl Display for UpdateMessageError {
         match self {
             Self::ContentInvalid { .. } => f.write_str("the message content is invalid"),
             Self::EmbedTooLarge { .. } => f.write_str("the embed's contents are too long"),
+            Self::TooManyComponents { .. } => f.write_str("more than 5 components were provided"),
         }
     }
 }
@@ -38,7 +48,7 @@ impl Display for UpdateMessageError {
 impl Error for UpdateMessageError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::ContentInvalid { .. } => None,
+            Self::ContentInvalid { .. } | Self::TooManyComponents { .. } => None,
             Self::EmbedTooLarge { source, .. } => Some(source),
         }
     }
@@ -48,6 +58,9 @@ impl Error for UpdateMessageError {
 struct UpdateMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) allowed_mentions: Option<AllowedMentions>,
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Option<Vec<Component>>>,
     // We don't serialize if this is Option::None, to avoid overwriting the
     // field without meaning to.
     //
@@ -123,6 +136,43 @@ impl<'a> UpdateMessage<'a> {
         }
     }
 
+    /// Set the message's list of [`Component`]s.
+    ///
+    /// Pass `None` if you want to remove the message components.
+    ///
+    /// Top-level components must be action rows, of which there may be up to
+    /// 5. Refer to [the discord docs] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateMessageError::TooManyComponents`] if more than 5
+    /// components are provided.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/message-components
+    pub fn components(
+        self,
+        components: impl Into<Option<Vec<Component>>>,
+    ) -> Result<Self, UpdateMessageError> {
+        self._components(components.into())
+    }
+
+    fn _components(
+        mut self,
+        components: Option<Vec<Component>>,
+    ) -> Result<Self, UpdateMessageError> {
+        if let Some(components_ref) = components.as_ref() {
+            if !validate::components(components_ref) {
+                return Err(UpdateMessageError::TooManyComponents {
+                    components: components.expect("components are known to be some"),
+                });
+            }
+        }
+
+        self.fields.components.replace(components);
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
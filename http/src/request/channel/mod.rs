@@ -2,6 +2,8 @@ pub mod allowed_mentions;
 pub mod invite;
 pub mod message;
 pub mod reaction;
+pub mod stage;
+pub mod thread;
 pub mod update_channel;
 pub mod webhook;
 
@@ -0,0 +1,103 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::stage_instance::{PrivacyLevel, StageInstance},
+    id::ChannelId,
+};
+
+/// The error returned when the stage instance can not be created as
+/// configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateStageInstanceError {
+    /// The length of the topic is either fewer than 1 UTF-16 character or
+    /// more than 120 UTF-16 characters.
+    TopicInvalid {
+        /// Provided topic.
+        topic: String,
+    },
+}
+
+impl Display for CreateStageInstanceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::TopicInvalid { .. } => f.write_str("the length of the topic is invalid"),
+        }
+    }
+}
+
+impl Error for CreateStageInstanceError {}
+
+#[derive(Serialize)]
+struct CreateStageInstanceFields {
+    channel_id: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    privacy_level: Option<PrivacyLevel>,
+    topic: String,
+}
+
+/// Create a stage instance in a stage channel, starting a live stage.
+///
+/// The topic must be at least 1 and at most 120 UTF-16 characters. Requires
+/// the user to be a moderator of the stage channel.
+///
+/// # Errors
+///
+/// Returns [`CreateStageInstanceError::TopicInvalid`] if the topic length is
+/// too short or too long.
+pub struct CreateStageInstance<'a> {
+    fields: CreateStageInstanceFields,
+    fut: Option<Pending<'a, StageInstance>>,
+    http: &'a Client,
+}
+
+impl<'a> CreateStageInstance<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        topic: impl Into<String>,
+    ) -> Result<Self, CreateStageInstanceError> {
+        Self::_new(http, channel_id, topic.into())
+    }
+
+    fn _new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        topic: String,
+    ) -> Result<Self, CreateStageInstanceError> {
+        if !validate::stage_topic(&topic) {
+            return Err(CreateStageInstanceError::TopicInvalid { topic });
+        }
+
+        Ok(Self {
+            fields: CreateStageInstanceFields {
+                channel_id,
+                privacy_level: None,
+                topic,
+            },
+            fut: None,
+            http,
+        })
+    }
+
+    /// Set the privacy level of the stage instance.
+    pub fn privacy_level(mut self, privacy_level: PrivacyLevel) -> Self {
+        self.fields.privacy_level.replace(privacy_level);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::CreateStageInstance,
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateStageInstance<'_>, StageInstance);
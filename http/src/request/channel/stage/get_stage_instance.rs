@@ -0,0 +1,31 @@
+use crate::request::prelude::*;
+use twilight_model::{channel::stage_instance::StageInstance, id::ChannelId};
+
+/// Get the stage instance of a stage channel.
+pub struct GetStageInstance<'a> {
+    channel_id: ChannelId,
+    fut: Option<Pending<'a, StageInstance>>,
+    http: &'a Client,
+}
+
+impl<'a> GetStageInstance<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fut: None,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetStageInstance {
+                channel_id: self.channel_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetStageInstance<'_>, StageInstance);
@@ -0,0 +1,105 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::stage_instance::{PrivacyLevel, StageInstance},
+    id::ChannelId,
+};
+
+/// The error returned when the stage instance can not be updated as
+/// configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateStageInstanceError {
+    /// The length of the topic is either fewer than 1 UTF-16 character or
+    /// more than 120 UTF-16 characters.
+    TopicInvalid {
+        /// Provided topic.
+        topic: String,
+    },
+}
+
+impl Display for UpdateStageInstanceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::TopicInvalid { .. } => f.write_str("the length of the topic is invalid"),
+        }
+    }
+}
+
+impl Error for UpdateStageInstanceError {}
+
+#[derive(Default, Serialize)]
+struct UpdateStageInstanceFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    privacy_level: Option<PrivacyLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    topic: Option<String>,
+}
+
+/// Update the stage instance of a stage channel.
+///
+/// All fields are optional. Requires the user to be a moderator of the stage
+/// channel.
+pub struct UpdateStageInstance<'a> {
+    channel_id: ChannelId,
+    fields: UpdateStageInstanceFields,
+    fut: Option<Pending<'a, StageInstance>>,
+    http: &'a Client,
+}
+
+impl<'a> UpdateStageInstance<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fields: UpdateStageInstanceFields::default(),
+            fut: None,
+            http,
+        }
+    }
+
+    /// Set the privacy level of the stage instance.
+    pub fn privacy_level(mut self, privacy_level: PrivacyLevel) -> Self {
+        self.fields.privacy_level.replace(privacy_level);
+
+        self
+    }
+
+    /// Set the topic.
+    ///
+    /// The minimum length is 1 UTF-16 character and the maximum is 120 UTF-16
+    /// characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateStageInstanceError::TopicInvalid`] if the topic length
+    /// is too short or too long.
+    pub fn topic(self, topic: impl Into<String>) -> Result<Self, UpdateStageInstanceError> {
+        self._topic(topic.into())
+    }
+
+    fn _topic(mut self, topic: String) -> Result<Self, UpdateStageInstanceError> {
+        if !validate::stage_topic(&topic) {
+            return Err(UpdateStageInstanceError::TopicInvalid { topic });
+        }
+
+        self.fields.topic.replace(topic);
+
+        Ok(self)
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::UpdateStageInstance {
+                channel_id: self.channel_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateStageInstance<'_>, StageInstance);
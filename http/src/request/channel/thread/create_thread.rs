@@ -0,0 +1,135 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::{thread::AutoArchiveDuration, ChannelType, GuildChannel},
+    id::ChannelId,
+};
+
+/// The error returned when the thread can not be created as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateThreadError {
+    /// The name is either fewer than 1 UTF-16 character or more than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+    /// The type of channel is not a thread.
+    TypeInvalid {
+        /// Provided type.
+        kind: ChannelType,
+    },
+}
+
+impl Display for CreateThreadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NameInvalid { .. } => f.write_str("the length of the name is invalid"),
+            Self::TypeInvalid { .. } => f.write_str("the channel type is not a thread"),
+        }
+    }
+}
+
+impl Error for CreateThreadError {}
+
+#[derive(Serialize)]
+struct CreateThreadFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_archive_duration: Option<AutoArchiveDuration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitable: Option<bool>,
+    #[serde(rename = "type")]
+    kind: ChannelType,
+    name: String,
+}
+
+/// Start a thread that is not connected to a message.
+///
+/// The name must be at least 1 and at most 100 UTF-16 characters, and the
+/// type must be one of the thread channel types. Private threads require the
+/// guild to have the `PRIVATE_THREADS` feature.
+///
+/// # Errors
+///
+/// Returns [`CreateThreadError::NameInvalid`] if the name length is too short
+/// or too long.
+///
+/// Returns [`CreateThreadError::TypeInvalid`] if the type is not a thread.
+pub struct CreateThread<'a> {
+    channel_id: ChannelId,
+    fields: CreateThreadFields,
+    fut: Option<Pending<'a, GuildChannel>>,
+    http: &'a Client,
+}
+
+impl<'a> CreateThread<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        name: impl Into<String>,
+        kind: ChannelType,
+    ) -> Result<Self, CreateThreadError> {
+        Self::_new(http, channel_id, name.into(), kind)
+    }
+
+    fn _new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        name: String,
+        kind: ChannelType,
+    ) -> Result<Self, CreateThreadError> {
+        if !validate::thread_name(&name) {
+            return Err(CreateThreadError::NameInvalid { name });
+        }
+
+        if !kind.is_thread() {
+            return Err(CreateThreadError::TypeInvalid { kind });
+        }
+
+        Ok(Self {
+            channel_id,
+            fields: CreateThreadFields {
+                auto_archive_duration: None,
+                invitable: None,
+                kind,
+                name,
+            },
+            fut: None,
+            http,
+        })
+    }
+
+    /// Set the duration of inactivity after which the thread is archived.
+    pub fn auto_archive_duration(mut self, auto_archive_duration: AutoArchiveDuration) -> Self {
+        self.fields
+            .auto_archive_duration
+            .replace(auto_archive_duration);
+
+        self
+    }
+
+    /// Set whether non-moderators can add other non-moderators to the thread.
+    ///
+    /// Only applicable to private threads.
+    pub fn invitable(mut self, invitable: bool) -> Self {
+        self.fields.invitable.replace(invitable);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::CreateThread {
+                channel_id: self.channel_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateThread<'_>, GuildChannel);
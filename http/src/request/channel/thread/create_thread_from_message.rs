@@ -0,0 +1,112 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::{thread::AutoArchiveDuration, GuildChannel},
+    id::{ChannelId, MessageId},
+};
+
+/// The error returned when the thread can not be created as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateThreadFromMessageError {
+    /// The name is either fewer than 1 UTF-16 character or more than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+}
+
+impl Display for CreateThreadFromMessageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NameInvalid { .. } => f.write_str("the length of the name is invalid"),
+        }
+    }
+}
+
+impl Error for CreateThreadFromMessageError {}
+
+#[derive(Serialize)]
+struct CreateThreadFromMessageFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_archive_duration: Option<AutoArchiveDuration>,
+    name: String,
+}
+
+/// Start a thread from an existing message.
+///
+/// The thread's ID will be the same as the message's ID. Threads started in a
+/// news channel are news threads, otherwise they are public threads.
+///
+/// The name must be at least 1 and at most 100 UTF-16 characters.
+///
+/// # Errors
+///
+/// Returns [`CreateThreadFromMessageError::NameInvalid`] if the name length is
+/// too short or too long.
+pub struct CreateThreadFromMessage<'a> {
+    channel_id: ChannelId,
+    fields: CreateThreadFromMessageFields,
+    fut: Option<Pending<'a, GuildChannel>>,
+    http: &'a Client,
+    message_id: MessageId,
+}
+
+impl<'a> CreateThreadFromMessage<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: impl Into<String>,
+    ) -> Result<Self, CreateThreadFromMessageError> {
+        Self::_new(http, channel_id, message_id, name.into())
+    }
+
+    fn _new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: String,
+    ) -> Result<Self, CreateThreadFromMessageError> {
+        if !validate::thread_name(&name) {
+            return Err(CreateThreadFromMessageError::NameInvalid { name });
+        }
+
+        Ok(Self {
+            channel_id,
+            fields: CreateThreadFromMessageFields {
+                auto_archive_duration: None,
+                name,
+            },
+            fut: None,
+            http,
+            message_id,
+        })
+    }
+
+    /// Set the duration of inactivity after which the thread is archived.
+    pub fn auto_archive_duration(mut self, auto_archive_duration: AutoArchiveDuration) -> Self {
+        self.fields
+            .auto_archive_duration
+            .replace(auto_archive_duration);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::CreateThreadFromMessage {
+                channel_id: self.channel_id.0,
+                message_id: self.message_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateThreadFromMessage<'_>, GuildChannel);
@@ -0,0 +1,33 @@
+use crate::request::prelude::*;
+use twilight_model::{channel::thread::ThreadsListing, id::GuildId};
+
+/// Get the active threads of a guild, including public and private threads.
+///
+/// Threads are ordered by their ID in descending order.
+pub struct GetActiveThreads<'a> {
+    fut: Option<Pending<'a, ThreadsListing>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> GetActiveThreads<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetActiveThreads {
+                guild_id: self.guild_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetActiveThreads<'_>, ThreadsListing);
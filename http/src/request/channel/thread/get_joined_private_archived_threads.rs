@@ -0,0 +1,61 @@
+use crate::request::prelude::*;
+use twilight_model::{channel::thread::ThreadsListing, id::ChannelId};
+
+struct GetJoinedPrivateArchivedThreadsFields {
+    before: Option<ChannelId>,
+    limit: Option<u64>,
+}
+
+/// Get the archived private threads of a channel that the current user has
+/// joined.
+///
+/// Threads are ordered by their ID in descending order. Requires the
+/// `READ_MESSAGE_HISTORY` permission.
+pub struct GetJoinedPrivateArchivedThreads<'a> {
+    channel_id: ChannelId,
+    fields: GetJoinedPrivateArchivedThreadsFields,
+    fut: Option<Pending<'a, ThreadsListing>>,
+    http: &'a Client,
+}
+
+impl<'a> GetJoinedPrivateArchivedThreads<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fields: GetJoinedPrivateArchivedThreadsFields {
+                before: None,
+                limit: None,
+            },
+            fut: None,
+            http,
+        }
+    }
+
+    /// Get threads before this thread id.
+    pub fn before(mut self, before: ChannelId) -> Self {
+        self.fields.before.replace(before);
+
+        self
+    }
+
+    /// Set the maximum number of threads to retrieve.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.fields.limit.replace(limit);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetJoinedPrivateArchivedThreads {
+                before: self.fields.before.map(|x| x.0),
+                channel_id: self.channel_id.0,
+                limit: self.fields.limit,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetJoinedPrivateArchivedThreads<'_>, ThreadsListing);
@@ -0,0 +1,60 @@
+use crate::request::prelude::*;
+use twilight_model::{channel::thread::ThreadsListing, id::ChannelId};
+
+struct GetPrivateArchivedThreadsFields {
+    before: Option<String>,
+    limit: Option<u64>,
+}
+
+/// Get the archived private threads of a channel.
+///
+/// Threads are ordered by their archive timestamp in descending order. Requires
+/// both the `READ_MESSAGE_HISTORY` and `MANAGE_THREADS` permissions.
+pub struct GetPrivateArchivedThreads<'a> {
+    channel_id: ChannelId,
+    fields: GetPrivateArchivedThreadsFields,
+    fut: Option<Pending<'a, ThreadsListing>>,
+    http: &'a Client,
+}
+
+impl<'a> GetPrivateArchivedThreads<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fields: GetPrivateArchivedThreadsFields {
+                before: None,
+                limit: None,
+            },
+            fut: None,
+            http,
+        }
+    }
+
+    /// Get threads archived before this ISO 8601 timestamp.
+    pub fn before(mut self, before: impl Into<String>) -> Self {
+        self.fields.before.replace(before.into());
+
+        self
+    }
+
+    /// Set the maximum number of threads to retrieve.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.fields.limit.replace(limit);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetPrivateArchivedThreads {
+                before: self.fields.before.clone(),
+                channel_id: self.channel_id.0,
+                limit: self.fields.limit,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetPrivateArchivedThreads<'_>, ThreadsListing);
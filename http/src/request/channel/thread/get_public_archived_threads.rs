@@ -0,0 +1,60 @@
+use crate::request::prelude::*;
+use twilight_model::{channel::thread::ThreadsListing, id::ChannelId};
+
+struct GetPublicArchivedThreadsFields {
+    before: Option<String>,
+    limit: Option<u64>,
+}
+
+/// Get the archived public threads of a channel.
+///
+/// Threads are ordered by their archive timestamp in descending order. Requires
+/// the `READ_MESSAGE_HISTORY` permission.
+pub struct GetPublicArchivedThreads<'a> {
+    channel_id: ChannelId,
+    fields: GetPublicArchivedThreadsFields,
+    fut: Option<Pending<'a, ThreadsListing>>,
+    http: &'a Client,
+}
+
+impl<'a> GetPublicArchivedThreads<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fields: GetPublicArchivedThreadsFields {
+                before: None,
+                limit: None,
+            },
+            fut: None,
+            http,
+        }
+    }
+
+    /// Get threads archived before this ISO 8601 timestamp.
+    pub fn before(mut self, before: impl Into<String>) -> Self {
+        self.fields.before.replace(before.into());
+
+        self
+    }
+
+    /// Set the maximum number of threads to retrieve.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.fields.limit.replace(limit);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetPublicArchivedThreads {
+                before: self.fields.before.clone(),
+                channel_id: self.channel_id.0,
+                limit: self.fields.limit,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetPublicArchivedThreads<'_>, ThreadsListing);
@@ -0,0 +1,33 @@
+use crate::request::prelude::*;
+use twilight_model::id::ChannelId;
+
+/// Add the current user to a thread.
+///
+/// The thread must not be archived.
+pub struct JoinThread<'a> {
+    channel_id: ChannelId,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+}
+
+impl<'a> JoinThread<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fut: None,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.verify(Request::from(
+            Route::JoinThread {
+                channel_id: self.channel_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(JoinThread<'_>, ());
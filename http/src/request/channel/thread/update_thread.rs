@@ -0,0 +1,204 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::{thread::AutoArchiveDuration, GuildChannel},
+    id::ChannelId,
+};
+
+/// Returned when the thread can not be updated as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateThreadError {
+    /// The length of the name is either fewer than 1 UTF-16 character or
+    /// more than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+    /// The seconds of the rate limit per user is more than 21600.
+    RateLimitPerUserInvalid {
+        /// Provided ratelimit is invalid.
+        rate_limit_per_user: u64,
+    },
+}
+
+impl Display for UpdateThreadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NameInvalid { .. } => f.write_str("the length of the name is invalid"),
+            Self::RateLimitPerUserInvalid { .. } => {
+                f.write_str("the rate limit per user is invalid")
+            }
+        }
+    }
+}
+
+impl Error for UpdateThreadError {}
+
+#[derive(Default, Serialize)]
+struct UpdateThreadFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_archive_duration: Option<AutoArchiveDuration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limit_per_user: Option<u64>,
+}
+
+/// Update a thread.
+///
+/// All fields are optional. The minimum length of the name is 1 UTF-16 character and the maximum
+/// is 100 UTF-16 characters.
+///
+/// # Errors
+///
+/// Returns a [`UpdateThreadError::NameInvalid`] when the length of the name is either fewer than
+/// 1 UTF-16 character or more than 100 UTF-16 characters.
+///
+/// Returns a [`UpdateThreadError::RateLimitPerUserInvalid`] when the seconds of the rate limit per
+/// user is more than 21600.
+pub struct UpdateThread<'a> {
+    channel_id: ChannelId,
+    fields: UpdateThreadFields,
+    fut: Option<Pending<'a, GuildChannel>>,
+    http: &'a Client,
+    reason: Option<String>,
+}
+
+impl<'a> UpdateThread<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fields: UpdateThreadFields::default(),
+            fut: None,
+            http,
+            reason: None,
+        }
+    }
+
+    /// Set whether the thread is archived.
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.fields.archived.replace(archived);
+
+        self
+    }
+
+    /// Set the duration of inactivity after which the thread is archived.
+    pub fn auto_archive_duration(mut self, auto_archive_duration: AutoArchiveDuration) -> Self {
+        self.fields
+            .auto_archive_duration
+            .replace(auto_archive_duration);
+
+        self
+    }
+
+    /// Set whether non-moderators can add other non-moderators to the thread.
+    ///
+    /// Only applicable to private threads.
+    pub fn invitable(mut self, invitable: bool) -> Self {
+        self.fields.invitable.replace(invitable);
+
+        self
+    }
+
+    /// Set whether the thread is locked.
+    ///
+    /// Locked threads can only be unarchived by members with the
+    /// `MANAGE_THREADS` permission.
+    pub fn locked(mut self, locked: bool) -> Self {
+        self.fields.locked.replace(locked);
+
+        self
+    }
+
+    /// Set the name.
+    ///
+    /// The minimum length is 1 UTF-16 character and the maximum is 100 UTF-16
+    /// characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateThreadError::NameInvalid`] if the name length is
+    /// too short or too long.
+    pub fn name(self, name: impl Into<String>) -> Result<Self, UpdateThreadError> {
+        self._name(name.into())
+    }
+
+    fn _name(mut self, name: String) -> Result<Self, UpdateThreadError> {
+        if !validate::thread_name(&name) {
+            return Err(UpdateThreadError::NameInvalid { name });
+        }
+
+        self.fields.name.replace(name);
+
+        Ok(self)
+    }
+
+    /// Set the number of seconds that a user must wait before before they are able to send another
+    /// message.
+    ///
+    /// The minimum is 0 and the maximum is 21600.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateThreadError::RateLimitPerUserInvalid`] if the amount is greater than
+    /// 21600.
+    pub fn rate_limit_per_user(
+        mut self,
+        rate_limit_per_user: u64,
+    ) -> Result<Self, UpdateThreadError> {
+        if rate_limit_per_user > 21600 {
+            return Err(UpdateThreadError::RateLimitPerUserInvalid {
+                rate_limit_per_user,
+            });
+        }
+
+        self.fields.rate_limit_per_user.replace(rate_limit_per_user);
+
+        Ok(self)
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = if let Some(reason) = &self.reason {
+            let headers = audit_header(&reason)?;
+            Request::from((
+                crate::json_to_vec(&self.fields)?,
+                headers,
+                Route::UpdateThread {
+                    channel_id: self.channel_id.0,
+                },
+            ))
+        } else {
+            Request::from((
+                crate::json_to_vec(&self.fields)?,
+                Route::UpdateThread {
+                    channel_id: self.channel_id.0,
+                },
+            ))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for UpdateThread<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(UpdateThread<'_>, GuildChannel);
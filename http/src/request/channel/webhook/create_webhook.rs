@@ -23,6 +23,8 @@ struct CreateWebhookFields {
 ///
 /// let webhook = client
 ///     .create_webhook(channel_id, "Twily Bot")
+///     .await?
+///     .model()
 ///     .await?;
 /// # Ok(()) }
 /// ```
@@ -1,21 +1,46 @@
-use crate::request::prelude::*;
-use futures_util::future::TryFutureExt;
+use crate::request::{multipart::Form, prelude::*, AttachmentFile};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 use twilight_model::{
-    channel::{embed::Embed, Message},
+    channel::{embed::Embed, message::Component, Message},
     id::WebhookId,
 };
 
+/// The error created when a webhook can not be executed as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ExecuteWebhookError {
+    /// Returned when more than 5 action rows of components are provided.
+    TooManyComponents {
+        /// Provided components.
+        components: Vec<Component>,
+    },
+}
+
+impl Display for ExecuteWebhookError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::TooManyComponents { .. } => f.write_str("more than 5 components were provided"),
+        }
+    }
+}
+
+impl Error for ExecuteWebhookError {}
+
 #[derive(Default, Serialize)]
 struct ExecuteWebhookFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     avatar_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<Vec<Embed>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    file: Option<Vec<u8>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     payload_json: Option<Vec<u8>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tts: Option<bool>,
@@ -27,7 +52,7 @@ struct ExecuteWebhookFields {
 
 /// Executes a webhook, sending a message to its channel.
 ///
-/// You can only specify one of [`content`], [`embeds`], or [`file`].
+/// You can only specify one of [`content`], [`embeds`], or [`attachment`].
 ///
 /// # Examples
 ///
@@ -49,8 +74,9 @@ struct ExecuteWebhookFields {
 ///
 /// [`content`]: Self::content
 /// [`embeds`]: Self::embeds
-/// [`file`]: Self::file
+/// [`attachment`]: Self::attachment
 pub struct ExecuteWebhook<'a> {
+    attachments: HashMap<String, AttachmentFile>,
     fields: ExecuteWebhookFields,
     fut: Option<Pending<'a, Option<Message>>>,
     http: &'a Client,
@@ -61,6 +87,7 @@ pub struct ExecuteWebhook<'a> {
 impl<'a> ExecuteWebhook<'a> {
     pub(crate) fn new(http: &'a Client, webhook_id: WebhookId, token: impl Into<String>) -> Self {
         Self {
+            attachments: HashMap::new(),
             fields: ExecuteWebhookFields::default(),
             fut: None,
             http,
@@ -69,6 +96,29 @@ impl<'a> ExecuteWebhook<'a> {
         }
     }
 
+    /// Attach a new file to the webhook's message.
+    ///
+    /// The file can be raw binary data, or a file on disk or reader that is
+    /// streamed when executing the webhook. Refer to [`AttachmentFile`] for
+    /// more information.
+    pub fn attachment(mut self, name: impl Into<String>, file: impl Into<AttachmentFile>) -> Self {
+        self.attachments.insert(name.into(), file.into());
+
+        self
+    }
+
+    /// Insert multiple attachments into the webhook's message.
+    pub fn attachments<N: Into<String>, F: Into<AttachmentFile>>(
+        mut self,
+        attachments: impl IntoIterator<Item = (N, F)>,
+    ) -> Self {
+        for (name, file) in attachments {
+            self = self.attachment(name, file);
+        }
+
+        self
+    }
+
     /// The URL of the avatar of the webhook.
     pub fn avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
         self.fields.avatar_url.replace(avatar_url.into());
@@ -76,6 +126,25 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
+    /// Set the list of [`Component`]s of the webhook's message.
+    ///
+    /// Only webhooks owned by an application may send components. Top-level
+    /// components must be action rows, of which there may be up to 5.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExecuteWebhookError::TooManyComponents`] if more than 5
+    /// components are provided.
+    pub fn components(mut self, components: Vec<Component>) -> Result<Self, ExecuteWebhookError> {
+        if !validate::components(&components) {
+            return Err(ExecuteWebhookError::TooManyComponents { components });
+        }
+
+        self.fields.components.replace(components);
+
+        Ok(self)
+    }
+
     /// The content of the webook's message.
     ///
     /// Up to 2000 UTF-16 codepoints, same as a message.
@@ -93,10 +162,11 @@ impl<'a> ExecuteWebhook<'a> {
     }
 
     /// Attach a file to the webhook.
-    pub fn file(mut self, file: impl Into<Vec<u8>>) -> Self {
-        self.fields.file.replace(file.into());
-
-        self
+    ///
+    /// The file is attached with the name `file`.
+    #[deprecated(note = "Use `attachment` which is more versatile", since = "0.3.5")]
+    pub fn file(self, file: impl Into<Vec<u8>>) -> Self {
+        self.attachment("file", file.into())
     }
 
     /// JSON encoded body of any additional request fields. See [Discord Docs/Create Message]
@@ -133,27 +203,57 @@ impl<'a> ExecuteWebhook<'a> {
     }
 
     fn start(&mut self) -> Result<()> {
-        let request = Request::from((
-            crate::json_to_vec(&self.fields)?,
-            Route::ExecuteWebhook {
-                token: self.token.to_owned(),
-                wait: self.fields.wait,
-                webhook_id: self.webhook_id.0,
-            },
-        ));
+        let route = Route::ExecuteWebhook {
+            token: self.token.to_owned(),
+            wait: self.fields.wait,
+            webhook_id: self.webhook_id.0,
+        };
 
-        match self.fields.wait {
-            Some(true) => {
-                self.fut.replace(Box::pin(self.http.request(request)));
-            }
-            _ => {
-                self.fut
-                    .replace(Box::pin(self.http.verify(request).map_ok(|_| None)));
+        let request = if self.attachments.is_empty() {
+            Request::from((crate::json_to_vec(&self.fields)?, route))
+        } else {
+            let mut multipart = Form::new();
+
+            for (index, (name, file)) in self.attachments.drain().enumerate() {
+                multipart.file(format!("{}", index).as_bytes(), name.as_bytes(), file);
             }
-        }
+
+            let body = crate::json_to_vec(&self.fields)?;
+            multipart.part(b"payload_json", &body);
+
+            Request::from((multipart, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
 }
 
 poll_req!(ExecuteWebhook<'_>, Option<Message>);
+
+#[cfg(test)]
+mod tests {
+    use super::{ExecuteWebhook, ExecuteWebhookError};
+    use crate::client::Client;
+    use twilight_model::{
+        channel::message::component::{ActionRow, Component},
+        id::WebhookId,
+    };
+
+    #[test]
+    fn test_components() {
+        let client = Client::new("token");
+        let row = Component::ActionRow(ActionRow {
+            components: Vec::new(),
+        });
+
+        assert!(ExecuteWebhook::new(&client, WebhookId(1), "token")
+            .components(vec![row.clone(); 5])
+            .is_ok());
+        assert!(matches!(
+            ExecuteWebhook::new(&client, WebhookId(1), "token").components(vec![row; 6]),
+            Err(ExecuteWebhookError::TooManyComponents { components }) if components.len() == 6
+        ));
+    }
+}
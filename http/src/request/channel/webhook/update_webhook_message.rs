@@ -17,7 +17,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    channel::embed::Embed,
+    channel::{embed::Embed, message::Component},
     id::{MessageId, WebhookId},
 };
 
@@ -43,6 +43,13 @@ pub enum UpdateWebhookMessageError {
         /// Source of the error.
         source: EmbedValidationError,
     },
+    /// Too many components were provided.
+    ///
+    /// A message can have up to 5 action rows of components.
+    TooManyComponents {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Too many embeds were provided.
     ///
     /// A webhook can have up to 10 embeds.
@@ -57,6 +64,10 @@ impl Display for UpdateWebhookMessageError {
         match self {
             Self::ContentInvalid { .. } => f.write_str("message content is invalid"),
             Self::EmbedTooLarge { .. } => f.write_str("length of one of the embeds is too large"),
+            Self::TooManyComponents { components } => f.write_fmt(format_args!(
+                "{} components were provided, but only 5 may be provided",
+                components.len()
+            )),
             Self::TooManyEmbeds { embeds } => f.write_fmt(format_args!(
                 "{} embeds were provided, but only 10 may be provided",
                 embeds.len()
@@ -69,7 +80,9 @@ impl Error for UpdateWebhookMessageError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::EmbedTooLarge { source, .. } => Some(source),
-            Self::ContentInvalid { .. } | Self::TooManyEmbeds { .. } => None,
+            Self::ContentInvalid { .. }
+            | Self::TooManyComponents { .. }
+            | Self::TooManyEmbeds { .. } => None,
         }
     }
 }
@@ -80,6 +93,9 @@ struct UpdateWebhookMessageFields {
     allowed_mentions: Option<AllowedMentions>,
     #[allow(clippy::option_option)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Option<Vec<Component>>>,
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<Option<String>>,
     #[allow(clippy::option_option)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -157,6 +173,41 @@ impl<'a> UpdateWebhookMessage<'a> {
         self
     }
 
+    /// Set the list of [`Component`]s of the message.
+    ///
+    /// Pass `None` if you want to remove the message components.
+    ///
+    /// Top-level components must be action rows, of which there may be up to
+    /// 5.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateWebhookMessageError::TooManyComponents`] if more than 5
+    /// components are provided.
+    pub fn components(
+        self,
+        components: impl Into<Option<Vec<Component>>>,
+    ) -> Result<Self, UpdateWebhookMessageError> {
+        self._components(components.into())
+    }
+
+    fn _components(
+        mut self,
+        components: Option<Vec<Component>>,
+    ) -> Result<Self, UpdateWebhookMessageError> {
+        if let Some(components_present) = components.as_deref() {
+            if !validate::components(components_present) {
+                return Err(UpdateWebhookMessageError::TooManyComponents {
+                    components: components.expect("components are known to be present"),
+                });
+            }
+        }
+
+        self.fields.components.replace(components);
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -311,6 +362,7 @@ mod tests {
 
         let body = crate::json_to_vec(&UpdateWebhookMessageFields {
             allowed_mentions: None,
+            components: None,
             content: Some(Some("test".to_owned())),
             embeds: None,
         })
@@ -325,4 +377,14 @@ mod tests {
         assert_eq!(expected.body, actual.body);
         assert_eq!(expected.path, actual.path);
     }
+
+    #[test]
+    fn test_remove_components() {
+        let client = Client::new("token");
+        let builder = UpdateWebhookMessage::new(&client, WebhookId(1), "token", MessageId(2))
+            .components(None)
+            .expect("components couldn't be removed");
+
+        assert_eq!(Some(None), builder.fields.components);
+    }
 }
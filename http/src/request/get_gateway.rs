@@ -15,7 +15,7 @@ use twilight_model::gateway::connection_info::ConnectionInfo;
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let info = client.gateway().await?;
+/// let info = client.gateway().await?.model().await?;
 /// # Ok(()) }
 /// ```
 ///
@@ -29,7 +29,7 @@ use twilight_model::gateway::connection_info::ConnectionInfo;
 /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 /// let client = Client::new("my token");
 ///
-/// let info = client.gateway().authed().await?;
+/// let info = client.gateway().authed().await?.model().await?;
 ///
 /// println!("URL: {}", info.url);
 /// println!("Recommended shards to use: {}", info.shards);
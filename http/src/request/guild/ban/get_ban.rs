@@ -8,7 +8,7 @@ use twilight_model::{
 ///
 /// Includes the user banned and the reason.
 pub struct GetBan<'a> {
-    fut: Option<PendingOption<'a>>,
+    fut: Option<Pending<'a, Ban>>,
     guild_id: GuildId,
     http: &'a Client,
     user_id: UserId,
@@ -26,12 +26,10 @@ impl<'a> GetBan<'a> {
 
     fn start(&mut self) -> Result<()> {
         self.fut
-            .replace(Box::pin(self.http.request_bytes(Request::from(
-                Route::GetBan {
-                    guild_id: self.guild_id.0,
-                    user_id: self.user_id.0,
-                },
-            ))));
+            .replace(Box::pin(self.http.request(Request::from(Route::GetBan {
+                guild_id: self.guild_id.0,
+                user_id: self.user_id.0,
+            }))));
 
         Ok(())
     }
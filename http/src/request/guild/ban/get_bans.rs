@@ -17,7 +17,7 @@ use twilight_model::{guild::Ban, id::GuildId};
 ///
 /// let guild_id = GuildId(1);
 ///
-/// let bans = client.bans(guild_id).await?;
+/// let bans = client.bans(guild_id).await?.model().await?;
 /// # Ok(()) }
 /// ```
 pub struct GetBans<'a> {
@@ -273,7 +273,7 @@ impl<'a> CreateGuild<'a> {
     ///     .add_category_builder(category)
     ///     .build();
     ///
-    /// let guild = client.create_guild("guild name")?.channels(channels)?.await?;
+    /// let guild = client.create_guild("guild name")?.channels(channels)?.await?.model().await?;
     /// # Ok(()) }
     /// ```
     ///
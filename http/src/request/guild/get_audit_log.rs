@@ -53,6 +53,8 @@ struct GetAuditLogFields {
 /// let audit_log = client
 /// // not done
 ///     .audit_log(guild_id)
+///     .await?
+///     .model()
 ///     .await?;
 /// # Ok(()) }
 /// ```
@@ -1,22 +1,9 @@
-use crate::request::prelude::*;
-use bytes::Bytes;
-use serde::de::DeserializeSeed;
+use crate::{request::prelude::*, response::MemberListBody};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
-    future::Future,
-    pin::Pin,
-    task::{Context, Poll},
 };
-use twilight_model::{
-    guild::member::{Member, MemberDeserializer},
-    id::{GuildId, UserId},
-};
-
-#[cfg(not(feature = "simd-json"))]
-use serde_json::Value;
-#[cfg(feature = "simd-json")]
-use simd_json::value::OwnedValue as Value;
+use twilight_model::id::{GuildId, UserId};
 
 /// The error created when the members can not be fetched as configured.
 #[derive(Clone, Debug)]
@@ -65,7 +52,12 @@ struct GetGuildMembersFields {
 ///
 /// let guild_id = GuildId(100);
 /// let user_id = UserId(3000);
-/// let members = client.guild_members(guild_id).after(user_id).await?;
+/// let members = client
+///     .guild_members(guild_id)
+///     .after(user_id)
+///     .await?
+///     .model()
+///     .await?;
 /// # Ok(()) }
 /// ```
 ///
@@ -74,7 +66,7 @@ struct GetGuildMembersFields {
 /// Returns [`GetGuildMembersError::LimitInvalid`] if the limit is invalid.
 pub struct GetGuildMembers<'a> {
     fields: GetGuildMembersFields,
-    fut: Option<Pending<'a, Bytes>>,
+    fut: Option<Pending<'a, MemberListBody>>,
     guild_id: GuildId,
     http: &'a Client,
 }
@@ -122,46 +114,20 @@ impl<'a> GetGuildMembers<'a> {
     }
 
     fn start(&mut self) -> Result<()> {
-        self.fut
-            .replace(Box::pin(self.http.request_bytes(Request::from(
-                Route::GetGuildMembers {
-                    after: self.fields.after.map(|x| x.0),
-                    guild_id: self.guild_id.0,
-                    limit: self.fields.limit,
-                    presences: self.fields.presences,
-                },
-            ))));
+        let guild_id = self.guild_id;
+        let fut = self.http.request(Request::from(Route::GetGuildMembers {
+            after: self.fields.after.map(|x| x.0),
+            guild_id: self.guild_id.0,
+            limit: self.fields.limit,
+            presences: self.fields.presences,
+        }));
+
+        self.fut.replace(Box::pin(
+            async move { Ok(fut.await?.set_guild_id(guild_id)) },
+        ));
 
         Ok(())
     }
 }
 
-impl Future for GetGuildMembers<'_> {
-    type Output = Result<Vec<Member>>;
-
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        if self.fut.is_none() {
-            self.as_mut().start()?;
-        }
-
-        let fut = self.fut.as_mut().expect("future is created");
-
-        match fut.as_mut().poll(cx) {
-            Poll::Ready(res) => {
-                let bytes = res?;
-                let mut members = Vec::new();
-
-                let mut bytes = bytes.as_ref().to_vec();
-                let values = crate::json_from_slice::<Vec<Value>>(&mut bytes)?;
-
-                for value in values {
-                    let member_deserializer = MemberDeserializer::new(self.guild_id);
-                    members.push(member_deserializer.deserialize(value)?);
-                }
-
-                Poll::Ready(Ok(members))
-            }
-            Poll::Pending => Poll::Pending,
-        }
-    }
-}
+poll_req!(GetGuildMembers<'_>, MemberListBody);
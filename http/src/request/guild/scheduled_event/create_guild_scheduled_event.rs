@@ -0,0 +1,269 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::scheduled_event::{
+        EntityMetadata, EntityType, GuildScheduledEvent, PrivacyLevel, RecurrenceRule,
+    },
+    id::{ChannelId, GuildId},
+};
+
+/// The error returned when the scheduled event can not be created as
+/// configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateGuildScheduledEventError {
+    /// The length of the description is either fewer than 1 UTF-16
+    /// character or more than 1000 UTF-16 characters.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+    },
+    /// The length of the location is either fewer than 1 UTF-16 character or
+    /// more than 100 UTF-16 characters.
+    LocationInvalid {
+        /// Provided location.
+        location: String,
+    },
+    /// The length of the name is either fewer than 1 UTF-16 character or
+    /// more than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+}
+
+impl Display for CreateGuildScheduledEventError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::DescriptionInvalid { .. } => {
+                f.write_str("the length of the description is invalid")
+            }
+            Self::LocationInvalid { .. } => f.write_str("the length of the location is invalid"),
+            Self::NameInvalid { .. } => f.write_str("the length of the name is invalid"),
+        }
+    }
+}
+
+impl Error for CreateGuildScheduledEventError {}
+
+#[derive(Serialize)]
+struct CreateGuildScheduledEventFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_metadata: Option<EntityMetadata>,
+    entity_type: EntityType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    name: String,
+    privacy_level: PrivacyLevel,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<RecurrenceRule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_end_time: Option<String>,
+    scheduled_start_time: String,
+}
+
+/// Create a scheduled event in a guild.
+///
+/// Events of type [`EntityType::StageInstance`] and [`EntityType::Voice`]
+/// require a [`channel_id`], while [`EntityType::External`] events require a
+/// [`location`] and a [`scheduled_end_time`].
+///
+/// The name must be at least 1 and at most 100 UTF-16 characters.
+///
+/// # Examples
+///
+/// Create an event taking place outside of Discord:
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::{guild::scheduled_event::EntityType, id::GuildId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::new("my token");
+///
+/// let event = client
+///     .create_guild_scheduled_event(
+///         GuildId(100),
+///         EntityType::External,
+///         "Community meetup",
+///         "2021-10-22T18:00:00+00:00",
+///     )?
+///     .location("The park")?
+///     .scheduled_end_time("2021-10-22T20:00:00+00:00")
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`CreateGuildScheduledEventError::NameInvalid`] if the name length
+/// is too short or too long.
+///
+/// [`channel_id`]: Self::channel_id
+/// [`location`]: Self::location
+/// [`scheduled_end_time`]: Self::scheduled_end_time
+pub struct CreateGuildScheduledEvent<'a> {
+    fields: CreateGuildScheduledEventFields,
+    fut: Option<Pending<'a, GuildScheduledEvent>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> CreateGuildScheduledEvent<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        entity_type: EntityType,
+        name: impl Into<String>,
+        scheduled_start_time: impl Into<String>,
+    ) -> Result<Self, CreateGuildScheduledEventError> {
+        Self::_new(
+            http,
+            guild_id,
+            entity_type,
+            name.into(),
+            scheduled_start_time.into(),
+        )
+    }
+
+    fn _new(
+        http: &'a Client,
+        guild_id: GuildId,
+        entity_type: EntityType,
+        name: String,
+        scheduled_start_time: String,
+    ) -> Result<Self, CreateGuildScheduledEventError> {
+        if !validate::scheduled_event_name(&name) {
+            return Err(CreateGuildScheduledEventError::NameInvalid { name });
+        }
+
+        Ok(Self {
+            fields: CreateGuildScheduledEventFields {
+                channel_id: None,
+                description: None,
+                entity_metadata: None,
+                entity_type,
+                image: None,
+                name,
+                privacy_level: PrivacyLevel::GuildOnly,
+                recurrence_rule: None,
+                scheduled_end_time: None,
+                scheduled_start_time,
+            },
+            fut: None,
+            guild_id,
+            http,
+        })
+    }
+
+    /// Set the stage or voice channel that the event takes place in.
+    pub fn channel_id(mut self, channel_id: ChannelId) -> Self {
+        self.fields.channel_id.replace(channel_id);
+
+        self
+    }
+
+    /// Set the description.
+    ///
+    /// The minimum length is 1 UTF-16 character and the maximum is 1000
+    /// UTF-16 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateGuildScheduledEventError::DescriptionInvalid`] if the
+    /// description length is too short or too long.
+    pub fn description(
+        self,
+        description: impl Into<String>,
+    ) -> Result<Self, CreateGuildScheduledEventError> {
+        self._description(description.into())
+    }
+
+    fn _description(mut self, description: String) -> Result<Self, CreateGuildScheduledEventError> {
+        if !validate::scheduled_event_description(&description) {
+            return Err(CreateGuildScheduledEventError::DescriptionInvalid { description });
+        }
+
+        self.fields.description.replace(description);
+
+        Ok(self)
+    }
+
+    /// Set the cover image of the event.
+    ///
+    /// This must be a Data URI, in the form of `data:image/{type};base64,{data}` where `{type}` is
+    /// the image MIME type and `{data}` is the base64-encoded image. Refer to [the discord docs]
+    /// for more information.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/reference#image-data
+    pub fn image(mut self, image: impl Into<String>) -> Self {
+        self.fields.image.replace(image.into());
+
+        self
+    }
+
+    /// Set the location of an [`EntityType::External`] event.
+    ///
+    /// The minimum length is 1 UTF-16 character and the maximum is 100 UTF-16
+    /// characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateGuildScheduledEventError::LocationInvalid`] if the
+    /// location length is too short or too long.
+    pub fn location(
+        self,
+        location: impl Into<String>,
+    ) -> Result<Self, CreateGuildScheduledEventError> {
+        self._location(location.into())
+    }
+
+    fn _location(mut self, location: String) -> Result<Self, CreateGuildScheduledEventError> {
+        if !validate::scheduled_event_location(&location) {
+            return Err(CreateGuildScheduledEventError::LocationInvalid { location });
+        }
+
+        self.fields.entity_metadata.replace(EntityMetadata {
+            location: Some(location),
+        });
+
+        Ok(self)
+    }
+
+    /// Set how often the event repeats.
+    pub fn recurrence_rule(mut self, recurrence_rule: RecurrenceRule) -> Self {
+        self.fields.recurrence_rule.replace(recurrence_rule);
+
+        self
+    }
+
+    /// Set when the event is scheduled to end.
+    pub fn scheduled_end_time(mut self, scheduled_end_time: impl Into<String>) -> Self {
+        self.fields
+            .scheduled_end_time
+            .replace(scheduled_end_time.into());
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::CreateGuildScheduledEvent {
+                guild_id: self.guild_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateGuildScheduledEvent<'_>, GuildScheduledEvent);
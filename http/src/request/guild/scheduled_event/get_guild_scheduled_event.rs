@@ -0,0 +1,56 @@
+use crate::request::prelude::*;
+use twilight_model::{
+    guild::scheduled_event::GuildScheduledEvent,
+    id::{GuildId, ScheduledEventId},
+};
+
+#[derive(Default)]
+struct GetGuildScheduledEventFields {
+    with_user_count: bool,
+}
+
+/// Get a scheduled event in a guild.
+pub struct GetGuildScheduledEvent<'a> {
+    fields: GetGuildScheduledEventFields,
+    fut: Option<Pending<'a, GuildScheduledEvent>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    scheduled_event_id: ScheduledEventId,
+}
+
+impl<'a> GetGuildScheduledEvent<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            fields: GetGuildScheduledEventFields::default(),
+            fut: None,
+            guild_id,
+            http,
+            scheduled_event_id,
+        }
+    }
+
+    /// Sets whether to include the number of users subscribed to the event.
+    pub fn with_user_count(mut self, with_user_count: bool) -> Self {
+        self.fields.with_user_count = with_user_count;
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildScheduledEvent {
+                guild_id: self.guild_id.0,
+                scheduled_event_id: self.scheduled_event_id.0,
+                with_user_count: self.fields.with_user_count,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetGuildScheduledEvent<'_>, GuildScheduledEvent);
@@ -0,0 +1,150 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::scheduled_event::GuildScheduledEventUser,
+    id::{GuildId, ScheduledEventId, UserId},
+};
+
+/// The error created when the users can not be fetched as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum GetGuildScheduledEventUsersError {
+    /// The limit is either 0 or more than 100.
+    LimitInvalid {
+        /// Provided limit.
+        limit: u64,
+    },
+}
+
+impl Display for GetGuildScheduledEventUsersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+        }
+    }
+}
+
+impl Error for GetGuildScheduledEventUsersError {}
+
+#[derive(Default)]
+struct GetGuildScheduledEventUsersFields {
+    after: Option<UserId>,
+    before: Option<UserId>,
+    limit: Option<u64>,
+    with_member: bool,
+}
+
+/// Get the users subscribed to a scheduled event in a guild.
+///
+/// The upper limit to this request is 100. If more than 100 users are needed,
+/// the requests must be chained with [`after`] or [`before`]. Discord defaults
+/// the limit to 100.
+///
+/// # Examples
+///
+/// Get the first 50 users subscribed to event `200` in guild `100` after user
+/// ID `3000`:
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::{GuildId, ScheduledEventId, UserId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::new("my token");
+///
+/// let users = client
+///     .guild_scheduled_event_users(GuildId(100), ScheduledEventId(200))
+///     .after(UserId(3000))
+///     .limit(50)?
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`after`]: Self::after
+/// [`before`]: Self::before
+pub struct GetGuildScheduledEventUsers<'a> {
+    fields: GetGuildScheduledEventUsersFields,
+    fut: Option<Pending<'a, Vec<GuildScheduledEventUser>>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    scheduled_event_id: ScheduledEventId,
+}
+
+impl<'a> GetGuildScheduledEventUsers<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            fields: GetGuildScheduledEventUsersFields::default(),
+            fut: None,
+            guild_id,
+            http,
+            scheduled_event_id,
+        }
+    }
+
+    /// Sets the user ID to get users after.
+    pub fn after(mut self, after: UserId) -> Self {
+        self.fields.after.replace(after);
+
+        self
+    }
+
+    /// Sets the user ID to get users before.
+    pub fn before(mut self, before: UserId) -> Self {
+        self.fields.before.replace(before);
+
+        self
+    }
+
+    /// Sets the number of users to retrieve per request.
+    ///
+    /// The limit must be greater than 0 and at most 100.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GetGuildScheduledEventUsersError::LimitInvalid`] if the limit
+    /// is 0 or greater than 100.
+    pub fn limit(mut self, limit: u64) -> Result<Self, GetGuildScheduledEventUsersError> {
+        if !validate::get_guild_scheduled_event_users_limit(limit) {
+            return Err(GetGuildScheduledEventUsersError::LimitInvalid { limit });
+        }
+
+        self.fields.limit.replace(limit);
+
+        Ok(self)
+    }
+
+    /// Sets whether to include the guild member data of each user.
+    pub fn with_member(mut self, with_member: bool) -> Self {
+        self.fields.with_member = with_member;
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildScheduledEventUsers {
+                after: self.fields.after.map(|x| x.0),
+                before: self.fields.before.map(|x| x.0),
+                guild_id: self.guild_id.0,
+                limit: self.fields.limit,
+                scheduled_event_id: self.scheduled_event_id.0,
+                with_member: self.fields.with_member,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(
+    GetGuildScheduledEventUsers<'_>,
+    Vec<GuildScheduledEventUser>
+);
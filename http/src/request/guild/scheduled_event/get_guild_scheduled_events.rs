@@ -0,0 +1,46 @@
+use crate::request::prelude::*;
+use twilight_model::{guild::scheduled_event::GuildScheduledEvent, id::GuildId};
+
+#[derive(Default)]
+struct GetGuildScheduledEventsFields {
+    with_user_count: bool,
+}
+
+/// Get the scheduled events in a guild.
+pub struct GetGuildScheduledEvents<'a> {
+    fields: GetGuildScheduledEventsFields,
+    fut: Option<Pending<'a, Vec<GuildScheduledEvent>>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> GetGuildScheduledEvents<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fields: GetGuildScheduledEventsFields::default(),
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    /// Sets whether to include the number of users subscribed to each event.
+    pub fn with_user_count(mut self, with_user_count: bool) -> Self {
+        self.fields.with_user_count = with_user_count;
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildScheduledEvents {
+                guild_id: self.guild_id.0,
+                with_user_count: self.fields.with_user_count,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetGuildScheduledEvents<'_>, Vec<GuildScheduledEvent>);
@@ -0,0 +1,16 @@
+pub mod create_guild_scheduled_event;
+pub mod get_guild_scheduled_event_users;
+pub mod update_guild_scheduled_event;
+
+mod delete_guild_scheduled_event;
+mod get_guild_scheduled_event;
+mod get_guild_scheduled_events;
+
+pub use self::{
+    create_guild_scheduled_event::CreateGuildScheduledEvent,
+    delete_guild_scheduled_event::DeleteGuildScheduledEvent,
+    get_guild_scheduled_event::GetGuildScheduledEvent,
+    get_guild_scheduled_event_users::GetGuildScheduledEventUsers,
+    get_guild_scheduled_events::GetGuildScheduledEvents,
+    update_guild_scheduled_event::UpdateGuildScheduledEvent,
+};
@@ -0,0 +1,287 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::scheduled_event::{
+        EntityMetadata, EntityType, GuildScheduledEvent, PrivacyLevel, RecurrenceRule, Status,
+    },
+    id::{ChannelId, GuildId, ScheduledEventId},
+};
+
+/// The error returned when the scheduled event can not be updated as
+/// configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateGuildScheduledEventError {
+    /// The length of the description is either fewer than 1 UTF-16
+    /// character or more than 1000 UTF-16 characters.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+    },
+    /// The length of the location is either fewer than 1 UTF-16 character or
+    /// more than 100 UTF-16 characters.
+    LocationInvalid {
+        /// Provided location.
+        location: String,
+    },
+    /// The length of the name is either fewer than 1 UTF-16 character or
+    /// more than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+}
+
+impl Display for UpdateGuildScheduledEventError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::DescriptionInvalid { .. } => {
+                f.write_str("the length of the description is invalid")
+            }
+            Self::LocationInvalid { .. } => f.write_str("the length of the location is invalid"),
+            Self::NameInvalid { .. } => f.write_str("the length of the name is invalid"),
+        }
+    }
+}
+
+impl Error for UpdateGuildScheduledEventError {}
+
+#[derive(Default, Serialize)]
+struct UpdateGuildScheduledEventFields {
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<Option<ChannelId>>,
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_metadata: Option<EntityMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_type: Option<EntityType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    privacy_level: Option<PrivacyLevel>,
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<Option<RecurrenceRule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_end_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_start_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<Status>,
+}
+
+/// Update a scheduled event in a guild.
+///
+/// All fields are optional. Changing the entity type to
+/// [`EntityType::External`] requires also setting a [`location`] and a
+/// [`scheduled_end_time`], and removing the [`channel_id`]. Refer to [the
+/// discord docs] for more information.
+///
+/// [`channel_id`]: Self::channel_id
+/// [`location`]: Self::location
+/// [`scheduled_end_time`]: Self::scheduled_end_time
+/// [the discord docs]: https://discord.com/developers/docs/resources/guild-scheduled-event#modify-guild-scheduled-event
+pub struct UpdateGuildScheduledEvent<'a> {
+    fields: UpdateGuildScheduledEventFields,
+    fut: Option<Pending<'a, GuildScheduledEvent>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    scheduled_event_id: ScheduledEventId,
+}
+
+impl<'a> UpdateGuildScheduledEvent<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            fields: UpdateGuildScheduledEventFields::default(),
+            fut: None,
+            guild_id,
+            http,
+            scheduled_event_id,
+        }
+    }
+
+    /// Set the stage or voice channel that the event takes place in.
+    ///
+    /// Pass `None` when changing the event to an [`EntityType::External`]
+    /// event.
+    pub fn channel_id(mut self, channel_id: impl Into<Option<ChannelId>>) -> Self {
+        self.fields.channel_id.replace(channel_id.into());
+
+        self
+    }
+
+    /// Set the description.
+    ///
+    /// The minimum length is 1 UTF-16 character and the maximum is 1000
+    /// UTF-16 characters. Pass `None` to remove the description.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildScheduledEventError::DescriptionInvalid`] if the
+    /// description length is too short or too long.
+    pub fn description(
+        self,
+        description: impl Into<Option<String>>,
+    ) -> Result<Self, UpdateGuildScheduledEventError> {
+        self._description(description.into())
+    }
+
+    fn _description(
+        mut self,
+        description: Option<String>,
+    ) -> Result<Self, UpdateGuildScheduledEventError> {
+        if let Some(description) = description.as_ref() {
+            if !validate::scheduled_event_description(description) {
+                return Err(UpdateGuildScheduledEventError::DescriptionInvalid {
+                    description: description.to_owned(),
+                });
+            }
+        }
+
+        self.fields.description.replace(description);
+
+        Ok(self)
+    }
+
+    /// Set where the event takes place.
+    pub fn entity_type(mut self, entity_type: EntityType) -> Self {
+        self.fields.entity_type.replace(entity_type);
+
+        self
+    }
+
+    /// Set the cover image of the event.
+    ///
+    /// This must be a Data URI, in the form of `data:image/{type};base64,{data}` where `{type}` is
+    /// the image MIME type and `{data}` is the base64-encoded image. Refer to [the discord docs]
+    /// for more information.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/reference#image-data
+    pub fn image(mut self, image: impl Into<String>) -> Self {
+        self.fields.image.replace(image.into());
+
+        self
+    }
+
+    /// Set the location of an [`EntityType::External`] event.
+    ///
+    /// The minimum length is 1 UTF-16 character and the maximum is 100 UTF-16
+    /// characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildScheduledEventError::LocationInvalid`] if the
+    /// location length is too short or too long.
+    pub fn location(
+        self,
+        location: impl Into<String>,
+    ) -> Result<Self, UpdateGuildScheduledEventError> {
+        self._location(location.into())
+    }
+
+    fn _location(mut self, location: String) -> Result<Self, UpdateGuildScheduledEventError> {
+        if !validate::scheduled_event_location(&location) {
+            return Err(UpdateGuildScheduledEventError::LocationInvalid { location });
+        }
+
+        self.fields.entity_metadata.replace(EntityMetadata {
+            location: Some(location),
+        });
+
+        Ok(self)
+    }
+
+    /// Set the name of the event.
+    ///
+    /// The minimum length is 1 UTF-16 character and the maximum is 100 UTF-16
+    /// characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildScheduledEventError::NameInvalid`] if the name
+    /// length is too short or too long.
+    pub fn name(self, name: impl Into<String>) -> Result<Self, UpdateGuildScheduledEventError> {
+        self._name(name.into())
+    }
+
+    fn _name(mut self, name: String) -> Result<Self, UpdateGuildScheduledEventError> {
+        if !validate::scheduled_event_name(&name) {
+            return Err(UpdateGuildScheduledEventError::NameInvalid { name });
+        }
+
+        self.fields.name.replace(name);
+
+        Ok(self)
+    }
+
+    /// Set the privacy level of the event.
+    pub fn privacy_level(mut self, privacy_level: PrivacyLevel) -> Self {
+        self.fields.privacy_level.replace(privacy_level);
+
+        self
+    }
+
+    /// Set how often the event repeats.
+    ///
+    /// Pass `None` to stop the event from repeating.
+    pub fn recurrence_rule(mut self, recurrence_rule: impl Into<Option<RecurrenceRule>>) -> Self {
+        self.fields.recurrence_rule.replace(recurrence_rule.into());
+
+        self
+    }
+
+    /// Set when the event is scheduled to end.
+    pub fn scheduled_end_time(mut self, scheduled_end_time: impl Into<String>) -> Self {
+        self.fields
+            .scheduled_end_time
+            .replace(scheduled_end_time.into());
+
+        self
+    }
+
+    /// Set when the event is scheduled to start.
+    pub fn scheduled_start_time(mut self, scheduled_start_time: impl Into<String>) -> Self {
+        self.fields
+            .scheduled_start_time
+            .replace(scheduled_start_time.into());
+
+        self
+    }
+
+    /// Set the status of the event, such as to start or cancel it.
+    ///
+    /// Scheduled events may become [`Status::Active`] or
+    /// [`Status::Cancelled`], and active events may become
+    /// [`Status::Completed`].
+    pub fn status(mut self, status: Status) -> Self {
+        self.fields.status.replace(status);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::UpdateGuildScheduledEvent {
+                guild_id: self.guild_id.0,
+                scheduled_event_id: self.scheduled_event_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateGuildScheduledEvent<'_>, GuildScheduledEvent);
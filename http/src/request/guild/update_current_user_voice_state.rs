@@ -0,0 +1,77 @@
+use crate::request::prelude::*;
+use twilight_model::id::{ChannelId, GuildId};
+
+#[derive(Serialize)]
+struct UpdateCurrentUserVoiceStateFields {
+    channel_id: ChannelId,
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_to_speak_timestamp: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suppress: Option<bool>,
+}
+
+/// Update the current user's voice state in a stage channel.
+///
+/// The current user must already be connected to the stage channel.
+pub struct UpdateCurrentUserVoiceState<'a> {
+    fields: UpdateCurrentUserVoiceStateFields,
+    fut: Option<Pending<'a, ()>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> UpdateCurrentUserVoiceState<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId, channel_id: ChannelId) -> Self {
+        Self {
+            fields: UpdateCurrentUserVoiceStateFields {
+                channel_id,
+                request_to_speak_timestamp: None,
+                suppress: None,
+            },
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    /// Set the user's request to speak.
+    ///
+    /// Set to an ISO 8601 timestamp, such as the present time, to request to
+    /// speak, or `None` to withdraw the request. Requires the
+    /// `REQUEST_TO_SPEAK` permission to request.
+    pub fn request_to_speak_timestamp(
+        mut self,
+        request_to_speak_timestamp: impl Into<Option<String>>,
+    ) -> Self {
+        self.fields
+            .request_to_speak_timestamp
+            .replace(request_to_speak_timestamp.into());
+
+        self
+    }
+
+    /// Toggle the user's suppress state.
+    ///
+    /// Setting this to `false` makes the user a speaker, while setting it to
+    /// `true` moves the user to the audience. Requires the `MUTE_MEMBERS`
+    /// permission to become a speaker.
+    pub fn suppress(mut self, suppress: bool) -> Self {
+        self.fields.suppress.replace(suppress);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.verify(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::UpdateCurrentUserVoiceState {
+                guild_id: self.guild_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateCurrentUserVoiceState<'_>, ());
@@ -0,0 +1,65 @@
+use crate::request::prelude::*;
+use twilight_model::id::{ChannelId, GuildId, UserId};
+
+#[derive(Serialize)]
+struct UpdateUserVoiceStateFields {
+    channel_id: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suppress: Option<bool>,
+}
+
+/// Update another user's voice state in a stage channel.
+///
+/// The user must already be connected to the stage channel.
+pub struct UpdateUserVoiceState<'a> {
+    fields: UpdateUserVoiceStateFields,
+    fut: Option<Pending<'a, ()>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    user_id: UserId,
+}
+
+impl<'a> UpdateUserVoiceState<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        user_id: UserId,
+        channel_id: ChannelId,
+    ) -> Self {
+        Self {
+            fields: UpdateUserVoiceStateFields {
+                channel_id,
+                suppress: None,
+            },
+            fut: None,
+            guild_id,
+            http,
+            user_id,
+        }
+    }
+
+    /// Toggle the user's suppress state.
+    ///
+    /// Setting this to `false` invites the user to speak, while setting it to
+    /// `true` moves the user to the audience. Requires the `MUTE_MEMBERS`
+    /// permission.
+    pub fn suppress(mut self, suppress: bool) -> Self {
+        self.fields.suppress.replace(suppress);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.verify(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::UpdateUserVoiceState {
+                guild_id: self.guild_id.0,
+                user_id: self.user_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateUserVoiceState<'_>, ());
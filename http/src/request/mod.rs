@@ -1,7 +1,7 @@
 macro_rules! poll_req {
     ($ty: ty, $ret: ty) => {
         impl std::future::Future for $ty {
-            type Output = $crate::error::Result<$ret>;
+            type Output = $crate::error::Result<$crate::response::Response<$ret>>;
 
             fn poll(
                 mut self: std::pin::Pin<&mut Self>,
@@ -22,35 +22,26 @@ macro_rules! poll_req {
 
     (opt, $ty: ty, $ret: ty) => {
         impl std::future::Future for $ty {
-            type Output = $crate::error::Result<Option<$ret>>;
+            type Output = $crate::error::Result<Option<$crate::response::Response<$ret>>>;
 
             fn poll(
                 mut self: std::pin::Pin<&mut Self>,
                 cx: &mut std::task::Context<'_>,
             ) -> ::std::task::Poll<Self::Output> {
-                use crate::json_from_slice;
                 use std::task::Poll;
 
                 loop {
                     if let Some(fut) = self.as_mut().fut.as_mut() {
-                        let bytes = match fut.as_mut().poll(cx) {
-                            Poll::Ready(Ok(bytes)) => bytes,
+                        return match fut.as_mut().poll(cx) {
+                            Poll::Ready(Ok(response)) => Poll::Ready(Ok(Some(response))),
                             Poll::Ready(Err(crate::Error::Response { status, .. }))
                                 if status == hyper::StatusCode::NOT_FOUND =>
                             {
-                                return Poll::Ready(Ok(None));
+                                Poll::Ready(Ok(None))
                             }
-                            Poll::Ready(Err(why)) => return Poll::Ready(Err(why)),
-                            Poll::Pending => return Poll::Pending,
+                            Poll::Ready(Err(why)) => Poll::Ready(Err(why)),
+                            Poll::Pending => Poll::Pending,
                         };
-
-                        let mut bytes = bytes.as_ref().to_vec();
-                        return Poll::Ready(json_from_slice(&mut bytes).map(Some).map_err(
-                            |source| crate::Error::Parsing {
-                                body: bytes.to_vec(),
-                                source,
-                            },
-                        ));
                     }
 
                     if let Err(why) = self.as_mut().start() {
@@ -64,9 +55,12 @@ macro_rules! poll_req {
 
 pub mod channel;
 pub mod guild;
+pub mod oauth;
 pub mod prelude;
+pub mod template;
 pub mod user;
 
+mod attachment;
 mod audit_reason;
 mod get_gateway;
 mod get_gateway_authed;
@@ -76,6 +70,7 @@ mod multipart;
 mod validate;
 
 pub use self::{
+    attachment::AttachmentFile,
     audit_reason::{AuditLogReason, AuditLogReasonError},
     get_gateway::GetGateway,
     get_gateway_authed::GetGatewayAuthed,
@@ -86,9 +81,9 @@ pub use self::{
 use self::multipart::Form;
 use crate::{
     error::{Error, Result},
+    response::Response,
     routing::{Path, Route},
 };
-use bytes::Bytes;
 use hyper::{
     header::{HeaderMap, HeaderName, HeaderValue},
     Method,
@@ -97,8 +92,7 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 use std::{borrow::Cow, future::Future, pin::Pin};
 
-type Pending<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
-type PendingOption<'a> = Pin<Box<dyn Future<Output = Result<Bytes>> + Send + 'a>>;
+type Pending<'a, T> = Pin<Box<dyn Future<Output = Result<Response<T>>> + Send + 'a>>;
 
 #[derive(Debug)]
 pub struct Request {
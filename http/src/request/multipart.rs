@@ -1,9 +1,31 @@
+use super::attachment::{AttachmentFile, Reader, Source};
+use bytes::Bytes;
+use futures_util::{ready, stream::Stream};
+use hyper::Body;
 use rand::{distributions::Alphanumeric, Rng};
+use std::{
+    collections::VecDeque,
+    convert::TryFrom,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    io::{Error as IoError, ErrorKind, Result as IoResult},
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::io::{AsyncRead, ReadBuf};
 
+/// Size of the chunks that files are read in.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Multipart form that streams its files when sent.
+///
+/// Fields are written to a buffer, which is split into a segment before each
+/// file so that the files aren't copied into the body.
 #[derive(Debug)]
 pub struct Form {
     boundary: [u8; 15],
     buffer: Vec<u8>,
+    segments: VecDeque<Segment>,
 }
 
 impl Form {
@@ -11,12 +33,16 @@ impl Form {
         Self::default()
     }
 
-    pub fn build(mut self) -> Vec<u8> {
+    /// Consume the form, returning its body and length.
+    pub fn build(mut self) -> (Body, u64) {
         self.buffer.extend(b"\r\n");
         self.boundary();
         self.buffer.extend(b"--");
+        self.flush();
+
+        let len = self.segments.iter().map(Segment::len).sum();
 
-        self.buffer
+        (Body::wrap_stream(FormStream(self.segments)), len)
     }
 
     pub fn content_type(&self) -> Vec<u8> {
@@ -29,11 +55,21 @@ impl Form {
         content_type
     }
 
-    pub fn file(&mut self, name: &[u8], filename: &[u8], data: &[u8]) -> &mut Self {
+    pub fn file(&mut self, name: &[u8], filename: &[u8], file: AttachmentFile) -> &mut Self {
         self.start();
         self.name(name);
         self.filename(filename);
-        self.data(data);
+        self.buffer.extend(b"\r\n\r\n");
+        self.flush();
+
+        self.segments.push_back(match file.0 {
+            Source::Bytes(bytes) => Segment::Bytes(bytes),
+            Source::Reader { len, reader } => Segment::Reader(ReaderSegment {
+                buf: Vec::new(),
+                reader,
+                remaining: len,
+            }),
+        });
 
         self
     }
@@ -73,6 +109,14 @@ impl Form {
         self.buffer.extend(b"\r\n\r\n");
         self.buffer.extend(data);
     }
+
+    /// Move the buffer into a segment.
+    fn flush(&mut self) {
+        if !self.buffer.is_empty() {
+            let buffer = mem::take(&mut self.buffer);
+            self.segments.push_back(Segment::Bytes(buffer.into()));
+        }
+    }
 }
 
 impl Default for Form {
@@ -87,6 +131,136 @@ impl Default for Form {
         Self {
             boundary,
             buffer: Vec::new(),
+            segments: VecDeque::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Segment {
+    Bytes(Bytes),
+    Reader(ReaderSegment),
+}
+
+impl Segment {
+    fn len(&self) -> u64 {
+        match self {
+            Self::Bytes(bytes) => bytes.len() as u64,
+            Self::Reader(reader) => reader.remaining,
+        }
+    }
+}
+
+/// File read in chunks until its length has been read.
+struct ReaderSegment {
+    buf: Vec<u8>,
+    reader: Reader,
+    remaining: u64,
+}
+
+impl ReaderSegment {
+    fn poll_chunk(&mut self, cx: &mut Context<'_>) -> Poll<Option<IoResult<Bytes>>> {
+        if self.remaining == 0 {
+            return Poll::Ready(None);
+        }
+
+        let size = usize::try_from(self.remaining)
+            .map_or(CHUNK_SIZE, |remaining| remaining.min(CHUNK_SIZE));
+        self.buf.resize(size, 0);
+
+        let mut buf = ReadBuf::new(&mut self.buf);
+
+        if let Err(source) = ready!(Pin::new(&mut self.reader).poll_read(cx, &mut buf)) {
+            return Poll::Ready(Some(Err(source)));
+        }
+
+        let read = buf.filled().len();
+
+        if read == 0 {
+            return Poll::Ready(Some(Err(IoError::new(
+                ErrorKind::UnexpectedEof,
+                "attachment ended before its length",
+            ))));
+        }
+
+        self.remaining -= read as u64;
+
+        Poll::Ready(Some(Ok(Bytes::copy_from_slice(&self.buf[..read]))))
+    }
+}
+
+impl Debug for ReaderSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ReaderSegment")
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+/// Stream of the segments of a form.
+struct FormStream(VecDeque<Segment>);
+
+impl Stream for FormStream {
+    type Item = IoResult<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.0.front_mut() {
+                Some(Segment::Bytes(bytes)) => {
+                    let bytes = mem::take(bytes);
+                    self.0.pop_front();
+
+                    if !bytes.is_empty() {
+                        return Poll::Ready(Some(Ok(bytes)));
+                    }
+                }
+                Some(Segment::Reader(reader)) => match ready!(reader.poll_chunk(cx)) {
+                    Some(chunk) => return Poll::Ready(Some(chunk)),
+                    None => {
+                        self.0.pop_front();
+                    }
+                },
+                None => return Poll::Ready(None),
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Form;
+    use crate::request::AttachmentFile;
+
+    #[tokio::test]
+    async fn test_streams_files() {
+        let mut form = Form::new();
+        form.file(b"0", b"a.txt", AttachmentFile::from("abc"))
+            .file(b"1", b"b.txt", AttachmentFile::from_reader(&b"defg"[..], 3))
+            .part(b"payload_json", b"{}");
+
+        let boundary = String::from_utf8(form.boundary.to_vec()).unwrap();
+        let (body, len) = form.build();
+        let bytes = hyper::body::to_bytes(body).await.unwrap();
+
+        let expected = format!(
+            "\r\n--{0}\r\nContent-Disposition: form-data; name=\"0\"; filename=\"a.txt\"\r\n\r\nabc\
+             \r\n--{0}\r\nContent-Disposition: form-data; name=\"1\"; filename=\"b.txt\"\r\n\r\ndef\
+             \r\n--{0}\r\nContent-Disposition: form-data; name=\"payload_json\"\r\n\r\n{{}}\
+             \r\n--{0}--",
+            boundary,
+        );
+
+        assert_eq!(expected.as_bytes(), &bytes[..]);
+        assert_eq!(expected.len() as u64, len);
+    }
+
+    #[tokio::test]
+    async fn test_reader_too_short() {
+        let mut form = Form::new();
+        form.file(b"0", b"a.txt", AttachmentFile::from_reader(&b"ab"[..], 3));
+
+        let (body, _) = form.build();
+
+        assert!(hyper::body::to_bytes(body).await.is_err());
+    }
+}
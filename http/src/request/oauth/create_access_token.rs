@@ -0,0 +1,48 @@
+use super::{form_body, headers};
+use crate::request::prelude::*;
+use hyper::header::HeaderValue;
+use twilight_model::oauth::AccessTokenResponse;
+
+/// Create an OAuth2 access token.
+///
+/// This is created by [`OAuthClient::exchange_code`],
+/// [`OAuthClient::refresh_token`] and [`OAuthClient::client_credentials`].
+///
+/// [`OAuthClient::client_credentials`]: crate::oauth::OAuthClient::client_credentials
+/// [`OAuthClient::exchange_code`]: crate::oauth::OAuthClient::exchange_code
+/// [`OAuthClient::refresh_token`]: crate::oauth::OAuthClient::refresh_token
+pub struct CreateAccessToken<'a> {
+    authorization: HeaderValue,
+    fields: Vec<(&'static str, String)>,
+    fut: Option<Pending<'a, AccessTokenResponse>>,
+    http: &'a Client,
+}
+
+impl<'a> CreateAccessToken<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        authorization: HeaderValue,
+        fields: Vec<(&'static str, String)>,
+    ) -> Self {
+        Self {
+            authorization,
+            fields,
+            fut: None,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::new(
+            Some(form_body(&self.fields)),
+            Some(headers(&self.authorization)),
+            Route::CreateAccessToken,
+        );
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateAccessToken<'_>, AccessTokenResponse);
@@ -0,0 +1,54 @@
+mod create_access_token;
+mod revoke_access_token;
+
+pub use self::{create_access_token::CreateAccessToken, revoke_access_token::RevokeAccessToken};
+
+use hyper::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+/// Create the headers of a token request, authenticated with the credentials
+/// of the application.
+fn headers(authorization: &HeaderValue) -> HeaderMap<HeaderValue> {
+    let mut headers = HeaderMap::new();
+    headers.insert(AUTHORIZATION, authorization.clone());
+    headers.insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static("application/x-www-form-urlencoded"),
+    );
+
+    headers
+}
+
+/// Encode fields as an `application/x-www-form-urlencoded` body.
+fn form_body(fields: &[(&str, String)]) -> Vec<u8> {
+    let mut body = String::new();
+
+    for (name, value) in fields {
+        if !body.is_empty() {
+            body.push('&');
+        }
+
+        body.push_str(name);
+        body.push('=');
+        body.extend(utf8_percent_encode(value, NON_ALPHANUMERIC));
+    }
+
+    body.into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_form_body() {
+        let body = super::form_body(&[
+            ("grant_type", "authorization_code".to_owned()),
+            ("redirect_uri", "https://twilight.rs/a b".to_owned()),
+        ]);
+
+        assert_eq!(
+            "grant_type=authorization%5Fcode&redirect_uri=https%3A%2F%2Ftwilight%2Ers%2Fa%20b"
+                .as_bytes(),
+            &body[..]
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use super::{form_body, headers};
+use crate::request::prelude::*;
+use hyper::header::HeaderValue;
+
+/// Revoke an OAuth2 access token or refresh token.
+///
+/// This is created by [`OAuthClient::revoke_token`].
+///
+/// [`OAuthClient::revoke_token`]: crate::oauth::OAuthClient::revoke_token
+pub struct RevokeAccessToken<'a> {
+    authorization: HeaderValue,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+    token: String,
+}
+
+impl<'a> RevokeAccessToken<'a> {
+    pub(crate) fn new(http: &'a Client, authorization: HeaderValue, token: String) -> Self {
+        Self {
+            authorization,
+            fut: None,
+            http,
+            token,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::new(
+            Some(form_body(&[("token", self.token.clone())])),
+            Some(headers(&self.authorization)),
+            Route::RevokeAccessToken,
+        );
+
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(RevokeAccessToken<'_>, ());
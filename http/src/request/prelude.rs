@@ -1,11 +1,13 @@
-pub(super) use super::{audit_header, validate, Pending, PendingOption, Request};
+pub(super) use super::{audit_header, validate, Pending, Request};
 pub use super::{
     audit_reason::{AuditLogReason, AuditLogReasonError},
-    channel::{invite::*, message::*, reaction::*, webhook::*, *},
+    channel::{invite::*, message::*, reaction::*, stage::*, thread::*, webhook::*, *},
     get_gateway::GetGateway,
     get_gateway_authed::GetGatewayAuthed,
     get_voice_regions::GetVoiceRegions,
-    guild::{ban::*, emoji::*, integration::*, member::*, role::*, *},
+    guild::{ban::*, emoji::*, integration::*, member::*, role::*, scheduled_event::*, *},
+    oauth::*,
+    template::*,
     user::*,
 };
 pub(super) use crate::{client::Client, error::Result, routing::Route};
@@ -0,0 +1,121 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{id::GuildId, template::Template};
+
+/// The error returned when the template can not be created as configured.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateTemplateError {
+    /// The description is more than 120 UTF-16 characters.
+    DescriptionTooLarge {
+        /// Provided description.
+        description: String,
+    },
+    /// The name is either fewer than 1 UTF-16 character or more than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+}
+
+impl Display for CreateTemplateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::DescriptionTooLarge { .. } => {
+                f.write_str("the template description is too large")
+            }
+            Self::NameInvalid { .. } => f.write_str("the template name is invalid"),
+        }
+    }
+}
+
+impl Error for CreateTemplateError {}
+
+#[derive(Serialize)]
+struct CreateTemplateFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    name: String,
+}
+
+/// Create a template from the current state of the guild.
+///
+/// Requires the `MANAGE_GUILD` permission. The name must be at least 1 and at most 100 UTF-16
+/// characters.
+///
+/// # Errors
+///
+/// Returns [`CreateTemplateError::NameInvalid`] if the name length is too short or too long.
+pub struct CreateTemplate<'a> {
+    fields: CreateTemplateFields,
+    fut: Option<Pending<'a, Template>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> CreateTemplate<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: impl Into<String>,
+    ) -> Result<Self, CreateTemplateError> {
+        Self::_new(http, guild_id, name.into())
+    }
+
+    fn _new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: String,
+    ) -> Result<Self, CreateTemplateError> {
+        if !validate::template_name(&name) {
+            return Err(CreateTemplateError::NameInvalid { name });
+        }
+
+        Ok(Self {
+            fields: CreateTemplateFields {
+                description: None,
+                name,
+            },
+            fut: None,
+            guild_id,
+            http,
+        })
+    }
+
+    /// Set the description of the template.
+    ///
+    /// The maximum length is 120 UTF-16 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateTemplateError::DescriptionTooLarge`] if the description is too long.
+    pub fn description(self, description: impl Into<String>) -> Result<Self, CreateTemplateError> {
+        self._description(description.into())
+    }
+
+    fn _description(mut self, description: String) -> Result<Self, CreateTemplateError> {
+        if !validate::template_description(&description) {
+            return Err(CreateTemplateError::DescriptionTooLarge { description });
+        }
+
+        self.fields.description.replace(description);
+
+        Ok(self)
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::CreateTemplate {
+                guild_id: self.guild_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateTemplate<'_>, Template);
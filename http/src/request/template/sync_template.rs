@@ -0,0 +1,40 @@
+use crate::request::prelude::*;
+use twilight_model::{id::GuildId, template::Template};
+
+/// Sync a template to the current state of its guild.
+///
+/// Requires the `MANAGE_GUILD` permission.
+pub struct SyncTemplate<'a> {
+    fut: Option<Pending<'a, Template>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    template_code: String,
+}
+
+impl<'a> SyncTemplate<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        template_code: impl Into<String>,
+    ) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+            template_code: template_code.into(),
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::SyncTemplate {
+                guild_id: self.guild_id.0,
+                template_code: self.template_code.clone(),
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(SyncTemplate<'_>, Template);
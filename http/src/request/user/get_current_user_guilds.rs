@@ -53,6 +53,8 @@ struct GetCurrentUserGuildsFields {
 ///     .after(after)
 ///     .before(before)
 ///     .limit(25)?
+///     .await?
+///     .model()
 ///     .await?;
 /// # Ok(()) }
 /// ```
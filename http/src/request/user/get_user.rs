@@ -3,7 +3,7 @@ use twilight_model::user::User;
 
 /// Get a user's information by id.
 pub struct GetUser<'a> {
-    fut: Option<PendingOption<'a>>,
+    fut: Option<Pending<'a, User>>,
     http: &'a Client,
     target_user: String,
 }
@@ -19,11 +19,9 @@ impl<'a> GetUser<'a> {
 
     fn start(&mut self) -> Result<()> {
         self.fut
-            .replace(Box::pin(self.http.request_bytes(Request::from(
-                Route::GetUser {
-                    target_user: self.target_user.clone(),
-                },
-            ))));
+            .replace(Box::pin(self.http.request(Request::from(Route::GetUser {
+                target_user: self.target_user.clone(),
+            }))));
 
         Ok(())
     }
@@ -7,7 +7,7 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::embed::Embed;
+use twilight_model::channel::{embed::Embed, message::Component};
 
 /// An embed is not valid.
 ///
@@ -179,6 +179,11 @@ fn _channel_name(value: &str) -> bool {
     (2..=100).contains(&len)
 }
 
+pub fn components(value: &[Component]) -> bool {
+    // <https://discord.com/developers/docs/interactions/message-components#action-rows>
+    value.len() <= 5
+}
+
 pub fn content_limit(value: impl AsRef<str>) -> bool {
     _content_limit(value.as_ref())
 }
@@ -284,6 +289,11 @@ pub fn get_guild_members_limit(value: u64) -> bool {
     (1..=1000).contains(&value)
 }
 
+pub fn get_guild_scheduled_event_users_limit(value: u64) -> bool {
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#get-guild-scheduled-event-users-query-string-params>
+    (1..=100).contains(&value)
+}
+
 pub fn get_reactions_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/channel#get-reactions-query-string-params>
     (1..=100).contains(&value)
@@ -316,6 +326,77 @@ fn _nickname(value: &str) -> bool {
     (1..=32).contains(&len)
 }
 
+pub fn template_description(value: impl AsRef<str>) -> bool {
+    // <https://discord.com/developers/docs/resources/template#create-guild-template-json-params>
+    value.as_ref().chars().count() <= 120
+}
+
+pub fn template_name(value: impl AsRef<str>) -> bool {
+    _template_name(value.as_ref())
+}
+
+fn _template_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/template#create-guild-template-json-params>
+    (1..=100).contains(&len)
+}
+
+pub fn scheduled_event_description(value: impl AsRef<str>) -> bool {
+    _scheduled_event_description(value.as_ref())
+}
+
+fn _scheduled_event_description(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event-json-params>
+    (1..=1000).contains(&len)
+}
+
+pub fn scheduled_event_location(value: impl AsRef<str>) -> bool {
+    _scheduled_event_location(value.as_ref())
+}
+
+fn _scheduled_event_location(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-metadata>
+    (1..=100).contains(&len)
+}
+
+pub fn scheduled_event_name(value: impl AsRef<str>) -> bool {
+    _scheduled_event_name(value.as_ref())
+}
+
+fn _scheduled_event_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event-json-params>
+    (1..=100).contains(&len)
+}
+
+pub fn stage_topic(value: impl AsRef<str>) -> bool {
+    _stage_topic(value.as_ref())
+}
+
+fn _stage_topic(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/stage-instance#create-stage-instance-json-params>
+    (1..=120).contains(&len)
+}
+
+pub fn thread_name(value: impl AsRef<str>) -> bool {
+    _thread_name(value.as_ref())
+}
+
+fn _thread_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/channel#start-thread-without-message-json-params>
+    (1..=100).contains(&len)
+}
+
 pub fn username(value: impl AsRef<str>) -> bool {
     // <https://discordapp.com/developers/docs/resources/user#usernames-and-nicknames>
     _username(value.as_ref())
@@ -330,7 +411,10 @@ fn _username(value: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use twilight_model::channel::embed::{EmbedAuthor, EmbedField, EmbedFooter};
+    use twilight_model::channel::{
+        embed::{EmbedAuthor, EmbedField, EmbedFooter},
+        message::component::ActionRow,
+    };
 
     fn base_embed() -> Embed {
         Embed {
@@ -369,6 +453,18 @@ mod tests {
         assert!(!channel_name("a".repeat(101)));
     }
 
+    #[test]
+    fn test_components() {
+        let row = Component::ActionRow(ActionRow {
+            components: Vec::new(),
+        });
+
+        assert!(components(&[]));
+        assert!(components(&vec![row.clone(); 5]));
+
+        assert!(!components(&vec![row; 6]));
+    }
+
     #[test]
     fn test_content_limit() {
         assert!(content_limit(""));
@@ -600,6 +696,15 @@ mod tests {
         assert!(!get_guild_members_limit(1001));
     }
 
+    #[test]
+    fn test_get_guild_scheduled_event_users_limit() {
+        assert!(get_guild_scheduled_event_users_limit(1));
+        assert!(get_guild_scheduled_event_users_limit(100));
+
+        assert!(!get_guild_scheduled_event_users_limit(0));
+        assert!(!get_guild_scheduled_event_users_limit(101));
+    }
+
     #[test]
     fn test_get_reactions_limit() {
         assert!(get_reactions_limit(1));
@@ -635,6 +740,68 @@ mod tests {
         assert!(!nickname("a".repeat(33)));
     }
 
+    #[test]
+    fn test_template_description() {
+        assert!(template_description(""));
+        assert!(template_description("a".repeat(120)));
+
+        assert!(!template_description("a".repeat(121)));
+    }
+
+    #[test]
+    fn test_template_name() {
+        assert!(template_name("a"));
+        assert!(template_name("a".repeat(100)));
+
+        assert!(!template_name(""));
+        assert!(!template_name("a".repeat(101)));
+    }
+
+    #[test]
+    fn test_scheduled_event_description() {
+        assert!(scheduled_event_description("a"));
+        assert!(scheduled_event_description("a".repeat(1000)));
+
+        assert!(!scheduled_event_description(""));
+        assert!(!scheduled_event_description("a".repeat(1001)));
+    }
+
+    #[test]
+    fn test_scheduled_event_location() {
+        assert!(scheduled_event_location("a"));
+        assert!(scheduled_event_location("a".repeat(100)));
+
+        assert!(!scheduled_event_location(""));
+        assert!(!scheduled_event_location("a".repeat(101)));
+    }
+
+    #[test]
+    fn test_scheduled_event_name() {
+        assert!(scheduled_event_name("a"));
+        assert!(scheduled_event_name("a".repeat(100)));
+
+        assert!(!scheduled_event_name(""));
+        assert!(!scheduled_event_name("a".repeat(101)));
+    }
+
+    #[test]
+    fn test_stage_topic() {
+        assert!(stage_topic("a"));
+        assert!(stage_topic("a".repeat(120)));
+
+        assert!(!stage_topic(""));
+        assert!(!stage_topic("a".repeat(121)));
+    }
+
+    #[test]
+    fn test_thread_name() {
+        assert!(thread_name("a"));
+        assert!(thread_name("a".repeat(100)));
+
+        assert!(!thread_name(""));
+        assert!(!thread_name("a".repeat(101)));
+    }
+
     #[test]
     fn test_username() {
         assert!(username("aa"));
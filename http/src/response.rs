@@ -0,0 +1,281 @@
+//! Responses of requests, with their status, headers and lazily read bodies.
+//!
+//! Request futures resolve to a [`Response`] once the status and headers of
+//! the response have been received. The body is only read when calling
+//! [`Response::bytes`] or [`Response::model`], so services that forward
+//! responses don't need to deserialize them first.
+//!
+//! # Examples
+//!
+//! Get a message, printing the remaining ratelimit of the route before
+//! deserializing the message:
+//!
+//! ```rust,no_run
+//! use twilight_http::{ratelimiting::RatelimitHeaders, Client};
+//! use twilight_model::id::{ChannelId, MessageId};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("my token");
+//!
+//! let response = client.message(ChannelId(1), MessageId(2)).await?;
+//!
+//! if let Some(response) = response {
+//!     if let Some(RatelimitHeaders::Present { remaining, .. }) = response.ratelimit_headers() {
+//!         println!("{} requests remaining", remaining);
+//!     }
+//!
+//!     let message = response.model().await?;
+//!     println!("content: {}", message.content);
+//! }
+//! # Ok(()) }
+//! ```
+
+use crate::{
+    error::{Error, Result},
+    ratelimiting::RatelimitHeaders,
+};
+use bytes::Bytes;
+use hyper::{
+    header::{HeaderMap, HeaderValue},
+    Body, Response as HyperResponse, StatusCode,
+};
+use serde::de::{DeserializeOwned, DeserializeSeed};
+use std::{
+    convert::TryFrom,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    marker::PhantomData,
+    result::Result as StdResult,
+};
+use twilight_model::{
+    guild::{
+        member::{MemberDeserializer, MemberListDeserializer},
+        Member,
+    },
+    id::GuildId,
+};
+
+#[cfg(not(feature = "simd-json"))]
+use serde_json::{Error as JsonError, Value};
+#[cfg(feature = "simd-json")]
+use simd_json::{value::OwnedValue as Value, Error as JsonError};
+
+/// Marker for a response body of a member.
+///
+/// Members are deserialized with the ID of the guild of the request, since
+/// it isn't in the body.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct MemberBody;
+
+/// Marker for a response body of a list of members.
+///
+/// Members are deserialized with the ID of the guild of the request, since
+/// it isn't in the body.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct MemberListBody;
+
+/// Response of a request, deserializing to `T`.
+///
+/// The status and headers of the response are available immediately, while
+/// the body is read when calling [`bytes`] or [`model`].
+///
+/// [`bytes`]: Self::bytes
+/// [`model`]: Self::model
+pub struct Response<T> {
+    guild_id: Option<GuildId>,
+    inner: HyperResponse<Body>,
+    phantom: PhantomData<fn() -> T>,
+    ratelimit_headers: Option<RatelimitHeaders>,
+}
+
+impl<T> Response<T> {
+    pub(crate) fn new(inner: HyperResponse<Body>) -> Self {
+        let ratelimit_headers = RatelimitHeaders::try_from(inner.headers()).ok();
+
+        Self {
+            guild_id: None,
+            inner,
+            phantom: PhantomData,
+            ratelimit_headers,
+        }
+    }
+
+    /// Set the ID of the guild that members are deserialized with.
+    pub(crate) fn set_guild_id(mut self, guild_id: GuildId) -> Self {
+        self.guild_id.replace(guild_id);
+
+        self
+    }
+
+    /// Return the status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.inner.status()
+    }
+
+    /// Return an immutable reference to the headers of the response.
+    pub fn headers(&self) -> &HeaderMap<HeaderValue> {
+        self.inner.headers()
+    }
+
+    /// Return the ratelimit headers of the response, if they could be parsed.
+    pub fn ratelimit_headers(&self) -> Option<&RatelimitHeaders> {
+        self.ratelimit_headers.as_ref()
+    }
+
+    /// Consume the response, returning the underlying hyper response.
+    pub fn into_inner(self) -> HyperResponse<Body> {
+        self.inner
+    }
+
+    /// Read the body of the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ChunkingResponse`] if reading the body failed.
+    pub async fn bytes(self) -> Result<Bytes> {
+        hyper::body::to_bytes(self.inner.into_body())
+            .await
+            .map_err(|source| Error::ChunkingResponse { source })
+    }
+
+    /// Read the body of the response and parse it as JSON.
+    ///
+    /// An empty body is parsed as `null`, so responses without content can be
+    /// deserialized as `()` or `None`.
+    async fn json<U>(self, f: impl FnOnce(&mut [u8]) -> StdResult<U, JsonError>) -> Result<U> {
+        let bytes = self.bytes().await?;
+
+        let mut body = if bytes.is_empty() {
+            b"null".to_vec()
+        } else {
+            bytes.to_vec()
+        };
+
+        f(&mut body).map_err(|source| Error::Parsing { body, source })
+    }
+}
+
+impl<T: DeserializeOwned> Response<T> {
+    /// Read the body of the response and deserialize it into the model.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ChunkingResponse`] if reading the body failed.
+    ///
+    /// Returns [`Error::Parsing`] if the body couldn't be deserialized.
+    pub async fn model(self) -> Result<T> {
+        self.json(|body| crate::json_from_slice(body)).await
+    }
+}
+
+impl Response<MemberBody> {
+    /// Read the body of the response and deserialize it into a member.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ChunkingResponse`] if reading the body failed.
+    ///
+    /// Returns [`Error::Parsing`] if the body couldn't be deserialized.
+    pub async fn model(self) -> Result<Member> {
+        let guild_id = self.guild_id.expect("member response has guild id");
+
+        self.json(|body| {
+            let value = crate::json_from_slice::<Value>(body)?;
+
+            MemberDeserializer::new(guild_id).deserialize(value)
+        })
+        .await
+    }
+}
+
+impl Response<MemberListBody> {
+    /// Read the body of the response and deserialize it into a list of
+    /// members.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ChunkingResponse`] if reading the body failed.
+    ///
+    /// Returns [`Error::Parsing`] if the body couldn't be deserialized.
+    pub async fn model(self) -> Result<Vec<Member>> {
+        let guild_id = self.guild_id.expect("member list response has guild id");
+
+        self.json(|body| {
+            let value = crate::json_from_slice::<Value>(body)?;
+
+            MemberListDeserializer::new(guild_id).deserialize(value)
+        })
+        .await
+    }
+}
+
+impl<T> Debug for Response<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Response")
+            .field("guild_id", &self.guild_id)
+            .field("inner", &self.inner)
+            .field("ratelimit_headers", &self.ratelimit_headers)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemberBody, MemberListBody, Response};
+    use hyper::{Body, Response as HyperResponse, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{id::GuildId, user::User};
+
+    assert_impl_all!(MemberBody: Clone, Copy, Debug, Send, Sync);
+    assert_impl_all!(MemberListBody: Clone, Copy, Debug, Send, Sync);
+    assert_impl_all!(Response<User>: Debug, Send, Sync);
+
+    fn response<T>(body: &'static str) -> Response<T> {
+        let inner = HyperResponse::builder()
+            .status(StatusCode::OK)
+            .header("x-ratelimit-global", "true")
+            .header("x-ratelimit-reset-after", "5")
+            .body(Body::from(body))
+            .unwrap();
+
+        Response::new(inner)
+    }
+
+    #[tokio::test]
+    async fn test_empty_body() {
+        assert!(response::<()>("").model().await.is_ok());
+        assert!(response::<Option<User>>("")
+            .model()
+            .await
+            .unwrap()
+            .is_none());
+        assert!(response::<User>("").model().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_members() {
+        const BODY: &str = r#"[{
+            "deaf": false,
+            "joined_at": null,
+            "mute": false,
+            "roles": [],
+            "user": {
+                "avatar": null,
+                "discriminator": "0001",
+                "id": "2",
+                "username": "twilight"
+            }
+        }]"#;
+
+        let response = response::<MemberListBody>(BODY);
+        assert_eq!(StatusCode::OK, response.status());
+        assert!(response.ratelimit_headers().unwrap().global());
+
+        let members = response.set_guild_id(GuildId(1)).model().await.unwrap();
+        assert_eq!(1, members.len());
+        assert_eq!(GuildId(1), members[0].guild_id);
+    }
+}
@@ -1,4 +1,5 @@
 use hyper::Method;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use std::{
     borrow::Cow,
     convert::TryFrom,
@@ -70,6 +71,8 @@ pub enum Path {
     ChannelsIdMessagesId(Method, u64),
     /// Crossposting an individual channel's message.
     ChannelsIdMessagesIdCrosspost(u64),
+    /// Operating on the threads started from an individual channel's message.
+    ChannelsIdMessagesIdThreads(u64),
     /// Operating on an individual channel's message's reactions.
     ChannelsIdMessagesIdReactions(u64),
     /// Operating on an individual channel's message's reactions while
@@ -81,8 +84,21 @@ pub enum Path {
     ChannelsIdPins(u64),
     /// Operating on a channel's individual pinned message.
     ChannelsIdPinsMessageId(u64),
+    /// Operating on a thread's members.
+    ChannelsIdThreadMembers(u64),
+    /// Operating on an individual member of a thread.
+    ChannelsIdThreadMembersId(u64),
+    /// Operating on a channel's threads.
+    ChannelsIdThreads(u64),
+    /// Operating on a channel's archived private threads.
+    ChannelsIdThreadsArchivedPrivate(u64),
+    /// Operating on a channel's archived public threads.
+    ChannelsIdThreadsArchivedPublic(u64),
     /// Operating on a channel's typing indicator.
     ChannelsIdTyping(u64),
+    /// Operating on a channel's archived private threads that the current
+    /// user has joined.
+    ChannelsIdUsersIdThreadsArchivedPrivate(u64),
     /// Operating on a channel's webhooks.
     ChannelsIdWebhooks(u64),
     /// Operating on a channel's followers.
@@ -116,11 +132,35 @@ pub enum Path {
     GuildsIdRegions(u64),
     GuildsIdRoles(u64),
     GuildsIdRolesId(u64),
+    /// Operating on a guild's scheduled events.
+    GuildsIdScheduledEvents(u64),
+    /// Operating on a scheduled event of a guild.
+    GuildsIdScheduledEventsId(u64),
+    /// Operating on the users subscribed to a scheduled event of a guild.
+    GuildsIdScheduledEventsIdUsers(u64),
+    /// Operating on a guild's templates.
+    GuildsIdTemplates(u64),
+    /// Operating on a template of a guild.
+    GuildsIdTemplatesCode(u64),
+    /// Operating on a guild's active threads.
+    GuildsIdThreadsActive(u64),
     GuildsIdVanityUrl(u64),
+    /// Operating on the voice states of members in a guild.
+    GuildsIdVoiceStates(u64),
     GuildsIdWebhooks(u64),
+    /// Operating on a template.
+    GuildsTemplatesCode,
     InvitesCode,
     UsersId,
     OauthApplicationsMe,
+    /// Operating on OAuth2 access tokens.
+    OauthToken,
+    /// Revoking OAuth2 access tokens.
+    OauthTokenRevoke,
+    /// Operating on stage instances.
+    StageInstances,
+    /// Operating on the stage instance of a stage channel.
+    StageInstancesId(u64),
     UsersIdConnections,
     UsersIdChannels,
     /// Operating on the state of a guild that the user is in.
@@ -183,14 +223,28 @@ impl FromStr for Path {
             ["channels", id, "messages", _, "reactions", _, _] => {
                 ChannelsIdMessagesIdReactionsUserIdType(id.parse()?)
             }
+            ["channels", id, "messages", _, "threads"] => ChannelsIdMessagesIdThreads(id.parse()?),
             ["channels", id, "permissions", _] => ChannelsIdPermissionsOverwriteId(id.parse()?),
             ["channels", id, "pins"] => ChannelsIdPins(id.parse()?),
             ["channels", id, "pins", _] => ChannelsIdPinsMessageId(id.parse()?),
+            ["channels", id, "thread-members"] => ChannelsIdThreadMembers(id.parse()?),
+            ["channels", id, "thread-members", _] => ChannelsIdThreadMembersId(id.parse()?),
+            ["channels", id, "threads"] => ChannelsIdThreads(id.parse()?),
+            ["channels", id, "threads", "archived", "private"] => {
+                ChannelsIdThreadsArchivedPrivate(id.parse()?)
+            }
+            ["channels", id, "threads", "archived", "public"] => {
+                ChannelsIdThreadsArchivedPublic(id.parse()?)
+            }
             ["channels", id, "typing"] => ChannelsIdTyping(id.parse()?),
+            ["channels", id, "users", _, "threads", "archived", "private"] => {
+                ChannelsIdUsersIdThreadsArchivedPrivate(id.parse()?)
+            }
             ["channels", id, "webhooks"] => ChannelsIdWebhooks(id.parse()?),
             ["gateway"] => Gateway,
             ["gateway", "bot"] => GatewayBot,
             ["guilds"] => Guilds,
+            ["guilds", "templates", _] => GuildsTemplatesCode,
             ["guilds", id] => GuildsId(id.parse()?),
             ["guilds", id, "bans"] => GuildsIdBans(id.parse()?),
             ["guilds", id, "bans", _] => GuildsIdBansUserId(id.parse()?),
@@ -211,10 +265,23 @@ impl FromStr for Path {
             ["guilds", id, "regions"] => GuildsIdRegions(id.parse()?),
             ["guilds", id, "roles"] => GuildsIdRoles(id.parse()?),
             ["guilds", id, "roles", _] => GuildsIdRolesId(id.parse()?),
+            ["guilds", id, "scheduled-events"] => GuildsIdScheduledEvents(id.parse()?),
+            ["guilds", id, "scheduled-events", _] => GuildsIdScheduledEventsId(id.parse()?),
+            ["guilds", id, "scheduled-events", _, "users"] => {
+                GuildsIdScheduledEventsIdUsers(id.parse()?)
+            }
+            ["guilds", id, "templates"] => GuildsIdTemplates(id.parse()?),
+            ["guilds", id, "templates", _] => GuildsIdTemplatesCode(id.parse()?),
+            ["guilds", id, "threads", "active"] => GuildsIdThreadsActive(id.parse()?),
             ["guilds", id, "vanity-url"] => GuildsIdVanityUrl(id.parse()?),
+            ["guilds", id, "voice-states", _] => GuildsIdVoiceStates(id.parse()?),
             ["guilds", id, "webhooks"] => GuildsIdWebhooks(id.parse()?),
             ["invites", _] => InvitesCode,
             ["oauth2", "applications", "@me"] => OauthApplicationsMe,
+            ["oauth2", "token"] => OauthToken,
+            ["oauth2", "token", "revoke"] => OauthTokenRevoke,
+            ["stage-instances"] => StageInstances,
+            ["stage-instances", id] => StageInstancesId(id.parse()?),
             ["users", _] => UsersId,
             ["users", _, "connections"] => UsersIdConnections,
             ["users", _, "channels"] => UsersIdChannels,
@@ -255,6 +322,15 @@ pub enum Route {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to add a user to a thread.
+    AddThreadMember {
+        /// The ID of the thread.
+        channel_id: u64,
+        /// The ID of the user.
+        user_id: u64,
+    },
+    /// Route information to create an OAuth2 access token.
+    CreateAccessToken,
     /// Route information to create a ban on a user in a guild.
     CreateBan {
         /// The number of days' worth of the user's messages to delete in the
@@ -279,6 +355,11 @@ pub enum Route {
     },
     /// Route information to create a guild.
     CreateGuild,
+    /// Route information to create a guild from a template.
+    CreateGuildFromTemplate {
+        /// The code of the template.
+        template_code: String,
+    },
     /// Route information to create a guild's integration.
     CreateGuildIntegration {
         /// The ID of the guild.
@@ -299,6 +380,11 @@ pub enum Route {
         /// pruned.
         include_roles: Vec<u64>,
     },
+    /// Route information to create a scheduled event in a guild.
+    CreateGuildScheduledEvent {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to create an invite to a channel.
     CreateInvite {
         /// The ID of the channel.
@@ -325,6 +411,25 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to create a stage instance.
+    CreateStageInstance,
+    /// Route information to create a template from a guild.
+    CreateTemplate {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
+    /// Route information to create a thread in a channel.
+    CreateThread {
+        /// The ID of the channel.
+        channel_id: u64,
+    },
+    /// Route information to create a thread from a message.
+    CreateThreadFromMessage {
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The ID of the message.
+        message_id: u64,
+    },
     /// Route information to create a typing trigger in a channel.
     CreateTypingTrigger {
         /// The ID of the channel.
@@ -373,6 +478,13 @@ pub enum Route {
         /// The ID of the integration.
         integration_id: u64,
     },
+    /// Route information to delete a scheduled event in a guild.
+    DeleteGuildScheduledEvent {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The ID of the scheduled event.
+        scheduled_event_id: u64,
+    },
     /// Route information to delete an invite.
     DeleteInvite {
         /// The unique invite code.
@@ -433,6 +545,18 @@ pub enum Route {
         /// The ID of the role.
         role_id: u64,
     },
+    /// Route information to delete the stage instance of a stage channel.
+    DeleteStageInstance {
+        /// The ID of the stage channel.
+        channel_id: u64,
+    },
+    /// Route information to delete a guild's template.
+    DeleteTemplate {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The code of the template.
+        template_code: String,
+    },
     /// Route information to delete a message created by a webhook.
     DeleteWebhookMessage {
         message_id: u64,
@@ -460,6 +584,11 @@ pub enum Route {
         /// The ID of the channel to follow.
         channel_id: u64,
     },
+    /// Route information to get the active threads of a guild.
+    GetActiveThreads {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a paginated list of audit logs in a guild.
     GetAuditLogs {
         /// The type of action to get audit logs for.
@@ -582,6 +711,37 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get a scheduled event in a guild.
+    GetGuildScheduledEvent {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The ID of the scheduled event.
+        scheduled_event_id: u64,
+        /// Whether to include the number of subscribed users.
+        with_user_count: bool,
+    },
+    /// Route information to get the users subscribed to a scheduled event.
+    GetGuildScheduledEventUsers {
+        /// The minimum ID of users to get.
+        after: Option<u64>,
+        /// The maximum ID of users to get.
+        before: Option<u64>,
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The maximum number of users to get.
+        limit: Option<u64>,
+        /// The ID of the scheduled event.
+        scheduled_event_id: u64,
+        /// Whether to include the guild member data of each user.
+        with_member: bool,
+    },
+    /// Route information to get a guild's scheduled events.
+    GetGuildScheduledEvents {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// Whether to include the number of subscribed users.
+        with_user_count: bool,
+    },
     /// Route information to get a guild's vanity URL.
     GetGuildVanityUrl {
         /// The ID of the guild.
@@ -640,11 +800,39 @@ pub enum Route {
         /// The maximum number of messages to get.
         limit: Option<u64>,
     },
+    /// Route information to get a channel's archived private threads that
+    /// the current user has joined.
+    GetJoinedPrivateArchivedThreads {
+        /// The ID of the thread to get threads before.
+        before: Option<u64>,
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The maximum number of threads to get.
+        limit: Option<u64>,
+    },
     /// Route information to get a channel's pins.
     GetPins {
         /// The ID of the channel.
         channel_id: u64,
     },
+    /// Route information to get a channel's archived private threads.
+    GetPrivateArchivedThreads {
+        /// The timestamp to get threads archived before.
+        before: Option<String>,
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The maximum number of threads to get.
+        limit: Option<u64>,
+    },
+    /// Route information to get a channel's archived public threads.
+    GetPublicArchivedThreads {
+        /// The timestamp to get threads archived before.
+        before: Option<String>,
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The maximum number of threads to get.
+        limit: Option<u64>,
+    },
     /// Route information to get the users who reacted to a message with a
     /// specified emoji.
     GetReactionUsers {
@@ -667,6 +855,26 @@ pub enum Route {
         /// user.
         target_user: String,
     },
+    /// Route information to get the stage instance of a stage channel.
+    GetStageInstance {
+        /// The ID of the stage channel.
+        channel_id: u64,
+    },
+    /// Route information to get a template.
+    GetTemplate {
+        /// The code of the template.
+        template_code: String,
+    },
+    /// Route information to get a guild's templates.
+    GetTemplates {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
+    /// Route information to get the members of a thread.
+    GetThreadMembers {
+        /// The ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to get the current user's connections.
     GetUserConnections,
     /// Route information to get the current user's private channels and groups.
@@ -681,11 +889,21 @@ pub enum Route {
         /// The ID of the webhook.
         webhook_id: u64,
     },
+    /// Route information to add the current user to a thread.
+    JoinThread {
+        /// The ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to leave the guild.
     LeaveGuild {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to remove the current user from a thread.
+    LeaveThread {
+        /// The ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to pin a message to a channel.
     PinMessage {
         /// The ID of the channel.
@@ -709,6 +927,15 @@ pub enum Route {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to remove a user from a thread.
+    RemoveThreadMember {
+        /// The ID of the thread.
+        channel_id: u64,
+        /// The ID of the user.
+        user_id: u64,
+    },
+    /// Route information to revoke an OAuth2 access token.
+    RevokeAccessToken,
     /// Route information to sync a guild's integration.
     SyncGuildIntegration {
         /// The ID of the guild.
@@ -716,6 +943,13 @@ pub enum Route {
         /// The ID of the integration.
         integration_id: u64,
     },
+    /// Route information to sync a template with its guild.
+    SyncTemplate {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The code of the template.
+        template_code: String,
+    },
     /// Route information to unpin a message from a channel.
     UnpinMessage {
         /// The ID of the channel.
@@ -730,6 +964,12 @@ pub enum Route {
     },
     /// Route information to update the current user.
     UpdateCurrentUser,
+    /// Route information to update the current user's voice state in a
+    /// guild's stage channel.
+    UpdateCurrentUserVoiceState {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to update an emoji.
     UpdateEmoji {
         /// The ID of the emoji.
@@ -759,6 +999,13 @@ pub enum Route {
         /// The ID of the integration.
         integration_id: u64,
     },
+    /// Route information to update a scheduled event in a guild.
+    UpdateGuildScheduledEvent {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The ID of the scheduled event.
+        scheduled_event_id: u64,
+    },
     /// Route information to update a member.
     UpdateMember {
         /// The ID of the guild.
@@ -798,6 +1045,31 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to update the stage instance of a stage channel.
+    UpdateStageInstance {
+        /// The ID of the stage channel.
+        channel_id: u64,
+    },
+    /// Route information to update a guild's template.
+    UpdateTemplate {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The code of the template.
+        template_code: String,
+    },
+    /// Route information to update a thread.
+    UpdateThread {
+        /// The ID of the thread.
+        channel_id: u64,
+    },
+    /// Route information to update another user's voice state in a guild's
+    /// stage channel.
+    UpdateUserVoiceState {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The ID of the user.
+        user_id: u64,
+    },
     /// Route information to update a message created by a webhook.
     UpdateWebhookMessage {
         message_id: u64,
@@ -838,6 +1110,15 @@ impl Route {
                 Path::GuildsIdMembersIdRolesId(guild_id),
                 format!("guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id).into(),
             ),
+            Self::AddThreadMember {
+                channel_id,
+                user_id,
+            } => (
+                Method::PUT,
+                Path::ChannelsIdThreadMembersId(channel_id),
+                format!("channels/{}/thread-members/{}", channel_id, user_id).into(),
+            ),
+            Self::CreateAccessToken => (Method::POST, Path::OauthToken, "oauth2/token".into()),
             Self::CreateBan {
                 guild_id,
                 delete_message_days,
@@ -870,6 +1151,11 @@ impl Route {
                 format!("guilds/{}/emojis", guild_id).into(),
             ),
             Self::CreateGuild => (Method::POST, Path::Guilds, "guilds".into()),
+            Self::CreateGuildFromTemplate { template_code } => (
+                Method::POST,
+                Path::GuildsTemplatesCode,
+                format!("guilds/templates/{}", template_code).into(),
+            ),
             Self::CreateGuildIntegration { guild_id } => (
                 Method::POST,
                 Path::GuildsIdIntegrationsId(guild_id),
@@ -905,6 +1191,11 @@ impl Route {
 
                 (Method::POST, Path::GuildsIdPrune(guild_id), path.into())
             }
+            Self::CreateGuildScheduledEvent { guild_id } => (
+                Method::POST,
+                Path::GuildsIdScheduledEvents(guild_id),
+                format!("guilds/{}/scheduled-events", guild_id).into(),
+            ),
             Self::CreateInvite { channel_id } => (
                 Method::POST,
                 Path::ChannelsIdInvites(channel_id),
@@ -938,6 +1229,27 @@ impl Route {
                 Path::GuildsIdRoles(guild_id),
                 format!("guilds/{}/roles", guild_id).into(),
             ),
+            Self::CreateStageInstance => {
+                (Method::POST, Path::StageInstances, "stage-instances".into())
+            }
+            Self::CreateTemplate { guild_id } => (
+                Method::POST,
+                Path::GuildsIdTemplates(guild_id),
+                format!("guilds/{}/templates", guild_id).into(),
+            ),
+            Self::CreateThread { channel_id } => (
+                Method::POST,
+                Path::ChannelsIdThreads(channel_id),
+                format!("channels/{}/threads", channel_id).into(),
+            ),
+            Self::CreateThreadFromMessage {
+                channel_id,
+                message_id,
+            } => (
+                Method::POST,
+                Path::ChannelsIdMessagesIdThreads(channel_id),
+                format!("channels/{}/messages/{}/threads", channel_id, message_id).into(),
+            ),
             Self::CreateTypingTrigger { channel_id } => (
                 Method::POST,
                 Path::ChannelsIdTyping(channel_id),
@@ -984,6 +1296,18 @@ impl Route {
                 Path::GuildsIdIntegrationsId(guild_id),
                 format!("guilds/{}/integrations/{}", guild_id, integration_id).into(),
             ),
+            Self::DeleteGuildScheduledEvent {
+                guild_id,
+                scheduled_event_id,
+            } => (
+                Method::DELETE,
+                Path::GuildsIdScheduledEventsId(guild_id),
+                format!(
+                    "guilds/{}/scheduled-events/{}",
+                    guild_id, scheduled_event_id
+                )
+                .into(),
+            ),
             Self::DeleteInvite { code } => (
                 Method::DELETE,
                 Path::InvitesCode,
@@ -1050,6 +1374,19 @@ impl Route {
                 Path::GuildsIdRolesId(guild_id),
                 format!("guilds/{}/roles/{}", guild_id, role_id).into(),
             ),
+            Self::DeleteStageInstance { channel_id } => (
+                Method::DELETE,
+                Path::StageInstancesId(channel_id),
+                format!("stage-instances/{}", channel_id).into(),
+            ),
+            Self::DeleteTemplate {
+                guild_id,
+                template_code,
+            } => (
+                Method::DELETE,
+                Path::GuildsIdTemplatesCode(guild_id),
+                format!("guilds/{}/templates/{}", guild_id, template_code).into(),
+            ),
             Self::DeleteWebhookMessage {
                 message_id,
                 token,
@@ -1087,6 +1424,11 @@ impl Route {
                 Path::ChannelsIdFollowers(channel_id),
                 format!("channels/{}/followers", channel_id).into(),
             ),
+            Self::GetActiveThreads { guild_id } => (
+                Method::GET,
+                Path::GuildsIdThreadsActive(guild_id),
+                format!("guilds/{}/threads/active", guild_id).into(),
+            ),
             Self::GetAuditLogs {
                 action_type,
                 before,
@@ -1243,6 +1585,77 @@ impl Route {
                 Path::GuildsIdRoles(guild_id),
                 format!("guilds/{}/roles", guild_id).into(),
             ),
+            Self::GetGuildScheduledEvent {
+                guild_id,
+                scheduled_event_id,
+                with_user_count,
+            } => {
+                let mut path = format!(
+                    "guilds/{}/scheduled-events/{}",
+                    guild_id, scheduled_event_id
+                );
+
+                if with_user_count {
+                    path.push_str("?with_user_count=true");
+                }
+
+                (
+                    Method::GET,
+                    Path::GuildsIdScheduledEventsId(guild_id),
+                    path.into(),
+                )
+            }
+            Self::GetGuildScheduledEventUsers {
+                after,
+                before,
+                guild_id,
+                limit,
+                scheduled_event_id,
+                with_member,
+            } => {
+                let mut path = format!(
+                    "guilds/{}/scheduled-events/{}/users?",
+                    guild_id, scheduled_event_id
+                );
+
+                if let Some(after) = after {
+                    let _ = write!(path, "after={}", after);
+                }
+
+                if let Some(before) = before {
+                    let _ = write!(path, "&before={}", before);
+                }
+
+                if let Some(limit) = limit {
+                    let _ = write!(path, "&limit={}", limit);
+                }
+
+                if with_member {
+                    path.push_str("&with_member=true");
+                }
+
+                (
+                    Method::GET,
+                    Path::GuildsIdScheduledEventsIdUsers(guild_id),
+                    path.into(),
+                )
+            }
+            Self::GetGuildScheduledEvents {
+                guild_id,
+                with_user_count,
+            } => {
+                let mut path = format!("guilds/{}/scheduled-events", guild_id);
+
+                if with_user_count {
+                    path.push_str("?with_user_count=true");
+                }
+
+                (
+                    Method::GET,
+                    Path::GuildsIdScheduledEvents(guild_id),
+                    path.into(),
+                )
+            }
             Self::GetGuildVanityUrl { guild_id } => (
                 Method::GET,
                 Path::GuildsIdVanityUrl(guild_id),
@@ -1328,11 +1741,83 @@ impl Route {
                     path.into(),
                 )
             }
+            Self::GetJoinedPrivateArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            } => {
+                let mut path =
+                    format!("channels/{}/users/@me/threads/archived/private", channel_id);
+
+                if let Some(before) = before {
+                    push_query(&mut path, "before", before);
+                }
+
+                if let Some(limit) = limit {
+                    push_query(&mut path, "limit", limit);
+                }
+
+                (
+                    Method::GET,
+                    Path::ChannelsIdUsersIdThreadsArchivedPrivate(channel_id),
+                    path.into(),
+                )
+            }
             Self::GetPins { channel_id } => (
                 Method::GET,
                 Path::ChannelsIdPins(channel_id),
                 format!("channels/{}/pins", channel_id).into(),
             ),
+            Self::GetPrivateArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            } => {
+                let mut path = format!("channels/{}/threads/archived/private", channel_id);
+
+                if let Some(before) = before {
+                    push_query(
+                        &mut path,
+                        "before",
+                        utf8_percent_encode(&before, NON_ALPHANUMERIC),
+                    );
+                }
+
+                if let Some(limit) = limit {
+                    push_query(&mut path, "limit", limit);
+                }
+
+                (
+                    Method::GET,
+                    Path::ChannelsIdThreadsArchivedPrivate(channel_id),
+                    path.into(),
+                )
+            }
+            Self::GetPublicArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            } => {
+                let mut path = format!("channels/{}/threads/archived/public", channel_id);
+
+                if let Some(before) = before {
+                    push_query(
+                        &mut path,
+                        "before",
+                        utf8_percent_encode(&before, NON_ALPHANUMERIC),
+                    );
+                }
+
+                if let Some(limit) = limit {
+                    push_query(&mut path, "limit", limit);
+                }
+
+                (
+                    Method::GET,
+                    Path::ChannelsIdThreadsArchivedPublic(channel_id),
+                    path.into(),
+                )
+            }
             Self::GetReactionUsers {
                 after,
                 before,
@@ -1364,6 +1849,26 @@ impl Route {
                     path.into(),
                 )
             }
+            Self::GetStageInstance { channel_id } => (
+                Method::GET,
+                Path::StageInstancesId(channel_id),
+                format!("stage-instances/{}", channel_id).into(),
+            ),
+            Self::GetTemplate { template_code } => (
+                Method::GET,
+                Path::GuildsTemplatesCode,
+                format!("guilds/templates/{}", template_code).into(),
+            ),
+            Self::GetTemplates { guild_id } => (
+                Method::GET,
+                Path::GuildsIdTemplates(guild_id),
+                format!("guilds/{}/templates", guild_id).into(),
+            ),
+            Self::GetThreadMembers { channel_id } => (
+                Method::GET,
+                Path::ChannelsIdThreadMembers(channel_id),
+                format!("channels/{}/thread-members", channel_id).into(),
+            ),
             Self::GetUserConnections => (
                 Method::GET,
                 Path::UsersIdConnections,
@@ -1390,11 +1895,21 @@ impl Route {
 
                 (Method::GET, Path::WebhooksId(webhook_id), path.into())
             }
+            Self::JoinThread { channel_id } => (
+                Method::PUT,
+                Path::ChannelsIdThreadMembersId(channel_id),
+                format!("channels/{}/thread-members/@me", channel_id).into(),
+            ),
             Self::LeaveGuild { guild_id } => (
                 Method::DELETE,
                 Path::UsersIdGuildsId,
                 format!("users/@me/guilds/{}", guild_id).into(),
             ),
+            Self::LeaveThread { channel_id } => (
+                Method::DELETE,
+                Path::ChannelsIdThreadMembersId(channel_id),
+                format!("channels/{}/thread-members/@me", channel_id).into(),
+            ),
             Self::PinMessage {
                 channel_id,
                 message_id,
@@ -1417,6 +1932,19 @@ impl Route {
                 Path::GuildsIdMembersIdRolesId(guild_id),
                 format!("guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id).into(),
             ),
+            Self::RemoveThreadMember {
+                channel_id,
+                user_id,
+            } => (
+                Method::DELETE,
+                Path::ChannelsIdThreadMembersId(channel_id),
+                format!("channels/{}/thread-members/{}", channel_id, user_id).into(),
+            ),
+            Self::RevokeAccessToken => (
+                Method::POST,
+                Path::OauthTokenRevoke,
+                "oauth2/token/revoke".into(),
+            ),
             Self::SyncGuildIntegration {
                 guild_id,
                 integration_id,
@@ -1425,6 +1953,14 @@ impl Route {
                 Path::GuildsIdIntegrationsIdSync(guild_id),
                 format!("guilds/{}/integrations/{}/sync", guild_id, integration_id).into(),
             ),
+            Self::SyncTemplate {
+                guild_id,
+                template_code,
+            } => (
+                Method::PUT,
+                Path::GuildsIdTemplatesCode(guild_id),
+                format!("guilds/{}/templates/{}", guild_id, template_code).into(),
+            ),
             Self::UnpinMessage {
                 channel_id,
                 message_id,
@@ -1439,6 +1975,11 @@ impl Route {
                 format!("channels/{}", channel_id).into(),
             ),
             Self::UpdateCurrentUser => (Method::PATCH, Path::UsersId, "users/@me".into()),
+            Self::UpdateCurrentUserVoiceState { guild_id } => (
+                Method::PATCH,
+                Path::GuildsIdVoiceStates(guild_id),
+                format!("guilds/{}/voice-states/@me", guild_id).into(),
+            ),
             Self::UpdateEmoji { emoji_id, guild_id } => (
                 Method::PATCH,
                 Path::GuildsIdEmojisId(guild_id),
@@ -1467,6 +2008,18 @@ impl Route {
                 Path::GuildsIdIntegrationsId(guild_id),
                 format!("guilds/{}/integrations/{}", guild_id, integration_id,).into(),
             ),
+            Self::UpdateGuildScheduledEvent {
+                guild_id,
+                scheduled_event_id,
+            } => (
+                Method::PATCH,
+                Path::GuildsIdScheduledEventsId(guild_id),
+                format!(
+                    "guilds/{}/scheduled-events/{}",
+                    guild_id, scheduled_event_id
+                )
+                .into(),
+            ),
             Self::UpdateMember { guild_id, user_id } => (
                 Method::PATCH,
                 Path::GuildsIdMembersId(guild_id),
@@ -1503,6 +2056,29 @@ impl Route {
                 Path::GuildsIdRolesId(guild_id),
                 format!("guilds/{}/roles", guild_id).into(),
             ),
+            Self::UpdateStageInstance { channel_id } => (
+                Method::PATCH,
+                Path::StageInstancesId(channel_id),
+                format!("stage-instances/{}", channel_id).into(),
+            ),
+            Self::UpdateTemplate {
+                guild_id,
+                template_code,
+            } => (
+                Method::PATCH,
+                Path::GuildsIdTemplatesCode(guild_id),
+                format!("guilds/{}/templates/{}", guild_id, template_code).into(),
+            ),
+            Self::UpdateThread { channel_id } => (
+                Method::PATCH,
+                Path::ChannelsId(channel_id),
+                format!("channels/{}", channel_id).into(),
+            ),
+            Self::UpdateUserVoiceState { guild_id, user_id } => (
+                Method::PATCH,
+                Path::GuildsIdVoiceStates(guild_id),
+                format!("guilds/{}/voice-states/{}", guild_id, user_id).into(),
+            ),
             Self::UpdateWebhookMessage {
                 message_id,
                 token,
@@ -1526,9 +2102,16 @@ impl Route {
     }
 }
 
+/// Append a query parameter to a path, separating it from the path or the
+/// previous parameter.
+fn push_query(path: &mut String, key: &str, value: impl Display) {
+    path.push(if path.contains('?') { '&' } else { '?' });
+    let _ = write!(path, "{}={}", key, value);
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Path, PathParseError};
+    use super::{Path, PathParseError, Route};
     use hyper::Method;
     use std::{convert::TryFrom, error::Error, str::FromStr};
 
@@ -1545,6 +2128,46 @@ mod tests {
         assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
         assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
         assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(
+            Path::GuildsTemplatesCode,
+            Path::from_str("/guilds/templates/abc")?
+        );
+        assert_eq!(
+            Path::GuildsIdTemplatesCode(123),
+            Path::from_str("/guilds/123/templates/abc")?
+        );
+        assert_eq!(
+            Path::ChannelsIdThreadsArchivedPublic(123),
+            Path::from_str("/channels/123/threads/archived/public")?
+        );
+        assert_eq!(
+            Path::ChannelsIdUsersIdThreadsArchivedPrivate(123),
+            Path::from_str("/channels/123/users/@me/threads/archived/private")?
+        );
+        assert_eq!(
+            Path::GuildsIdThreadsActive(123),
+            Path::from_str("/guilds/123/threads/active")?
+        );
+        assert_eq!(
+            Path::GuildsIdVoiceStates(123),
+            Path::from_str("/guilds/123/voice-states/@me")?
+        );
+        assert_eq!(
+            Path::StageInstancesId(123),
+            Path::from_str("/stage-instances/123")?
+        );
+        assert_eq!(
+            Path::OauthTokenRevoke,
+            Path::from_str("/oauth2/token/revoke")?
+        );
+        assert_eq!(
+            Path::GuildsIdScheduledEventsId(123),
+            Path::from_str("/guilds/123/scheduled-events/456")?
+        );
+        assert_eq!(
+            Path::GuildsIdScheduledEventsIdUsers(123),
+            Path::from_str("/guilds/123/scheduled-events/456/users")?
+        );
 
         Ok(())
     }
@@ -1562,4 +2185,34 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_archived_threads_query() {
+        let (_, _, path) = Route::GetPublicArchivedThreads {
+            before: Some("2021-01-01T00:00:00+00:00".to_owned()),
+            channel_id: 123,
+            limit: Some(10),
+        }
+        .into_parts();
+        assert_eq!(
+            "channels/123/threads/archived/public?before=2021%2D01%2D01T00%3A00%3A00%2B00%3A00&limit=10",
+            path
+        );
+
+        let (_, _, path) = Route::GetPrivateArchivedThreads {
+            before: None,
+            channel_id: 123,
+            limit: Some(10),
+        }
+        .into_parts();
+        assert_eq!("channels/123/threads/archived/private?limit=10", path);
+
+        let (_, _, path) = Route::GetJoinedPrivateArchivedThreads {
+            before: None,
+            channel_id: 123,
+            limit: None,
+        }
+        .into_parts();
+        assert_eq!("channels/123/users/@me/threads/archived/private", path);
+    }
 }
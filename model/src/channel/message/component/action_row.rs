@@ -0,0 +1,11 @@
+use super::Component;
+
+/// Non-interactive [`Component`] that contains other components.
+///
+/// An action row can contain up to five buttons or a single select menu, and
+/// can not contain other action rows.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ActionRow {
+    /// List of components within the action row.
+    pub components: Vec<Component>,
+}
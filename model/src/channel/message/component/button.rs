@@ -0,0 +1,75 @@
+use crate::channel::ReactionType;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Clickable [`Component`] below messages.
+///
+/// Buttons with a [`ButtonStyle::Link`] style must have a [`url`] and no
+/// [`custom_id`], while all other buttons must have a [`custom_id`] and no
+/// [`url`].
+///
+/// [`Component`]: super::Component
+/// [`custom_id`]: Self::custom_id
+/// [`url`]: Self::url
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Button {
+    /// User defined identifier for the button, sent with interactions.
+    pub custom_id: Option<String>,
+    /// Whether the button is disabled.
+    pub disabled: bool,
+    /// Visual emoji for clients to display with the button.
+    pub emoji: Option<ReactionType>,
+    /// Text appearing on the button.
+    pub label: Option<String>,
+    /// Style variant of the button.
+    pub style: ButtonStyle,
+    /// URL for buttons of a [`ButtonStyle::Link`] style.
+    pub url: Option<String>,
+}
+
+/// Style of a [`Button`].
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum ButtonStyle {
+    /// Button indicates a primary action.
+    ///
+    /// Selecting this button style requires specifying the
+    /// [`Button::custom_id`] field.
+    Primary = 1,
+    /// Button indicates a secondary action.
+    ///
+    /// Selecting this button style requires specifying the
+    /// [`Button::custom_id`] field.
+    Secondary = 2,
+    /// Button indicates a successful action.
+    ///
+    /// Selecting this button style requires specifying the
+    /// [`Button::custom_id`] field.
+    Success = 3,
+    /// Button indicates a dangerous action.
+    ///
+    /// Selecting this button style requires specifying the
+    /// [`Button::custom_id`] field.
+    Danger = 4,
+    /// Button indicates an action with a link.
+    ///
+    /// Selecting this button style requires specifying the [`Button::url`]
+    /// field.
+    Link = 5,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ButtonStyle;
+    use serde_test::Token;
+
+    #[test]
+    fn test_button_style() {
+        serde_test::assert_tokens(&ButtonStyle::Primary, &[Token::U8(1)]);
+        serde_test::assert_tokens(&ButtonStyle::Secondary, &[Token::U8(2)]);
+        serde_test::assert_tokens(&ButtonStyle::Success, &[Token::U8(3)]);
+        serde_test::assert_tokens(&ButtonStyle::Danger, &[Token::U8(4)]);
+        serde_test::assert_tokens(&ButtonStyle::Link, &[Token::U8(5)]);
+    }
+}
@@ -0,0 +1,56 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of a [`Component`].
+///
+/// [`Component`]: super::Component
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum ComponentType {
+    /// Component is an [`ActionRow`].
+    ///
+    /// [`ActionRow`]: super::ActionRow
+    ActionRow = 1,
+    /// Component is a [`Button`].
+    ///
+    /// [`Button`]: super::Button
+    Button = 2,
+    /// Component is a [`SelectMenu`].
+    ///
+    /// [`SelectMenu`]: super::SelectMenu
+    SelectMenu = 3,
+}
+
+impl ComponentType {
+    /// Name of the component type.
+    ///
+    /// Variants have a name equivalent to the variant name itself.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ActionRow => "ActionRow",
+            Self::Button => "Button",
+            Self::SelectMenu => "SelectMenu",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ComponentType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&ComponentType::ActionRow, &[Token::U8(1)]);
+        serde_test::assert_tokens(&ComponentType::Button, &[Token::U8(2)]);
+        serde_test::assert_tokens(&ComponentType::SelectMenu, &[Token::U8(3)]);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!("ActionRow", ComponentType::ActionRow.name());
+        assert_eq!("Button", ComponentType::Button.name());
+        assert_eq!("SelectMenu", ComponentType::SelectMenu.name());
+    }
+}
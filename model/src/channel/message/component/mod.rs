@@ -0,0 +1,513 @@
+//! Interactive message elements, such as buttons and select menus.
+//!
+//! Refer to the [Discord documentation] for more information.
+//!
+//! [Discord documentation]: https://discord.com/developers/docs/interactions/message-components
+
+mod action_row;
+mod button;
+mod kind;
+mod select_menu;
+
+pub use self::{
+    action_row::ActionRow,
+    button::{Button, ButtonStyle},
+    kind::ComponentType,
+    select_menu::{SelectMenu, SelectMenuOption},
+};
+
+use crate::channel::ReactionType;
+use serde::{
+    de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Serialize,
+};
+use std::fmt::{Formatter, Result as FmtResult};
+
+/// Interactive element of a message.
+///
+/// Top-level components of a message must be [`ActionRow`]s, which then
+/// contain [`Button`]s or a [`SelectMenu`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Component {
+    /// Row containing other components.
+    ActionRow(ActionRow),
+    /// Clickable button.
+    Button(Button),
+    /// Dropdown menu of options.
+    SelectMenu(SelectMenu),
+}
+
+impl Component {
+    /// Type of component that this is.
+    ///
+    /// ```
+    /// use twilight_model::channel::message::component::{
+    ///     Button, ButtonStyle, Component, ComponentType,
+    /// };
+    ///
+    /// let component = Component::Button(Button {
+    ///     custom_id: None,
+    ///     disabled: false,
+    ///     emoji: None,
+    ///     label: Some("ping".to_owned()),
+    ///     style: ButtonStyle::Primary,
+    ///     url: None,
+    /// });
+    ///
+    /// assert_eq!(ComponentType::Button, component.kind());
+    /// ```
+    pub fn kind(&self) -> ComponentType {
+        match self {
+            Self::ActionRow(_) => ComponentType::ActionRow,
+            Self::Button(_) => ComponentType::Button,
+            Self::SelectMenu(_) => ComponentType::SelectMenu,
+        }
+    }
+}
+
+impl From<ActionRow> for Component {
+    fn from(action_row: ActionRow) -> Self {
+        Self::ActionRow(action_row)
+    }
+}
+
+impl From<Button> for Component {
+    fn from(button: Button) -> Self {
+        Self::Button(button)
+    }
+}
+
+impl From<SelectMenu> for Component {
+    fn from(select_menu: SelectMenu) -> Self {
+        Self::SelectMenu(select_menu)
+    }
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ComponentVisitor)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum ComponentField {
+    Components,
+    CustomId,
+    Disabled,
+    Emoji,
+    Label,
+    MaxValues,
+    MinValues,
+    Options,
+    Placeholder,
+    Style,
+    Type,
+    Url,
+}
+
+struct ComponentVisitor;
+
+impl<'de> Visitor<'de> for ComponentVisitor {
+    type Value = Component;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("struct Component")
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+        let mut components: Option<Vec<Component>> = None;
+        let mut custom_id: Option<String> = None;
+        let mut disabled: Option<bool> = None;
+        let mut emoji: Option<Option<ReactionType>> = None;
+        let mut kind: Option<ComponentType> = None;
+        let mut label: Option<Option<String>> = None;
+        let mut max_values: Option<Option<u8>> = None;
+        let mut min_values: Option<Option<u8>> = None;
+        let mut options: Option<Vec<SelectMenuOption>> = None;
+        let mut placeholder: Option<Option<String>> = None;
+        let mut style: Option<ButtonStyle> = None;
+        let mut url: Option<Option<String>> = None;
+
+        loop {
+            let key = match map.next_key() {
+                Ok(Some(key)) => key,
+                Ok(None) => break,
+                Err(_) => {
+                    // Encountered when we run into an unknown key.
+                    map.next_value::<IgnoredAny>()?;
+
+                    continue;
+                }
+            };
+
+            match key {
+                ComponentField::Components => {
+                    if components.is_some() {
+                        return Err(DeError::duplicate_field("components"));
+                    }
+
+                    components = Some(map.next_value()?);
+                }
+                ComponentField::CustomId => {
+                    if custom_id.is_some() {
+                        return Err(DeError::duplicate_field("custom_id"));
+                    }
+
+                    custom_id = Some(map.next_value()?);
+                }
+                ComponentField::Disabled => {
+                    if disabled.is_some() {
+                        return Err(DeError::duplicate_field("disabled"));
+                    }
+
+                    disabled = Some(map.next_value()?);
+                }
+                ComponentField::Emoji => {
+                    if emoji.is_some() {
+                        return Err(DeError::duplicate_field("emoji"));
+                    }
+
+                    emoji = Some(map.next_value()?);
+                }
+                ComponentField::Label => {
+                    if label.is_some() {
+                        return Err(DeError::duplicate_field("label"));
+                    }
+
+                    label = Some(map.next_value()?);
+                }
+                ComponentField::MaxValues => {
+                    if max_values.is_some() {
+                        return Err(DeError::duplicate_field("max_values"));
+                    }
+
+                    max_values = Some(map.next_value()?);
+                }
+                ComponentField::MinValues => {
+                    if min_values.is_some() {
+                        return Err(DeError::duplicate_field("min_values"));
+                    }
+
+                    min_values = Some(map.next_value()?);
+                }
+                ComponentField::Options => {
+                    if options.is_some() {
+                        return Err(DeError::duplicate_field("options"));
+                    }
+
+                    options = Some(map.next_value()?);
+                }
+                ComponentField::Placeholder => {
+                    if placeholder.is_some() {
+                        return Err(DeError::duplicate_field("placeholder"));
+                    }
+
+                    placeholder = Some(map.next_value()?);
+                }
+                ComponentField::Style => {
+                    if style.is_some() {
+                        return Err(DeError::duplicate_field("style"));
+                    }
+
+                    style = Some(map.next_value()?);
+                }
+                ComponentField::Type => {
+                    if kind.is_some() {
+                        return Err(DeError::duplicate_field("type"));
+                    }
+
+                    kind = Some(map.next_value()?);
+                }
+                ComponentField::Url => {
+                    if url.is_some() {
+                        return Err(DeError::duplicate_field("url"));
+                    }
+
+                    url = Some(map.next_value()?);
+                }
+            }
+        }
+
+        let kind = kind.ok_or_else(|| DeError::missing_field("type"))?;
+
+        Ok(match kind {
+            ComponentType::ActionRow => {
+                let components = components.ok_or_else(|| DeError::missing_field("components"))?;
+
+                Component::ActionRow(ActionRow { components })
+            }
+            ComponentType::Button => {
+                let style = style.ok_or_else(|| DeError::missing_field("style"))?;
+
+                Component::Button(Button {
+                    custom_id,
+                    disabled: disabled.unwrap_or_default(),
+                    emoji: emoji.flatten(),
+                    label: label.flatten(),
+                    style,
+                    url: url.flatten(),
+                })
+            }
+            ComponentType::SelectMenu => {
+                let custom_id = custom_id.ok_or_else(|| DeError::missing_field("custom_id"))?;
+                let options = options.ok_or_else(|| DeError::missing_field("options"))?;
+
+                Component::SelectMenu(SelectMenu {
+                    custom_id,
+                    disabled: disabled.unwrap_or_default(),
+                    max_values: max_values.flatten(),
+                    min_values: min_values.flatten(),
+                    options,
+                    placeholder: placeholder.flatten(),
+                })
+            }
+        })
+    }
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::ActionRow(action_row) => {
+                let mut state = serializer.serialize_struct("Component", 2)?;
+                state.serialize_field("type", &ComponentType::ActionRow)?;
+                state.serialize_field("components", &action_row.components)?;
+
+                state.end()
+            }
+            Self::Button(button) => {
+                // Type and style are always present, along with each field that
+                // is set.
+                let len = 2
+                    + usize::from(button.custom_id.is_some())
+                    + usize::from(button.disabled)
+                    + usize::from(button.emoji.is_some())
+                    + usize::from(button.label.is_some())
+                    + usize::from(button.url.is_some());
+
+                let mut state = serializer.serialize_struct("Component", len)?;
+                state.serialize_field("type", &ComponentType::Button)?;
+
+                if let Some(custom_id) = &button.custom_id {
+                    state.serialize_field("custom_id", custom_id)?;
+                } else {
+                    state.skip_field("custom_id")?;
+                }
+
+                if button.disabled {
+                    state.serialize_field("disabled", &button.disabled)?;
+                } else {
+                    state.skip_field("disabled")?;
+                }
+
+                if button.emoji.is_some() {
+                    state.serialize_field("emoji", &button.emoji)?;
+                } else {
+                    state.skip_field("emoji")?;
+                }
+
+                if button.label.is_some() {
+                    state.serialize_field("label", &button.label)?;
+                } else {
+                    state.skip_field("label")?;
+                }
+
+                state.serialize_field("style", &button.style)?;
+
+                if button.url.is_some() {
+                    state.serialize_field("url", &button.url)?;
+                } else {
+                    state.skip_field("url")?;
+                }
+
+                state.end()
+            }
+            Self::SelectMenu(select_menu) => {
+                // Type, custom ID, and options are always present, along with
+                // each field that is set.
+                let len = 3
+                    + usize::from(select_menu.disabled)
+                    + usize::from(select_menu.max_values.is_some())
+                    + usize::from(select_menu.min_values.is_some())
+                    + usize::from(select_menu.placeholder.is_some());
+
+                let mut state = serializer.serialize_struct("Component", len)?;
+                state.serialize_field("type", &ComponentType::SelectMenu)?;
+                state.serialize_field("custom_id", &select_menu.custom_id)?;
+
+                if select_menu.disabled {
+                    state.serialize_field("disabled", &select_menu.disabled)?;
+                } else {
+                    state.skip_field("disabled")?;
+                }
+
+                if select_menu.max_values.is_some() {
+                    state.serialize_field("max_values", &select_menu.max_values)?;
+                } else {
+                    state.skip_field("max_values")?;
+                }
+
+                if select_menu.min_values.is_some() {
+                    state.serialize_field("min_values", &select_menu.min_values)?;
+                } else {
+                    state.skip_field("min_values")?;
+                }
+
+                state.serialize_field("options", &select_menu.options)?;
+
+                if select_menu.placeholder.is_some() {
+                    state.serialize_field("placeholder", &select_menu.placeholder)?;
+                } else {
+                    state.skip_field("placeholder")?;
+                }
+
+                state.end()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ActionRow, Button, ButtonStyle, Component, ComponentType, SelectMenu, SelectMenuOption,
+    };
+    use crate::channel::ReactionType;
+    use serde_test::Token;
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn test_action_row() {
+        let value = Component::ActionRow(ActionRow {
+            components: vec![
+                Component::Button(Button {
+                    custom_id: Some("button-1".to_owned()),
+                    disabled: false,
+                    emoji: Some(ReactionType::Unicode {
+                        name: "\u{1f643}".to_owned(),
+                    }),
+                    label: Some("a label".to_owned()),
+                    style: ButtonStyle::Primary,
+                    url: None,
+                }),
+                Component::Button(Button {
+                    custom_id: None,
+                    disabled: true,
+                    emoji: None,
+                    label: None,
+                    style: ButtonStyle::Link,
+                    url: Some("https://twilight.rs".to_owned()),
+                }),
+            ],
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(ComponentType::ActionRow as u8),
+                Token::Str("components"),
+                Token::Seq { len: Some(2) },
+                Token::Struct {
+                    name: "Component",
+                    len: 5,
+                },
+                Token::Str("type"),
+                Token::U8(ComponentType::Button as u8),
+                Token::Str("custom_id"),
+                Token::Str("button-1"),
+                Token::Str("emoji"),
+                Token::Some,
+                Token::Struct {
+                    name: "ReactionType",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Str("\u{1f643}"),
+                Token::StructEnd,
+                Token::Str("label"),
+                Token::Some,
+                Token::Str("a label"),
+                Token::Str("style"),
+                Token::U8(ButtonStyle::Primary as u8),
+                Token::StructEnd,
+                Token::Struct {
+                    name: "Component",
+                    len: 4,
+                },
+                Token::Str("type"),
+                Token::U8(ComponentType::Button as u8),
+                Token::Str("disabled"),
+                Token::Bool(true),
+                Token::Str("style"),
+                Token::U8(ButtonStyle::Link as u8),
+                Token::Str("url"),
+                Token::Some,
+                Token::Str("https://twilight.rs"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_select_menu() {
+        let value = Component::SelectMenu(SelectMenu {
+            custom_id: "select-1".to_owned(),
+            disabled: false,
+            max_values: Some(2),
+            min_values: None,
+            options: vec![SelectMenuOption {
+                default: false,
+                description: None,
+                emoji: None,
+                label: "a label".to_owned(),
+                value: "a value".to_owned(),
+            }],
+            placeholder: Some("a placeholder".to_owned()),
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 5,
+                },
+                Token::Str("type"),
+                Token::U8(ComponentType::SelectMenu as u8),
+                Token::Str("custom_id"),
+                Token::Str("select-1"),
+                Token::Str("max_values"),
+                Token::Some,
+                Token::U8(2),
+                Token::Str("options"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "SelectMenuOption",
+                    len: 3,
+                },
+                Token::Str("default"),
+                Token::Bool(false),
+                Token::Str("label"),
+                Token::Str("a label"),
+                Token::Str("value"),
+                Token::Str("a value"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("placeholder"),
+                Token::Some,
+                Token::Str("a placeholder"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::channel::ReactionType;
+use serde::{Deserialize, Serialize};
+
+/// Dropdown-style [`Component`] that renders below messages.
+///
+/// [`Component`]: super::Component
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SelectMenu {
+    /// Developer defined identifier, sent with interactions.
+    pub custom_id: String,
+    /// Whether the select menu is disabled.
+    pub disabled: bool,
+    /// Maximum number of options that may be chosen.
+    pub max_values: Option<u8>,
+    /// Minimum number of options that must be chosen.
+    pub min_values: Option<u8>,
+    /// List of available options.
+    pub options: Vec<SelectMenuOption>,
+    /// Custom placeholder text if no option is selected.
+    pub placeholder: Option<String>,
+}
+
+/// Dropdown options that are part of a [`SelectMenu`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SelectMenuOption {
+    /// Whether the option will be selected by default.
+    #[serde(default)]
+    pub default: bool,
+    /// Additional description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Emoji associated with the option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ReactionType>,
+    /// User-facing name.
+    pub label: String,
+    /// Developer defined value.
+    pub value: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SelectMenuOption;
+    use crate::{channel::ReactionType, id::EmojiId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_select_menu_option() {
+        let value = SelectMenuOption {
+            default: true,
+            description: Some("the description".to_owned()),
+            emoji: Some(ReactionType::Custom {
+                animated: false,
+                id: EmojiId(1),
+                name: Some("test".to_owned()),
+            }),
+            label: "the label".to_owned(),
+            value: "the value".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "SelectMenuOption",
+                    len: 5,
+                },
+                Token::Str("default"),
+                Token::Bool(true),
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("the description"),
+                Token::Str("emoji"),
+                Token::Some,
+                Token::Struct {
+                    name: "ReactionType",
+                    len: 3,
+                },
+                Token::Str("animated"),
+                Token::Bool(false),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "EmojiId" },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Some,
+                Token::Str("test"),
+                Token::StructEnd,
+                Token::Str("label"),
+                Token::Str("the label"),
+                Token::Str("value"),
+                Token::Str("the value"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
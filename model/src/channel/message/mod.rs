@@ -1,3 +1,4 @@
+pub mod component;
 pub mod sticker;
 
 mod activity;
@@ -11,8 +12,8 @@ mod reference;
 
 pub use self::{
     activity::MessageActivity, activity_type::MessageActivityType, application::MessageApplication,
-    flags::MessageFlags, kind::MessageType, mention::Mention, reaction::MessageReaction,
-    reference::MessageReference, sticker::Sticker,
+    component::Component, flags::MessageFlags, kind::MessageType, mention::Mention,
+    reaction::MessageReaction, reference::MessageReference, sticker::Sticker,
 };
 
 use crate::{
@@ -32,6 +33,9 @@ pub struct Message {
     pub attachments: Vec<Attachment>,
     pub author: User,
     pub channel_id: ChannelId,
+    /// List of provided components, such as buttons.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
     pub content: String,
     pub edited_timestamp: Option<String>,
     pub embeds: Vec<Embed>,
@@ -107,6 +111,7 @@ mod tests {
                 verified: None,
             },
             channel_id: ChannelId(2),
+            components: Vec::new(),
             content: "ping".to_owned(),
             edited_timestamp: None,
             embeds: Vec::new(),
@@ -295,6 +300,7 @@ mod tests {
                 verified: None,
             },
             channel_id: ChannelId(2),
+            components: Vec::new(),
             content: "ping".to_owned(),
             edited_timestamp: Some("123".to_owned()),
             embeds: Vec::new(),
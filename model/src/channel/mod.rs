@@ -1,6 +1,8 @@
 pub mod embed;
 pub mod message;
 pub mod permission_overwrite;
+pub mod stage_instance;
+pub mod thread;
 
 mod attachment;
 mod category_channel;
@@ -12,6 +14,7 @@ mod private_channel;
 mod reaction;
 mod reaction_type;
 mod text_channel;
+mod thread_channel;
 mod voice_channel;
 mod webhook;
 mod webhook_type;
@@ -20,16 +23,17 @@ pub use self::{
     attachment::Attachment, category_channel::CategoryChannel, channel_mention::ChannelMention,
     channel_type::ChannelType, followed_channel::FollowedChannel, group::Group, message::Message,
     private_channel::PrivateChannel, reaction::Reaction, reaction_type::ReactionType,
-    text_channel::TextChannel, voice_channel::VoiceChannel, webhook::Webhook,
-    webhook_type::WebhookType,
+    text_channel::TextChannel, thread_channel::ThreadChannel, voice_channel::VoiceChannel,
+    webhook::Webhook, webhook_type::WebhookType,
 };
 
-use crate::id::{ChannelId, GuildId, MessageId};
+use crate::id::{ChannelId, GuildId, MessageId, UserId};
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     Deserialize, Serialize,
 };
 use std::fmt::{self, Formatter, Result as FmtResult};
+use thread::{ThreadMember, ThreadMetadata};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum ConversionError {
@@ -82,7 +86,9 @@ impl Channel {
 #[serde(untagged)]
 pub enum GuildChannel {
     Category(CategoryChannel),
+    Stage(VoiceChannel),
     Text(TextChannel),
+    Thread(ThreadChannel),
     Voice(VoiceChannel),
 }
 
@@ -91,7 +97,9 @@ impl GuildChannel {
     pub fn guild_id(&self) -> Option<GuildId> {
         match self {
             Self::Category(category) => category.guild_id,
+            Self::Stage(voice) => voice.guild_id,
             Self::Text(text) => text.guild_id,
+            Self::Thread(thread) => thread.guild_id,
             Self::Voice(voice) => voice.guild_id,
         }
     }
@@ -100,16 +108,31 @@ impl GuildChannel {
     pub fn id(&self) -> ChannelId {
         match self {
             Self::Category(category) => category.id,
+            Self::Stage(voice) => voice.id,
             Self::Text(text) => text.id,
+            Self::Thread(thread) => thread.id,
             Self::Voice(voice) => voice.id,
         }
     }
 
+    /// Return the type of the inner guild channel.
+    pub fn kind(&self) -> ChannelType {
+        match self {
+            Self::Category(category) => category.kind,
+            Self::Stage(voice) => voice.kind,
+            Self::Text(text) => text.kind,
+            Self::Thread(thread) => thread.kind,
+            Self::Voice(voice) => voice.kind,
+        }
+    }
+
     /// Return an immutable reference to the name of the inner guild channel.
     pub fn name(&self) -> &str {
         match self {
             Self::Category(category) => category.name.as_ref(),
+            Self::Stage(voice) => voice.name.as_ref(),
             Self::Text(text) => text.name.as_ref(),
+            Self::Thread(thread) => thread.name.as_ref(),
             Self::Voice(voice) => voice.name.as_ref(),
         }
     }
@@ -123,12 +146,17 @@ enum GuildChannelField {
     Id,
     LastMessageId,
     LastPinTimestamp,
+    Member,
+    MemberCount,
+    MessageCount,
     Name,
     Nsfw,
+    OwnerId,
     ParentId,
     PermissionOverwrites,
     Position,
     RateLimitPerUser,
+    ThreadMetadata,
     Topic,
     Type,
     UserLimit,
@@ -148,6 +176,10 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         const VARIANTS: &[&str] = &[
             "GuildCategory",
             "GuildNews",
+            "GuildNewsThread",
+            "GuildPrivateThread",
+            "GuildPublicThread",
+            "GuildStageVoice",
             "GuildStore",
             "GuildText",
             "GuildVoice",
@@ -156,15 +188,20 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         let mut bitrate = None;
         let mut guild_id = None;
         let mut id = None;
-        let mut kind = None;
+        let mut kind: Option<ChannelType> = None;
         let mut last_message_id: Option<Option<MessageId>> = None;
         let mut last_pin_timestamp: Option<Option<String>> = None;
+        let mut member: Option<Option<ThreadMember>> = None;
+        let mut member_count = None;
+        let mut message_count = None;
         let mut name = None;
         let mut nsfw = None;
+        let mut owner_id: Option<Option<UserId>> = None;
         let mut parent_id: Option<Option<ChannelId>> = None;
         let mut permission_overwrites = None;
         let mut position = None;
         let mut rate_limit_per_user = None;
+        let mut thread_metadata: Option<ThreadMetadata> = None;
         let mut topic: Option<Option<String>> = None;
         let mut user_limit = None;
 
@@ -235,6 +272,27 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     last_pin_timestamp = Some(map.next_value()?);
                 }
+                GuildChannelField::Member => {
+                    if member.is_some() {
+                        return Err(DeError::duplicate_field("member"));
+                    }
+
+                    member = Some(map.next_value()?);
+                }
+                GuildChannelField::MemberCount => {
+                    if member_count.is_some() {
+                        return Err(DeError::duplicate_field("member_count"));
+                    }
+
+                    member_count = Some(map.next_value()?);
+                }
+                GuildChannelField::MessageCount => {
+                    if message_count.is_some() {
+                        return Err(DeError::duplicate_field("message_count"));
+                    }
+
+                    message_count = Some(map.next_value()?);
+                }
                 GuildChannelField::Name => {
                     if name.is_some() {
                         return Err(DeError::duplicate_field("name"));
@@ -249,6 +307,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     nsfw = Some(map.next_value()?);
                 }
+                GuildChannelField::OwnerId => {
+                    if owner_id.is_some() {
+                        return Err(DeError::duplicate_field("owner_id"));
+                    }
+
+                    owner_id = Some(map.next_value()?);
+                }
                 GuildChannelField::ParentId => {
                     if parent_id.is_some() {
                         return Err(DeError::duplicate_field("parent_id"));
@@ -277,6 +342,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     rate_limit_per_user = Some(map.next_value()?);
                 }
+                GuildChannelField::ThreadMetadata => {
+                    if thread_metadata.is_some() {
+                        return Err(DeError::duplicate_field("thread_metadata"));
+                    }
+
+                    thread_metadata = Some(map.next_value()?);
+                }
                 GuildChannelField::Topic => {
                     if topic.is_some() {
                         return Err(DeError::duplicate_field("topic"));
@@ -299,9 +371,6 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         let id = id.ok_or_else(|| DeError::missing_field("id"))?;
         let kind = kind.ok_or_else(|| DeError::missing_field("type"))?;
         let name = name.ok_or_else(|| DeError::missing_field("name"))?;
-        let permission_overwrites =
-            permission_overwrites.ok_or_else(|| DeError::missing_field("permission_overwrites"))?;
-        let position = position.ok_or_else(|| DeError::missing_field("position"))?;
 
         let nsfw = nsfw.unwrap_or_default();
         let parent_id = parent_id.unwrap_or_default();
@@ -312,11 +381,52 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
             %name,
             %nsfw,
             ?parent_id,
-            ?permission_overwrites,
-            %position,
             "common fields of all variants exist"
         );
 
+        if kind.is_thread() {
+            let member_count =
+                member_count.ok_or_else(|| DeError::missing_field("member_count"))?;
+            let message_count =
+                message_count.ok_or_else(|| DeError::missing_field("message_count"))?;
+            let thread_metadata =
+                thread_metadata.ok_or_else(|| DeError::missing_field("thread_metadata"))?;
+            let last_message_id = last_message_id.unwrap_or_default();
+            let member = member.unwrap_or_default();
+            let owner_id = owner_id.unwrap_or_default();
+
+            tracing::trace!(
+                %member_count,
+                %message_count,
+                ?owner_id,
+                ?thread_metadata,
+                "handling thread channel"
+            );
+
+            return Ok(GuildChannel::Thread(ThreadChannel {
+                guild_id,
+                id,
+                kind,
+                last_message_id,
+                member,
+                member_count,
+                message_count,
+                name,
+                owner_id,
+                parent_id,
+                rate_limit_per_user,
+                thread_metadata,
+            }));
+        }
+
+        // Channels other than threads have permission overwrites and
+        // positions.
+        let permission_overwrites =
+            permission_overwrites.ok_or_else(|| DeError::missing_field("permission_overwrites"))?;
+        let position = position.ok_or_else(|| DeError::missing_field("position"))?;
+
+        tracing::trace!(?permission_overwrites, %position, "fields of non-thread variants exist");
+
         Ok(match kind {
             ChannelType::GuildCategory => {
                 tracing::trace!("handling category channel");
@@ -330,13 +440,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
                     position,
                 })
             }
-            ChannelType::GuildVoice => {
+            ChannelType::GuildStageVoice | ChannelType::GuildVoice => {
                 let bitrate = bitrate.ok_or_else(|| DeError::missing_field("bitrate"))?;
                 let user_limit = user_limit.ok_or_else(|| DeError::missing_field("user_limit"))?;
 
-                tracing::trace!(%bitrate, ?user_limit, "handling voice channel");
+                tracing::trace!(%bitrate, ?user_limit, "handling stage or voice channel");
 
-                GuildChannel::Voice(VoiceChannel {
+                let channel = VoiceChannel {
                     id,
                     bitrate,
                     guild_id,
@@ -346,7 +456,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
                     parent_id,
                     position,
                     user_limit,
-                })
+                };
+
+                if kind == ChannelType::GuildStageVoice {
+                    GuildChannel::Stage(channel)
+                } else {
+                    GuildChannel::Voice(channel)
+                }
             }
             ChannelType::GuildNews | ChannelType::GuildStore | ChannelType::GuildText => {
                 let last_message_id = last_message_id.unwrap_or_default();
@@ -390,10 +506,13 @@ impl<'de> Deserialize<'de> for GuildChannel {
 mod tests {
     use super::{
         CategoryChannel, Channel, ChannelType, Group, GuildChannel, PrivateChannel, TextChannel,
-        VoiceChannel,
+        ThreadChannel, VoiceChannel,
     };
     use crate::{
-        channel::permission_overwrite::PermissionOverwrite,
+        channel::{
+            permission_overwrite::PermissionOverwrite,
+            thread::{AutoArchiveDuration, ThreadMember, ThreadMetadata},
+        },
         id::{ChannelId, GuildId, MessageId, UserId},
     };
 
@@ -612,6 +731,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_guild_stage_channel_deserialization() {
+        let value = GuildChannel::Stage(VoiceChannel {
+            bitrate: 64000,
+            guild_id: Some(GuildId(2)),
+            id: ChannelId(1),
+            kind: ChannelType::GuildStageVoice,
+            name: "stage".to_owned(),
+            permission_overwrites: Vec::new(),
+            parent_id: None,
+            position: 3,
+            user_limit: Some(0),
+        });
+        let permission_overwrites: Vec<PermissionOverwrite> = Vec::new();
+
+        assert_eq!(
+            value,
+            serde_json::from_value(serde_json::json!({
+                "bitrate": 64000,
+                "id": "1",
+                "guild_id": "2",
+                "name": "stage",
+                "parent_id": None::<ChannelId>,
+                "permission_overwrites": permission_overwrites,
+                "position": 3,
+                "type": 13,
+                "user_limit": 0,
+            }))
+            .unwrap()
+        );
+    }
+
     #[test]
     fn test_guild_news_channel_deserialization() {
         let value = GuildChannel::Text(TextChannel {
@@ -681,4 +832,61 @@ mod tests {
             .unwrap()
         );
     }
+
+    #[test]
+    fn test_guild_thread_channel_deserialization() {
+        let value = GuildChannel::Thread(ThreadChannel {
+            guild_id: Some(GuildId(1)),
+            id: ChannelId(2),
+            kind: ChannelType::GuildPrivateThread,
+            last_message_id: Some(MessageId(3)),
+            member: Some(ThreadMember {
+                flags: 0,
+                id: None,
+                join_timestamp: "2021-06-01T00:00:00.000000+00:00".to_owned(),
+                user_id: None,
+            }),
+            member_count: 4,
+            message_count: 5,
+            name: "thread".to_owned(),
+            owner_id: Some(UserId(6)),
+            parent_id: Some(ChannelId(7)),
+            rate_limit_per_user: Some(0),
+            thread_metadata: ThreadMetadata {
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::ThreeDays,
+                archive_timestamp: "2021-06-01T00:00:00.000000+00:00".to_owned(),
+                invitable: Some(true),
+                locked: false,
+            },
+        });
+
+        assert_eq!(
+            value,
+            serde_json::from_value(serde_json::json!({
+                "guild_id": "1",
+                "id": "2",
+                "last_message_id": "3",
+                "member": {
+                    "flags": 0,
+                    "join_timestamp": "2021-06-01T00:00:00.000000+00:00",
+                },
+                "member_count": 4,
+                "message_count": 5,
+                "name": "thread",
+                "owner_id": "6",
+                "parent_id": "7",
+                "rate_limit_per_user": 0,
+                "thread_metadata": {
+                    "archived": false,
+                    "auto_archive_duration": 4320,
+                    "archive_timestamp": "2021-06-01T00:00:00.000000+00:00",
+                    "invitable": true,
+                    "locked": false,
+                },
+                "type": 12,
+            }))
+            .unwrap()
+        );
+    }
 }
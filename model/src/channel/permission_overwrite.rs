@@ -2,11 +2,7 @@ use crate::{
     guild::Permissions,
     id::{RoleId, UserId},
 };
-use serde::{
-    de::{Deserializer, Error as DeError},
-    ser::SerializeStruct,
-    Deserialize, Serialize, Serializer,
-};
+use serde::{de::Deserializer, ser::SerializeStruct, Deserialize, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -26,7 +22,9 @@ pub enum PermissionOverwriteType {
 struct PermissionOverwriteData {
     allow: Permissions,
     deny: Permissions,
-    id: String,
+    /// ID of the target, which is an integer in guild templates.
+    #[serde(with = "crate::id::string")]
+    id: u64,
     #[serde(rename = "type")]
     kind: PermissionOverwriteTargetType,
 }
@@ -51,13 +49,13 @@ impl<'de> Deserialize<'de> for PermissionOverwrite {
 
         let kind = match data.kind {
             PermissionOverwriteTargetType::Member => {
-                let id = UserId(data.id.parse().map_err(DeError::custom)?);
+                let id = UserId(data.id);
                 tracing::trace!(id = %id.0, kind = ?data.kind);
 
                 PermissionOverwriteType::Member(id)
             }
             PermissionOverwriteTargetType::Role => {
-                let id = RoleId(data.id.parse().map_err(DeError::custom)?);
+                let id = RoleId(data.id);
                 tracing::trace!(id = %id.0, kind = ?data.kind);
 
                 PermissionOverwriteType::Role(id)
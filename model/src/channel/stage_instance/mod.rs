@@ -0,0 +1,68 @@
+//! Models of live stages in stage channels.
+
+mod privacy_level;
+
+pub use self::privacy_level::PrivacyLevel;
+
+use crate::id::{ChannelId, GuildId, StageId};
+use serde::{Deserialize, Serialize};
+
+/// Live stage in a stage channel.
+///
+/// A stage instance exists for as long as the stage is live.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct StageInstance {
+    /// ID of the stage channel that the stage is in.
+    pub channel_id: ChannelId,
+    /// Whether stage discovery is disabled.
+    #[serde(default)]
+    pub discoverable_disabled: bool,
+    pub guild_id: GuildId,
+    pub id: StageId,
+    pub privacy_level: PrivacyLevel,
+    pub topic: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChannelId, GuildId, PrivacyLevel, StageId, StageInstance};
+    use serde_test::Token;
+
+    #[test]
+    fn test_stage_instance() {
+        let value = StageInstance {
+            channel_id: ChannelId(100),
+            discoverable_disabled: false,
+            guild_id: GuildId(200),
+            id: StageId(300),
+            privacy_level: PrivacyLevel::GuildOnly,
+            topic: "a topic".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "StageInstance",
+                    len: 6,
+                },
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("100"),
+                Token::Str("discoverable_disabled"),
+                Token::Bool(false),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("200"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "StageId" },
+                Token::Str("300"),
+                Token::Str("privacy_level"),
+                Token::U8(2),
+                Token::Str("topic"),
+                Token::Str("a topic"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Who can see a stage instance.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum PrivacyLevel {
+    /// Visible publicly, such as in stage discovery.
+    Public = 1,
+    /// Visible only to members of the guild.
+    GuildOnly = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrivacyLevel;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&PrivacyLevel::Public, &[Token::U8(1)]);
+        serde_test::assert_tokens(&PrivacyLevel::GuildOnly, &[Token::U8(2)]);
+    }
+}
@@ -0,0 +1,17 @@
+use super::ThreadMember;
+use crate::channel::GuildChannel;
+use serde::{Deserialize, Serialize};
+
+/// List of threads and the current user's thread members, returned when
+/// listing active or archived threads.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadsListing {
+    /// Whether there are more threads to request.
+    ///
+    /// This isn't present when listing active threads.
+    #[serde(default)]
+    pub has_more: bool,
+    /// Thread members of the current user in the threads.
+    pub members: Vec<ThreadMember>,
+    pub threads: Vec<GuildChannel>,
+}
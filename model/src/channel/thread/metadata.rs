@@ -0,0 +1,53 @@
+use super::AutoArchiveDuration;
+use serde::{Deserialize, Serialize};
+
+/// Metadata of a thread that other channels don't have.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadMetadata {
+    pub archived: bool,
+    pub auto_archive_duration: AutoArchiveDuration,
+    /// When the thread was last archived or unarchived.
+    pub archive_timestamp: String,
+    /// Whether non-moderators can invite others to a private thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>,
+    /// Whether only moderators can unarchive the thread.
+    #[serde(default)]
+    pub locked: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoArchiveDuration, ThreadMetadata};
+    use serde_test::Token;
+
+    #[test]
+    fn test_thread_metadata() {
+        let value = ThreadMetadata {
+            archived: true,
+            auto_archive_duration: AutoArchiveDuration::Day,
+            archive_timestamp: "2021-06-01T00:00:00.000000+00:00".to_owned(),
+            invitable: None,
+            locked: false,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ThreadMetadata",
+                    len: 4,
+                },
+                Token::Str("archived"),
+                Token::Bool(true),
+                Token::Str("auto_archive_duration"),
+                Token::U16(1440),
+                Token::Str("archive_timestamp"),
+                Token::Str("2021-06-01T00:00:00.000000+00:00"),
+                Token::Str("locked"),
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! Models of threads and the members of threads.
+
+mod auto_archive_duration;
+mod listing;
+mod member;
+mod metadata;
+
+pub use self::{
+    auto_archive_duration::AutoArchiveDuration, listing::ThreadsListing, member::ThreadMember,
+    metadata::ThreadMetadata,
+};
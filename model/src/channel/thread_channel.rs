@@ -0,0 +1,126 @@
+use crate::{
+    channel::{
+        thread::{ThreadMember, ThreadMetadata},
+        ChannelType,
+    },
+    id::{ChannelId, GuildId, MessageId, UserId},
+};
+use serde::{Deserialize, Serialize};
+
+/// Thread in a text or news channel.
+///
+/// The [`kind`] is one of the thread channel types.
+///
+/// [`kind`]: Self::kind
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadChannel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    pub id: ChannelId,
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_message_id: Option<MessageId>,
+    /// Thread member of the current user, if they have joined the thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<ThreadMember>,
+    /// Approximate number of members, which stops counting at 50.
+    pub member_count: u8,
+    /// Approximate number of messages, which stops counting at 50.
+    pub message_count: u8,
+    pub name: String,
+    /// ID of the user who created the thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<UserId>,
+    /// ID of the channel that the thread was created in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<u64>,
+    pub thread_metadata: ThreadMetadata,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThreadChannel;
+    use crate::{
+        channel::{
+            thread::{AutoArchiveDuration, ThreadMetadata},
+            ChannelType,
+        },
+        id::{ChannelId, GuildId, UserId},
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn test_thread_channel() {
+        let value = ThreadChannel {
+            guild_id: Some(GuildId(1)),
+            id: ChannelId(2),
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            member: None,
+            member_count: 3,
+            message_count: 4,
+            name: "thread".to_owned(),
+            owner_id: Some(UserId(5)),
+            parent_id: Some(ChannelId(6)),
+            rate_limit_per_user: None,
+            thread_metadata: ThreadMetadata {
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Hour,
+                archive_timestamp: "2021-06-01T00:00:00.000000+00:00".to_owned(),
+                invitable: None,
+                locked: false,
+            },
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ThreadChannel",
+                    len: 9,
+                },
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::Str("type"),
+                Token::U8(11),
+                Token::Str("member_count"),
+                Token::U8(3),
+                Token::Str("message_count"),
+                Token::U8(4),
+                Token::Str("name"),
+                Token::Str("thread"),
+                Token::Str("owner_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("5"),
+                Token::Str("parent_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("6"),
+                Token::Str("thread_metadata"),
+                Token::Struct {
+                    name: "ThreadMetadata",
+                    len: 4,
+                },
+                Token::Str("archived"),
+                Token::Bool(false),
+                Token::Str("auto_archive_duration"),
+                Token::U16(60),
+                Token::Str("archive_timestamp"),
+                Token::Str("2021-06-01T00:00:00.000000+00:00"),
+                Token::Str("locked"),
+                Token::Bool(false),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
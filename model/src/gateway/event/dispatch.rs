@@ -26,6 +26,11 @@ pub enum DispatchEvent {
     GuildDelete(Box<GuildDelete>),
     GuildEmojisUpdate(GuildEmojisUpdate),
     GuildIntegrationsUpdate(GuildIntegrationsUpdate),
+    GuildScheduledEventCreate(Box<GuildScheduledEventCreate>),
+    GuildScheduledEventDelete(Box<GuildScheduledEventDelete>),
+    GuildScheduledEventUpdate(Box<GuildScheduledEventUpdate>),
+    GuildScheduledEventUserAdd(GuildScheduledEventUserAdd),
+    GuildScheduledEventUserRemove(GuildScheduledEventUserRemove),
     GuildUpdate(Box<GuildUpdate>),
     InviteCreate(Box<InviteCreate>),
     InviteDelete(InviteDelete),
@@ -48,6 +53,15 @@ pub enum DispatchEvent {
     RoleCreate(RoleCreate),
     RoleDelete(RoleDelete),
     RoleUpdate(RoleUpdate),
+    StageInstanceCreate(StageInstanceCreate),
+    StageInstanceDelete(StageInstanceDelete),
+    StageInstanceUpdate(StageInstanceUpdate),
+    ThreadCreate(ThreadCreate),
+    ThreadDelete(ThreadDelete),
+    ThreadListSync(ThreadListSync),
+    ThreadMemberUpdate(ThreadMemberUpdate),
+    ThreadMembersUpdate(ThreadMembersUpdate),
+    ThreadUpdate(ThreadUpdate),
     TypingStart(Box<TypingStart>),
     UnavailableGuild(UnavailableGuild),
     UserUpdate(UserUpdate),
@@ -71,6 +85,11 @@ impl DispatchEvent {
             Self::GuildDelete(_) => EventType::GuildDelete,
             Self::GuildEmojisUpdate(_) => EventType::GuildEmojisUpdate,
             Self::GuildIntegrationsUpdate(_) => EventType::GuildIntegrationsUpdate,
+            Self::GuildScheduledEventCreate(_) => EventType::GuildScheduledEventCreate,
+            Self::GuildScheduledEventDelete(_) => EventType::GuildScheduledEventDelete,
+            Self::GuildScheduledEventUpdate(_) => EventType::GuildScheduledEventUpdate,
+            Self::GuildScheduledEventUserAdd(_) => EventType::GuildScheduledEventUserAdd,
+            Self::GuildScheduledEventUserRemove(_) => EventType::GuildScheduledEventUserRemove,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
             Self::InviteCreate(_) => EventType::InviteCreate,
             Self::InviteDelete(_) => EventType::InviteDelete,
@@ -93,6 +112,15 @@ impl DispatchEvent {
             Self::RoleCreate(_) => EventType::RoleCreate,
             Self::RoleDelete(_) => EventType::RoleDelete,
             Self::RoleUpdate(_) => EventType::RoleUpdate,
+            Self::StageInstanceCreate(_) => EventType::StageInstanceCreate,
+            Self::StageInstanceDelete(_) => EventType::StageInstanceDelete,
+            Self::StageInstanceUpdate(_) => EventType::StageInstanceUpdate,
+            Self::ThreadCreate(_) => EventType::ThreadCreate,
+            Self::ThreadDelete(_) => EventType::ThreadDelete,
+            Self::ThreadListSync(_) => EventType::ThreadListSync,
+            Self::ThreadMemberUpdate(_) => EventType::ThreadMemberUpdate,
+            Self::ThreadMembersUpdate(_) => EventType::ThreadMembersUpdate,
+            Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
             Self::UserUpdate(_) => EventType::UserUpdate,
@@ -119,6 +147,11 @@ impl TryFrom<Event> for DispatchEvent {
             Event::GuildDelete(v) => Self::GuildDelete(v),
             Event::GuildEmojisUpdate(v) => Self::GuildEmojisUpdate(v),
             Event::GuildIntegrationsUpdate(v) => Self::GuildIntegrationsUpdate(v),
+            Event::GuildScheduledEventCreate(v) => Self::GuildScheduledEventCreate(v),
+            Event::GuildScheduledEventDelete(v) => Self::GuildScheduledEventDelete(v),
+            Event::GuildScheduledEventUpdate(v) => Self::GuildScheduledEventUpdate(v),
+            Event::GuildScheduledEventUserAdd(v) => Self::GuildScheduledEventUserAdd(v),
+            Event::GuildScheduledEventUserRemove(v) => Self::GuildScheduledEventUserRemove(v),
             Event::GuildUpdate(v) => Self::GuildUpdate(v),
             Event::InviteCreate(v) => Self::InviteCreate(v),
             Event::InviteDelete(v) => Self::InviteDelete(v),
@@ -141,6 +174,15 @@ impl TryFrom<Event> for DispatchEvent {
             Event::RoleCreate(v) => Self::RoleCreate(v),
             Event::RoleDelete(v) => Self::RoleDelete(v),
             Event::RoleUpdate(v) => Self::RoleUpdate(v),
+            Event::StageInstanceCreate(v) => Self::StageInstanceCreate(v),
+            Event::StageInstanceDelete(v) => Self::StageInstanceDelete(v),
+            Event::StageInstanceUpdate(v) => Self::StageInstanceUpdate(v),
+            Event::ThreadCreate(v) => Self::ThreadCreate(v),
+            Event::ThreadDelete(v) => Self::ThreadDelete(v),
+            Event::ThreadListSync(v) => Self::ThreadListSync(v),
+            Event::ThreadMemberUpdate(v) => Self::ThreadMemberUpdate(v),
+            Event::ThreadMembersUpdate(v) => Self::ThreadMembersUpdate(v),
+            Event::ThreadUpdate(v) => Self::ThreadUpdate(v),
             Event::TypingStart(v) => Self::TypingStart(v),
             Event::UnavailableGuild(v) => Self::UnavailableGuild(v),
             Event::UserUpdate(v) => Self::UserUpdate(v),
@@ -224,6 +266,21 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
             "GUILD_ROLE_UPDATE" => {
                 DispatchEvent::RoleUpdate(RoleUpdate::deserialize(deserializer)?)
             }
+            "GUILD_SCHEDULED_EVENT_CREATE" => DispatchEvent::GuildScheduledEventCreate(Box::new(
+                GuildScheduledEventCreate::deserialize(deserializer)?,
+            )),
+            "GUILD_SCHEDULED_EVENT_DELETE" => DispatchEvent::GuildScheduledEventDelete(Box::new(
+                GuildScheduledEventDelete::deserialize(deserializer)?,
+            )),
+            "GUILD_SCHEDULED_EVENT_UPDATE" => DispatchEvent::GuildScheduledEventUpdate(Box::new(
+                GuildScheduledEventUpdate::deserialize(deserializer)?,
+            )),
+            "GUILD_SCHEDULED_EVENT_USER_ADD" => DispatchEvent::GuildScheduledEventUserAdd(
+                GuildScheduledEventUserAdd::deserialize(deserializer)?,
+            ),
+            "GUILD_SCHEDULED_EVENT_USER_REMOVE" => DispatchEvent::GuildScheduledEventUserRemove(
+                GuildScheduledEventUserRemove::deserialize(deserializer)?,
+            ),
             "GUILD_UPDATE" => {
                 DispatchEvent::GuildUpdate(Box::new(GuildUpdate::deserialize(deserializer)?))
             }
@@ -271,6 +328,33 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
 
                 DispatchEvent::Resumed
             }
+            "STAGE_INSTANCE_CREATE" => {
+                DispatchEvent::StageInstanceCreate(StageInstanceCreate::deserialize(deserializer)?)
+            }
+            "STAGE_INSTANCE_DELETE" => {
+                DispatchEvent::StageInstanceDelete(StageInstanceDelete::deserialize(deserializer)?)
+            }
+            "STAGE_INSTANCE_UPDATE" => {
+                DispatchEvent::StageInstanceUpdate(StageInstanceUpdate::deserialize(deserializer)?)
+            }
+            "THREAD_CREATE" => {
+                DispatchEvent::ThreadCreate(ThreadCreate::deserialize(deserializer)?)
+            }
+            "THREAD_DELETE" => {
+                DispatchEvent::ThreadDelete(ThreadDelete::deserialize(deserializer)?)
+            }
+            "THREAD_LIST_SYNC" => {
+                DispatchEvent::ThreadListSync(ThreadListSync::deserialize(deserializer)?)
+            }
+            "THREAD_MEMBER_UPDATE" => {
+                DispatchEvent::ThreadMemberUpdate(ThreadMemberUpdate::deserialize(deserializer)?)
+            }
+            "THREAD_MEMBERS_UPDATE" => {
+                DispatchEvent::ThreadMembersUpdate(ThreadMembersUpdate::deserialize(deserializer)?)
+            }
+            "THREAD_UPDATE" => {
+                DispatchEvent::ThreadUpdate(ThreadUpdate::deserialize(deserializer)?)
+            }
             "TYPING_START" => {
                 DispatchEvent::TypingStart(Box::new(TypingStart::deserialize(deserializer)?))
             }
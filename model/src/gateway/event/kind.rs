@@ -23,6 +23,11 @@ pub enum EventType {
     GuildDelete,
     GuildEmojisUpdate,
     GuildIntegrationsUpdate,
+    GuildScheduledEventCreate,
+    GuildScheduledEventDelete,
+    GuildScheduledEventUpdate,
+    GuildScheduledEventUserAdd,
+    GuildScheduledEventUserRemove,
     GuildUpdate,
     InviteCreate,
     InviteDelete,
@@ -63,6 +68,16 @@ pub enum EventType {
     ShardReconnecting,
     ShardPayload,
     ShardResuming,
+    ShardZombied,
+    StageInstanceCreate,
+    StageInstanceDelete,
+    StageInstanceUpdate,
+    ThreadCreate,
+    ThreadDelete,
+    ThreadListSync,
+    ThreadMemberUpdate,
+    ThreadMembersUpdate,
+    ThreadUpdate,
     TypingStart,
     UnavailableGuild,
     UserUpdate,
@@ -85,6 +100,11 @@ impl EventType {
             Self::GuildDelete => Some("GUILD_DELETE"),
             Self::GuildEmojisUpdate => Some("GUILD_EMOJIS_UPDATE"),
             Self::GuildIntegrationsUpdate => Some("GUILD_INTEGRATIONS_UPDATE"),
+            Self::GuildScheduledEventCreate => Some("GUILD_SCHEDULED_EVENT_CREATE"),
+            Self::GuildScheduledEventDelete => Some("GUILD_SCHEDULED_EVENT_DELETE"),
+            Self::GuildScheduledEventUpdate => Some("GUILD_SCHEDULED_EVENT_UPDATE"),
+            Self::GuildScheduledEventUserAdd => Some("GUILD_SCHEDULED_EVENT_USER_ADD"),
+            Self::GuildScheduledEventUserRemove => Some("GUILD_SCHEDULED_EVENT_USER_REMOVE"),
             Self::GuildUpdate => Some("GUILD_UPDATE"),
             Self::InviteCreate => Some("INVITE_CREATE"),
             Self::InviteDelete => Some("INVITE_DELETE"),
@@ -107,6 +127,15 @@ impl EventType {
             Self::RoleCreate => Some("GUILD_ROLE_CREATE"),
             Self::RoleDelete => Some("GUILD_ROLE_DELETE"),
             Self::RoleUpdate => Some("GUILD_ROLE_UPDATE"),
+            Self::StageInstanceCreate => Some("STAGE_INSTANCE_CREATE"),
+            Self::StageInstanceDelete => Some("STAGE_INSTANCE_DELETE"),
+            Self::StageInstanceUpdate => Some("STAGE_INSTANCE_UPDATE"),
+            Self::ThreadCreate => Some("THREAD_CREATE"),
+            Self::ThreadDelete => Some("THREAD_DELETE"),
+            Self::ThreadListSync => Some("THREAD_LIST_SYNC"),
+            Self::ThreadMemberUpdate => Some("THREAD_MEMBER_UPDATE"),
+            Self::ThreadMembersUpdate => Some("THREAD_MEMBERS_UPDATE"),
+            Self::ThreadUpdate => Some("THREAD_UPDATE"),
             Self::TypingStart => Some("TYPING_START"),
             Self::UnavailableGuild => Some("UNAVAILABLE_GUILD"),
             Self::UserUpdate => Some("USER_UPDATE"),
@@ -124,7 +153,8 @@ impl EventType {
             | Self::ShardIdentifying
             | Self::ShardReconnecting
             | Self::ShardPayload
-            | Self::ShardResuming => None,
+            | Self::ShardResuming
+            | Self::ShardZombied => None,
         }
     }
 }
@@ -145,6 +175,11 @@ impl<'a> TryFrom<&'a str> for EventType {
             "GUILD_DELETE" => Ok(Self::GuildDelete),
             "GUILD_EMOJIS_UPDATE" => Ok(Self::GuildEmojisUpdate),
             "GUILD_INTEGRATIONS_UPDATE" => Ok(Self::GuildIntegrationsUpdate),
+            "GUILD_SCHEDULED_EVENT_CREATE" => Ok(Self::GuildScheduledEventCreate),
+            "GUILD_SCHEDULED_EVENT_DELETE" => Ok(Self::GuildScheduledEventDelete),
+            "GUILD_SCHEDULED_EVENT_UPDATE" => Ok(Self::GuildScheduledEventUpdate),
+            "GUILD_SCHEDULED_EVENT_USER_ADD" => Ok(Self::GuildScheduledEventUserAdd),
+            "GUILD_SCHEDULED_EVENT_USER_REMOVE" => Ok(Self::GuildScheduledEventUserRemove),
             "GUILD_UPDATE" => Ok(Self::GuildUpdate),
             "INVITE_CREATE" => Ok(Self::InviteCreate),
             "INVITE_DELETE" => Ok(Self::InviteDelete),
@@ -167,6 +202,15 @@ impl<'a> TryFrom<&'a str> for EventType {
             "GUILD_ROLE_CREATE" => Ok(Self::RoleCreate),
             "GUILD_ROLE_DELETE" => Ok(Self::RoleDelete),
             "GUILD_ROLE_UPDATE" => Ok(Self::RoleUpdate),
+            "STAGE_INSTANCE_CREATE" => Ok(Self::StageInstanceCreate),
+            "STAGE_INSTANCE_DELETE" => Ok(Self::StageInstanceDelete),
+            "STAGE_INSTANCE_UPDATE" => Ok(Self::StageInstanceUpdate),
+            "THREAD_CREATE" => Ok(Self::ThreadCreate),
+            "THREAD_DELETE" => Ok(Self::ThreadDelete),
+            "THREAD_LIST_SYNC" => Ok(Self::ThreadListSync),
+            "THREAD_MEMBER_UPDATE" => Ok(Self::ThreadMemberUpdate),
+            "THREAD_MEMBERS_UPDATE" => Ok(Self::ThreadMembersUpdate),
+            "THREAD_UPDATE" => Ok(Self::ThreadUpdate),
             "TYPING_START" => Ok(Self::TypingStart),
             "UNAVAILABLE_GUILD" => Ok(Self::UnavailableGuild),
             "USER_UPDATE" => Ok(Self::UserUpdate),
@@ -217,6 +261,26 @@ mod tests {
             EventType::GuildIntegrationsUpdate,
             "GUILD_INTEGRATIONS_UPDATE",
         );
+        assert_variant(
+            EventType::GuildScheduledEventCreate,
+            "GUILD_SCHEDULED_EVENT_CREATE",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventDelete,
+            "GUILD_SCHEDULED_EVENT_DELETE",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventUpdate,
+            "GUILD_SCHEDULED_EVENT_UPDATE",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventUserAdd,
+            "GUILD_SCHEDULED_EVENT_USER_ADD",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventUserRemove,
+            "GUILD_SCHEDULED_EVENT_USER_REMOVE",
+        );
         assert_variant(EventType::GuildUpdate, "GUILD_UPDATE");
         assert_variant(EventType::InviteCreate, "INVITE_CREATE");
         assert_variant(EventType::InviteDelete, "INVITE_DELETE");
@@ -249,6 +313,16 @@ mod tests {
         assert_variant(EventType::ShardPayload, "SHARD_PAYLOAD");
         assert_variant(EventType::ShardReconnecting, "SHARD_RECONNECTING");
         assert_variant(EventType::ShardResuming, "SHARD_RESUMING");
+        assert_variant(EventType::ShardZombied, "SHARD_ZOMBIED");
+        assert_variant(EventType::StageInstanceCreate, "STAGE_INSTANCE_CREATE");
+        assert_variant(EventType::StageInstanceDelete, "STAGE_INSTANCE_DELETE");
+        assert_variant(EventType::StageInstanceUpdate, "STAGE_INSTANCE_UPDATE");
+        assert_variant(EventType::ThreadCreate, "THREAD_CREATE");
+        assert_variant(EventType::ThreadDelete, "THREAD_DELETE");
+        assert_variant(EventType::ThreadListSync, "THREAD_LIST_SYNC");
+        assert_variant(EventType::ThreadMemberUpdate, "THREAD_MEMBER_UPDATE");
+        assert_variant(EventType::ThreadMembersUpdate, "THREAD_MEMBERS_UPDATE");
+        assert_variant(EventType::ThreadUpdate, "THREAD_UPDATE");
         assert_variant(EventType::TypingStart, "TYPING_START");
         assert_variant(EventType::UnavailableGuild, "UNAVAILABLE_GUILD");
         assert_variant(EventType::UserUpdate, "USER_UPDATE");
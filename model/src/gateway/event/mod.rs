@@ -57,6 +57,16 @@ pub enum Event {
     GuildEmojisUpdate(GuildEmojisUpdate),
     /// A guild's integrations were updated.
     GuildIntegrationsUpdate(GuildIntegrationsUpdate),
+    /// A scheduled event was created in a guild.
+    GuildScheduledEventCreate(Box<GuildScheduledEventCreate>),
+    /// A scheduled event was deleted from a guild.
+    GuildScheduledEventDelete(Box<GuildScheduledEventDelete>),
+    /// A scheduled event in a guild was updated.
+    GuildScheduledEventUpdate(Box<GuildScheduledEventUpdate>),
+    /// A user subscribed to a scheduled event.
+    GuildScheduledEventUserAdd(GuildScheduledEventUserAdd),
+    /// A user unsubscribed from a scheduled event.
+    GuildScheduledEventUserRemove(GuildScheduledEventUserRemove),
     /// A guild was updated.
     GuildUpdate(Box<GuildUpdate>),
     /// A invite was made.
@@ -121,6 +131,26 @@ pub enum Event {
     ShardPayload(Payload),
     /// A shard is now in a Resuming stage after a disconnect.
     ShardResuming(Resuming),
+    /// A shard's connection was determined to be a zombie.
+    ShardZombied(Zombied),
+    /// A stage instance was created in a stage channel.
+    StageInstanceCreate(StageInstanceCreate),
+    /// A stage instance was deleted.
+    StageInstanceDelete(StageInstanceDelete),
+    /// A stage instance was updated.
+    StageInstanceUpdate(StageInstanceUpdate),
+    /// A thread was created or the current user was added to a private thread.
+    ThreadCreate(ThreadCreate),
+    /// A thread was deleted.
+    ThreadDelete(ThreadDelete),
+    /// The current user gained access to channels, syncing their active threads.
+    ThreadListSync(ThreadListSync),
+    /// The thread member of the current user was updated.
+    ThreadMemberUpdate(ThreadMemberUpdate),
+    /// Members were added to or removed from a thread.
+    ThreadMembersUpdate(ThreadMembersUpdate),
+    /// A thread was updated.
+    ThreadUpdate(ThreadUpdate),
     /// A user started typing in a channel.
     TypingStart(Box<TypingStart>),
     /// A guild is now unavailable.
@@ -154,6 +184,11 @@ impl Event {
             Self::GuildDelete(_) => EventType::GuildDelete,
             Self::GuildEmojisUpdate(_) => EventType::GuildEmojisUpdate,
             Self::GuildIntegrationsUpdate(_) => EventType::GuildIntegrationsUpdate,
+            Self::GuildScheduledEventCreate(_) => EventType::GuildScheduledEventCreate,
+            Self::GuildScheduledEventDelete(_) => EventType::GuildScheduledEventDelete,
+            Self::GuildScheduledEventUpdate(_) => EventType::GuildScheduledEventUpdate,
+            Self::GuildScheduledEventUserAdd(_) => EventType::GuildScheduledEventUserAdd,
+            Self::GuildScheduledEventUserRemove(_) => EventType::GuildScheduledEventUserRemove,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
             Self::InviteCreate(_) => EventType::InviteCreate,
             Self::InviteDelete(_) => EventType::InviteDelete,
@@ -183,6 +218,16 @@ impl Event {
             Self::ShardReconnecting(_) => EventType::ShardReconnecting,
             Self::ShardPayload(_) => EventType::ShardPayload,
             Self::ShardResuming(_) => EventType::ShardResuming,
+            Self::ShardZombied(_) => EventType::ShardZombied,
+            Self::StageInstanceCreate(_) => EventType::StageInstanceCreate,
+            Self::StageInstanceDelete(_) => EventType::StageInstanceDelete,
+            Self::StageInstanceUpdate(_) => EventType::StageInstanceUpdate,
+            Self::ThreadCreate(_) => EventType::ThreadCreate,
+            Self::ThreadDelete(_) => EventType::ThreadDelete,
+            Self::ThreadListSync(_) => EventType::ThreadListSync,
+            Self::ThreadMemberUpdate(_) => EventType::ThreadMemberUpdate,
+            Self::ThreadMembersUpdate(_) => EventType::ThreadMembersUpdate,
+            Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
             Self::UserUpdate(_) => EventType::UserUpdate,
@@ -207,6 +252,13 @@ impl From<Box<DispatchEvent>> for Event {
             DispatchEvent::GuildDelete(v) => Self::GuildDelete(v),
             DispatchEvent::GuildEmojisUpdate(v) => Self::GuildEmojisUpdate(v),
             DispatchEvent::GuildIntegrationsUpdate(v) => Self::GuildIntegrationsUpdate(v),
+            DispatchEvent::GuildScheduledEventCreate(v) => Self::GuildScheduledEventCreate(v),
+            DispatchEvent::GuildScheduledEventDelete(v) => Self::GuildScheduledEventDelete(v),
+            DispatchEvent::GuildScheduledEventUpdate(v) => Self::GuildScheduledEventUpdate(v),
+            DispatchEvent::GuildScheduledEventUserAdd(v) => Self::GuildScheduledEventUserAdd(v),
+            DispatchEvent::GuildScheduledEventUserRemove(v) => {
+                Self::GuildScheduledEventUserRemove(v)
+            }
             DispatchEvent::InviteCreate(v) => Self::InviteCreate(v),
             DispatchEvent::InviteDelete(v) => Self::InviteDelete(v),
             DispatchEvent::MemberAdd(v) => Self::MemberAdd(v),
@@ -229,6 +281,15 @@ impl From<Box<DispatchEvent>> for Event {
             DispatchEvent::ReactionRemoveEmoji(v) => Self::ReactionRemoveEmoji(v),
             DispatchEvent::Ready(v) => Self::Ready(v),
             DispatchEvent::Resumed => Self::Resumed,
+            DispatchEvent::StageInstanceCreate(v) => Self::StageInstanceCreate(v),
+            DispatchEvent::StageInstanceDelete(v) => Self::StageInstanceDelete(v),
+            DispatchEvent::StageInstanceUpdate(v) => Self::StageInstanceUpdate(v),
+            DispatchEvent::ThreadCreate(v) => Self::ThreadCreate(v),
+            DispatchEvent::ThreadDelete(v) => Self::ThreadDelete(v),
+            DispatchEvent::ThreadListSync(v) => Self::ThreadListSync(v),
+            DispatchEvent::ThreadMemberUpdate(v) => Self::ThreadMemberUpdate(v),
+            DispatchEvent::ThreadMembersUpdate(v) => Self::ThreadMembersUpdate(v),
+            DispatchEvent::ThreadUpdate(v) => Self::ThreadUpdate(v),
             DispatchEvent::TypingStart(v) => Self::TypingStart(v),
             DispatchEvent::UnavailableGuild(v) => Self::UnavailableGuild(v),
             DispatchEvent::UserUpdate(v) => Self::UserUpdate(v),
@@ -262,6 +323,7 @@ impl From<ShardEvent> for Event {
             ShardEvent::Payload(v) => Self::ShardPayload(v),
             ShardEvent::Reconnecting(v) => Self::ShardReconnecting(v),
             ShardEvent::Resuming(v) => Self::ShardResuming(v),
+            ShardEvent::Zombied(v) => Self::ShardZombied(v),
         }
     }
 }
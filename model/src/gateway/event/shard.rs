@@ -65,6 +65,28 @@ pub struct Resuming {
     pub shard_id: u64,
 }
 
+/// Reason a shard's connection was determined to be a zombie.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ZombieReason {
+    /// No dispatch events were received within the configured window.
+    DispatchSilence,
+    /// The last heartbeat wasn't acknowledged before the next was due.
+    HeartbeatAckMissed,
+}
+
+/// Indicator that a shard's connection is a zombie, which appears to be open
+/// while the gateway is no longer sending anything over it.
+///
+/// The shard closes the connection and resumes the session.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Zombied {
+    /// Reason the connection was determined to be a zombie.
+    pub reason: ZombieReason,
+    /// The ID of the shard with the zombied connection.
+    pub shard_id: u64,
+}
+
 /// "Meta" events about a shard's status, not from the gateway.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(untagged)]
@@ -86,6 +108,8 @@ pub enum ShardEvent {
     Reconnecting(Reconnecting),
     /// A shard is now in a Resuming stage after a disconnect.
     Resuming(Resuming),
+    /// A shard's connection was determined to be a zombie.
+    Zombied(Zombied),
 }
 
 impl TryFrom<Event> for ShardEvent {
@@ -100,6 +124,7 @@ impl TryFrom<Event> for ShardEvent {
             Event::ShardPayload(v) => Self::Payload(v),
             Event::ShardReconnecting(v) => Self::Reconnecting(v),
             Event::ShardResuming(v) => Self::Resuming(v),
+            Event::ShardZombied(v) => Self::Zombied(v),
 
             _ => return Err(EventConversionError::new(event)),
         })
@@ -110,7 +135,7 @@ impl TryFrom<Event> for ShardEvent {
 mod tests {
     use super::{
         Connected, Connecting, Disconnected, Event, Identifying, Payload, Reconnecting, Resuming,
-        ShardEvent,
+        ShardEvent, ZombieReason, Zombied,
     };
     use serde_test::Token;
     use std::convert::TryInto;
@@ -274,6 +299,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_zombied() {
+        let value = Zombied {
+            reason: ZombieReason::DispatchSilence,
+            shard_id: 4,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Zombied",
+                    len: 2,
+                },
+                Token::Str("reason"),
+                Token::UnitVariant {
+                    name: "ZombieReason",
+                    variant: "DISPATCH_SILENCE",
+                },
+                Token::Str("shard_id"),
+                Token::U64(4),
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn test_shard_event_try_from_event() {
         let connected = Event::ShardConnected(Connected {
@@ -333,5 +384,14 @@ mod tests {
             resuming.try_into().unwrap(),
             ShardEvent::Resuming(_)
         ));
+
+        let zombied = Event::ShardZombied(Zombied {
+            reason: ZombieReason::HeartbeatAckMissed,
+            shard_id: 4,
+        });
+        assert!(matches!(
+            zombied.try_into().unwrap(),
+            ShardEvent::Zombied(_)
+        ));
     }
 }
@@ -27,6 +27,15 @@ bitflags! {
         ///  - [`CHANNEL_UPDATE`]
         ///  - [`CHANNEL_DELETE`]
         ///  - [`CHANNEL_PINS_UPDATE`]
+        ///  - [`THREAD_CREATE`]
+        ///  - [`THREAD_UPDATE`]
+        ///  - [`THREAD_DELETE`]
+        ///  - [`THREAD_LIST_SYNC`]
+        ///  - [`THREAD_MEMBER_UPDATE`]
+        ///  - [`THREAD_MEMBERS_UPDATE`]
+        ///  - [`STAGE_INSTANCE_CREATE`]
+        ///  - [`STAGE_INSTANCE_UPDATE`]
+        ///  - [`STAGE_INSTANCE_DELETE`]
         ///
         /// [`GUILD_CREATE`]: super::event::Event::GuildCreate
         /// [`GUILD_UPDATE`]: super::event::Event::GuildUpdate
@@ -38,6 +47,15 @@ bitflags! {
         /// [`CHANNEL_UPDATE`]: super::event::Event::ChannelUpdate
         /// [`CHANNEL_DELETE`]: super::event::Event::ChannelDelete
         /// [`CHANNEL_PINS_UPDATE`]: super::event::Event::ChannelPinsUpdate
+        /// [`THREAD_CREATE`]: super::event::Event::ThreadCreate
+        /// [`THREAD_UPDATE`]: super::event::Event::ThreadUpdate
+        /// [`THREAD_DELETE`]: super::event::Event::ThreadDelete
+        /// [`THREAD_LIST_SYNC`]: super::event::Event::ThreadListSync
+        /// [`THREAD_MEMBER_UPDATE`]: super::event::Event::ThreadMemberUpdate
+        /// [`THREAD_MEMBERS_UPDATE`]: super::event::Event::ThreadMembersUpdate
+        /// [`STAGE_INSTANCE_CREATE`]: super::event::Event::StageInstanceCreate
+        /// [`STAGE_INSTANCE_UPDATE`]: super::event::Event::StageInstanceUpdate
+        /// [`STAGE_INSTANCE_DELETE`]: super::event::Event::StageInstanceDelete
         const GUILDS = 1;
         /// Guild members intent.
         ///
@@ -47,11 +65,13 @@ bitflags! {
         ///  - [`GUILD_MEMBER_ADD`]
         ///  - [`GUILD_MEMBER_UPDATE`]
         ///  - [`GUILD_MEMBER_REMOVE`]
+        ///  - [`THREAD_MEMBERS_UPDATE`]
         ///
         /// [the discord docs]: https://discord.com/developers/docs/topics/gateway#privileged-intents
         /// [`GUILD_MEMBER_ADD`]: super::event::Event::MemberAdd
         /// [`GUILD_MEMBER_UPDATE`]: super::event::Event::MemberUpdate
         /// [`GUILD_MEMBER_REMOVE`]: super::event::Event::MemberRemove
+        /// [`THREAD_MEMBERS_UPDATE`]: super::event::Event::ThreadMembersUpdate
         const GUILD_MEMBERS = 1 << 1;
         /// Guild bans intent.
         ///
@@ -189,6 +209,21 @@ bitflags! {
         /// [`TYPING_START`]: super::event::Event::TypingStart
         /// [`GUILD_MESSAGE_TYPING`]: Self::GUILD_MESSAGE_TYPING
         const DIRECT_MESSAGE_TYPING = 1 << 14;
+        /// Guild scheduled events intent.
+        ///
+        /// Event(s) received:
+        ///  - [`GUILD_SCHEDULED_EVENT_CREATE`]
+        ///  - [`GUILD_SCHEDULED_EVENT_UPDATE`]
+        ///  - [`GUILD_SCHEDULED_EVENT_DELETE`]
+        ///  - [`GUILD_SCHEDULED_EVENT_USER_ADD`]
+        ///  - [`GUILD_SCHEDULED_EVENT_USER_REMOVE`]
+        ///
+        /// [`GUILD_SCHEDULED_EVENT_CREATE`]: super::event::Event::GuildScheduledEventCreate
+        /// [`GUILD_SCHEDULED_EVENT_UPDATE`]: super::event::Event::GuildScheduledEventUpdate
+        /// [`GUILD_SCHEDULED_EVENT_DELETE`]: super::event::Event::GuildScheduledEventDelete
+        /// [`GUILD_SCHEDULED_EVENT_USER_ADD`]: super::event::Event::GuildScheduledEventUserAdd
+        /// [`GUILD_SCHEDULED_EVENT_USER_REMOVE`]: super::event::Event::GuildScheduledEventUserRemove
+        const GUILD_SCHEDULED_EVENTS = 1 << 16;
     }
 }
 
@@ -229,5 +264,6 @@ mod tests {
         serde_test::assert_tokens(&Intents::DIRECT_MESSAGES, &[Token::U64(1 << 12)]);
         serde_test::assert_tokens(&Intents::DIRECT_MESSAGE_REACTIONS, &[Token::U64(1 << 13)]);
         serde_test::assert_tokens(&Intents::DIRECT_MESSAGE_TYPING, &[Token::U64(1 << 14)]);
+        serde_test::assert_tokens(&Intents::GUILD_SCHEDULED_EVENTS, &[Token::U64(1 << 16)]);
     }
 }
@@ -1,5 +1,9 @@
 use crate::{
-    channel::{embed::Embed, message::MessageType, Attachment},
+    channel::{
+        embed::Embed,
+        message::{component::Component, MessageType},
+        Attachment,
+    },
     id::{ChannelId, GuildId, MessageId, RoleId},
     user::User,
 };
@@ -12,6 +16,8 @@ pub struct MessageUpdate {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<User>,
     pub channel_id: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Component>>,
     pub content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub edited_timestamp: Option<String>,
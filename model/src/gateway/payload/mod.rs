@@ -14,6 +14,11 @@ mod guild_create;
 mod guild_delete;
 mod guild_emojis_update;
 mod guild_integrations_update;
+mod guild_scheduled_event_create;
+mod guild_scheduled_event_delete;
+mod guild_scheduled_event_update;
+mod guild_scheduled_event_user_add;
+mod guild_scheduled_event_user_remove;
 mod guild_update;
 mod heartbeat;
 mod invite_create;
@@ -34,6 +39,15 @@ mod ready;
 mod role_create;
 mod role_delete;
 mod role_update;
+mod stage_instance_create;
+mod stage_instance_delete;
+mod stage_instance_update;
+mod thread_create;
+mod thread_delete;
+mod thread_list_sync;
+mod thread_member_update;
+mod thread_members_update;
+mod thread_update;
 mod typing_start;
 mod unavailable_guild;
 mod update_voice_state;
@@ -47,15 +61,23 @@ pub use self::{
     channel_delete::ChannelDelete, channel_pins_update::ChannelPinsUpdate,
     channel_update::ChannelUpdate, guild_create::GuildCreate, guild_delete::GuildDelete,
     guild_emojis_update::GuildEmojisUpdate, guild_integrations_update::GuildIntegrationsUpdate,
-    guild_update::GuildUpdate, heartbeat::Heartbeat, invite_create::InviteCreate,
-    invite_delete::InviteDelete, member_add::MemberAdd, member_chunk::MemberChunk,
-    member_remove::MemberRemove, member_update::MemberUpdate, message_create::MessageCreate,
-    message_delete::MessageDelete, message_delete_bulk::MessageDeleteBulk,
-    message_update::MessageUpdate, presence_update::PresenceUpdate, reaction_add::ReactionAdd,
-    reaction_remove::ReactionRemove, reaction_remove_all::ReactionRemoveAll,
-    reaction_remove_emoji::ReactionRemoveEmoji, ready::Ready,
-    request_guild_members::RequestGuildMembers, role_create::RoleCreate, role_delete::RoleDelete,
-    role_update::RoleUpdate, typing_start::TypingStart, unavailable_guild::UnavailableGuild,
+    guild_scheduled_event_create::GuildScheduledEventCreate,
+    guild_scheduled_event_delete::GuildScheduledEventDelete,
+    guild_scheduled_event_update::GuildScheduledEventUpdate,
+    guild_scheduled_event_user_add::GuildScheduledEventUserAdd,
+    guild_scheduled_event_user_remove::GuildScheduledEventUserRemove, guild_update::GuildUpdate,
+    heartbeat::Heartbeat, invite_create::InviteCreate, invite_delete::InviteDelete,
+    member_add::MemberAdd, member_chunk::MemberChunk, member_remove::MemberRemove,
+    member_update::MemberUpdate, message_create::MessageCreate, message_delete::MessageDelete,
+    message_delete_bulk::MessageDeleteBulk, message_update::MessageUpdate,
+    presence_update::PresenceUpdate, reaction_add::ReactionAdd, reaction_remove::ReactionRemove,
+    reaction_remove_all::ReactionRemoveAll, reaction_remove_emoji::ReactionRemoveEmoji,
+    ready::Ready, request_guild_members::RequestGuildMembers, role_create::RoleCreate,
+    role_delete::RoleDelete, role_update::RoleUpdate, stage_instance_create::StageInstanceCreate,
+    stage_instance_delete::StageInstanceDelete, stage_instance_update::StageInstanceUpdate,
+    thread_create::ThreadCreate, thread_delete::ThreadDelete, thread_list_sync::ThreadListSync,
+    thread_member_update::ThreadMemberUpdate, thread_members_update::ThreadMembersUpdate,
+    thread_update::ThreadUpdate, typing_start::TypingStart, unavailable_guild::UnavailableGuild,
     update_status::UpdateStatus, update_voice_state::UpdateVoiceState, user_update::UserUpdate,
     voice_server_update::VoiceServerUpdate, voice_state_update::VoiceStateUpdate,
     webhooks_update::WebhooksUpdate,
@@ -0,0 +1,20 @@
+use crate::{
+    channel::{thread::ThreadMember, GuildChannel},
+    id::{ChannelId, GuildId},
+};
+use serde::{Deserialize, Serialize};
+
+/// Active threads of a guild, sent when the current user gains access to
+/// channels.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadListSync {
+    /// IDs of the parent channels whose threads are being synced.
+    ///
+    /// If empty, the threads of the whole guild are being synced.
+    #[serde(default)]
+    pub channel_ids: Vec<ChannelId>,
+    pub guild_id: GuildId,
+    /// Thread members of the current user in the threads.
+    pub members: Vec<ThreadMember>,
+    pub threads: Vec<GuildChannel>,
+}
@@ -0,0 +1,47 @@
+use crate::{
+    channel::thread::ThreadMember,
+    id::{ChannelId, GuildId, UserId},
+};
+use serde::{Deserialize, Serialize};
+
+/// Members were added to or removed from a thread.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadMembersUpdate {
+    #[serde(default)]
+    pub added_members: Vec<ThreadMember>,
+    pub guild_id: GuildId,
+    /// ID of the thread.
+    pub id: ChannelId,
+    /// Approximate number of members, which stops counting at 50.
+    pub member_count: u8,
+    #[serde(default)]
+    pub removed_member_ids: Vec<UserId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThreadMembersUpdate;
+    use crate::id::{ChannelId, GuildId, UserId};
+
+    #[test]
+    fn test_thread_members_update() {
+        let value = ThreadMembersUpdate {
+            added_members: Vec::new(),
+            guild_id: GuildId(1),
+            id: ChannelId(2),
+            member_count: 3,
+            removed_member_ids: vec![UserId(4)],
+        };
+
+        assert_eq!(
+            value,
+            serde_json::from_value(serde_json::json!({
+                "guild_id": "1",
+                "id": "2",
+                "member_count": 3,
+                "removed_member_ids": ["4"],
+            }))
+            .unwrap()
+        );
+    }
+}
@@ -48,6 +48,7 @@ mod tests {
                 },
             }),
             mute: false,
+            request_to_speak_timestamp: None,
             self_deaf: false,
             self_mute: false,
             self_stream: false,
@@ -175,6 +176,7 @@ mod tests {
                 },
             }),
             mute: false,
+            request_to_speak_timestamp: None,
             self_deaf: false,
             self_mute: false,
             self_stream: false,
@@ -1,5 +1,6 @@
 pub mod audit_log;
 pub mod member;
+pub mod scheduled_event;
 
 mod ban;
 mod default_message_notification_level;
@@ -37,10 +38,10 @@ pub use self::{
     widget::GuildWidget,
 };
 
-use self::member::MemberListDeserializer;
+use self::{member::MemberListDeserializer, scheduled_event::GuildScheduledEvent};
 use super::gateway::presence::PresenceListDeserializer;
 use crate::{
-    channel::GuildChannel,
+    channel::{stage_instance::StageInstance, GuildChannel},
     gateway::presence::Presence,
     id::{ApplicationId, ChannelId, GuildId, UserId},
     voice::voice_state::VoiceState,
@@ -69,6 +70,11 @@ pub struct Guild {
     pub emojis: Vec<Emoji>,
     pub explicit_content_filter: ExplicitContentFilter,
     pub features: Vec<String>,
+    /// Events scheduled in the guild.
+    ///
+    /// This is only present in guilds received over the gateway.
+    #[serde(default)]
+    pub guild_scheduled_events: Vec<GuildScheduledEvent>,
     pub icon: Option<String>,
     pub id: GuildId,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -105,8 +111,18 @@ pub struct Guild {
     pub roles: Vec<Role>,
     pub rules_channel_id: Option<ChannelId>,
     pub splash: Option<String>,
+    /// Live stages in the guild's stage channels.
+    ///
+    /// This is only present in guilds received over the gateway.
+    #[serde(default)]
+    pub stage_instances: Vec<StageInstance>,
     pub system_channel_flags: SystemChannelFlags,
     pub system_channel_id: Option<ChannelId>,
+    /// Active threads that the current user can access.
+    ///
+    /// This is only present in guilds received over the gateway.
+    #[serde(default)]
+    pub threads: Vec<GuildChannel>,
     #[serde(default)]
     pub unavailable: bool,
     pub vanity_url_code: Option<String>,
@@ -138,6 +154,7 @@ impl<'de> Deserialize<'de> for Guild {
             Emojis,
             ExplicitContentFilter,
             Features,
+            GuildScheduledEvents,
             Icon,
             Id,
             JoinedAt,
@@ -160,9 +177,11 @@ impl<'de> Deserialize<'de> for Guild {
             Region,
             Roles,
             Splash,
+            StageInstances,
             SystemChannelFlags,
             SystemChannelId,
             RulesChannelId,
+            Threads,
             Unavailable,
             VerificationLevel,
             VoiceStates,
@@ -195,6 +214,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut emojis = None;
                 let mut explicit_content_filter = None;
                 let mut features = None;
+                let mut guild_scheduled_events = None::<Vec<GuildScheduledEvent>>;
                 let mut icon = None::<Option<_>>;
                 let mut id = None;
                 let mut joined_at = None::<Option<_>>;
@@ -217,9 +237,11 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut region = None;
                 let mut roles = None;
                 let mut splash = None::<Option<_>>;
+                let mut stage_instances = None::<Vec<StageInstance>>;
                 let mut system_channel_id = None::<Option<_>>;
                 let mut system_channel_flags = None;
                 let mut rules_channel_id = None::<Option<_>>;
+                let mut threads = None::<Vec<GuildChannel>>;
                 let mut unavailable = None;
                 let mut verification_level = None;
                 let mut voice_states = None::<Vec<VoiceState>>;
@@ -345,6 +367,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             features = Some(map.next_value()?);
                         }
+                        Field::GuildScheduledEvents => {
+                            if guild_scheduled_events.is_some() {
+                                return Err(DeError::duplicate_field("guild_scheduled_events"));
+                            }
+
+                            guild_scheduled_events = Some(map.next_value()?);
+                        }
                         Field::Icon => {
                             if icon.is_some() {
                                 return Err(DeError::duplicate_field("icon"));
@@ -503,6 +532,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             splash = Some(map.next_value()?);
                         }
+                        Field::StageInstances => {
+                            if stage_instances.is_some() {
+                                return Err(DeError::duplicate_field("stage_instances"));
+                            }
+
+                            stage_instances = Some(map.next_value()?);
+                        }
                         Field::SystemChannelId => {
                             if system_channel_id.is_some() {
                                 return Err(DeError::duplicate_field("system_channel_id"));
@@ -524,6 +560,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             rules_channel_id = Some(map.next_value()?);
                         }
+                        Field::Threads => {
+                            if threads.is_some() {
+                                return Err(DeError::duplicate_field("threads"));
+                            }
+
+                            threads = Some(map.next_value()?);
+                        }
                         Field::Unavailable => {
                             if unavailable.is_some() {
                                 return Err(DeError::duplicate_field("unavailable"));
@@ -595,9 +638,11 @@ impl<'de> Deserialize<'de> for Guild {
                 let approximate_presence_count = approximate_presence_count.unwrap_or_default();
                 let banner = banner.unwrap_or_default();
                 let mut channels = channels.unwrap_or_default();
+                let mut threads = threads.unwrap_or_default();
                 let description = description.unwrap_or_default();
                 let discovery_splash = discovery_splash.unwrap_or_default();
                 let emojis = emojis.unwrap_or_default();
+                let mut guild_scheduled_events = guild_scheduled_events.unwrap_or_default();
                 let icon = icon.unwrap_or_default();
                 let large = large.unwrap_or_default();
                 let joined_at = joined_at.unwrap_or_default();
@@ -614,6 +659,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut presences = presences.unwrap_or_default();
                 let rules_channel_id = rules_channel_id.unwrap_or_default();
                 let splash = splash.unwrap_or_default();
+                let mut stage_instances = stage_instances.unwrap_or_default();
                 let system_channel_id = system_channel_id.unwrap_or_default();
                 let unavailable = unavailable.unwrap_or_default();
                 let vanity_url_code = vanity_url_code.unwrap_or_default();
@@ -635,6 +681,7 @@ impl<'de> Deserialize<'de> for Guild {
                     ?emojis,
                     ?explicit_content_filter,
                     ?features,
+                    ?guild_scheduled_events,
                     ?icon,
                     %id,
                     ?large,
@@ -662,8 +709,10 @@ impl<'de> Deserialize<'de> for Guild {
                     ?rules_channel_id,
                     ?roles,
                     ?splash,
+                    ?stage_instances,
                     ?system_channel_flags,
                     ?system_channel_id,
+                    ?threads,
                     ?unavailable,
                     ?vanity_url_code,
                     ?voice_states,
@@ -672,20 +721,30 @@ impl<'de> Deserialize<'de> for Guild {
                     ?verification_level,
                 );
 
-                for channel in &mut channels {
+                for channel in channels.iter_mut().chain(threads.iter_mut()) {
                     match channel {
                         GuildChannel::Category(c) => {
                             c.guild_id.replace(id);
                         }
+                        GuildChannel::Stage(c) => {
+                            c.guild_id.replace(id);
+                        }
                         GuildChannel::Text(c) => {
                             c.guild_id.replace(id);
                         }
+                        GuildChannel::Thread(c) => {
+                            c.guild_id.replace(id);
+                        }
                         GuildChannel::Voice(c) => {
                             c.guild_id.replace(id);
                         }
                     }
                 }
 
+                for guild_scheduled_event in &mut guild_scheduled_events {
+                    guild_scheduled_event.guild_id = id;
+                }
+
                 for member in &mut members {
                     member.guild_id = id;
                 }
@@ -694,6 +753,10 @@ impl<'de> Deserialize<'de> for Guild {
                     presence.guild_id = id;
                 }
 
+                for stage_instance in &mut stage_instances {
+                    stage_instance.guild_id = id;
+                }
+
                 for voice_state in &mut voice_states {
                     voice_state.guild_id.replace(id);
                 }
@@ -712,6 +775,7 @@ impl<'de> Deserialize<'de> for Guild {
                     emojis,
                     explicit_content_filter,
                     features,
+                    guild_scheduled_events,
                     icon,
                     id,
                     joined_at,
@@ -734,9 +798,11 @@ impl<'de> Deserialize<'de> for Guild {
                     region,
                     roles,
                     splash,
+                    stage_instances,
                     system_channel_id,
                     system_channel_flags,
                     rules_channel_id,
+                    threads,
                     unavailable,
                     verification_level,
                     voice_states,
@@ -761,6 +827,7 @@ impl<'de> Deserialize<'de> for Guild {
             "emojis",
             "explicit_content_filter",
             "features",
+            "guild_scheduled_events",
             "icon",
             "id",
             "joined_at",
@@ -783,9 +850,11 @@ impl<'de> Deserialize<'de> for Guild {
             "region",
             "roles",
             "splash",
+            "stage_instances",
             "system_channel_id",
             "system_channel_flags",
             "rules_channel_id",
+            "threads",
             "unavailable",
             "verification_level",
             "voice_states",
@@ -823,6 +892,7 @@ mod tests {
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::MembersWithoutRole,
             features: vec!["a feature".to_owned()],
+            guild_scheduled_events: Vec::new(),
             icon: Some("icon hash".to_owned()),
             id: GuildId(1),
             joined_at: Some("timestamp".to_owned()),
@@ -846,8 +916,10 @@ mod tests {
             roles: Vec::new(),
             rules_channel_id: Some(ChannelId(6)),
             splash: Some("splash hash".to_owned()),
+            stage_instances: Vec::new(),
             system_channel_flags: SystemChannelFlags::SUPPRESS_PREMIUM_SUBSCRIPTIONS,
             system_channel_id: Some(ChannelId(7)),
+            threads: Vec::new(),
             unavailable: false,
             vanity_url_code: Some("twilight".to_owned()),
             verification_level: VerificationLevel::Medium,
@@ -861,7 +933,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Guild",
-                    len: 44,
+                    len: 47,
                 },
                 Token::Str("afk_channel_id"),
                 Token::Some,
@@ -904,6 +976,9 @@ mod tests {
                 Token::Seq { len: Some(1) },
                 Token::Str("a feature"),
                 Token::SeqEnd,
+                Token::Str("guild_scheduled_events"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("icon"),
                 Token::Some,
                 Token::Str("icon hash"),
@@ -968,12 +1043,18 @@ mod tests {
                 Token::Str("splash"),
                 Token::Some,
                 Token::Str("splash hash"),
+                Token::Str("stage_instances"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("system_channel_flags"),
                 Token::U64(2),
                 Token::Str("system_channel_id"),
                 Token::Some,
                 Token::NewtypeStruct { name: "ChannelId" },
                 Token::Str("7"),
+                Token::Str("threads"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("unavailable"),
                 Token::Bool(false),
                 Token::Str("vanity_url_code"),
@@ -0,0 +1,176 @@
+//! Models of events scheduled in a guild, such as stages or meetups.
+
+mod entity_type;
+mod privacy_level;
+mod recurrence_rule;
+mod status;
+
+pub use self::{
+    entity_type::EntityType,
+    privacy_level::PrivacyLevel,
+    recurrence_rule::{Frequency, Month, NWeekday, RecurrenceRule, Weekday},
+    status::Status,
+};
+
+use crate::{
+    guild::PartialMember,
+    id::{ChannelId, GenericId, GuildId, ScheduledEventId, UserId},
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+
+/// Event scheduled in a guild.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildScheduledEvent {
+    /// ID of the stage or voice channel that the event takes place in.
+    ///
+    /// This is `None` for [`EntityType::External`] events.
+    pub channel_id: Option<ChannelId>,
+    /// User that created the event.
+    ///
+    /// This is not present for events created before October 25th, 2021.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<User>,
+    /// ID of the user that created the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// ID of the entity associated with the event, such as a stage instance.
+    pub entity_id: Option<GenericId>,
+    /// Additional information about the event.
+    ///
+    /// This is present for [`EntityType::External`] events.
+    pub entity_metadata: Option<EntityMetadata>,
+    pub entity_type: EntityType,
+    pub guild_id: GuildId,
+    pub id: ScheduledEventId,
+    /// Hash of the event's cover image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    pub name: String,
+    pub privacy_level: PrivacyLevel,
+    /// How often the event repeats, if at all.
+    pub recurrence_rule: Option<RecurrenceRule>,
+    /// When the event is scheduled to end.
+    ///
+    /// This is required for [`EntityType::External`] events.
+    pub scheduled_end_time: Option<String>,
+    /// When the event is scheduled to start.
+    pub scheduled_start_time: String,
+    pub status: Status,
+    /// Number of users subscribed to the event.
+    ///
+    /// This is only present when requested over HTTP.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<u64>,
+}
+
+/// Additional information about a scheduled event.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct EntityMetadata {
+    /// Location of an [`EntityType::External`] event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+/// User subscribed to a scheduled event.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildScheduledEventUser {
+    /// ID of the event that the user is subscribed to.
+    pub guild_scheduled_event_id: ScheduledEventId,
+    /// Guild member data of the user.
+    ///
+    /// This is only present when requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<PartialMember>,
+    pub user: User,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        EntityMetadata, EntityType, GuildId, GuildScheduledEvent, PrivacyLevel, ScheduledEventId,
+        Status,
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn test_guild_scheduled_event() {
+        let value = GuildScheduledEvent {
+            channel_id: None,
+            creator: None,
+            creator_id: None,
+            description: Some("a description".to_owned()),
+            entity_id: None,
+            entity_metadata: Some(EntityMetadata {
+                location: Some("the park".to_owned()),
+            }),
+            entity_type: EntityType::External,
+            guild_id: GuildId(1),
+            id: ScheduledEventId(2),
+            image: None,
+            name: "a meetup".to_owned(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
+            scheduled_end_time: Some("2021-10-22T20:00:00+00:00".to_owned()),
+            scheduled_start_time: "2021-10-22T18:00:00+00:00".to_owned(),
+            status: Status::Scheduled,
+            user_count: Some(3),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildScheduledEvent",
+                    len: 14,
+                },
+                Token::Str("channel_id"),
+                Token::None,
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("a description"),
+                Token::Str("entity_id"),
+                Token::None,
+                Token::Str("entity_metadata"),
+                Token::Some,
+                Token::Struct {
+                    name: "EntityMetadata",
+                    len: 1,
+                },
+                Token::Str("location"),
+                Token::Some,
+                Token::Str("the park"),
+                Token::StructEnd,
+                Token::Str("entity_type"),
+                Token::U8(3),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::Str("2"),
+                Token::Str("name"),
+                Token::Str("a meetup"),
+                Token::Str("privacy_level"),
+                Token::U8(2),
+                Token::Str("recurrence_rule"),
+                Token::None,
+                Token::Str("scheduled_end_time"),
+                Token::Some,
+                Token::Str("2021-10-22T20:00:00+00:00"),
+                Token::Str("scheduled_start_time"),
+                Token::Str("2021-10-22T18:00:00+00:00"),
+                Token::Str("status"),
+                Token::U8(1),
+                Token::Str("user_count"),
+                Token::Some,
+                Token::U64(3),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// How often a scheduled event repeats.
+///
+/// Refer to [the discord docs] for the combinations of fields that are
+/// allowed.
+///
+/// [the discord docs]: https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RecurrenceRule {
+    /// Specific days within a month that the event repeats on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_month_day: Option<Vec<u8>>,
+    /// Specific months that the event repeats in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_month: Option<Vec<Month>>,
+    /// Specific days within a specific week that the event repeats on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_n_weekday: Option<Vec<NWeekday>>,
+    /// Specific days of the week that the event repeats on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_weekday: Option<Vec<Weekday>>,
+    /// Specific days within a year that the event repeats on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_year_day: Option<Vec<u16>>,
+    /// Total number of times that the event is allowed to recur.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    /// When the recurrence ends.
+    pub end: Option<String>,
+    /// How often the event occurs.
+    pub frequency: Frequency,
+    /// Spacing between events, in units of the [`frequency`].
+    ///
+    /// [`frequency`]: Self::frequency
+    pub interval: u16,
+    /// When the recurrence starts.
+    pub start: String,
+}
+
+/// How often a scheduled event occurs.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum Frequency {
+    Yearly = 0,
+    Monthly = 1,
+    Weekly = 2,
+    Daily = 3,
+}
+
+/// Month of the year that a scheduled event repeats in.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum Month {
+    January = 1,
+    February = 2,
+    March = 3,
+    April = 4,
+    May = 5,
+    June = 6,
+    July = 7,
+    August = 8,
+    September = 9,
+    October = 10,
+    November = 11,
+    December = 12,
+}
+
+/// Day within a specific week of the month that a scheduled event repeats on.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct NWeekday {
+    /// Day of the week.
+    pub day: Weekday,
+    /// Week of the month, from 1 to 5.
+    pub n: u8,
+}
+
+/// Day of the week that a scheduled event repeats on.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum Weekday {
+    Monday = 0,
+    Tuesday = 1,
+    Wednesday = 2,
+    Thursday = 3,
+    Friday = 4,
+    Saturday = 5,
+    Sunday = 6,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frequency, Month, NWeekday, RecurrenceRule, Weekday};
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&Frequency::Yearly, &[Token::U8(0)]);
+        serde_test::assert_tokens(&Frequency::Monthly, &[Token::U8(1)]);
+        serde_test::assert_tokens(&Frequency::Weekly, &[Token::U8(2)]);
+        serde_test::assert_tokens(&Frequency::Daily, &[Token::U8(3)]);
+        serde_test::assert_tokens(&Month::January, &[Token::U8(1)]);
+        serde_test::assert_tokens(&Month::December, &[Token::U8(12)]);
+        serde_test::assert_tokens(&Weekday::Monday, &[Token::U8(0)]);
+        serde_test::assert_tokens(&Weekday::Sunday, &[Token::U8(6)]);
+    }
+
+    #[test]
+    fn test_recurrence_rule() {
+        let value = RecurrenceRule {
+            by_month_day: None,
+            by_month: None,
+            by_n_weekday: Some(vec![NWeekday {
+                day: Weekday::Friday,
+                n: 2,
+            }]),
+            by_weekday: None,
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: Frequency::Monthly,
+            interval: 1,
+            start: "2021-10-22T18:00:00+00:00".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "RecurrenceRule",
+                    len: 5,
+                },
+                Token::Str("by_n_weekday"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "NWeekday",
+                    len: 2,
+                },
+                Token::Str("day"),
+                Token::U8(4),
+                Token::Str("n"),
+                Token::U8(2),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("end"),
+                Token::None,
+                Token::Str("frequency"),
+                Token::U8(1),
+                Token::Str("interval"),
+                Token::U16(1),
+                Token::Str("start"),
+                Token::Str("2021-10-22T18:00:00+00:00"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Status of a scheduled event.
+///
+/// Events start out as [`Scheduled`], and may then become [`Active`] and then
+/// [`Completed`]. A scheduled event may instead be [`Cancelled`].
+///
+/// [`Active`]: Self::Active
+/// [`Cancelled`]: Self::Cancelled
+/// [`Completed`]: Self::Completed
+/// [`Scheduled`]: Self::Scheduled
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum Status {
+    /// Event has yet to start.
+    Scheduled = 1,
+    /// Event is in progress.
+    Active = 2,
+    /// Event has ended.
+    Completed = 3,
+    /// Event was cancelled before it started.
+    Cancelled = 4,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Status;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&Status::Scheduled, &[Token::U8(1)]);
+        serde_test::assert_tokens(&Status::Active, &[Token::U8(2)]);
+        serde_test::assert_tokens(&Status::Completed, &[Token::U8(3)]);
+        serde_test::assert_tokens(&Status::Cancelled, &[Token::U8(4)]);
+    }
+}
@@ -23,14 +23,35 @@ pub struct Invite {
     pub approximate_presence_count: Option<u64>,
     pub channel: InviteChannel,
     pub code: String,
+    /// When the invite was created.
+    ///
+    /// This and the other metadata of the invite, such as [`uses`], are only
+    /// present when getting the invites of a channel or guild.
+    ///
+    /// [`uses`]: Self::uses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild: Option<InviteGuild>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inviter: Option<User>,
+    /// Number of seconds after which the invite expires, or 0 if it never
+    /// expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age: Option<u64>,
+    /// Number of times the invite can be used, or 0 if it's unlimited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_uses: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_user_type: Option<TargetUserType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_user: Option<User>,
+    /// Whether the invite only grants temporary membership.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporary: Option<bool>,
+    /// Number of times the invite has been used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uses: Option<u64>,
 }
 
 #[cfg(test)]
@@ -57,10 +78,15 @@ mod tests {
                 name: None,
             },
             code: "uniquecode".to_owned(),
+            created_at: Some("2021-01-01T00:00:00+00:00".to_owned()),
             guild: None,
             inviter: None,
+            max_age: Some(86400),
+            max_uses: Some(10),
             target_user_type: Some(TargetUserType::Stream),
             target_user: None,
+            temporary: Some(false),
+            uses: Some(3),
         };
 
         serde_test::assert_tokens(
@@ -68,7 +94,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Invite",
-                    len: 5,
+                    len: 10,
                 },
                 Token::Str("approximate_member_count"),
                 Token::Some,
@@ -89,9 +115,24 @@ mod tests {
                 Token::StructEnd,
                 Token::Str("code"),
                 Token::Str("uniquecode"),
+                Token::Str("created_at"),
+                Token::Some,
+                Token::Str("2021-01-01T00:00:00+00:00"),
+                Token::Str("max_age"),
+                Token::Some,
+                Token::U64(86400),
+                Token::Str("max_uses"),
+                Token::Some,
+                Token::U64(10),
                 Token::Str("target_user_type"),
                 Token::Some,
                 Token::U8(1),
+                Token::Str("temporary"),
+                Token::Some,
+                Token::Bool(false),
+                Token::Str("uses"),
+                Token::Some,
+                Token::U64(3),
                 Token::StructEnd,
             ],
         );
@@ -109,6 +150,7 @@ mod tests {
                 name: None,
             },
             code: "uniquecode".to_owned(),
+            created_at: None,
             guild: Some(InviteGuild {
                 banner: Some("banner hash".to_owned()),
                 description: Some("a description".to_owned()),
@@ -152,6 +194,8 @@ mod tests {
                 system: None,
                 verified: None,
             }),
+            max_age: None,
+            max_uses: None,
             target_user_type: Some(TargetUserType::Stream),
             target_user: Some(User {
                 avatar: None,
@@ -168,6 +212,8 @@ mod tests {
                 system: None,
                 verified: None,
             }),
+            temporary: None,
+            uses: None,
         };
 
         serde_test::assert_tokens(
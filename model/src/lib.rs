@@ -53,5 +53,6 @@ pub mod guild;
 pub mod id;
 pub mod invite;
 pub mod oauth;
+pub mod template;
 pub mod user;
 pub mod voice;
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+/// Access token granted to an application through OAuth2.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/topics/oauth2#authorization-code-grant-access-token-response
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AccessTokenResponse {
+    /// Token to authenticate requests on behalf of the user with.
+    pub access_token: String,
+    /// Number of seconds until the access token expires.
+    pub expires_in: u64,
+    /// Token to get a new access token with once it expires.
+    ///
+    /// This isn't present for tokens granted through the client credentials
+    /// flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// Space-separated list of the scopes that were granted.
+    pub scope: String,
+    /// Type of the token, which is always `Bearer`.
+    pub token_type: String,
+}
+
+impl AccessTokenResponse {
+    /// Return an iterator over the names of the scopes that were granted.
+    ///
+    /// The names can be compared to [`Scope::name`].
+    ///
+    /// [`Scope::name`]: super::Scope::name
+    pub fn scopes(&self) -> impl Iterator<Item = &str> {
+        self.scope.split_whitespace()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AccessTokenResponse;
+    use serde_test::Token;
+
+    #[test]
+    fn test_access_token_response() {
+        let value = AccessTokenResponse {
+            access_token: "6qrZcUqja7812RVdnEKjpzOL4CvHBFG".to_owned(),
+            expires_in: 604_800,
+            refresh_token: Some("D43f5y0ahjqew82jZ4NViEr2YafMKhue".to_owned()),
+            scope: "identify guilds".to_owned(),
+            token_type: "Bearer".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AccessTokenResponse",
+                    len: 5,
+                },
+                Token::Str("access_token"),
+                Token::Str("6qrZcUqja7812RVdnEKjpzOL4CvHBFG"),
+                Token::Str("expires_in"),
+                Token::U64(604_800),
+                Token::Str("refresh_token"),
+                Token::Some,
+                Token::Str("D43f5y0ahjqew82jZ4NViEr2YafMKhue"),
+                Token::Str("scope"),
+                Token::Str("identify guilds"),
+                Token::Str("token_type"),
+                Token::Str("Bearer"),
+                Token::StructEnd,
+            ],
+        );
+
+        assert_eq!(
+            vec!["identify", "guilds"],
+            value.scopes().collect::<Vec<_>>()
+        );
+    }
+}
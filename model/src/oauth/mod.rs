@@ -1,6 +1,11 @@
 pub mod id;
 pub mod team;
 
+mod access_token_response;
 mod current_application_info;
+mod scope;
 
-pub use self::current_application_info::CurrentApplicationInfo;
+pub use self::{
+    access_token_response::AccessTokenResponse, current_application_info::CurrentApplicationInfo,
+    scope::Scope,
+};
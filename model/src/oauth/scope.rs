@@ -0,0 +1,331 @@
+use serde::{Deserialize, Serialize};
+
+/// Scope of the access granted to an application by a user through OAuth2.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/topics/oauth2#shared-resources-oauth2-scopes
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+pub enum Scope {
+    /// Fetch the activities of the user's friends.
+    #[serde(rename = "activities.read")]
+    ActivitiesRead,
+    /// Update the activity of the user, for applications using the Game SDK.
+    #[serde(rename = "activities.write")]
+    ActivitiesWrite,
+    /// Read the build data of the user's applications.
+    #[serde(rename = "applications.builds.read")]
+    ApplicationsBuildsRead,
+    /// Upload and update builds for the user's applications.
+    #[serde(rename = "applications.builds.upload")]
+    ApplicationsBuildsUpload,
+    /// Use slash commands in a guild.
+    #[serde(rename = "applications.commands")]
+    ApplicationsCommands,
+    /// Update the application's commands, only in the client credentials flow.
+    #[serde(rename = "applications.commands.update")]
+    ApplicationsCommandsUpdate,
+    /// Read the entitlements of the user's applications.
+    #[serde(rename = "applications.entitlements")]
+    ApplicationsEntitlements,
+    /// Read and update the store data of the user's applications.
+    #[serde(rename = "applications.store.update")]
+    ApplicationsStoreUpdate,
+    /// Add a bot to a guild.
+    #[serde(rename = "bot")]
+    Bot,
+    /// Read the third-party accounts connected to the user.
+    #[serde(rename = "connections")]
+    Connections,
+    /// Read the email of the user.
+    #[serde(rename = "email")]
+    Email,
+    /// Join the user to a group DM.
+    #[serde(rename = "gdm.join")]
+    GroupDmJoin,
+    /// Read the guilds that the user is in.
+    #[serde(rename = "guilds")]
+    Guilds,
+    /// Join the user to a guild.
+    #[serde(rename = "guilds.join")]
+    GuildsJoin,
+    /// Read the user, without their email.
+    #[serde(rename = "identify")]
+    Identify,
+    /// Read messages from the channels of the user's local RPC client.
+    #[serde(rename = "messages.read")]
+    MessagesRead,
+    /// Read the friends of the user.
+    #[serde(rename = "relationships.read")]
+    RelationshipsRead,
+    /// Control the user's local Discord client over RPC.
+    #[serde(rename = "rpc")]
+    Rpc,
+    /// Update the activity of the user over RPC.
+    #[serde(rename = "rpc.activities.write")]
+    RpcActivitiesWrite,
+    /// Receive notifications sent to the user over RPC.
+    #[serde(rename = "rpc.notifications.read")]
+    RpcNotificationsRead,
+    /// Read the voice settings of the user over RPC.
+    #[serde(rename = "rpc.voice.read")]
+    RpcVoiceRead,
+    /// Update the voice settings of the user over RPC.
+    #[serde(rename = "rpc.voice.write")]
+    RpcVoiceWrite,
+    /// Create a webhook in a channel chosen by the user.
+    #[serde(rename = "webhook.incoming")]
+    WebhookIncoming,
+}
+
+impl Scope {
+    /// Return the name of the scope, as used in authorization URLs and
+    /// token requests.
+    ///
+    /// ```rust
+    /// use twilight_model::oauth::Scope;
+    ///
+    /// assert_eq!("guilds.join", Scope::GuildsJoin.name());
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ActivitiesRead => "activities.read",
+            Self::ActivitiesWrite => "activities.write",
+            Self::ApplicationsBuildsRead => "applications.builds.read",
+            Self::ApplicationsBuildsUpload => "applications.builds.upload",
+            Self::ApplicationsCommands => "applications.commands",
+            Self::ApplicationsCommandsUpdate => "applications.commands.update",
+            Self::ApplicationsEntitlements => "applications.entitlements",
+            Self::ApplicationsStoreUpdate => "applications.store.update",
+            Self::Bot => "bot",
+            Self::Connections => "connections",
+            Self::Email => "email",
+            Self::GroupDmJoin => "gdm.join",
+            Self::Guilds => "guilds",
+            Self::GuildsJoin => "guilds.join",
+            Self::Identify => "identify",
+            Self::MessagesRead => "messages.read",
+            Self::RelationshipsRead => "relationships.read",
+            Self::Rpc => "rpc",
+            Self::RpcActivitiesWrite => "rpc.activities.write",
+            Self::RpcNotificationsRead => "rpc.notifications.read",
+            Self::RpcVoiceRead => "rpc.voice.read",
+            Self::RpcVoiceWrite => "rpc.voice.write",
+            Self::WebhookIncoming => "webhook.incoming",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scope;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(
+            &Scope::ActivitiesRead,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "activities.read",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::ActivitiesWrite,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "activities.write",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::ApplicationsBuildsRead,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "applications.builds.read",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::ApplicationsBuildsUpload,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "applications.builds.upload",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::ApplicationsCommands,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "applications.commands",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::ApplicationsCommandsUpdate,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "applications.commands.update",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::ApplicationsEntitlements,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "applications.entitlements",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::ApplicationsStoreUpdate,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "applications.store.update",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::Bot,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "bot",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::Connections,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "connections",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::Email,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "email",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::GroupDmJoin,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "gdm.join",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::Guilds,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "guilds",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::GuildsJoin,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "guilds.join",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::Identify,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "identify",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::MessagesRead,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "messages.read",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::RelationshipsRead,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "relationships.read",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::Rpc,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "rpc",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::RpcActivitiesWrite,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "rpc.activities.write",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::RpcNotificationsRead,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "rpc.notifications.read",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::RpcVoiceRead,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "rpc.voice.read",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::RpcVoiceWrite,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "rpc.voice.write",
+            }],
+        );
+        serde_test::assert_tokens(
+            &Scope::WebhookIncoming,
+            &[Token::UnitVariant {
+                name: "Scope",
+                variant: "webhook.incoming",
+            }],
+        );
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!("activities.read", Scope::ActivitiesRead.name());
+        assert_eq!("activities.write", Scope::ActivitiesWrite.name());
+        assert_eq!(
+            "applications.builds.read",
+            Scope::ApplicationsBuildsRead.name()
+        );
+        assert_eq!(
+            "applications.builds.upload",
+            Scope::ApplicationsBuildsUpload.name()
+        );
+        assert_eq!("applications.commands", Scope::ApplicationsCommands.name());
+        assert_eq!(
+            "applications.commands.update",
+            Scope::ApplicationsCommandsUpdate.name()
+        );
+        assert_eq!(
+            "applications.entitlements",
+            Scope::ApplicationsEntitlements.name()
+        );
+        assert_eq!(
+            "applications.store.update",
+            Scope::ApplicationsStoreUpdate.name()
+        );
+        assert_eq!("bot", Scope::Bot.name());
+        assert_eq!("connections", Scope::Connections.name());
+        assert_eq!("email", Scope::Email.name());
+        assert_eq!("gdm.join", Scope::GroupDmJoin.name());
+        assert_eq!("guilds", Scope::Guilds.name());
+        assert_eq!("guilds.join", Scope::GuildsJoin.name());
+        assert_eq!("identify", Scope::Identify.name());
+        assert_eq!("messages.read", Scope::MessagesRead.name());
+        assert_eq!("relationships.read", Scope::RelationshipsRead.name());
+        assert_eq!("rpc", Scope::Rpc.name());
+        assert_eq!("rpc.activities.write", Scope::RpcActivitiesWrite.name());
+        assert_eq!("rpc.notifications.read", Scope::RpcNotificationsRead.name());
+        assert_eq!("rpc.voice.read", Scope::RpcVoiceRead.name());
+        assert_eq!("rpc.voice.write", Scope::RpcVoiceWrite.name());
+        assert_eq!("webhook.incoming", Scope::WebhookIncoming.name());
+    }
+}
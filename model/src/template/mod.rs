@@ -0,0 +1,199 @@
+mod guild;
+mod role;
+
+pub use self::{guild::TemplateGuild, role::TemplateRole};
+
+use crate::{
+    id::{GuildId, UserId},
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+
+/// Template of a guild's channels, roles and settings, which new guilds can
+/// be created from.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Template {
+    pub code: String,
+    pub created_at: String,
+    pub creator: User,
+    pub creator_id: UserId,
+    pub description: Option<String>,
+    /// Whether the guild has changed since the template was last synced.
+    pub is_dirty: Option<bool>,
+    pub name: String,
+    pub serialized_source_guild: TemplateGuild,
+    pub source_guild_id: GuildId,
+    pub updated_at: String,
+    pub usage_count: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Template, TemplateGuild, TemplateRole};
+    use crate::{
+        channel::{
+            permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+            CategoryChannel, ChannelType, GuildChannel, TextChannel,
+        },
+        guild::{
+            DefaultMessageNotificationLevel, ExplicitContentFilter, Permissions,
+            SystemChannelFlags, VerificationLevel,
+        },
+        id::{ChannelId, GuildId, RoleId, UserId},
+        user::User,
+    };
+
+    #[test]
+    fn test_template() {
+        const TEMPLATE: &str = r#"{
+            "code": "hgM48av5Q69A",
+            "name": "Friends & Family",
+            "description": null,
+            "usage_count": 49605,
+            "creator_id": "132837293881950208",
+            "creator": {
+                "id": "132837293881950208",
+                "username": "hoges",
+                "avatar": null,
+                "discriminator": "0001",
+                "public_flags": 0
+            },
+            "created_at": "2020-04-02T21:10:38+00:00",
+            "updated_at": "2020-05-01T17:57:38+00:00",
+            "source_guild_id": "678070694164299796",
+            "serialized_source_guild": {
+                "name": "Friends & Family",
+                "description": null,
+                "region": "us-west",
+                "verification_level": 0,
+                "default_message_notifications": 0,
+                "explicit_content_filter": 0,
+                "preferred_locale": "en-US",
+                "afk_timeout": 300,
+                "roles": [{
+                    "id": 0,
+                    "name": "@everyone",
+                    "permissions": "104324689",
+                    "color": 0,
+                    "hoist": false,
+                    "mentionable": false
+                }],
+                "channels": [{
+                    "name": "Text Channels",
+                    "position": 1,
+                    "topic": null,
+                    "bitrate": 64000,
+                    "user_limit": 0,
+                    "nsfw": false,
+                    "rate_limit_per_user": 0,
+                    "parent_id": null,
+                    "permission_overwrites": [],
+                    "id": 1,
+                    "type": 4
+                }, {
+                    "name": "general",
+                    "position": 1,
+                    "topic": null,
+                    "bitrate": 64000,
+                    "user_limit": 0,
+                    "nsfw": false,
+                    "rate_limit_per_user": 0,
+                    "parent_id": 1,
+                    "permission_overwrites": [{
+                        "id": 0,
+                        "type": 0,
+                        "allow": "0",
+                        "deny": "2048"
+                    }],
+                    "id": 2,
+                    "type": 0
+                }],
+                "afk_channel_id": null,
+                "system_channel_id": 2,
+                "system_channel_flags": 0,
+                "icon_hash": null
+            },
+            "is_dirty": null
+        }"#;
+
+        let expected = Template {
+            code: "hgM48av5Q69A".to_owned(),
+            created_at: "2020-04-02T21:10:38+00:00".to_owned(),
+            creator: User {
+                avatar: None,
+                bot: false,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id: UserId(132_837_293_881_950_208),
+                locale: None,
+                mfa_enabled: None,
+                name: "hoges".to_owned(),
+                premium_type: None,
+                public_flags: Some(crate::user::UserFlags::empty()),
+                system: None,
+                verified: None,
+            },
+            creator_id: UserId(132_837_293_881_950_208),
+            description: None,
+            is_dirty: None,
+            name: "Friends & Family".to_owned(),
+            serialized_source_guild: TemplateGuild {
+                afk_channel_id: None,
+                afk_timeout: 300,
+                channels: vec![
+                    GuildChannel::Category(CategoryChannel {
+                        guild_id: None,
+                        id: ChannelId(1),
+                        kind: ChannelType::GuildCategory,
+                        name: "Text Channels".to_owned(),
+                        permission_overwrites: Vec::new(),
+                        position: 1,
+                    }),
+                    GuildChannel::Text(TextChannel {
+                        guild_id: None,
+                        id: ChannelId(2),
+                        kind: ChannelType::GuildText,
+                        last_message_id: None,
+                        last_pin_timestamp: None,
+                        name: "general".to_owned(),
+                        nsfw: false,
+                        permission_overwrites: vec![PermissionOverwrite {
+                            allow: Permissions::empty(),
+                            deny: Permissions::SEND_MESSAGES,
+                            kind: PermissionOverwriteType::Role(RoleId(0)),
+                        }],
+                        parent_id: Some(ChannelId(1)),
+                        position: 1,
+                        rate_limit_per_user: Some(0),
+                        topic: None,
+                    }),
+                ],
+                default_message_notifications: DefaultMessageNotificationLevel::All,
+                description: None,
+                explicit_content_filter: ExplicitContentFilter::None,
+                icon_hash: None,
+                name: "Friends & Family".to_owned(),
+                preferred_locale: "en-US".to_owned(),
+                region: "us-west".to_owned(),
+                roles: vec![TemplateRole {
+                    color: 0,
+                    hoist: false,
+                    id: RoleId(0),
+                    mentionable: false,
+                    name: "@everyone".to_owned(),
+                    permissions: Permissions::from_bits_truncate(104_324_689),
+                }],
+                system_channel_flags: SystemChannelFlags::empty(),
+                system_channel_id: Some(ChannelId(2)),
+                verification_level: VerificationLevel::None,
+            },
+            source_guild_id: GuildId(678_070_694_164_299_796),
+            updated_at: "2020-05-01T17:57:38+00:00".to_owned(),
+            usage_count: 49605,
+        };
+
+        let template = serde_json::from_str::<Template>(TEMPLATE).unwrap();
+        assert_eq!(expected, template);
+    }
+}
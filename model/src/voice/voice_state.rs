@@ -18,6 +18,9 @@ pub struct VoiceState {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub member: Option<Member>,
     pub mute: bool,
+    /// When the user requested to speak in a stage channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_to_speak_timestamp: Option<String>,
     pub self_deaf: bool,
     pub self_mute: bool,
     /// Whether this user is streaming via "Go Live".
@@ -38,6 +41,7 @@ enum Field {
     GuildId,
     Member,
     Mute,
+    RequestToSpeakTimestamp,
     SelfDeaf,
     SelfMute,
     SelfStream,
@@ -63,6 +67,7 @@ impl<'de> Visitor<'de> for VoiceStateVisitor {
         let mut guild_id = None;
         let mut member = None;
         let mut mute = None;
+        let mut request_to_speak_timestamp = None;
         let mut self_deaf = None;
         let mut self_mute = None;
         let mut self_stream = None;
@@ -133,6 +138,13 @@ impl<'de> Visitor<'de> for VoiceStateVisitor {
 
                     mute = Some(map.next_value()?);
                 }
+                Field::RequestToSpeakTimestamp => {
+                    if request_to_speak_timestamp.is_some() {
+                        return Err(DeError::duplicate_field("request_to_speak_timestamp"));
+                    }
+
+                    request_to_speak_timestamp = map.next_value()?;
+                }
                 Field::SelfDeaf => {
                     if self_deaf.is_some() {
                         return Err(DeError::duplicate_field("self_deaf"));
@@ -218,6 +230,7 @@ impl<'de> Visitor<'de> for VoiceStateVisitor {
             guild_id,
             member,
             mute,
+            request_to_speak_timestamp,
             self_deaf,
             self_mute,
             self_stream,
@@ -237,6 +250,7 @@ impl<'de> Deserialize<'de> for VoiceState {
             "guild_id",
             "member",
             "mute",
+            "request_to_speak_timestamp",
             "self_deaf",
             "self_mute",
             "self_stream",
@@ -264,6 +278,7 @@ mod tests {
             guild_id: Some(GuildId(2)),
             member: None,
             mute: true,
+            request_to_speak_timestamp: None,
             self_deaf: false,
             self_mute: true,
             self_stream: false,
@@ -344,6 +359,7 @@ mod tests {
                 },
             }),
             mute: true,
+            request_to_speak_timestamp: Some("2021-04-21T22:16:50.000000+00:00".to_owned()),
             self_deaf: false,
             self_mute: true,
             self_stream: false,
@@ -358,7 +374,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "VoiceState",
-                    len: 12,
+                    len: 13,
                 },
                 Token::Str("channel_id"),
                 Token::Some,
@@ -421,6 +437,9 @@ mod tests {
                 Token::StructEnd,
                 Token::Str("mute"),
                 Token::Bool(true),
+                Token::Str("request_to_speak_timestamp"),
+                Token::Some,
+                Token::Str("2021-04-21T22:16:50.000000+00:00"),
                 Token::Str("self_deaf"),
                 Token::Bool(false),
                 Token::Str("self_mute"),
@@ -808,6 +808,11 @@ fn event_guild_id(event: &Event) -> Option<GuildId> {
         Event::GuildDelete(e) => Some(e.id),
         Event::GuildEmojisUpdate(e) => Some(e.guild_id),
         Event::GuildIntegrationsUpdate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventCreate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventDelete(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUpdate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUserAdd(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUserRemove(e) => Some(e.guild_id),
         Event::GuildUpdate(e) => Some(e.id),
         Event::InviteCreate(e) => Some(e.guild_id),
         Event::InviteDelete(e) => Some(e.guild_id),
@@ -837,6 +842,16 @@ fn event_guild_id(event: &Event) -> Option<GuildId> {
         Event::ShardPayload(_) => None,
         Event::ShardReconnecting(_) => None,
         Event::ShardResuming(_) => None,
+        Event::ShardZombied(_) => None,
+        Event::StageInstanceCreate(e) => Some(e.guild_id),
+        Event::StageInstanceDelete(e) => Some(e.guild_id),
+        Event::StageInstanceUpdate(e) => Some(e.guild_id),
+        Event::ThreadCreate(e) => e.guild_id(),
+        Event::ThreadDelete(e) => Some(e.guild_id),
+        Event::ThreadListSync(e) => Some(e.guild_id),
+        Event::ThreadMemberUpdate(_) => None,
+        Event::ThreadMembersUpdate(e) => Some(e.guild_id),
+        Event::ThreadUpdate(e) => e.guild_id(),
         Event::TypingStart(e) => e.guild_id,
         Event::UnavailableGuild(e) => Some(e.id),
         Event::UserUpdate(_) => None,
@@ -896,6 +911,7 @@ mod tests {
                 verified: None,
             },
             channel_id: ChannelId(1),
+            components: Vec::new(),
             content: "test".to_owned(),
             edited_timestamp: None,
             embeds: Vec::new(),
@@ -85,6 +85,11 @@
 //! considered core crates due to being vendor-specific or non-essential for
 //! most users.
 //!
+//! ### [`twilight-component-builder`]
+//!
+//! Utility crate for creating and validating message components, such as
+//! buttons and select menus, to be used when creating or updating messages.
+//!
 //! ### [`twilight-embed-builder`]
 //!
 //! Utility crate for creating and validating message embeds, to be used when
@@ -222,6 +227,7 @@
 //! [`tracing-log`]: https://github.com/tokio-rs/tracing/tree/master/tracing-log
 //! [`twilight-cache-inmemory`]: https://twilight.rs/chapter_1_crates/section_4_cache_inmemory.html
 //! [`twilight-command-parser`]: https://twilight.rs/chapter_1_crates/section_5_command_parser.html
+//! [`twilight-component-builder`]: https://docs.rs/twilight-component-builder
 //! [`twilight-embed-builder`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_1_embed_builder.html
 //! [`twilight-gateway-queue`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_5_gateway_queue.html
 //! [`twilight-gateway`]: https://twilight.rs/chapter_1_crates/section_3_gateway.html